@@ -0,0 +1,82 @@
+//! Every filter at 1MP, 12MP and 40MP
+//!
+//! Run natively: `cargo bench --target x86_64-unknown-linux-gnu --bench filters`
+//! Narrow down with a filter, e.g. `-- blur/12MP`
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use pixlab_wasm::filters::{blur, brightness, crop, flip, grayscale, rotate};
+use std::hint::black_box;
+
+/// (label, width, height): 1MP, 12MP (4:3 phone camera), 40MP (4:3 full frame)
+const SIZES: [(&str, u32, u32); 3] = [
+    ("1MP", 1000, 1000),
+    ("12MP", 4000, 3000),
+    ("40MP", 7300, 5475),
+];
+
+fn image(width: u32, height: u32) -> Vec<u8> {
+    (0..width * height * 4).map(|i| (i * 31 % 251) as u8).collect()
+}
+
+/// Benchmark a copying filter (`&[u8]` in, new buffer out)
+fn bench<F>(c: &mut Criterion, name: &str, f: F)
+where
+    F: Fn(&[u8], u32, u32) -> Result<Vec<u8>, String>,
+{
+    let mut group = c.benchmark_group(name);
+    group.sample_size(10);
+
+    for (label, width, height) in SIZES {
+        let data = image(width, height);
+        group.throughput(Throughput::Bytes(data.len() as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(label), &data, |b, data| {
+            b.iter(|| f(black_box(data), width, height).unwrap())
+        });
+    }
+
+    group.finish();
+}
+
+/// Benchmark an in-place filter; applying it repeatedly to one buffer is fine
+/// because the cost does not depend on pixel values
+fn bench_in_place<F>(c: &mut Criterion, name: &str, f: F)
+where
+    F: Fn(&mut [u8], u32, u32) -> Result<(), String>,
+{
+    let mut group = c.benchmark_group(name);
+    group.sample_size(10);
+
+    for (label, width, height) in SIZES {
+        let mut data = image(width, height);
+        group.throughput(Throughput::Bytes(data.len() as u64));
+        group.bench_function(BenchmarkId::from_parameter(label), |b| {
+            b.iter(|| f(black_box(&mut data), width, height).unwrap())
+        });
+    }
+
+    group.finish();
+}
+
+fn color(c: &mut Criterion) {
+    bench(c, "grayscale", grayscale::apply);
+    bench(c, "brightness", |d, w, h| brightness::apply(d, w, h, 40.0));
+    bench(c, "blur/radius_5", |d, w, h| blur::apply(d, w, h, 5.0));
+    bench(c, "blur/radius_50", |d, w, h| blur::apply(d, w, h, 50.0));
+}
+
+fn geometry(c: &mut Criterion) {
+    bench(c, "flip_horizontal", flip::apply_horizontal);
+    bench(c, "flip_vertical", flip::apply_vertical);
+    bench(c, "rotate_90_cw", rotate::rotate_90_cw);
+    bench(c, "rotate_180", rotate::rotate_180);
+    bench(c, "rotate_270_cw", rotate::rotate_270_cw);
+    // Centered crop keeping half of each dimension
+    bench(c, "crop", |d, w, h| crop::apply(d, w, h, w / 4, h / 4, w / 2, h / 2));
+
+    bench_in_place(c, "flip_horizontal_in_place", flip::apply_horizontal_in_place);
+    bench_in_place(c, "flip_vertical_in_place", flip::apply_vertical_in_place);
+    bench_in_place(c, "rotate_180_in_place", rotate::rotate_180_in_place);
+}
+
+criterion_group!(benches, color, geometry);
+criterion_main!(benches);
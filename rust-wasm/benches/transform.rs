@@ -0,0 +1,110 @@
+//! Geometric transforms vs. the per-pixel loops they replaced
+//!
+//! Run natively: `cargo bench --target x86_64-unknown-linux-gnu --bench transform`
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use pixlab_wasm::filters::{flip, rotate};
+use std::hint::black_box;
+
+/// (label, width, height)
+const SIZES: [(&str, u32, u32); 2] = [("1MP", 1000, 1000), ("12MP", 4000, 3000)];
+
+fn image(width: u32, height: u32) -> Vec<u8> {
+    (0..width * height * 4).map(|i| (i * 31 % 251) as u8).collect()
+}
+
+/// Previous implementations: one 4-byte copy per pixel, column-strided writes
+mod per_pixel {
+    pub fn rotate_90_cw(data: &[u8], width: usize, height: usize) -> Vec<u8> {
+        let mut output = vec![0u8; data.len()];
+        for y in 0..height {
+            for x in 0..width {
+                let src_idx = (y * width + x) * 4;
+                let dst_idx = (x * height + (height - 1 - y)) * 4;
+                output[dst_idx..dst_idx + 4].copy_from_slice(&data[src_idx..src_idx + 4]);
+            }
+        }
+        output
+    }
+
+    pub fn rotate_270_cw(data: &[u8], width: usize, height: usize) -> Vec<u8> {
+        let mut output = vec![0u8; data.len()];
+        for y in 0..height {
+            for x in 0..width {
+                let src_idx = (y * width + x) * 4;
+                let dst_idx = ((width - 1 - x) * height + y) * 4;
+                output[dst_idx..dst_idx + 4].copy_from_slice(&data[src_idx..src_idx + 4]);
+            }
+        }
+        output
+    }
+
+    pub fn flip_horizontal(data: &[u8], width: usize, height: usize) -> Vec<u8> {
+        let mut output = vec![0u8; data.len()];
+        for y in 0..height {
+            for x in 0..width {
+                let src_idx = (y * width + x) * 4;
+                let dst_idx = (y * width + (width - 1 - x)) * 4;
+                output[dst_idx..dst_idx + 4].copy_from_slice(&data[src_idx..src_idx + 4]);
+            }
+        }
+        output
+    }
+
+    pub fn flip_vertical(data: &[u8], width: usize, height: usize) -> Vec<u8> {
+        let mut output = vec![0u8; data.len()];
+        for y in 0..height {
+            for x in 0..width {
+                let src_idx = (y * width + x) * 4;
+                let dst_idx = ((height - 1 - y) * width + x) * 4;
+                output[dst_idx..dst_idx + 4].copy_from_slice(&data[src_idx..src_idx + 4]);
+            }
+        }
+        output
+    }
+
+    pub fn rotate_180(data: &[u8], width: usize, height: usize) -> Vec<u8> {
+        let mut output = vec![0u8; data.len()];
+        for y in 0..height {
+            for x in 0..width {
+                let src_idx = (y * width + x) * 4;
+                let dst_idx = ((height - 1 - y) * width + (width - 1 - x)) * 4;
+                output[dst_idx..dst_idx + 4].copy_from_slice(&data[src_idx..src_idx + 4]);
+            }
+        }
+        output
+    }
+}
+
+type Current = fn(&[u8], u32, u32) -> Result<Vec<u8>, String>;
+type Previous = fn(&[u8], usize, usize) -> Vec<u8>;
+
+fn compare(c: &mut Criterion, name: &str, current: Current, previous: Previous) {
+    let mut group = c.benchmark_group(name);
+    group.sample_size(20);
+
+    for (label, width, height) in SIZES {
+        let data = image(width, height);
+        group.throughput(Throughput::Bytes(data.len() as u64));
+
+        group.bench_with_input(BenchmarkId::new("current", label), &data, |b, data| {
+            b.iter(|| current(black_box(data), width, height).unwrap())
+        });
+        group.bench_with_input(BenchmarkId::new("per_pixel", label), &data, |b, data| {
+            b.iter(|| previous(black_box(data), width as usize, height as usize))
+        });
+    }
+
+    group.finish();
+}
+
+fn transforms(c: &mut Criterion) {
+    compare(c, "rotate_90_cw", rotate::rotate_90_cw, per_pixel::rotate_90_cw);
+    compare(c, "rotate_270_cw", rotate::rotate_270_cw, per_pixel::rotate_270_cw);
+    compare(c, "rotate_180", rotate::rotate_180, per_pixel::rotate_180);
+    compare(c, "flip_horizontal", flip::apply_horizontal, per_pixel::flip_horizontal);
+    compare(c, "flip_vertical", flip::apply_vertical, per_pixel::flip_vertical);
+}
+
+criterion_group!(benches, transforms);
+criterion_main!(benches);
@@ -0,0 +1,8 @@
+#!/bin/bash
+# Multithreaded build (`parallel` feature, wasm-bindgen-rayon)
+# Requires nightly for the atomics-enabled std, and the page must be
+# cross-origin isolated (COOP/COEP headers) for SharedArrayBuffer.
+export PATH="$HOME/.cargo/bin:$PATH"
+export RUSTFLAGS="-C target-feature=+atomics,+bulk-memory,+mutable-globals,+simd128"
+rustup run nightly wasm-pack build --target web --release -- \
+  --features parallel -Z build-std=panic_abort,std
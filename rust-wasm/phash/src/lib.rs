@@ -0,0 +1,254 @@
+//! Perceptual hashes (aHash, dHash, pHash) for near-duplicate detection
+//!
+//! Shared by `pixlab-wasm` (as `analysis::phash`) and the wasmCloud
+//! image-metadata service, so hashes computed in the browser and on the server
+//! are comparable. It must stay free of dependencies.
+
+/// Hamming distance at or below which two pHashes are considered near-duplicates
+pub const NEAR_DUPLICATE_THRESHOLD: u32 = 10;
+
+/// Side length of the grayscale thumbnail used for the pHash DCT
+const PHASH_SIZE: usize = 32;
+
+/// Side length of the low-frequency DCT block kept for pHash
+const PHASH_LOW_FREQ: usize = 8;
+
+/// All three 64-bit perceptual hashes of an image
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Hashes {
+    pub ahash: u64,
+    pub dhash: u64,
+    pub phash: u64,
+}
+
+/// Compute aHash, dHash and pHash of RGBA image data
+pub fn compute(image_data: &[u8], width: u32, height: u32) -> Result<Hashes, String> {
+    // Validate input
+    // usize math: width * height * 4 wraps in u32 for large images
+    let expected_len = (width as usize).checked_mul(height as usize).and_then(|pixels| pixels.checked_mul(4));
+    if expected_len != Some(image_data.len()) {
+        return Err(format!(
+            "Invalid image data length: expected {}x{}x4, got {}",
+            width,
+            height,
+            image_data.len()
+        ));
+    }
+
+    if width == 0 || height == 0 {
+        return Err(format!("Image dimensions must be non-zero: {}x{}", width, height));
+    }
+
+    let width = width as usize;
+    let height = height as usize;
+
+    Ok(Hashes {
+        ahash: average_hash(image_data, width, height),
+        dhash: difference_hash(image_data, width, height),
+        phash: dct_hash(image_data, width, height),
+    })
+}
+
+/// Number of differing bits between two hashes
+pub fn hamming_distance(a: u64, b: u64) -> u32 {
+    (a ^ b).count_ones()
+}
+
+/// Format a hash as 16 lowercase hex digits
+pub fn to_hex(hash: u64) -> String {
+    format!("{:016x}", hash)
+}
+
+/// Parse a hash produced by [`to_hex`]
+pub fn from_hex(hex: &str) -> Result<u64, String> {
+    if hex.len() != 16 {
+        return Err(format!("Hash must be 16 hex digits, got {:?}", hex));
+    }
+    u64::from_str_radix(hex, 16).map_err(|e| format!("Invalid hash {:?}: {}", hex, e))
+}
+
+/// aHash: 8x8 thumbnail, bit set where the pixel is brighter than the mean
+fn average_hash(image_data: &[u8], width: usize, height: usize) -> u64 {
+    let thumb = downsample_luma(image_data, width, height, 8, 8);
+    let mean = thumb.iter().sum::<f64>() / thumb.len() as f64;
+    pack_bits(thumb.iter().map(|&v| v > mean))
+}
+
+/// dHash: 9x8 thumbnail, bit set where a pixel is brighter than its right neighbour
+fn difference_hash(image_data: &[u8], width: usize, height: usize) -> u64 {
+    let thumb = downsample_luma(image_data, width, height, 9, 8);
+    pack_bits((0..8).flat_map(|y| {
+        let row = &thumb[y * 9..(y + 1) * 9];
+        (0..8).map(move |x| row[x] > row[x + 1])
+    }))
+}
+
+/// pHash: 32x32 thumbnail, 2D DCT-II, bit set where a low-frequency
+/// coefficient is above the median of the 8x8 low-frequency block
+fn dct_hash(image_data: &[u8], width: usize, height: usize) -> u64 {
+    let thumb = downsample_luma(image_data, width, height, PHASH_SIZE, PHASH_SIZE);
+
+    // cos[k][n] = cos(pi / N * (n + 0.5) * k)
+    let mut cos = vec![0.0f64; PHASH_LOW_FREQ * PHASH_SIZE];
+    for k in 0..PHASH_LOW_FREQ {
+        for n in 0..PHASH_SIZE {
+            cos[k * PHASH_SIZE + n] =
+                (std::f64::consts::PI / PHASH_SIZE as f64 * (n as f64 + 0.5) * k as f64).cos();
+        }
+    }
+
+    // Row pass: only the low-frequency columns are needed
+    let mut rows = vec![0.0f64; PHASH_SIZE * PHASH_LOW_FREQ];
+    for y in 0..PHASH_SIZE {
+        for k in 0..PHASH_LOW_FREQ {
+            rows[y * PHASH_LOW_FREQ + k] = (0..PHASH_SIZE)
+                .map(|n| thumb[y * PHASH_SIZE + n] * cos[k * PHASH_SIZE + n])
+                .sum();
+        }
+    }
+
+    // Column pass over the low-frequency rows
+    let mut coeffs = vec![0.0f64; PHASH_LOW_FREQ * PHASH_LOW_FREQ];
+    for k in 0..PHASH_LOW_FREQ {
+        for u in 0..PHASH_LOW_FREQ {
+            coeffs[k * PHASH_LOW_FREQ + u] = (0..PHASH_SIZE)
+                .map(|n| rows[n * PHASH_LOW_FREQ + u] * cos[k * PHASH_SIZE + n])
+                .sum();
+        }
+    }
+
+    let mut sorted = coeffs.clone();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+    let median = (sorted[31] + sorted[32]) / 2.0;
+
+    pack_bits(coeffs.iter().map(|&c| c > median))
+}
+
+/// Box-filter RGBA data down to a `target_w` x `target_h` Rec.601 luma thumbnail
+///
+/// Each target cell averages the source pixels it covers; images smaller than
+/// the target repeat pixels so every cell samples at least one pixel.
+fn downsample_luma(
+    image_data: &[u8],
+    width: usize,
+    height: usize,
+    target_w: usize,
+    target_h: usize,
+) -> Vec<f64> {
+    let mut output = Vec::with_capacity(target_w * target_h);
+
+    for ty in 0..target_h {
+        let y0 = ty * height / target_h;
+        let y1 = ((ty + 1) * height / target_h).max(y0 + 1);
+
+        for tx in 0..target_w {
+            let x0 = tx * width / target_w;
+            let x1 = ((tx + 1) * width / target_w).max(x0 + 1);
+
+            let mut sum = 0u64;
+            for y in y0..y1 {
+                for x in x0..x1 {
+                    let idx = (y * width + x) * 4;
+                    let (r, g, b) = (
+                        image_data[idx] as u64,
+                        image_data[idx + 1] as u64,
+                        image_data[idx + 2] as u64,
+                    );
+                    // Integer Rec.601 weights (x1000) keep the result platform independent
+                    sum += 299 * r + 587 * g + 114 * b;
+                }
+            }
+
+            let count = ((y1 - y0) * (x1 - x0)) as f64;
+            output.push(sum as f64 / (count * 1000.0));
+        }
+    }
+
+    output
+}
+
+/// Pack 64 booleans into a u64, first bit is the most significant
+fn pack_bits(bits: impl Iterator<Item = bool>) -> u64 {
+    bits.fold(0u64, |hash, bit| (hash << 1) | bit as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Horizontal gradient with a bright square, enough structure for all hashes
+    fn test_image(width: u32, height: u32) -> Vec<u8> {
+        let mut data = Vec::with_capacity((width * height * 4) as usize);
+        for y in 0..height {
+            for x in 0..width {
+                let in_square = x > width / 4 && x < width / 2 && y > height / 3 && y < height * 2 / 3;
+                let v = if in_square { 255 } else { (x * 200 / width) as u8 };
+                data.extend_from_slice(&[v, v / 2, 255 - v, 255]);
+            }
+        }
+        data
+    }
+
+    #[test]
+    fn test_identical_images_have_identical_hashes() {
+        let data = test_image(64, 48);
+        let a = compute(&data, 64, 48).unwrap();
+        let b = compute(&data, 64, 48).unwrap();
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn test_resized_image_is_near_duplicate() {
+        let small = compute(&test_image(64, 48), 64, 48).unwrap();
+        let large = compute(&test_image(256, 192), 256, 192).unwrap();
+        assert!(hamming_distance(small.phash, large.phash) <= NEAR_DUPLICATE_THRESHOLD);
+        assert!(hamming_distance(small.dhash, large.dhash) <= NEAR_DUPLICATE_THRESHOLD);
+    }
+
+    #[test]
+    fn test_flipped_image_differs() {
+        let data = test_image(64, 48);
+        let flipped: Vec<u8> = data.chunks_exact(64 * 4).flat_map(|row| row.chunks_exact(4).rev().flatten().copied()).collect();
+        let a = compute(&data, 64, 48).unwrap();
+        let b = compute(&flipped, 64, 48).unwrap();
+        assert!(hamming_distance(a.phash, b.phash) > NEAR_DUPLICATE_THRESHOLD);
+    }
+
+    #[test]
+    fn test_hamming_distance() {
+        assert_eq!(hamming_distance(0, 0), 0);
+        assert_eq!(hamming_distance(0, u64::MAX), 64);
+        assert_eq!(hamming_distance(0b1010, 0b0110), 2);
+    }
+
+    #[test]
+    fn test_hex_round_trip() {
+        let hash = 0x00ff_1234_abcd_0001;
+        assert_eq!(to_hex(hash), "00ff1234abcd0001");
+        assert_eq!(from_hex(&to_hex(hash)).unwrap(), hash);
+        assert!(from_hex("xyz").is_err());
+    }
+
+    #[test]
+    fn test_tiny_image() {
+        // Smaller than every thumbnail size
+        let data = vec![255, 0, 0, 255];
+        assert!(compute(&data, 1, 1).is_ok());
+    }
+
+    #[test]
+    fn test_invalid_dimensions() {
+        let data = vec![255, 0, 0, 255];
+        assert!(compute(&data, 2, 2).is_err());
+        assert!(compute(&[], 0, 0).is_err());
+    }
+
+    #[test]
+    fn test_oversized_dimensions() {
+        // 65536 x 65536 x 4 wraps to 0 in u32 math, which matched the empty buffer
+        for (width, height) in [(65536, 65536), (u32::MAX, u32::MAX)] {
+            let err = compute(&[], width, height).unwrap_err();
+            assert!(err.starts_with("Invalid image data length"), "{}", err);
+        }
+    }
+}
@@ -1,4942 +1,3111 @@
-let wasm;
+/* @ts-self-types="./pixlab_wasm.d.ts" */
 
-let heap = new Array(128).fill(undefined);
-
-heap.push(undefined, null, true, false);
-
-function getObject(idx) { return heap[idx]; }
-
-let heap_next = heap.length;
-
-function addHeapObject(obj) {
-    if (heap_next === heap.length) heap.push(heap.length + 1);
-    const idx = heap_next;
-    heap_next = heap[idx];
-
-    heap[idx] = obj;
-    return idx;
-}
-
-function handleError(f, args) {
-    try {
-        return f.apply(this, args);
-    } catch (e) {
-        wasm.__wbindgen_export_0(addHeapObject(e));
+/**
+ * Thrown when a call is stopped through its `cancel` argument
+ * Check with `error instanceof Cancelled`
+ */
+export class Cancelled {
+    static __wrap(ptr) {
+        const obj = Object.create(Cancelled.prototype);
+        obj.__wbg_ptr = ptr;
+        CancelledFinalization.register(obj, obj.__wbg_ptr, obj);
+        return obj;
     }
-}
-
-function isLikeNone(x) {
-    return x === undefined || x === null;
-}
-
-let cachedUint8ArrayMemory0 = null;
-
-function getUint8ArrayMemory0() {
-    if (cachedUint8ArrayMemory0 === null || cachedUint8ArrayMemory0.byteLength === 0) {
-        cachedUint8ArrayMemory0 = new Uint8Array(wasm.memory.buffer);
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        CancelledFinalization.unregister(this);
+        return ptr;
     }
-    return cachedUint8ArrayMemory0;
-}
-
-let cachedTextDecoder = new TextDecoder('utf-8', { ignoreBOM: true, fatal: true });
-
-cachedTextDecoder.decode();
-
-const MAX_SAFARI_DECODE_BYTES = 2146435072;
-let numBytesDecoded = 0;
-function decodeText(ptr, len) {
-    numBytesDecoded += len;
-    if (numBytesDecoded >= MAX_SAFARI_DECODE_BYTES) {
-        cachedTextDecoder = new TextDecoder('utf-8', { ignoreBOM: true, fatal: true });
-        cachedTextDecoder.decode();
-        numBytesDecoded = len;
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_cancelled_free(ptr, 0);
     }
-    return cachedTextDecoder.decode(getUint8ArrayMemory0().subarray(ptr, ptr + len));
-}
-
-function getStringFromWasm0(ptr, len) {
-    ptr = ptr >>> 0;
-    return decodeText(ptr, len);
-}
-
-let WASM_VECTOR_LEN = 0;
-
-function passArray8ToWasm0(arg, malloc) {
-    const ptr = malloc(arg.length * 1, 1) >>> 0;
-    getUint8ArrayMemory0().set(arg, ptr / 1);
-    WASM_VECTOR_LEN = arg.length;
-    return ptr;
-}
-
-let cachedDataViewMemory0 = null;
-
-function getDataViewMemory0() {
-    if (cachedDataViewMemory0 === null || cachedDataViewMemory0.buffer.detached === true || (cachedDataViewMemory0.buffer.detached === undefined && cachedDataViewMemory0.buffer !== wasm.memory.buffer)) {
-        cachedDataViewMemory0 = new DataView(wasm.memory.buffer);
+    /**
+     * Name of the exported function that was cancelled
+     * @returns {string}
+     */
+    get operation() {
+        let deferred1_0;
+        let deferred1_1;
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            wasm.__wbg_get_cancelled_operation(retptr, this.__wbg_ptr);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            deferred1_0 = r0;
+            deferred1_1 = r1;
+            return getStringFromWasm0(r0, r1);
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+            wasm.__wbindgen_export2(deferred1_0, deferred1_1, 1);
+        }
     }
-    return cachedDataViewMemory0;
-}
-
-let cachedUint8ClampedArrayMemory0 = null;
-
-function getUint8ClampedArrayMemory0() {
-    if (cachedUint8ClampedArrayMemory0 === null || cachedUint8ClampedArrayMemory0.byteLength === 0) {
-        cachedUint8ClampedArrayMemory0 = new Uint8ClampedArray(wasm.memory.buffer);
+    /**
+     * Name of the exported function that was cancelled
+     * @param {string} arg0
+     */
+    set operation(arg0) {
+        const ptr0 = passStringToWasm0(arg0, wasm.__wbindgen_export3, wasm.__wbindgen_export4);
+        const len0 = WASM_VECTOR_LEN;
+        wasm.__wbg_set_cancelled_operation(this.__wbg_ptr, ptr0, len0);
     }
-    return cachedUint8ClampedArrayMemory0;
-}
-
-function getClampedArrayU8FromWasm0(ptr, len) {
-    ptr = ptr >>> 0;
-    return getUint8ClampedArrayMemory0().subarray(ptr / 1, ptr / 1 + len);
-}
-
-function getArrayU8FromWasm0(ptr, len) {
-    ptr = ptr >>> 0;
-    return getUint8ArrayMemory0().subarray(ptr / 1, ptr / 1 + len);
 }
+if (Symbol.dispose) Cancelled.prototype[Symbol.dispose] = Cancelled.prototype.free;
 
-const cachedTextEncoder = new TextEncoder();
-
-if (!('encodeInto' in cachedTextEncoder)) {
-    cachedTextEncoder.encodeInto = function (arg, view) {
-        const buf = cachedTextEncoder.encode(arg);
-        view.set(buf);
-        return {
-            read: arg.length,
-            written: buf.length
-        };
+/**
+ * Hue, saturation and lightness bounds for `select_color_range`; selects everything by default
+ */
+export class ColorRange {
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        ColorRangeFinalization.unregister(this);
+        return ptr;
+    }
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_colorrange_free(ptr, 0);
+    }
+    constructor() {
+        const ret = wasm.colorrange_new();
+        this.__wbg_ptr = ret;
+        ColorRangeFinalization.register(this, this.__wbg_ptr, this);
+        return this;
+    }
+    /**
+     * Soft falloff outside the bounds, 0.0 (hard) to 1.0
+     * @returns {number}
+     */
+    get fuzziness() {
+        const ret = wasm.__wbg_get_colorrange_fuzziness(this.__wbg_ptr);
+        return ret;
+    }
+    /**
+     * Degrees either side of `hue`; 180 covers all hues
+     * @returns {number}
+     */
+    get hue_tolerance() {
+        const ret = wasm.__wbg_get_colorrange_hue_tolerance(this.__wbg_ptr);
+        return ret;
+    }
+    /**
+     * Center hue in degrees: 0 red, 120 green, 240 blue
+     * @returns {number}
+     */
+    get hue() {
+        const ret = wasm.__wbg_get_colorrange_hue(this.__wbg_ptr);
+        return ret;
+    }
+    /**
+     * @returns {number}
+     */
+    get max_lightness() {
+        const ret = wasm.__wbg_get_colorrange_max_lightness(this.__wbg_ptr);
+        return ret;
+    }
+    /**
+     * @returns {number}
+     */
+    get max_saturation() {
+        const ret = wasm.__wbg_get_colorrange_max_saturation(this.__wbg_ptr);
+        return ret;
+    }
+    /**
+     * @returns {number}
+     */
+    get min_lightness() {
+        const ret = wasm.__wbg_get_colorrange_min_lightness(this.__wbg_ptr);
+        return ret;
+    }
+    /**
+     * @returns {number}
+     */
+    get min_saturation() {
+        const ret = wasm.__wbg_get_colorrange_min_saturation(this.__wbg_ptr);
+        return ret;
+    }
+    /**
+     * Soft falloff outside the bounds, 0.0 (hard) to 1.0
+     * @param {number} arg0
+     */
+    set fuzziness(arg0) {
+        wasm.__wbg_set_colorrange_fuzziness(this.__wbg_ptr, arg0);
+    }
+    /**
+     * Degrees either side of `hue`; 180 covers all hues
+     * @param {number} arg0
+     */
+    set hue_tolerance(arg0) {
+        wasm.__wbg_set_colorrange_hue_tolerance(this.__wbg_ptr, arg0);
+    }
+    /**
+     * Center hue in degrees: 0 red, 120 green, 240 blue
+     * @param {number} arg0
+     */
+    set hue(arg0) {
+        wasm.__wbg_set_colorrange_hue(this.__wbg_ptr, arg0);
+    }
+    /**
+     * @param {number} arg0
+     */
+    set max_lightness(arg0) {
+        wasm.__wbg_set_colorrange_max_lightness(this.__wbg_ptr, arg0);
+    }
+    /**
+     * @param {number} arg0
+     */
+    set max_saturation(arg0) {
+        wasm.__wbg_set_colorrange_max_saturation(this.__wbg_ptr, arg0);
+    }
+    /**
+     * @param {number} arg0
+     */
+    set min_lightness(arg0) {
+        wasm.__wbg_set_colorrange_min_lightness(this.__wbg_ptr, arg0);
+    }
+    /**
+     * @param {number} arg0
+     */
+    set min_saturation(arg0) {
+        wasm.__wbg_set_colorrange_min_saturation(this.__wbg_ptr, arg0);
     }
 }
+if (Symbol.dispose) ColorRange.prototype[Symbol.dispose] = ColorRange.prototype.free;
 
-function passStringToWasm0(arg, malloc, realloc) {
-
-    if (realloc === undefined) {
-        const buf = cachedTextEncoder.encode(arg);
-        const ptr = malloc(buf.length, 1) >>> 0;
-        getUint8ArrayMemory0().subarray(ptr, ptr + buf.length).set(buf);
-        WASM_VECTOR_LEN = buf.length;
+/**
+ * Fill and outline for the `draw_*` functions; colors are 0xRRGGBBAA, unset means none
+ */
+export class DrawStyle {
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        DrawStyleFinalization.unregister(this);
         return ptr;
     }
-
-    let len = arg.length;
-    let ptr = malloc(len, 1) >>> 0;
-
-    const mem = getUint8ArrayMemory0();
-
-    let offset = 0;
-
-    for (; offset < len; offset++) {
-        const code = arg.charCodeAt(offset);
-        if (code > 0x7F) break;
-        mem[ptr + offset] = code;
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_drawstyle_free(ptr, 0);
     }
-
-    if (offset !== len) {
-        if (offset !== 0) {
-            arg = arg.slice(offset);
-        }
-        ptr = realloc(ptr, len, len = offset + arg.length * 3, 1) >>> 0;
-        const view = getUint8ArrayMemory0().subarray(ptr + offset, ptr + len);
-        const ret = cachedTextEncoder.encodeInto(arg, view);
-
-        offset += ret.written;
-        ptr = realloc(ptr, len, offset, 1) >>> 0;
+    constructor() {
+        const ret = wasm.drawstyle_new();
+        this.__wbg_ptr = ret;
+        DrawStyleFinalization.register(this, this.__wbg_ptr, this);
+        return this;
     }
-
-    WASM_VECTOR_LEN = offset;
-    return ptr;
-}
-
-function debugString(val) {
-    // primitive types
-    const type = typeof val;
-    if (type == 'number' || type == 'boolean' || val == null) {
-        return  `${val}`;
-    }
-    if (type == 'string') {
-        return `"${val}"`;
-    }
-    if (type == 'symbol') {
-        const description = val.description;
-        if (description == null) {
-            return 'Symbol';
-        } else {
-            return `Symbol(${description})`;
-        }
+    /**
+     * @returns {number | undefined}
+     */
+    get fill() {
+        const ret = wasm.__wbg_get_drawstyle_fill(this.__wbg_ptr);
+        return ret === Number.MAX_SAFE_INTEGER ? undefined : ret;
     }
-    if (type == 'function') {
-        const name = val.name;
-        if (typeof name == 'string' && name.length > 0) {
-            return `Function(${name})`;
-        } else {
-            return 'Function';
-        }
+    /**
+     * Stroke width in pixels, centred on the shape's edge
+     * @returns {number}
+     */
+    get stroke_width() {
+        const ret = wasm.__wbg_get_drawstyle_stroke_width(this.__wbg_ptr);
+        return ret;
     }
-    // objects
-    if (Array.isArray(val)) {
-        const length = val.length;
-        let debug = '[';
-        if (length > 0) {
-            debug += debugString(val[0]);
-        }
-        for(let i = 1; i < length; i++) {
-            debug += ', ' + debugString(val[i]);
-        }
-        debug += ']';
-        return debug;
-    }
-    // Test for built-in
-    const builtInMatches = /\[object ([^\]]+)\]/.exec(toString.call(val));
-    let className;
-    if (builtInMatches && builtInMatches.length > 1) {
-        className = builtInMatches[1];
-    } else {
-        // Failed to match the standard '[object ClassName]'
-        return toString.call(val);
+    /**
+     * @returns {number | undefined}
+     */
+    get stroke() {
+        const ret = wasm.__wbg_get_drawstyle_stroke(this.__wbg_ptr);
+        return ret === Number.MAX_SAFE_INTEGER ? undefined : ret;
     }
-    if (className == 'Object') {
-        // we're a user defined class or Object
-        // JSON.stringify avoids problems with cycles, and is generally much
-        // easier than looping through ownProperties of `val`.
-        try {
-            return 'Object(' + JSON.stringify(val) + ')';
-        } catch (_) {
-            return 'Object';
-        }
+    /**
+     * @param {number | null} [arg0]
+     */
+    set fill(arg0) {
+        wasm.__wbg_set_drawstyle_fill(this.__wbg_ptr, isLikeNone(arg0) ? Number.MAX_SAFE_INTEGER : (arg0) >>> 0);
+    }
+    /**
+     * Stroke width in pixels, centred on the shape's edge
+     * @param {number} arg0
+     */
+    set stroke_width(arg0) {
+        wasm.__wbg_set_drawstyle_stroke_width(this.__wbg_ptr, arg0);
     }
-    // errors
-    if (val instanceof Error) {
-        return `${val.name}: ${val.message}\n${val.stack}`;
+    /**
+     * @param {number | null} [arg0]
+     */
+    set stroke(arg0) {
+        wasm.__wbg_set_drawstyle_stroke(this.__wbg_ptr, isLikeNone(arg0) ? Number.MAX_SAFE_INTEGER : (arg0) >>> 0);
     }
-    // TODO we could test for more things here, like `Set`s and `Map`s.
-    return className;
-}
-
-function dropObject(idx) {
-    if (idx < 132) return;
-    heap[idx] = heap_next;
-    heap_next = idx;
-}
-
-function takeObject(idx) {
-    const ret = getObject(idx);
-    dropObject(idx);
-    return ret;
-}
-/**
- * Initialize the WASM module
- * This should be called once when the module is loaded
- */
-export function init() {
-    wasm.init();
 }
+if (Symbol.dispose) DrawStyle.prototype[Symbol.dispose] = DrawStyle.prototype.free;
 
 /**
- * Get a greeting message (test function)
- * @param {string} name
- * @returns {string}
+ * Filter settings of the editor, mirrors `FilterState` in `web/src/types/filters.ts`
  */
-export function greet(name) {
-    let deferred2_0;
-    let deferred2_1;
-    try {
-        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
-        const ptr0 = passStringToWasm0(name, wasm.__wbindgen_export_1, wasm.__wbindgen_export_3);
-        const len0 = WASM_VECTOR_LEN;
-        wasm.greet(retptr, ptr0, len0);
-        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
-        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
-        deferred2_0 = r0;
-        deferred2_1 = r1;
-        return getStringFromWasm0(r0, r1);
-    } finally {
-        wasm.__wbindgen_add_to_stack_pointer(16);
-        wasm.__wbindgen_export_2(deferred2_0, deferred2_1, 1);
+export class FilterSettings {
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        FilterSettingsFinalization.unregister(this);
+        return ptr;
     }
-}
-
-/**
- * Convert image to grayscale
- * @param {Uint8Array} image_data
- * @param {number} width
- * @param {number} height
- * @returns {Uint8Array}
- */
-export function apply_grayscale(image_data, width, height) {
-    try {
-        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
-        const ptr0 = passArray8ToWasm0(image_data, wasm.__wbindgen_export_1);
-        const len0 = WASM_VECTOR_LEN;
-        wasm.apply_grayscale(retptr, ptr0, len0, width, height);
-        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
-        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
-        var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
-        var r3 = getDataViewMemory0().getInt32(retptr + 4 * 3, true);
-        if (r3) {
-            throw takeObject(r2);
-        }
-        var v2 = getArrayU8FromWasm0(r0, r1).slice();
-        wasm.__wbindgen_export_2(r0, r1 * 1, 1);
-        return v2;
-    } finally {
-        wasm.__wbindgen_add_to_stack_pointer(16);
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_filtersettings_free(ptr, 0);
+    }
+    clear_crop() {
+        wasm.filtersettings_clear_crop(this.__wbg_ptr);
+    }
+    constructor() {
+        const ret = wasm.filtersettings_new();
+        this.__wbg_ptr = ret;
+        FilterSettingsFinalization.register(this, this.__wbg_ptr, this);
+        return this;
+    }
+    /**
+     * Crop rectangle in the coordinates of the rotated image
+     * @param {number} x
+     * @param {number} y
+     * @param {number} width
+     * @param {number} height
+     */
+    set_crop(x, y, width, height) {
+        wasm.filtersettings_set_crop(this.__wbg_ptr, x, y, width, height);
+    }
+    /**
+     * Same parameters as `apply_grain`; amount 0 turns it off
+     * @param {number} amount
+     * @param {number | null} [size]
+     * @param {number | null} [roughness]
+     * @param {number | null} [seed]
+     */
+    set_grain(amount, size, roughness, seed) {
+        wasm.filtersettings_set_grain(this.__wbg_ptr, amount, isLikeNone(size) ? Number.MAX_SAFE_INTEGER : Math.fround(size), isLikeNone(roughness) ? Number.MAX_SAFE_INTEGER : Math.fround(roughness), isLikeNone(seed) ? Number.MAX_SAFE_INTEGER : (seed) >>> 0);
+    }
+    /**
+     * Same parameters as `apply_vignette`; amount 0 turns it off
+     * @param {number} amount
+     * @param {number | null} [midpoint]
+     * @param {number | null} [roundness]
+     * @param {number | null} [feather]
+     * @param {number | null} [highlights]
+     */
+    set_vignette(amount, midpoint, roundness, feather, highlights) {
+        wasm.filtersettings_set_vignette(this.__wbg_ptr, amount, isLikeNone(midpoint) ? Number.MAX_SAFE_INTEGER : Math.fround(midpoint), isLikeNone(roundness) ? Number.MAX_SAFE_INTEGER : Math.fround(roundness), isLikeNone(feather) ? Number.MAX_SAFE_INTEGER : Math.fround(feather), isLikeNone(highlights) ? Number.MAX_SAFE_INTEGER : Math.fround(highlights));
+    }
+    /**
+     * @returns {number}
+     */
+    get blur() {
+        const ret = wasm.__wbg_get_filtersettings_blur(this.__wbg_ptr);
+        return ret;
+    }
+    /**
+     * @returns {number}
+     */
+    get brightness() {
+        const ret = wasm.__wbg_get_filtersettings_brightness(this.__wbg_ptr);
+        return ret;
+    }
+    /**
+     * @returns {boolean}
+     */
+    get flip_horizontal() {
+        const ret = wasm.__wbg_get_filtersettings_flip_horizontal(this.__wbg_ptr);
+        return ret !== 0;
+    }
+    /**
+     * @returns {boolean}
+     */
+    get flip_vertical() {
+        const ret = wasm.__wbg_get_filtersettings_flip_vertical(this.__wbg_ptr);
+        return ret !== 0;
+    }
+    /**
+     * @returns {boolean}
+     */
+    get grayscale() {
+        const ret = wasm.__wbg_get_filtersettings_grayscale(this.__wbg_ptr);
+        return ret !== 0;
+    }
+    /**
+     * Clockwise degrees: 0, 90, 180 or 270
+     * @returns {number}
+     */
+    get rotation() {
+        const ret = wasm.__wbg_get_filtersettings_rotation(this.__wbg_ptr);
+        return ret >>> 0;
+    }
+    /**
+     * White balance, see `apply_white_balance`
+     * @returns {number}
+     */
+    get temperature() {
+        const ret = wasm.__wbg_get_filtersettings_temperature(this.__wbg_ptr);
+        return ret;
+    }
+    /**
+     * @returns {number}
+     */
+    get tint() {
+        const ret = wasm.__wbg_get_filtersettings_tint(this.__wbg_ptr);
+        return ret;
+    }
+    /**
+     * @param {number} arg0
+     */
+    set blur(arg0) {
+        wasm.__wbg_set_filtersettings_blur(this.__wbg_ptr, arg0);
+    }
+    /**
+     * @param {number} arg0
+     */
+    set brightness(arg0) {
+        wasm.__wbg_set_filtersettings_brightness(this.__wbg_ptr, arg0);
+    }
+    /**
+     * @param {boolean} arg0
+     */
+    set flip_horizontal(arg0) {
+        wasm.__wbg_set_filtersettings_flip_horizontal(this.__wbg_ptr, arg0);
+    }
+    /**
+     * @param {boolean} arg0
+     */
+    set flip_vertical(arg0) {
+        wasm.__wbg_set_filtersettings_flip_vertical(this.__wbg_ptr, arg0);
+    }
+    /**
+     * @param {boolean} arg0
+     */
+    set grayscale(arg0) {
+        wasm.__wbg_set_filtersettings_grayscale(this.__wbg_ptr, arg0);
+    }
+    /**
+     * Clockwise degrees: 0, 90, 180 or 270
+     * @param {number} arg0
+     */
+    set rotation(arg0) {
+        wasm.__wbg_set_filtersettings_rotation(this.__wbg_ptr, arg0);
+    }
+    /**
+     * White balance, see `apply_white_balance`
+     * @param {number} arg0
+     */
+    set temperature(arg0) {
+        wasm.__wbg_set_filtersettings_temperature(this.__wbg_ptr, arg0);
+    }
+    /**
+     * @param {number} arg0
+     */
+    set tint(arg0) {
+        wasm.__wbg_set_filtersettings_tint(this.__wbg_ptr, arg0);
     }
 }
+if (Symbol.dispose) FilterSettings.prototype[Symbol.dispose] = FilterSettings.prototype.free;
 
 /**
- * Apply Gaussian blur
- * @param {Uint8Array} image_data
- * @param {number} width
- * @param {number} height
- * @param {number} radius
- * @returns {Uint8Array}
+ * Re-renders edits of one image, recomputing only stages whose settings changed
+ *
+ * Every stage output is cached (keyed by its settings and its input) within
+ * a memory budget; least recently used outputs are evicted first.
  */
-export function apply_blur(image_data, width, height, radius) {
-    try {
-        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
-        const ptr0 = passArray8ToWasm0(image_data, wasm.__wbindgen_export_1);
-        const len0 = WASM_VECTOR_LEN;
-        wasm.apply_blur(retptr, ptr0, len0, width, height, radius);
-        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
-        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
-        var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
-        var r3 = getDataViewMemory0().getInt32(retptr + 4 * 3, true);
-        if (r3) {
-            throw takeObject(r2);
+export class IncrementalRenderer {
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        IncrementalRendererFinalization.unregister(this);
+        return ptr;
+    }
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_incrementalrenderer_free(ptr, 0);
+    }
+    /**
+     * @returns {number}
+     */
+    cached_bytes() {
+        const ret = wasm.incrementalrenderer_cached_bytes(this.__wbg_ptr);
+        return ret >>> 0;
+    }
+    clear() {
+        wasm.incrementalrenderer_clear(this.__wbg_ptr);
+    }
+    /**
+     * budget_bytes: memory for cached stage outputs (default 256MB)
+     * @param {Uint8Array} image_data
+     * @param {number} width
+     * @param {number} height
+     * @param {number | null} [budget_bytes]
+     */
+    constructor(image_data, width, height, budget_bytes) {
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            const ptr0 = passArray8ToWasm0(image_data, wasm.__wbindgen_export3);
+            const len0 = WASM_VECTOR_LEN;
+            wasm.incrementalrenderer_new(retptr, ptr0, len0, width, height, isLikeNone(budget_bytes) ? Number.MAX_SAFE_INTEGER : (budget_bytes) >>> 0);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+            if (r2) {
+                throw takeObject(r1);
+            }
+            this.__wbg_ptr = r0;
+            IncrementalRendererFinalization.register(this, this.__wbg_ptr, this);
+            return this;
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
         }
-        var v2 = getArrayU8FromWasm0(r0, r1).slice();
-        wasm.__wbindgen_export_2(r0, r1 * 1, 1);
-        return v2;
-    } finally {
-        wasm.__wbindgen_add_to_stack_pointer(16);
     }
-}
-
-/**
- * Apply brightness adjustment
- * adjustment: -255.0 (darker) to +255.0 (brighter)
- * @param {Uint8Array} image_data
- * @param {number} width
- * @param {number} height
- * @param {number} adjustment
- * @returns {Uint8Array}
- */
-export function apply_brightness(image_data, width, height, adjustment) {
-    try {
-        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
-        const ptr0 = passArray8ToWasm0(image_data, wasm.__wbindgen_export_1);
-        const len0 = WASM_VECTOR_LEN;
-        wasm.apply_brightness(retptr, ptr0, len0, width, height, adjustment);
-        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
-        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
-        var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
-        var r3 = getDataViewMemory0().getInt32(retptr + 4 * 3, true);
-        if (r3) {
-            throw takeObject(r2);
+    /**
+     * on_progress, progress_every, cancel: as for `apply_blur`; totalRows counts
+     * the rows of the stages that are recomputed. Stages finished before a
+     * cancellation stay cached.
+     * @param {FilterSettings} settings
+     * @param {Function | null} [on_progress]
+     * @param {number | null} [progress_every]
+     * @param {AbortSignal | Int32Array | null} [cancel]
+     * @returns {RenderedImage}
+     */
+    render(settings, on_progress, progress_every, cancel) {
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            _assertClass(settings, FilterSettings);
+            wasm.incrementalrenderer_render(retptr, this.__wbg_ptr, settings.__wbg_ptr, isLikeNone(on_progress) ? 0 : addHeapObject(on_progress), isLikeNone(progress_every) ? Number.MAX_SAFE_INTEGER : (progress_every) >>> 0, isLikeNone(cancel) ? 0 : addHeapObject(cancel));
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+            if (r2) {
+                throw takeObject(r1);
+            }
+            return RenderedImage.__wrap(r0);
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
         }
-        var v2 = getArrayU8FromWasm0(r0, r1).slice();
-        wasm.__wbindgen_export_2(r0, r1 * 1, 1);
-        return v2;
-    } finally {
-        wasm.__wbindgen_add_to_stack_pointer(16);
     }
-}
-
-/**
- * Apply horizontal flip (mirror left-right)
- * @param {Uint8Array} image_data
- * @param {number} width
- * @param {number} height
- * @returns {Uint8Array}
- */
-export function apply_flip_horizontal(image_data, width, height) {
-    try {
-        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
-        const ptr0 = passArray8ToWasm0(image_data, wasm.__wbindgen_export_1);
-        const len0 = WASM_VECTOR_LEN;
-        wasm.apply_flip_horizontal(retptr, ptr0, len0, width, height);
-        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
-        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
-        var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
-        var r3 = getDataViewMemory0().getInt32(retptr + 4 * 3, true);
-        if (r3) {
-            throw takeObject(r2);
+    /**
+     * @param {number} budget_bytes
+     */
+    set_budget(budget_bytes) {
+        wasm.incrementalrenderer_set_budget(this.__wbg_ptr, budget_bytes);
+    }
+    /**
+     * Replace the source image and drop the cache
+     * @param {Uint8Array} image_data
+     * @param {number} width
+     * @param {number} height
+     */
+    set_source(image_data, width, height) {
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            const ptr0 = passArray8ToWasm0(image_data, wasm.__wbindgen_export3);
+            const len0 = WASM_VECTOR_LEN;
+            wasm.incrementalrenderer_set_source(retptr, this.__wbg_ptr, ptr0, len0, width, height);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            if (r1) {
+                throw takeObject(r0);
+            }
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
         }
-        var v2 = getArrayU8FromWasm0(r0, r1).slice();
-        wasm.__wbindgen_export_2(r0, r1 * 1, 1);
-        return v2;
-    } finally {
-        wasm.__wbindgen_add_to_stack_pointer(16);
     }
 }
+if (Symbol.dispose) IncrementalRenderer.prototype[Symbol.dispose] = IncrementalRenderer.prototype.free;
 
 /**
- * Apply vertical flip (mirror top-bottom)
- * @param {Uint8Array} image_data
- * @param {number} width
- * @param {number} height
- * @returns {Uint8Array}
+ * Layers on a fixed-size canvas, composited with blend modes
+ *
+ * Index 0 is the bottom layer. Layer data and `composite` output use
+ * straight alpha, like `ImageData`.
  */
-export function apply_flip_vertical(image_data, width, height) {
-    try {
-        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
-        const ptr0 = passArray8ToWasm0(image_data, wasm.__wbindgen_export_1);
-        const len0 = WASM_VECTOR_LEN;
-        wasm.apply_flip_vertical(retptr, ptr0, len0, width, height);
-        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
-        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
-        var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
-        var r3 = getDataViewMemory0().getInt32(retptr + 4 * 3, true);
-        if (r3) {
-            throw takeObject(r2);
+export class LayerStack {
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        LayerStackFinalization.unregister(this);
+        return ptr;
+    }
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_layerstack_free(ptr, 0);
+    }
+    /**
+     * Add a visible, opaque, normal layer at (0, 0) on top; returns its index
+     * @param {Uint8Array} image_data
+     * @param {number} width
+     * @param {number} height
+     * @returns {number}
+     */
+    add_layer(image_data, width, height) {
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            const ptr0 = passArray8ToWasm0(image_data, wasm.__wbindgen_export3);
+            const len0 = WASM_VECTOR_LEN;
+            wasm.layerstack_add_layer(retptr, this.__wbg_ptr, ptr0, len0, width, height);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+            if (r2) {
+                throw takeObject(r1);
+            }
+            return r0 >>> 0;
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
         }
-        var v2 = getArrayU8FromWasm0(r0, r1).slice();
-        wasm.__wbindgen_export_2(r0, r1 * 1, 1);
-        return v2;
-    } finally {
-        wasm.__wbindgen_add_to_stack_pointer(16);
     }
-}
-
-/**
- * Rotate image 90 degrees clockwise
- * @param {Uint8Array} image_data
- * @param {number} width
- * @param {number} height
- * @returns {Uint8Array}
- */
-export function apply_rotate_90_cw(image_data, width, height) {
-    try {
-        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
-        const ptr0 = passArray8ToWasm0(image_data, wasm.__wbindgen_export_1);
-        const len0 = WASM_VECTOR_LEN;
-        wasm.apply_rotate_90_cw(retptr, ptr0, len0, width, height);
-        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
-        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
-        var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
-        var r3 = getDataViewMemory0().getInt32(retptr + 4 * 3, true);
-        if (r3) {
-            throw takeObject(r2);
+    /**
+     * Flatten the visible layers into one image of the canvas size
+     * @returns {RenderedImage}
+     */
+    composite() {
+        const ret = wasm.layerstack_composite(this.__wbg_ptr);
+        return RenderedImage.__wrap(ret);
+    }
+    /**
+     * @returns {number}
+     */
+    layer_count() {
+        const ret = wasm.layerstack_layer_count(this.__wbg_ptr);
+        return ret >>> 0;
+    }
+    /**
+     * @param {number} from
+     * @param {number} to
+     */
+    move_layer(from, to) {
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            wasm.layerstack_move_layer(retptr, this.__wbg_ptr, from, to);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            if (r1) {
+                throw takeObject(r0);
+            }
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
         }
-        var v2 = getArrayU8FromWasm0(r0, r1).slice();
-        wasm.__wbindgen_export_2(r0, r1 * 1, 1);
-        return v2;
-    } finally {
-        wasm.__wbindgen_add_to_stack_pointer(16);
     }
-}
-
-/**
- * Rotate image 180 degrees
- * @param {Uint8Array} image_data
- * @param {number} width
- * @param {number} height
- * @returns {Uint8Array}
- */
-export function apply_rotate_180(image_data, width, height) {
-    try {
-        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
-        const ptr0 = passArray8ToWasm0(image_data, wasm.__wbindgen_export_1);
-        const len0 = WASM_VECTOR_LEN;
-        wasm.apply_rotate_180(retptr, ptr0, len0, width, height);
-        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
-        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
-        var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
-        var r3 = getDataViewMemory0().getInt32(retptr + 4 * 3, true);
-        if (r3) {
-            throw takeObject(r2);
+    /**
+     * @param {number} width
+     * @param {number} height
+     */
+    constructor(width, height) {
+        const ret = wasm.layerstack_new(width, height);
+        this.__wbg_ptr = ret;
+        LayerStackFinalization.register(this, this.__wbg_ptr, this);
+        return this;
+    }
+    /**
+     * @param {number} index
+     */
+    remove_layer(index) {
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            wasm.layerstack_remove_layer(retptr, this.__wbg_ptr, index);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            if (r1) {
+                throw takeObject(r0);
+            }
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
         }
-        var v2 = getArrayU8FromWasm0(r0, r1).slice();
-        wasm.__wbindgen_export_2(r0, r1 * 1, 1);
-        return v2;
-    } finally {
-        wasm.__wbindgen_add_to_stack_pointer(16);
     }
-}
-
-/**
- * Rotate image 270 degrees clockwise (90 degrees counter-clockwise)
- * @param {Uint8Array} image_data
- * @param {number} width
- * @param {number} height
- * @returns {Uint8Array}
- */
-export function apply_rotate_270_cw(image_data, width, height) {
-    try {
-        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
-        const ptr0 = passArray8ToWasm0(image_data, wasm.__wbindgen_export_1);
-        const len0 = WASM_VECTOR_LEN;
-        wasm.apply_rotate_270_cw(retptr, ptr0, len0, width, height);
-        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
-        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
-        var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
-        var r3 = getDataViewMemory0().getInt32(retptr + 4 * 3, true);
-        if (r3) {
-            throw takeObject(r2);
+    /**
+     * mode: CSS `mix-blend-mode` name: "normal", "multiply", "screen", "overlay",
+     * "soft-light", "darken", "lighten", "difference", "color-dodge" or "color-burn"
+     * @param {number} index
+     * @param {string} mode
+     */
+    set_blend_mode(index, mode) {
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            const ptr0 = passStringToWasm0(mode, wasm.__wbindgen_export3, wasm.__wbindgen_export4);
+            const len0 = WASM_VECTOR_LEN;
+            wasm.layerstack_set_blend_mode(retptr, this.__wbg_ptr, index, ptr0, len0);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            if (r1) {
+                throw takeObject(r0);
+            }
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
         }
-        var v2 = getArrayU8FromWasm0(r0, r1).slice();
-        wasm.__wbindgen_export_2(r0, r1 * 1, 1);
-        return v2;
-    } finally {
-        wasm.__wbindgen_add_to_stack_pointer(16);
     }
-}
-
-/**
- * Crop image to specified rectangle
- * @param {Uint8Array} image_data
- * @param {number} width
- * @param {number} height
- * @param {number} x
- * @param {number} y
- * @param {number} crop_width
- * @param {number} crop_height
- * @returns {Uint8Array}
- */
-export function apply_crop(image_data, width, height, x, y, crop_width, crop_height) {
-    try {
-        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
-        const ptr0 = passArray8ToWasm0(image_data, wasm.__wbindgen_export_1);
-        const len0 = WASM_VECTOR_LEN;
-        wasm.apply_crop(retptr, ptr0, len0, width, height, x, y, crop_width, crop_height);
-        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
-        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
-        var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
-        var r3 = getDataViewMemory0().getInt32(retptr + 4 * 3, true);
-        if (r3) {
-            throw takeObject(r2);
+    /**
+     * Replace a layer's pixels, keeping its settings
+     * @param {number} index
+     * @param {Uint8Array} image_data
+     * @param {number} width
+     * @param {number} height
+     */
+    set_layer_data(index, image_data, width, height) {
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            const ptr0 = passArray8ToWasm0(image_data, wasm.__wbindgen_export3);
+            const len0 = WASM_VECTOR_LEN;
+            wasm.layerstack_set_layer_data(retptr, this.__wbg_ptr, index, ptr0, len0, width, height);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            if (r1) {
+                throw takeObject(r0);
+            }
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
         }
-        var v2 = getArrayU8FromWasm0(r0, r1).slice();
-        wasm.__wbindgen_export_2(r0, r1 * 1, 1);
-        return v2;
-    } finally {
-        wasm.__wbindgen_add_to_stack_pointer(16);
     }
-}
-
-/**
- * ! [temp] Check if WASM is supported.
- */
-export function run() {
-    try {
-        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
-        wasm.run(retptr);
-        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
-        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
-        if (r1) {
-            throw takeObject(r0);
+    /**
+     * Position of the layer's top-left corner on the canvas, may be negative
+     * @param {number} index
+     * @param {number} x
+     * @param {number} y
+     */
+    set_offset(index, x, y) {
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            wasm.layerstack_set_offset(retptr, this.__wbg_ptr, index, x, y);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            if (r1) {
+                throw takeObject(r0);
+            }
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+        }
+    }
+    /**
+     * opacity: 0.0 to 1.0
+     * @param {number} index
+     * @param {number} opacity
+     */
+    set_opacity(index, opacity) {
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            wasm.layerstack_set_opacity(retptr, this.__wbg_ptr, index, opacity);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            if (r1) {
+                throw takeObject(r0);
+            }
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+        }
+    }
+    /**
+     * @param {number} index
+     * @param {boolean} visible
+     */
+    set_visible(index, visible) {
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            wasm.layerstack_set_visible(retptr, this.__wbg_ptr, index, visible);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            if (r1) {
+                throw takeObject(r0);
+            }
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
         }
-    } finally {
-        wasm.__wbindgen_add_to_stack_pointer(16);
     }
 }
+if (Symbol.dispose) LayerStack.prototype[Symbol.dispose] = LayerStack.prototype.free;
 
-let stack_pointer = 128;
-
-function addBorrowedObject(obj) {
-    if (stack_pointer == 1) throw new Error('out of js stack');
-    heap[--stack_pointer] = obj;
-    return stack_pointer;
-}
 /**
- * Get the ImageData from a 2D canvas context
- * @param {HTMLCanvasElement} canvas
- * @param {CanvasRenderingContext2D} ctx
- * @returns {ImageData}
+ * A color lookup table parsed from a `.cube` file, for `apply_lut`
  */
-export function get_image_data(canvas, ctx) {
-    try {
-        const ret = wasm.get_image_data(addBorrowedObject(canvas), addBorrowedObject(ctx));
-        return takeObject(ret);
-    } finally {
-        heap[stack_pointer++] = undefined;
-        heap[stack_pointer++] = undefined;
+export class Lut {
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        LutFinalization.unregister(this);
+        return ptr;
     }
-}
-
-function _assertClass(instance, klass) {
-    if (!(instance instanceof klass)) {
-        throw new Error(`expected instance of ${klass.name}`);
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_lut_free(ptr, 0);
+    }
+    /**
+     * Parse the text of an Adobe or Resolve `.cube` file (1D, 3D or 1D shaper + 3D)
+     * @param {string} text
+     */
+    constructor(text) {
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            const ptr0 = passStringToWasm0(text, wasm.__wbindgen_export3, wasm.__wbindgen_export4);
+            const len0 = WASM_VECTOR_LEN;
+            wasm.lut_new(retptr, ptr0, len0);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+            if (r2) {
+                throw takeObject(r1);
+            }
+            this.__wbg_ptr = r0;
+            LutFinalization.register(this, this.__wbg_ptr, this);
+            return this;
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+        }
+    }
+    /**
+     * TITLE of the file, if it has one
+     * @returns {string | undefined}
+     */
+    title() {
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            wasm.lut_title(retptr, this.__wbg_ptr);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            let v1;
+            if (r0 !== 0) {
+                v1 = getStringFromWasm0(r0, r1);
+                wasm.__wbindgen_export2(r0, r1 * 1, 1);
+            }
+            return v1;
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+        }
     }
 }
+if (Symbol.dispose) Lut.prototype[Symbol.dispose] = Lut.prototype.free;
+
 /**
- * Place a PhotonImage onto a 2D canvas.
- * @param {HTMLCanvasElement} canvas
- * @param {CanvasRenderingContext2D} ctx
- * @param {PhotonImage} new_image
+ * Selection mask: one coverage byte per pixel, 0 = unselected, 255 = selected
+ *
+ * Build it from shapes, combine and feather it, then pass `data()` as the
+ * `mask` argument of a filter.
  */
-export function putImageData(canvas, ctx, new_image) {
-    _assertClass(new_image, PhotonImage);
-    var ptr0 = new_image.__destroy_into_raw();
-    wasm.putImageData(addHeapObject(canvas), addHeapObject(ctx), ptr0);
-}
-
-/**
- * Convert a HTML5 Canvas Element to a PhotonImage.
- *
- * This converts the ImageData found in the canvas context to a PhotonImage,
- * which can then have effects or filters applied to it.
- * @param {HTMLCanvasElement} canvas
- * @param {CanvasRenderingContext2D} ctx
- * @returns {PhotonImage}
- */
-export function open_image(canvas, ctx) {
-    const ret = wasm.open_image(addHeapObject(canvas), addHeapObject(ctx));
-    return PhotonImage.__wrap(ret);
-}
-
-/**
- * Convert ImageData to a raw pixel vec of u8s.
- * @param {ImageData} imgdata
- * @returns {Uint8Array}
- */
-export function to_raw_pixels(imgdata) {
-    try {
-        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
-        wasm.to_raw_pixels(retptr, addHeapObject(imgdata));
-        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
-        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
-        var v1 = getArrayU8FromWasm0(r0, r1).slice();
-        wasm.__wbindgen_export_2(r0, r1 * 1, 1);
-        return v1;
-    } finally {
-        wasm.__wbindgen_add_to_stack_pointer(16);
+export class Mask {
+    static __wrap(ptr) {
+        const obj = Object.create(Mask.prototype);
+        obj.__wbg_ptr = ptr;
+        MaskFinalization.register(obj, obj.__wbg_ptr, obj);
+        return obj;
     }
-}
-
-/**
- * Convert a base64 string to a PhotonImage.
- * @param {string} base64
- * @returns {PhotonImage}
- */
-export function base64_to_image(base64) {
-    const ptr0 = passStringToWasm0(base64, wasm.__wbindgen_export_1, wasm.__wbindgen_export_3);
-    const len0 = WASM_VECTOR_LEN;
-    const ret = wasm.base64_to_image(ptr0, len0);
-    return PhotonImage.__wrap(ret);
-}
-
-/**
- * Convert a base64 string to a Vec of u8s.
- * @param {string} base64
- * @returns {Uint8Array}
- */
-export function base64_to_vec(base64) {
-    try {
-        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
-        const ptr0 = passStringToWasm0(base64, wasm.__wbindgen_export_1, wasm.__wbindgen_export_3);
-        const len0 = WASM_VECTOR_LEN;
-        wasm.base64_to_vec(retptr, ptr0, len0);
-        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
-        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
-        var v2 = getArrayU8FromWasm0(r0, r1).slice();
-        wasm.__wbindgen_export_2(r0, r1 * 1, 1);
-        return v2;
-    } finally {
-        wasm.__wbindgen_add_to_stack_pointer(16);
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        MaskFinalization.unregister(this);
+        return ptr;
+    }
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_mask_free(ptr, 0);
+    }
+    /**
+     * `[x, y, width, height]` of the selected area, e.g. for `apply_crop`; undefined if empty
+     * @returns {Uint32Array | undefined}
+     */
+    bounds() {
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            wasm.mask_bounds(retptr, this.__wbg_ptr);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            let v1;
+            if (r0 !== 0) {
+                v1 = getArrayU32FromWasm0(r0, r1).slice();
+                wasm.__wbindgen_export2(r0, r1 * 4, 4);
+            }
+            return v1;
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+        }
+    }
+    /**
+     * @returns {Uint8Array}
+     */
+    data() {
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            wasm.mask_data(retptr, this.__wbg_ptr);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            var v1 = getArrayU8FromWasm0(r0, r1).slice();
+            wasm.__wbindgen_export2(r0, r1 * 1, 1);
+            return v1;
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+        }
+    }
+    /**
+     * @param {number} width
+     * @param {number} height
+     * @param {number} cx
+     * @param {number} cy
+     * @param {number} rx
+     * @param {number} ry
+     * @returns {Mask}
+     */
+    static ellipse(width, height, cx, cy, rx, ry) {
+        const ret = wasm.mask_ellipse(width, height, cx, cy, rx, ry);
+        return Mask.__wrap(ret);
+    }
+    /**
+     * Copy with edges softened by a Gaussian blur of `radius` pixels
+     * @param {number} radius
+     * @returns {Mask}
+     */
+    feather(radius) {
+        const ret = wasm.mask_feather(this.__wbg_ptr, radius);
+        return Mask.__wrap(ret);
+    }
+    /**
+     * Everything selected
+     * @param {number} width
+     * @param {number} height
+     * @returns {Mask}
+     */
+    static full(width, height) {
+        const ret = wasm.mask_full(width, height);
+        return Mask.__wrap(ret);
+    }
+    /**
+     * @param {Mask} other
+     */
+    intersect(other) {
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            _assertClass(other, Mask);
+            wasm.mask_intersect(retptr, this.__wbg_ptr, other.__wbg_ptr);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            if (r1) {
+                throw takeObject(r0);
+            }
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+        }
+    }
+    invert() {
+        wasm.mask_invert(this.__wbg_ptr);
+    }
+    /**
+     * Nothing selected
+     * @param {number} width
+     * @param {number} height
+     */
+    constructor(width, height) {
+        const ret = wasm.mask_new(width, height);
+        this.__wbg_ptr = ret;
+        MaskFinalization.register(this, this.__wbg_ptr, this);
+        return this;
+    }
+    /**
+     * points: flat `[x0, y0, x1, y1, ...]`, closed automatically
+     * @param {number} width
+     * @param {number} height
+     * @param {Float32Array} points
+     * @returns {Mask}
+     */
+    static polygon(width, height, points) {
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            const ptr0 = passArrayF32ToWasm0(points, wasm.__wbindgen_export3);
+            const len0 = WASM_VECTOR_LEN;
+            wasm.mask_polygon(retptr, width, height, ptr0, len0);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+            if (r2) {
+                throw takeObject(r1);
+            }
+            return Mask.__wrap(r0);
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+        }
+    }
+    /**
+     * @param {number} width
+     * @param {number} height
+     * @param {number} x
+     * @param {number} y
+     * @param {number} rect_width
+     * @param {number} rect_height
+     * @returns {Mask}
+     */
+    static rect(width, height, x, y, rect_width, rect_height) {
+        const ret = wasm.mask_rect(width, height, x, y, rect_width, rect_height);
+        return Mask.__wrap(ret);
+    }
+    /**
+     * @param {Mask} other
+     */
+    subtract(other) {
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            _assertClass(other, Mask);
+            wasm.mask_subtract(retptr, this.__wbg_ptr, other.__wbg_ptr);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            if (r1) {
+                throw takeObject(r0);
+            }
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+        }
+    }
+    /**
+     * @param {Mask} other
+     */
+    union(other) {
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            _assertClass(other, Mask);
+            wasm.mask_union(retptr, this.__wbg_ptr, other.__wbg_ptr);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            if (r1) {
+                throw takeObject(r0);
+            }
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+        }
     }
 }
+if (Symbol.dispose) Mask.prototype[Symbol.dispose] = Mask.prototype.free;
 
 /**
- * Convert a PhotonImage to JS-compatible ImageData.
- * @param {PhotonImage} photon_image
- * @returns {ImageData}
- */
-export function to_image_data(photon_image) {
-    _assertClass(photon_image, PhotonImage);
-    var ptr0 = photon_image.__destroy_into_raw();
-    const ret = wasm.to_image_data(ptr0);
-    return takeObject(ret);
-}
-
-/**
- * Alter a select channel by incrementing or decrementing its value by a constant.
- *
- * # Arguments
- * * `img` - A PhotonImage.
- * * `channel` - The channel you wish to alter, it should be either 0, 1 or 2,
- * representing R, G, or B respectively. (O=Red, 1=Green, 2=Blue)
- * * `amount` - The amount to increment/decrement the channel's value by for that pixel.
- * A positive value will increment/decrement the channel's value, a negative value will decrement the channel's value.
- *
- * ## Example
- *
- * ```no_run
- * // For example, to increase the Red channel for all pixels by 10:
- * use photon_rs::channels::alter_channel;
- * use photon_rs::native::{open_image};
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * alter_channel(&mut img, 0_usize, 10_i16);
- * ```
- *
- * Adds a constant to a select R, G, or B channel's value.
- *
- * ### Decrease a channel's value
- * // For example, to decrease the Green channel for all pixels by 20:
- * ```no_run
- * use photon_rs::channels::alter_channel;
- * use photon_rs::native::open_image;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * alter_channel(&mut img, 1_usize, -20_i16);
- * ```
- * **Note**: Note the use of a minus symbol when decreasing the channel.
- * @param {PhotonImage} img
- * @param {number} channel
- * @param {number} amt
- */
-export function alter_channel(img, channel, amt) {
-    _assertClass(img, PhotonImage);
-    wasm.alter_channel(img.__wbg_ptr, channel, amt);
-}
-
-/**
- * Increment or decrement every pixel's Red channel by a constant.
- *
- * # Arguments
- * * `img` - A PhotonImage. See the PhotonImage struct for details.
- * * `amt` - The amount to increment or decrement the channel's value by for that pixel.
- *
- * # Example
- *
- * ```no_run
- * // For example, to increase the Red channel for all pixels by 10:
- * use photon_rs::channels::alter_red_channel;
- * use photon_rs::native::open_image;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * alter_red_channel(&mut img, 10_i16);
- * ```
- * @param {PhotonImage} photon_image
- * @param {number} amt
- */
-export function alter_red_channel(photon_image, amt) {
-    _assertClass(photon_image, PhotonImage);
-    wasm.alter_red_channel(photon_image.__wbg_ptr, amt);
-}
-
-/**
- * Increment or decrement every pixel's Green channel by a constant.
- *
- * # Arguments
- * * `img` - A PhotonImage.
- * * `amt` - The amount to increment/decrement the channel's value by for that pixel.
- *
- * # Example
- *
- * ```no_run
- * // For example, to increase the Green channel for all pixels by 20:
- * use photon_rs::channels::alter_green_channel;
- * use photon_rs::native::open_image;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * alter_green_channel(&mut img, 20_i16);
- * ```
- * @param {PhotonImage} img
- * @param {number} amt
- */
-export function alter_green_channel(img, amt) {
-    _assertClass(img, PhotonImage);
-    wasm.alter_green_channel(img.__wbg_ptr, amt);
-}
-
-/**
- * Increment or decrement every pixel's Blue channel by a constant.
- *
- * # Arguments
- * * `img` - A PhotonImage.
- * * `amt` - The amount to increment or decrement the channel's value by for that pixel.
- *
- * # Example
- *
- * ```no_run
- * // For example, to increase the Blue channel for all pixels by 10:
- * use photon_rs::channels::alter_blue_channel;
- * use photon_rs::native::open_image;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * alter_blue_channel(&mut img, 10_i16);
- * ```
- * @param {PhotonImage} img
- * @param {number} amt
- */
-export function alter_blue_channel(img, amt) {
-    _assertClass(img, PhotonImage);
-    wasm.alter_blue_channel(img.__wbg_ptr, amt);
-}
-
-/**
- * Increment/decrement two channels' values simultaneously by adding an amt to each channel per pixel.
- *
- * # Arguments
- * * `img` - A PhotonImage.
- * * `channel1` - A usize from 0 to 2 that represents either the R, G or B channels.
- * * `amt1` - The amount to increment/decrement the channel's value by for that pixel.
- * * `channel2` -A usize from 0 to 2 that represents either the R, G or B channels.
- * * `amt2` - The amount to increment/decrement the channel's value by for that pixel.
- *
- * # Example
- *
- * ```no_run
- * // For example, to increase the values of the Red and Blue channels per pixel:
- * use photon_rs::channels::alter_two_channels;
- * use photon_rs::native::open_image;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * alter_two_channels(&mut img, 0_usize, 10_i16, 2_usize, 20_i16);
- * ```
- * @param {PhotonImage} img
- * @param {number} channel1
- * @param {number} amt1
- * @param {number} channel2
- * @param {number} amt2
- */
-export function alter_two_channels(img, channel1, amt1, channel2, amt2) {
-    _assertClass(img, PhotonImage);
-    wasm.alter_two_channels(img.__wbg_ptr, channel1, amt1, channel2, amt2);
-}
-
-/**
- * Increment all 3 channels' values by adding an amt to each channel per pixel.
- *
- * # Arguments
- * * `img` - A PhotonImage.
- * * `r_amt` - The amount to increment/decrement the Red channel by.
- * * `g_amt` - The amount to increment/decrement the Green channel by.
- * * `b_amt` - The amount to increment/decrement the Blue channel by.
- *
- * # Example
- *
- * ```no_run
- * // For example, to increase the values of the Red channel by 10, the Green channel by 20,
- * // and the Blue channel by 50:
- * use photon_rs::channels::alter_channels;
- * use photon_rs::native::open_image;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * alter_channels(&mut img, 10_i16, 20_i16, 50_i16);
- * ```
- * @param {PhotonImage} img
- * @param {number} r_amt
- * @param {number} g_amt
- * @param {number} b_amt
- */
-export function alter_channels(img, r_amt, g_amt, b_amt) {
-    _assertClass(img, PhotonImage);
-    wasm.alter_channels(img.__wbg_ptr, r_amt, g_amt, b_amt);
-}
-
-/**
- * Set a certain channel to zero, thus removing the channel's influence in the pixels' final rendered colour.
- *
- * # Arguments
- * * `img` - A PhotonImage.
- * * `channel` - The channel to be removed; must be a usize from 0 to 2, with 0 representing Red, 1 representing Green, and 2 representing Blue.
- * * `min_filter` - Minimum filter. Value between 0 and 255. Only remove the channel if the current pixel's channel value is less than this minimum filter. To completely
- * remove the channel, set this value to 255, to leave the channel as is, set to 0, and to set a channel to zero for a pixel whose red value is greater than 50,
- * then channel would be 0 and min_filter would be 50.
- *
- * # Example
- *
- * ```no_run
- * // For example, to remove the Red channel with a min_filter of 100:
- * use photon_rs::channels::remove_channel;
- * use photon_rs::native::open_image;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * remove_channel(&mut img, 0_usize, 100_u8);
- * ```
- * @param {PhotonImage} img
- * @param {number} channel
- * @param {number} min_filter
- */
-export function remove_channel(img, channel, min_filter) {
-    _assertClass(img, PhotonImage);
-    wasm.remove_channel(img.__wbg_ptr, channel, min_filter);
-}
-
-/**
- * Remove the Red channel's influence in an image.
- *
- * # Arguments
- * * `img` - A PhotonImage.
- * * `min_filter` - Only remove the channel if the current pixel's channel value is less than this minimum filter.
- *
- * # Example
- *
- * ```no_run
- * // For example, to remove the red channel for red channel pixel values less than 50:
- * use photon_rs::channels::remove_red_channel;
- * use photon_rs::native::open_image;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * remove_red_channel(&mut img, 50_u8);
- * ```
- * @param {PhotonImage} img
- * @param {number} min_filter
- */
-export function remove_red_channel(img, min_filter) {
-    _assertClass(img, PhotonImage);
-    wasm.remove_red_channel(img.__wbg_ptr, min_filter);
-}
-
-/**
- * Remove the Green channel's influence in an image.
- *
- * # Arguments
- * * `img` - A PhotonImage.
- * * `min_filter` - Only remove the channel if the current pixel's channel value is less than this minimum filter.
- *
- * # Example
- *
- * ```no_run
- * // For example, to remove the green channel for green channel pixel values less than 50:
- * use photon_rs::channels::remove_green_channel;
- * use photon_rs::native::open_image;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * remove_green_channel(&mut img, 50_u8);
- * ```
- * @param {PhotonImage} img
- * @param {number} min_filter
- */
-export function remove_green_channel(img, min_filter) {
-    _assertClass(img, PhotonImage);
-    wasm.remove_green_channel(img.__wbg_ptr, min_filter);
-}
-
-/**
- * Remove the Blue channel's influence in an image.
- *
- * # Arguments
- * * `img` - A PhotonImage.
- * * `min_filter` - Only remove the channel if the current pixel's channel value is less than this minimum filter.
- *
- * # Example
- *
- * ```no_run
- * // For example, to remove the blue channel for blue channel pixel values less than 50:
- * use photon_rs::channels::remove_blue_channel;
- * use photon_rs::native::open_image;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * remove_blue_channel(&mut img, 50_u8);
- * ```
- * @param {PhotonImage} img
- * @param {number} min_filter
- */
-export function remove_blue_channel(img, min_filter) {
-    _assertClass(img, PhotonImage);
-    wasm.remove_blue_channel(img.__wbg_ptr, min_filter);
-}
-
-/**
- * Swap two channels.
- *
- * # Arguments
- * * `img` - A PhotonImage.
- * * `channel1` - An index from 0 to 2, representing the Red, Green or Blue channels respectively. Red would be represented by 0, Green by 1, and Blue by 2.
- * * `channel2` - An index from 0 to 2, representing the Red, Green or Blue channels respectively. Same as above.
- *
- * # Example
- *
- * ```no_run
- * // For example, to swap the values of the Red channel with the values of the Blue channel:
- * use photon_rs::channels::swap_channels;
- * use photon_rs::native::open_image;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * swap_channels(&mut img, 0_usize, 2_usize);
- * ```
- * @param {PhotonImage} img
- * @param {number} channel1
- * @param {number} channel2
- */
-export function swap_channels(img, channel1, channel2) {
-    _assertClass(img, PhotonImage);
-    wasm.swap_channels(img.__wbg_ptr, channel1, channel2);
-}
-
-/**
- * Invert RGB value of an image.
- *
- * # Arguments
- * * `photon_image` - A DynamicImage that contains a view into the image.
- * # Example
- *
- * ```no_run
- * use photon_rs::channels::invert;
- * use photon_rs::native::open_image;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * invert(&mut img);
- * ```
- * @param {PhotonImage} photon_image
- */
-export function invert(photon_image) {
-    _assertClass(photon_image, PhotonImage);
-    wasm.invert(photon_image.__wbg_ptr);
-}
-
-/**
- * Selective hue rotation.
- *
- * Only rotate the hue of a pixel if its RGB values are within a specified range.
- * This function only rotates a pixel's hue to another  if it is visually similar to the colour specified.
- * For example, if a user wishes all pixels that are blue to be changed to red, they can selectively specify  only the blue pixels to be changed.
- * # Arguments
- * * `img` - A PhotonImage.
- * * `ref_color` - The `RGB` value of the reference color (to be compared to)
- * * `degrees` - The amount of degrees to hue rotate by.
- *
- * # Example
- *
- * ```no_run
- * // For example, to only rotate the pixels that are of RGB value RGB{20, 40, 60}:
- * use photon_rs::Rgb;
- * use photon_rs::channels::selective_hue_rotate;
- * use photon_rs::native::open_image;
- *
- * let ref_color = Rgb::new(20_u8, 40_u8, 60_u8);
- * let mut img = open_image("img.jpg").expect("File should open");
- * selective_hue_rotate(&mut img, ref_color, 180_f32);
- * ```
- * @param {PhotonImage} photon_image
- * @param {Rgb} ref_color
- * @param {number} degrees
- */
-export function selective_hue_rotate(photon_image, ref_color, degrees) {
-    _assertClass(photon_image, PhotonImage);
-    _assertClass(ref_color, Rgb);
-    var ptr0 = ref_color.__destroy_into_raw();
-    wasm.selective_hue_rotate(photon_image.__wbg_ptr, ptr0, degrees);
-}
-
-/**
- * Selectively change pixel colours which are similar to the reference colour provided.
- *
- * Similarity between two colours is calculated via the CIE76 formula.
- * Only changes the color of a pixel if its similarity to the reference colour is within the range in the algorithm.
- * For example, with this function, a user can change the color of all blue pixels by mixing them with red by 10%.
- * # Arguments
- * * `photon_image` - A PhotonImage.
- * * `ref_color` - The `RGB` value of the reference color (to be compared to)
- * * `new_color` - The `RGB` value of the new color (to be mixed with the matched pixels)
- * * `fraction` - The amount of mixing the new colour with the matched pixels
- *
- * # Example
- *
- * ```no_run
- * // For example, to only change the color of pixels that are similar to the RGB value RGB{200, 120, 30} by mixing RGB{30, 120, 200} with 25%:
- * use photon_rs::Rgb;
- * use photon_rs::channels::selective_color_convert;
- * use photon_rs::native::open_image;
- *
- * let ref_color = Rgb::new(200, 120, 30);
- * let new_color = Rgb::new(30, 120, 200);
- * let mut img = open_image("img.jpg").expect("File should open");
- * selective_color_convert(&mut img, ref_color, new_color, 0.25);
- * ```
- * @param {PhotonImage} photon_image
- * @param {Rgb} ref_color
- * @param {Rgb} new_color
- * @param {number} fraction
- */
-export function selective_color_convert(photon_image, ref_color, new_color, fraction) {
-    _assertClass(photon_image, PhotonImage);
-    _assertClass(ref_color, Rgb);
-    var ptr0 = ref_color.__destroy_into_raw();
-    _assertClass(new_color, Rgb);
-    var ptr1 = new_color.__destroy_into_raw();
-    wasm.selective_color_convert(photon_image.__wbg_ptr, ptr0, ptr1, fraction);
-}
-
-/**
- * Selectively lighten an image.
- *
- * Only lighten the hue of a pixel if its colour matches or is similar to the RGB colour specified.
- * For example, if a user wishes all pixels that are blue to be lightened, they can selectively specify  only the blue pixels to be changed.
- * # Arguments
- * * `img` - A PhotonImage.
- * * `ref_color` - The `RGB` value of the reference color (to be compared to)
- * * `amt` - The level from 0 to 1 to lighten the hue by. Increasing by 10% would have an `amt` of 0.1
- *
- * # Example
- *
- * ```no_run
- * // For example, to only lighten the pixels that are of or similar to RGB value RGB{20, 40, 60}:
- * use photon_rs::Rgb;
- * use photon_rs::channels::selective_lighten;
- * use photon_rs::native::open_image;
- *
- * let ref_color = Rgb::new(20_u8, 40_u8, 60_u8);
- * let mut img = open_image("img.jpg").expect("File should open");
- * selective_lighten(&mut img, ref_color, 0.2_f32);
- * ```
- * @param {PhotonImage} img
- * @param {Rgb} ref_color
- * @param {number} amt
- */
-export function selective_lighten(img, ref_color, amt) {
-    _assertClass(img, PhotonImage);
-    _assertClass(ref_color, Rgb);
-    var ptr0 = ref_color.__destroy_into_raw();
-    wasm.selective_lighten(img.__wbg_ptr, ptr0, amt);
-}
-
-/**
- * Selectively desaturate pixel colours which are similar to the reference colour provided.
- *
- * Similarity between two colours is calculated via the CIE76 formula.
- * Only desaturates the hue of a pixel if its similarity to the reference colour is within the range in the algorithm.
- * For example, if a user wishes all pixels that are blue to be desaturated by 0.1, they can selectively specify  only the blue pixels to be changed.
- * # Arguments
- * * `img` - A PhotonImage.
- * * `ref_color` - The `RGB` value of the reference color (to be compared to)
- * * `amt` - The amount of desaturate the colour by.
- *
- * # Example
- *
- * ```no_run
- * // For example, to only desaturate the pixels that are similar to the RGB value RGB{20, 40, 60}:
- * use photon_rs::Rgb;
- * use photon_rs::channels::selective_desaturate;
- * use photon_rs::native::open_image;
- *
- * let ref_color = Rgb::new(20_u8, 40_u8, 60_u8);
- * let mut img = open_image("img.jpg").expect("File should open");
- * selective_desaturate(&mut img, ref_color, 0.1_f32);
- * ```
- * @param {PhotonImage} img
- * @param {Rgb} ref_color
- * @param {number} amt
- */
-export function selective_desaturate(img, ref_color, amt) {
-    _assertClass(img, PhotonImage);
-    _assertClass(ref_color, Rgb);
-    var ptr0 = ref_color.__destroy_into_raw();
-    wasm.selective_desaturate(img.__wbg_ptr, ptr0, amt);
-}
-
-/**
- * Selectively saturate pixel colours which are similar to the reference colour provided.
- *
- * Similarity between two colours is calculated via the CIE76 formula.
- * Only saturates the hue of a pixel if its similarity to the reference colour is within the range in the algorithm.
- * For example, if a user wishes all pixels that are blue to have an increase in saturation by 10%, they can selectively specify only the blue pixels to be changed.
- * # Arguments
- * * `img` - A PhotonImage.
- * * `ref_color` - The `RGB` value of the reference color (to be compared to)
- * * `amt` - The amount of saturate the colour by.
- *
- * # Example
- *
- * ```no_run
- * // For example, to only increase the saturation of pixels that are similar to the RGB value RGB{20, 40, 60}:
- * use photon_rs::Rgb;
- * use photon_rs::channels::selective_saturate;
- * use photon_rs::native::open_image;
- *
- * let ref_color = Rgb::new(20_u8, 40_u8, 60_u8);
- * let mut img = open_image("img.jpg").expect("File should open");
- * selective_saturate(&mut img, ref_color, 0.1_f32);
- * ```
- * @param {PhotonImage} img
- * @param {Rgb} ref_color
- * @param {number} amt
- */
-export function selective_saturate(img, ref_color, amt) {
-    _assertClass(img, PhotonImage);
-    _assertClass(ref_color, Rgb);
-    var ptr0 = ref_color.__destroy_into_raw();
-    wasm.selective_saturate(img.__wbg_ptr, ptr0, amt);
-}
-
-/**
- * Selectively changes a pixel to greyscale if it is *not* visually similar or close to the colour specified.
- * Only changes the colour of a pixel if its RGB values are within a specified range.
- *
- * (Similarity between two colours is calculated via the CIE76 formula.)
- * For example, if a user wishes all pixels that are *NOT* blue to be displayed in greyscale, they can selectively specify only the blue pixels to be
- * kept in the photo.
- * # Arguments
- * * `img` - A PhotonImage.
- * * `ref_color` - The `RGB` value of the reference color (to be compared to)
- *
- * # Example
- *
- * ```no_run
- * // For example, to greyscale all pixels that are *not* visually similar to the RGB colour RGB{20, 40, 60}:
- * use photon_rs::Rgb;
- * use photon_rs::channels::selective_greyscale;
- * use photon_rs::native::open_image;
- *
- * let ref_color = Rgb::new(20_u8, 40_u8, 60_u8);
- * let mut img = open_image("img.jpg").expect("File should open");
- * selective_greyscale(img, ref_color);
- * ```
- * @param {PhotonImage} photon_image
- * @param {Rgb} ref_color
- */
-export function selective_greyscale(photon_image, ref_color) {
-    _assertClass(photon_image, PhotonImage);
-    var ptr0 = photon_image.__destroy_into_raw();
-    _assertClass(ref_color, Rgb);
-    var ptr1 = ref_color.__destroy_into_raw();
-    wasm.selective_greyscale(ptr0, ptr1);
-}
-
-/**
- * Applies gamma correction to an image.
- * # Arguments
- * * `photon_image` - A PhotonImage that contains a view into the image.
- * * `red` - Gamma value for red channel.
- * * `green` - Gamma value for green channel.
- * * `blue` - Gamma value for blue channel.
- * # Example
- *
- * ```no_run
- * // For example, to turn an image of type `PhotonImage` into a gamma corrected image:
- * use photon_rs::colour_spaces::gamma_correction;
- * use photon_rs::native::open_image;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * gamma_correction(&mut img, 2.2, 2.2, 2.2);
- * ```
- * @param {PhotonImage} photon_image
- * @param {number} red
- * @param {number} green
- * @param {number} blue
- */
-export function gamma_correction(photon_image, red, green, blue) {
-    _assertClass(photon_image, PhotonImage);
-    wasm.gamma_correction(photon_image.__wbg_ptr, red, green, blue);
-}
-
-/**
- * Image manipulation effects in the HSLuv colour space
- *
- * Effects include:
- * * **saturate** - Saturation increase.
- * * **desaturate** - Desaturate the image.
- * * **shift_hue** - Hue rotation by a specified number of degrees.
- * * **darken** - Decrease the brightness.
- * * **lighten** - Increase the brightness.
- *
- * # Arguments
- * * `photon_image` - A PhotonImage.
- * * `mode` - The effect desired to be applied. Choose from: `saturate`, `desaturate`, `shift_hue`, `darken`, `lighten`
- * * `amt` - A float value from 0 to 1 which represents the amount the effect should be increased by.
- * # Example
- * ```no_run
- * // For example to increase the saturation by 10%:
- * use photon_rs::colour_spaces::hsluv;
- * use photon_rs::native::open_image;
- *
- * // Open the image. A PhotonImage is returned.
- * let mut img = open_image("img.jpg").expect("File should open");
- * hsluv(&mut img, "saturate", 0.1_f32);
- * ```
- * @param {PhotonImage} photon_image
- * @param {string} mode
- * @param {number} amt
- */
-export function hsluv(photon_image, mode, amt) {
-    _assertClass(photon_image, PhotonImage);
-    const ptr0 = passStringToWasm0(mode, wasm.__wbindgen_export_1, wasm.__wbindgen_export_3);
-    const len0 = WASM_VECTOR_LEN;
-    wasm.hsluv(photon_image.__wbg_ptr, ptr0, len0, amt);
-}
-
-/**
- * Image manipulation effects in the LCh colour space
- *
- * Effects include:
- * * **saturate** - Saturation increase.
- * * **desaturate** - Desaturate the image.
- * * **shift_hue** - Hue rotation by a specified number of degrees.
- * * **darken** - Decrease the brightness.
- * * **lighten** - Increase the brightness.
- *
- * # Arguments
- * * `photon_image` - A PhotonImage.
- * * `mode` - The effect desired to be applied. Choose from: `saturate`, `desaturate`, `shift_hue`, `darken`, `lighten`
- * * `amt` - A float value from 0 to 1 which represents the amount the effect should be increased by.
- * # Example
- * ```no_run
- * // For example to increase the saturation by 10%:
- * use photon_rs::colour_spaces::lch;
- * use photon_rs::native::open_image;
- *
- * // Open the image. A PhotonImage is returned.
- * let mut img = open_image("img.jpg").expect("File should open");
- * lch(&mut img, "saturate", 0.1_f32);
- * ```
- * @param {PhotonImage} photon_image
- * @param {string} mode
- * @param {number} amt
- */
-export function lch(photon_image, mode, amt) {
-    _assertClass(photon_image, PhotonImage);
-    const ptr0 = passStringToWasm0(mode, wasm.__wbindgen_export_1, wasm.__wbindgen_export_3);
-    const len0 = WASM_VECTOR_LEN;
-    wasm.lch(photon_image.__wbg_ptr, ptr0, len0, amt);
-}
-
-/**
- * Image manipulation effects in the HSL colour space.
- *
- * Effects include:
- * * **saturate** - Saturation increase.
- * * **desaturate** - Desaturate the image.
- * * **shift_hue** - Hue rotation by a specified number of degrees.
- * * **darken** - Decrease the brightness.
- * * **lighten** - Increase the brightness.
- *
- * # Arguments
- * * `photon_image` - A PhotonImage.
- * * `mode` - The effect desired to be applied. Choose from: `saturate`, `desaturate`, `shift_hue`, `darken`, `lighten`
- * * `amt` - A float value from 0 to 1 which represents the amount the effect should be increased by.
- * # Example
- * ```no_run
- * // For example to increase the saturation by 10%:
- * use photon_rs::colour_spaces::hsl;
- * use photon_rs::native::open_image;
- *
- * // Open the image. A PhotonImage is returned.
- * let mut img = open_image("img.jpg").expect("File should open");
- * hsl(&mut img, "saturate", 0.1_f32);
- * ```
- * @param {PhotonImage} photon_image
- * @param {string} mode
- * @param {number} amt
- */
-export function hsl(photon_image, mode, amt) {
-    _assertClass(photon_image, PhotonImage);
-    const ptr0 = passStringToWasm0(mode, wasm.__wbindgen_export_1, wasm.__wbindgen_export_3);
-    const len0 = WASM_VECTOR_LEN;
-    wasm.hsl(photon_image.__wbg_ptr, ptr0, len0, amt);
-}
-
-/**
- * Image manipulation in the HSV colour space.
- *
- * Effects include:
- * * **saturate** - Saturation increase.
- * * **desaturate** - Desaturate the image.
- * * **shift_hue** - Hue rotation by a specified number of degrees.
- * * **darken** - Decrease the brightness.
- * * **lighten** - Increase the brightness.
- *
- * # Arguments
- * * `photon_image` - A PhotonImage.
- * * `mode` - The effect desired to be applied. Choose from: `saturate`, `desaturate`, `shift_hue`, `darken`, `lighten`
- * * `amt` - A float value from 0 to 1 which represents the amount the effect should be increased by.
- *
- * # Example
- * ```no_run
- * // For example to increase the saturation by 10%:
- * use photon_rs::colour_spaces::hsv;
- * use photon_rs::native::open_image;
- *
- * // Open the image. A PhotonImage is returned.
- * let mut img = open_image("img.jpg").expect("File should open");
- * hsv(&mut img, "saturate", 0.1_f32);
- * ```
- * @param {PhotonImage} photon_image
- * @param {string} mode
- * @param {number} amt
- */
-export function hsv(photon_image, mode, amt) {
-    _assertClass(photon_image, PhotonImage);
-    const ptr0 = passStringToWasm0(mode, wasm.__wbindgen_export_1, wasm.__wbindgen_export_3);
-    const len0 = WASM_VECTOR_LEN;
-    wasm.hsv(photon_image.__wbg_ptr, ptr0, len0, amt);
-}
-
-/**
- * Shift hue by a specified number of degrees in the HSL colour space.
- * # Arguments
- * * `img` - A PhotonImage.
- * * `mode` - A float value from 0 to 1 which is the amount to shift the hue by, or hue rotate by.
- *
- * # Example
- * ```no_run
- * // For example to hue rotate/shift the hue by 120 degrees in the HSL colour space:
- * use photon_rs::colour_spaces::hue_rotate_hsl;
- * use photon_rs::native::open_image;
- *
- * // Open the image. A PhotonImage is returned.
- * let mut img = open_image("img.jpg").expect("File should open");
- * hue_rotate_hsl(&mut img, 120_f32);
- * ```
- * @param {PhotonImage} img
- * @param {number} degrees
- */
-export function hue_rotate_hsl(img, degrees) {
-    _assertClass(img, PhotonImage);
-    wasm.hue_rotate_hsl(img.__wbg_ptr, degrees);
-}
-
-/**
- * Shift hue by a specified number of degrees in the HSV colour space.
- * # Arguments
- * * `img` - A PhotonImage.
- * * `mode` - A float value from 0 to 1 which is the amount to shift the hue by, or hue rotate by.
- *
- * # Example
- * ```no_run
- * // For example to hue rotate/shift the hue by 120 degrees in the HSV colour space:
- * use photon_rs::colour_spaces::hue_rotate_hsv;
- * use photon_rs::native::open_image;
- *
- * // Open the image. A PhotonImage is returned.
- * let mut img = open_image("img.jpg").expect("File should open");
- * hue_rotate_hsv(&mut img, 120_f32);
- * ```
- * @param {PhotonImage} img
- * @param {number} degrees
- */
-export function hue_rotate_hsv(img, degrees) {
-    _assertClass(img, PhotonImage);
-    wasm.hue_rotate_hsv(img.__wbg_ptr, degrees);
-}
-
-/**
- * Shift hue by a specified number of degrees in the LCh colour space.
- * # Arguments
- * * `img` - A PhotonImage.
- * * `mode` - A float value from 0 to 1 which is the amount to shift the hue by, or hue rotate by.
- *
- * # Example
- * ```no_run
- * // For example to hue rotate/shift the hue by 120 degrees in the HSL colour space:
- * use photon_rs::colour_spaces::hue_rotate_lch;
- * use photon_rs::native::open_image;
- *
- * // Open the image. A PhotonImage is returned.
- * let mut img = open_image("img.jpg").expect("File should open");
- * hue_rotate_lch(&mut img, 120_f32);
- * ```
- * @param {PhotonImage} img
- * @param {number} degrees
- */
-export function hue_rotate_lch(img, degrees) {
-    _assertClass(img, PhotonImage);
-    wasm.hue_rotate_lch(img.__wbg_ptr, degrees);
-}
-
-/**
- * Shift hue by a specified number of degrees in the HSLuv colour space.
- * # Arguments
- * * `img` - A PhotonImage.
- * * `mode` - A float value from 0 to 1 which is the amount to shift the hue by, or hue rotate by.
- *
- * # Example
- * ```no_run
- * // For example to hue rotate/shift the hue by 120 degrees in the HSL colour space:
- * use photon_rs::colour_spaces::hue_rotate_hsluv;
- * use photon_rs::native::open_image;
- *
- * // Open the image. A PhotonImage is returned.
- * let mut img = open_image("img.jpg").expect("File should open");
- * hue_rotate_hsluv(&mut img, 120_f32);
- * ```
- * @param {PhotonImage} img
- * @param {number} degrees
- */
-export function hue_rotate_hsluv(img, degrees) {
-    _assertClass(img, PhotonImage);
-    wasm.hue_rotate_hsluv(img.__wbg_ptr, degrees);
-}
-
-/**
- * Increase the image's saturation by converting each pixel's colour to the HSL colour space
- * and increasing the colour's saturation.
- * # Arguments
- * * `img` - A PhotonImage.
- * * `level` - Float value from 0 to 1 representing the level to which to increase the saturation by.
- * The `level` must be from 0 to 1 in floating-point, `f32` format.
- * Increasing saturation by 80% would be represented by a `level` of 0.8
- *
- * # Example
- * ```no_run
- * // For example to increase saturation by 10% in the HSL colour space:
- * use photon_rs::colour_spaces::saturate_hsl;
- * use photon_rs::native::open_image;
- *
- * // Open the image. A PhotonImage is returned.
- * let mut img = open_image("img.jpg").expect("File should open");
- * saturate_hsl(&mut img, 0.1_f32);
- * ```
- * @param {PhotonImage} img
- * @param {number} level
- */
-export function saturate_hsl(img, level) {
-    _assertClass(img, PhotonImage);
-    wasm.saturate_hsl(img.__wbg_ptr, level);
-}
-
-/**
- * Increase the image's saturation in the LCh colour space.
- * # Arguments
- * * `img` - A PhotonImage.
- * * `level` - Float value from 0 to 1 representing the level to which to increase the saturation by.
- * The `level` must be from 0 to 1 in floating-point, `f32` format.
- * Increasing saturation by 80% would be represented by a `level` of 0.8
- *
- * # Example
- * ```no_run
- * // For example to increase saturation by 40% in the Lch colour space:
- * use photon_rs::colour_spaces::saturate_lch;
- * use photon_rs::native::open_image;
- *
- * // Open the image. A PhotonImage is returned.
- * let mut img = open_image("img.jpg").expect("File should open");
- * saturate_lch(&mut img, 0.4_f32);
- * ```
- * @param {PhotonImage} img
- * @param {number} level
- */
-export function saturate_lch(img, level) {
-    _assertClass(img, PhotonImage);
-    wasm.saturate_lch(img.__wbg_ptr, level);
-}
-
-/**
- * Increase the image's saturation in the HSLuv colour space.
- * # Arguments
- * * `img` - A PhotonImage.
- * * `level` - Float value from 0 to 1 representing the level to which to increase the saturation by.
- * The `level` must be from 0 to 1 in floating-point, `f32` format.
- * Increasing saturation by 80% would be represented by a `level` of 0.8
- *
- * # Example
- * ```no_run
- * // For example to increase saturation by 40% in the HSLuv colour space:
- * use photon_rs::colour_spaces::saturate_hsluv;
- * use photon_rs::native::open_image;
- *
- * // Open the image. A PhotonImage is returned.
- * let mut img = open_image("img.jpg").expect("File should open");
- * saturate_hsluv(&mut img, 0.4_f32);
- * ```
- * @param {PhotonImage} img
- * @param {number} level
- */
-export function saturate_hsluv(img, level) {
-    _assertClass(img, PhotonImage);
-    wasm.saturate_hsluv(img.__wbg_ptr, level);
-}
-
-/**
- * Increase the image's saturation in the HSV colour space.
- * # Arguments
- * * `img` - A PhotonImage.
- * * `level` - Float value from 0 to 1 representing the level by which to increase the saturation by.
- * The `level` must be from 0 to 1 in floating-point, `f32` format.
- * Increasing saturation by 80% would be represented by a `level` of 0.8
- *
- * # Example
- * ```no_run
- * // For example to increase saturation by 30% in the HSV colour space:
- * use photon_rs::colour_spaces::saturate_hsv;
- * use photon_rs::native::open_image;
- *
- * // Open the image. A PhotonImage is returned.
- * let mut img = open_image("img.jpg").expect("File should open");
- * saturate_hsv(&mut img, 0.3_f32);
- * ```
- * @param {PhotonImage} img
- * @param {number} level
- */
-export function saturate_hsv(img, level) {
-    _assertClass(img, PhotonImage);
-    wasm.saturate_hsv(img.__wbg_ptr, level);
-}
-
-/**
- * Lighten an image by a specified amount in the LCh colour space.
- *
- * # Arguments
- * * `img` - A PhotonImage.
- * * `level` - Float value from 0 to 1 representing the level to which to lighten the image by.
- * The `level` must be from 0 to 1 in floating-point, `f32` format.
- * Lightening by 80% would be represented by a `level` of 0.8
- *
- * # Example
- * ```no_run
- * // For example to lighten an image by 10% in the LCh colour space:
- * use photon_rs::colour_spaces::lighten_lch;
- * use photon_rs::native::open_image;
- *
- * // Open the image. A PhotonImage is returned.
- * let mut img = open_image("img.jpg").expect("File should open");
- * lighten_lch(&mut img, 0.1_f32);
- * ```
- * @param {PhotonImage} img
- * @param {number} level
- */
-export function lighten_lch(img, level) {
-    _assertClass(img, PhotonImage);
-    wasm.lighten_lch(img.__wbg_ptr, level);
-}
-
-/**
- * Lighten an image by a specified amount in the HSLuv colour space.
- *
- * # Arguments
- * * `img` - A PhotonImage.
- * * `level` - Float value from 0 to 1 representing the level to which to lighten the image by.
- * The `level` must be from 0 to 1 in floating-point, `f32` format.
- * Lightening by 80% would be represented by a `level` of 0.8
- *
- * # Example
- * ```no_run
- * // For example to lighten an image by 10% in the HSLuv colour space:
- * use photon_rs::colour_spaces::lighten_hsluv;
- * use photon_rs::native::open_image;
- *
- * // Open the image. A PhotonImage is returned.
- * let mut img = open_image("img.jpg").expect("File should open");
- * lighten_hsluv(&mut img, 0.1_f32);
- * ```
- * @param {PhotonImage} img
- * @param {number} level
- */
-export function lighten_hsluv(img, level) {
-    _assertClass(img, PhotonImage);
-    wasm.lighten_hsluv(img.__wbg_ptr, level);
-}
-
-/**
- * Lighten an image by a specified amount in the HSL colour space.
- * # Arguments
- * * `img` - A PhotonImage.
- * * `level` - Float value from 0 to 1 representing the level to which to lighten the image by.
- * The `level` must be from 0 to 1 in floating-point, `f32` format.
- * Lightening by 80% would be represented by a `level` of 0.8
- *
- * # Example
- * ```no_run
- * // For example to lighten an image by 10% in the HSL colour space:
- * use photon_rs::colour_spaces::lighten_hsl;
- * use photon_rs::native::open_image;
- *
- * // Open the image. A PhotonImage is returned.
- * let mut img = open_image("img.jpg").expect("File should open");
- * lighten_hsl(&mut img, 0.1_f32);
- * ```
- * @param {PhotonImage} img
- * @param {number} level
- */
-export function lighten_hsl(img, level) {
-    _assertClass(img, PhotonImage);
-    wasm.lighten_hsl(img.__wbg_ptr, level);
-}
-
-/**
- * Lighten an image by a specified amount in the HSV colour space.
- *
- * # Arguments
- * * `img` - A PhotonImage.
- * * `level` - Float value from 0 to 1 representing the level to which to lighten the image by.
- * The `level` must be from 0 to 1 in floating-point, `f32` format.
- * Lightening by 80% would be represented by a `level` of 0.8
- *
- * # Example
- * ```no_run
- * // For example to lighten an image by 10% in the HSV colour space:
- * use photon_rs::colour_spaces::lighten_hsv;
- * use photon_rs::native::open_image;
- *
- * // Open the image. A PhotonImage is returned.
- * let mut img = open_image("img.jpg").expect("File should open");
- * lighten_hsv(&mut img, 0.1_f32);
- * ```
- * @param {PhotonImage} img
- * @param {number} level
- */
-export function lighten_hsv(img, level) {
-    _assertClass(img, PhotonImage);
-    wasm.lighten_hsv(img.__wbg_ptr, level);
-}
-
-/**
- * Darken the image by a specified amount in the LCh colour space.
- *
- * # Arguments
- * * `img` - A PhotonImage.
- * * `level` - Float value from 0 to 1 representing the level to which to darken the image by.
- * The `level` must be from 0 to 1 in floating-point, `f32` format.
- * Darkening by 80% would be represented by a `level` of 0.8
- *
- * # Example
- * ```no_run
- * // For example to darken an image by 10% in the LCh colour space:
- * use photon_rs::colour_spaces::darken_lch;
- * use photon_rs::native::open_image;
- *
- * // Open the image. A PhotonImage is returned.
- * let mut img = open_image("img.jpg").expect("File should open");
- * darken_lch(&mut img, 0.1_f32);
- * ```
- * @param {PhotonImage} img
- * @param {number} level
- */
-export function darken_lch(img, level) {
-    _assertClass(img, PhotonImage);
-    wasm.darken_lch(img.__wbg_ptr, level);
-}
-
-/**
- * Darken the image by a specified amount in the HSLuv colour space.
- *
- * # Arguments
- * * `img` - A PhotonImage.
- * * `level` - Float value from 0 to 1 representing the level to which to darken the image by.
- * The `level` must be from 0 to 1 in floating-point, `f32` format.
- * Darkening by 80% would be represented by a `level` of 0.8
- *
- * # Example
- * ```no_run
- * // For example to darken an image by 10% in the HSLuv colour space:
- * use photon_rs::colour_spaces::darken_hsluv;
- * use photon_rs::native::open_image;
- *
- * // Open the image. A PhotonImage is returned.
- * let mut img = open_image("img.jpg").expect("File should open");
- * darken_hsluv(&mut img, 0.1_f32);
- * ```
- * @param {PhotonImage} img
- * @param {number} level
- */
-export function darken_hsluv(img, level) {
-    _assertClass(img, PhotonImage);
-    wasm.darken_hsluv(img.__wbg_ptr, level);
-}
-
-/**
- * Darken the image by a specified amount in the HSL colour space.
- *
- * # Arguments
- * * `img` - A PhotonImage.
- * * `level` - Float value from 0 to 1 representing the level to which to darken the image by.
- * The `level` must be from 0 to 1 in floating-point, `f32` format.
- * Darkening by 80% would be represented by a `level` of 0.8
- *
- * # Example
- * ```no_run
- * // For example to darken an image by 10% in the HSL colour space:
- * use photon_rs::colour_spaces::darken_hsl;
- * use photon_rs::native::open_image;
- *
- * // Open the image. A PhotonImage is returned.
- * let mut img = open_image("img.jpg").expect("File should open");
- * darken_hsl(&mut img, 0.1_f32);
- * ```
- * @param {PhotonImage} img
- * @param {number} level
- */
-export function darken_hsl(img, level) {
-    _assertClass(img, PhotonImage);
-    wasm.darken_hsl(img.__wbg_ptr, level);
-}
-
-/**
- * Darken the image's colours by a specified amount in the HSV colour space.
- *
- * # Arguments
- * * `img` - A PhotonImage.
- * * `level` - Float value from 0 to 1 representing the level to which to darken the image by.
- * The `level` must be from 0 to 1 in floating-point, `f32` format.
- * Darkening by 80% would be represented by a `level` of 0.8
- *
- * # Example
- * ```no_run
- * // For example to darken an image by 10% in the HSV colour space:
- * use photon_rs::colour_spaces::darken_hsv;
- * use photon_rs::native::open_image;
- *
- * // Open the image. A PhotonImage is returned.
- * let mut img = open_image("img.jpg").expect("File should open");
- * darken_hsv(&mut img, 0.1_f32);
- * ```
- * @param {PhotonImage} img
- * @param {number} level
- */
-export function darken_hsv(img, level) {
-    _assertClass(img, PhotonImage);
-    wasm.darken_hsv(img.__wbg_ptr, level);
-}
-
-/**
- * Desaturate the image by a specified amount in the HSV colour space.
- *
- * # Arguments
- * * `img` - A PhotonImage.
- * * `level` - Float value from 0 to 1 representing the level to which to desaturate the image by.
- * The `level` must be from 0 to 1 in floating-point, `f32` format.
- * Desaturating by 80% would be represented by a `level` of 0.8
- *
- * # Example
- * ```no_run
- * // For example to desaturate an image by 10% in the HSV colour space:
- * use photon_rs::colour_spaces::desaturate_hsv;
- * use photon_rs::native::open_image;
- *
- * // Open the image. A PhotonImage is returned.
- * let mut img = open_image("img.jpg").expect("File should open");
- * desaturate_hsv(&mut img, 0.1_f32);
- * ```
- * @param {PhotonImage} img
- * @param {number} level
- */
-export function desaturate_hsv(img, level) {
-    _assertClass(img, PhotonImage);
-    wasm.desaturate_hsv(img.__wbg_ptr, level);
-}
-
-/**
- * Desaturate the image by a specified amount in the HSL colour space.
- *
- * # Arguments
- * * `img` - A PhotonImage.
- * * `level` - Float value from 0 to 1 representing the level to which to desaturate the image by.
- * The `level` must be from 0 to 1 in floating-point, `f32` format.
- * Desaturating by 80% would be represented by a `level` of 0.8
- *
- * # Example
- * ```no_run
- * // For example to desaturate an image by 10% in the LCh colour space:
- * use photon_rs::colour_spaces::desaturate_hsl;
- * use photon_rs::native::open_image;
- *
- * // Open the image. A PhotonImage is returned.
- * let mut img = open_image("img.jpg").expect("File should open");
- * desaturate_hsl(&mut img, 0.1_f32);
- * ```
- * @param {PhotonImage} img
- * @param {number} level
- */
-export function desaturate_hsl(img, level) {
-    _assertClass(img, PhotonImage);
-    wasm.desaturate_hsl(img.__wbg_ptr, level);
-}
-
-/**
- * Desaturate the image by a specified amount in the LCh colour space.
- *
- * # Arguments
- * * `img` - A PhotonImage.
- * * `level` - Float value from 0 to 1 representing the level to which to desaturate the image by.
- * The `level` must be from 0 to 1 in floating-point, `f32` format.
- * Desaturating by 80% would be represented by a `level` of 0.8
- *
- * # Example
- * ```no_run
- * // For example to desaturate an image by 10% in the LCh colour space:
- * use photon_rs::colour_spaces::desaturate_lch;
- * use photon_rs::native::open_image;
- *
- * // Open the image. A PhotonImage is returned.
- * let mut img = open_image("img.jpg").expect("File should open");
- * desaturate_lch(&mut img, 0.1_f32);
- * ```
- * @param {PhotonImage} img
- * @param {number} level
- */
-export function desaturate_lch(img, level) {
-    _assertClass(img, PhotonImage);
-    wasm.desaturate_lch(img.__wbg_ptr, level);
-}
-
-/**
- * Desaturate the image by a specified amount in the HSLuv colour space.
- *
- * # Arguments
- * * `img` - A PhotonImage.
- * * `level` - Float value from 0 to 1 representing the level to which to desaturate the image by.
- * The `level` must be from 0 to 1 in floating-point, `f32` format.
- * Desaturating by 80% would be represented by a `level` of 0.8
- *
- * # Example
- * ```no_run
- * // For example to desaturate an image by 10% in the HSLuv colour space:
- * use photon_rs::colour_spaces::desaturate_hsluv;
- * use photon_rs::native::open_image;
- *
- * // Open the image. A PhotonImage is returned.
- * let mut img = open_image("img.jpg").expect("File should open");
- * desaturate_hsluv(&mut img, 0.1_f32);
- * ```
- * @param {PhotonImage} img
- * @param {number} level
- */
-export function desaturate_hsluv(img, level) {
-    _assertClass(img, PhotonImage);
-    wasm.desaturate_hsluv(img.__wbg_ptr, level);
-}
-
-/**
- * Mix image with a single color, supporting passing `opacity`.
- * The algorithm comes from Jimp. See `function mix` and `function colorFn` at following link:
- * https://github.com/oliver-moran/jimp/blob/29679faa597228ff2f20d34c5758e4d2257065a3/packages/plugin-color/src/index.js
- * Specifically, result_value = (mix_color_value - origin_value) * opacity + origin_value =
- * mix_color_value * opacity + (1 - opacity) * origin_value for each
- * of RGB channel.
- *
- * # Arguments
- * * `photon_image` - A PhotonImage that contains a view into the image.
- * * `mix_color` - the color to be mixed in, as an RGB value.
- * * `opacity` - the opacity of color when mixed to image. Float value from 0 to 1.
- * # Example
- *
- * ```no_run
- * // For example, to mix an image with rgb (50, 255, 254) and opacity 0.4:
- * use photon_rs::Rgb;
- * use photon_rs::colour_spaces::mix_with_colour;
- * use photon_rs::native::open_image;
- *
- * let mix_colour = Rgb::new(50_u8, 255_u8, 254_u8);
- * let mut img = open_image("img.jpg").expect("File should open");
- * mix_with_colour(&mut img, mix_colour, 0.4_f32);
- * ```
- * @param {PhotonImage} photon_image
- * @param {Rgb} mix_colour
- * @param {number} opacity
- */
-export function mix_with_colour(photon_image, mix_colour, opacity) {
-    _assertClass(photon_image, PhotonImage);
-    _assertClass(mix_colour, Rgb);
-    var ptr0 = mix_colour.__destroy_into_raw();
-    wasm.mix_with_colour(photon_image.__wbg_ptr, ptr0, opacity);
-}
-
-/**
- * Noise reduction.
- *
- * # Arguments
- * * `img` - A PhotonImage.
- *
- * # Example
- *
- * ```no_run
- * // For example, to noise reduct an image:
- * use photon_rs::conv::noise_reduction;
- * use photon_rs::native::open_image;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * noise_reduction(&mut img);
- * ```
- * Adds a constant to a select R, G, or B channel's value.
- * @param {PhotonImage} photon_image
- */
-export function noise_reduction(photon_image) {
-    _assertClass(photon_image, PhotonImage);
-    wasm.noise_reduction(photon_image.__wbg_ptr);
-}
-
-/**
- * Sharpen an image.
- *
- * # Arguments
- * * `img` - A PhotonImage.
- *
- * # Example
- *
- * ```no_run
- * // For example, to sharpen an image:
- * use photon_rs::conv::sharpen;
- * use photon_rs::native::open_image;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * sharpen(&mut img);
- * ```
- * Adds a constant to a select R, G, or B channel's value.
- * @param {PhotonImage} photon_image
- */
-export function sharpen(photon_image) {
-    _assertClass(photon_image, PhotonImage);
-    wasm.sharpen(photon_image.__wbg_ptr);
-}
-
-/**
- * Apply edge detection to an image, to create a dark version with its edges highlighted.
- *
- * # Arguments
- * * `img` - A PhotonImage.
- *
- * # Example
- *
- * ```no_run
- * // For example, to increase the Red channel for all pixels by 10:
- * use photon_rs::conv::edge_detection;
- * use photon_rs::native::open_image;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * edge_detection(&mut img);
- * ```
- * @param {PhotonImage} photon_image
- */
-export function edge_detection(photon_image) {
-    _assertClass(photon_image, PhotonImage);
-    wasm.edge_detection(photon_image.__wbg_ptr);
-}
-
-/**
- * Apply an identity kernel convolution to an image.
- *
- * # Arguments
- * * `img` -A PhotonImage.
- *
- * # Example
- *
- * ```no_run
- * // For example, to apply an identity kernel convolution:
- * use photon_rs::conv::identity;
- * use photon_rs::native::open_image;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * identity(&mut img);
- * ```
- * @param {PhotonImage} photon_image
- */
-export function identity(photon_image) {
-    _assertClass(photon_image, PhotonImage);
-    wasm.identity(photon_image.__wbg_ptr);
-}
-
-/**
- * Apply a box blur effect.
- *
- * # Arguments
- * * `img` - A PhotonImage.
- *
- * # Example
- *
- * ```no_run
- * // For example, to apply a box blur effect:
- * use photon_rs::conv::box_blur;
- * use photon_rs::native::open_image;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * box_blur(&mut img);
- * ```
- * @param {PhotonImage} photon_image
- */
-export function box_blur(photon_image) {
-    _assertClass(photon_image, PhotonImage);
-    wasm.box_blur(photon_image.__wbg_ptr);
-}
-
-/**
- * Gaussian blur in linear time.
- *
- * Reference: http://blog.ivank.net/fastest-gaussian-blur.html
- *
- * # Arguments
- * * `photon_image` - A PhotonImage
- * * `radius` - blur radius
- * # Example
- *
- * ```no_run
- * use photon_rs::conv::gaussian_blur;
- * use photon_rs::native::open_image;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * gaussian_blur(&mut img, 3_i32);
- * ```
- * @param {PhotonImage} photon_image
- * @param {number} radius
- */
-export function gaussian_blur(photon_image, radius) {
-    _assertClass(photon_image, PhotonImage);
-    wasm.gaussian_blur(photon_image.__wbg_ptr, radius);
-}
-
-/**
- * Detect horizontal lines in an image, and highlight these only.
- *
- * # Arguments
- * * `img` - A PhotonImage.
- *
- * # Example
- *
- * ```no_run
- * // For example, to display the horizontal lines in an image:
- * use photon_rs::conv::detect_horizontal_lines;
- * use photon_rs::native::open_image;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * detect_horizontal_lines(&mut img);
- * ```
- * @param {PhotonImage} photon_image
- */
-export function detect_horizontal_lines(photon_image) {
-    _assertClass(photon_image, PhotonImage);
-    wasm.detect_horizontal_lines(photon_image.__wbg_ptr);
-}
-
-/**
- * Detect vertical lines in an image, and highlight these only.
- *
- * # Arguments
- * * `img` - A PhotonImage.
- *
- * # Example
- *
- * ```no_run
- * // For example, to display the vertical lines in an image:
- * use photon_rs::conv::detect_vertical_lines;
- * use photon_rs::native::open_image;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * detect_vertical_lines(&mut img);
- * ```
- * @param {PhotonImage} photon_image
- */
-export function detect_vertical_lines(photon_image) {
-    _assertClass(photon_image, PhotonImage);
-    wasm.detect_vertical_lines(photon_image.__wbg_ptr);
-}
-
-/**
- * Detect lines at a forty five degree angle in an image, and highlight these only.
- *
- * # Arguments
- * * `img` - A PhotonImage.
- *
- * # Example
- *
- * ```no_run
- * // For example, to display the lines at a forty five degree angle in an image:
- * use photon_rs::conv::detect_45_deg_lines;
- * use photon_rs::native::open_image;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * detect_45_deg_lines(&mut img);
- * ```
- * @param {PhotonImage} photon_image
- */
-export function detect_45_deg_lines(photon_image) {
-    _assertClass(photon_image, PhotonImage);
-    wasm.detect_45_deg_lines(photon_image.__wbg_ptr);
-}
-
-/**
- * Detect lines at a 135 degree angle in an image, and highlight these only.
- *
- * # Arguments
- * * `img` - A PhotonImage.
- *
- * # Example
- *
- * ```no_run
- * // For example, to display the lines at a 135 degree angle in an image:
- * use photon_rs::conv::detect_135_deg_lines;
- * use photon_rs::native::open_image;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * detect_135_deg_lines(&mut img);
- * ```
- * @param {PhotonImage} photon_image
- */
-export function detect_135_deg_lines(photon_image) {
-    _assertClass(photon_image, PhotonImage);
-    wasm.detect_135_deg_lines(photon_image.__wbg_ptr);
-}
-
-/**
- * Apply a standard laplace convolution.
- *
- * # Arguments
- * * `img` - A PhotonImage.
- *
- * # Example
- *
- * ```no_run
- * // For example, to apply a laplace effect:
- * use photon_rs::conv::laplace;
- * use photon_rs::native::open_image;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * laplace(&mut img);
- * ```
- * @param {PhotonImage} photon_image
- */
-export function laplace(photon_image) {
-    _assertClass(photon_image, PhotonImage);
-    wasm.laplace(photon_image.__wbg_ptr);
-}
-
-/**
- * Preset edge effect.
- *
- * # Arguments
- * * `img` - A PhotonImage.
- *
- * # Example
- *
- * ```no_run
- * // For example, to apply this effect:
- * use photon_rs::conv::edge_one;
- * use photon_rs::native::open_image;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * edge_one(&mut img);
- * ```
- * @param {PhotonImage} photon_image
- */
-export function edge_one(photon_image) {
-    _assertClass(photon_image, PhotonImage);
-    wasm.edge_one(photon_image.__wbg_ptr);
-}
-
-/**
- * Apply an emboss effect to an image.
- *
- * # Arguments
- * * `img` - A PhotonImage.
- *
- * # Example
- *
- * ```no_run
- * // For example, to apply an emboss effect:
- * use photon_rs::conv::emboss;
- * use photon_rs::native::open_image;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * emboss(&mut img);
- * ```
- * @param {PhotonImage} photon_image
- */
-export function emboss(photon_image) {
-    _assertClass(photon_image, PhotonImage);
-    wasm.emboss(photon_image.__wbg_ptr);
-}
-
-/**
- * Apply a horizontal Sobel filter to an image.
- *
- * # Arguments
- * * `img` - A PhotonImage.
- *
- * # Example
- *
- * ```no_run
- * // For example, to apply a horizontal Sobel filter:
- * use photon_rs::conv::sobel_horizontal;
- * use photon_rs::native::open_image;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * sobel_horizontal(&mut img);
- * ```
- * @param {PhotonImage} photon_image
- */
-export function sobel_horizontal(photon_image) {
-    _assertClass(photon_image, PhotonImage);
-    wasm.sobel_horizontal(photon_image.__wbg_ptr);
-}
-
-/**
- * Apply a horizontal Prewitt convolution to an image.
- *
- * # Arguments
- * * `img` - A PhotonImage.
- *
- * # Example
- *
- * ```no_run
- * // For example, to apply a horizontal Prewitt convolution effect:
- * use photon_rs::conv::prewitt_horizontal;
- * use photon_rs::native::open_image;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * prewitt_horizontal(&mut img);
- * ```
- * @param {PhotonImage} photon_image
- */
-export function prewitt_horizontal(photon_image) {
-    _assertClass(photon_image, PhotonImage);
-    wasm.prewitt_horizontal(photon_image.__wbg_ptr);
-}
-
-/**
- * Apply a vertical Sobel filter to an image.
- *
- * # Arguments
- * * `img` - A PhotonImage.
- *
- * # Example
- *
- * ```no_run
- * // For example, to apply a vertical Sobel filter:
- * use photon_rs::conv::sobel_vertical;
- * use photon_rs::native::open_image;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * sobel_vertical(&mut img);
- * ```
- * @param {PhotonImage} photon_image
- */
-export function sobel_vertical(photon_image) {
-    _assertClass(photon_image, PhotonImage);
-    wasm.sobel_vertical(photon_image.__wbg_ptr);
-}
-
-/**
- * Apply a global Sobel filter to an image
- *
- * Each pixel is calculated as the magnitude of the horizontal and vertical components of the Sobel filter,
- * ie if X is the horizontal sobel and Y is the vertical, for each pixel, we calculate sqrt(X^2 + Y^2)
- *
- * # Arguments
- * * `img` - A PhotonImage.
- *
- * # Example
- *
- * ```no_run
- * // For example, to apply a global Sobel filter:
- * use photon_rs::conv::sobel_global;
- * use photon_rs::native::open_image;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * sobel_global(&mut img);
- * ```
- * @param {PhotonImage} photon_image
- */
-export function sobel_global(photon_image) {
-    _assertClass(photon_image, PhotonImage);
-    wasm.sobel_global(photon_image.__wbg_ptr);
-}
-
-/**
- * Adds an offset to the image by a certain number of pixels.
- *
- * This creates an RGB shift effect.
- *
- * # Arguments
- * * `img` - A PhotonImage that contains a view into the image.
- * * `channel_index`: The index of the channel to increment. 0 for red, 1 for green and 2 for blue.
- * * `offset` - The offset is added to the pixels in the image.
- * # Example
- *
- * ```no_run
- * // For example, to offset pixels by 30 pixels on the red channel:
- * use photon_rs::effects::offset;
- * use photon_rs::native::open_image;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * offset(&mut img, 0_usize, 30_u32);
- * ```
- * @param {PhotonImage} photon_image
- * @param {number} channel_index
- * @param {number} offset
- */
-export function offset(photon_image, channel_index, offset) {
-    _assertClass(photon_image, PhotonImage);
-    wasm.offset(photon_image.__wbg_ptr, channel_index, offset);
-}
-
-/**
- * Adds an offset to the red channel by a certain number of pixels.
- *
- * # Arguments
- * * `img` - A PhotonImage that contains a view into the image.
- * * `offset` - The offset you want to move the red channel by.
- * # Example
- *
- * ```no_run
- * // For example, to add an offset to the red channel by 30 pixels.
- * use photon_rs::effects::offset_red;
- * use photon_rs::native::open_image;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * offset_red(&mut img, 30_u32);
- * ```
- * @param {PhotonImage} img
- * @param {number} offset_amt
- */
-export function offset_red(img, offset_amt) {
-    _assertClass(img, PhotonImage);
-    wasm.offset_red(img.__wbg_ptr, offset_amt);
-}
-
-/**
- * Adds an offset to the green channel by a certain number of pixels.
- *
- * # Arguments
- * * `img` - A PhotonImage that contains a view into the image.
- * * `offset` - The offset you want to move the green channel by.
- * # Example
- *
- * ```no_run
- * // For example, to add an offset to the green channel by 30 pixels.
- * use photon_rs::effects::offset_green;
- * use photon_rs::native::open_image;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * offset_green(&mut img, 30_u32);
- * ```
- * @param {PhotonImage} img
- * @param {number} offset_amt
- */
-export function offset_green(img, offset_amt) {
-    _assertClass(img, PhotonImage);
-    wasm.offset_green(img.__wbg_ptr, offset_amt);
-}
-
-/**
- * Adds an offset to the blue channel by a certain number of pixels.
- *
- * # Arguments
- * * `img` - A PhotonImage that contains a view into the image.
- * * `offset_amt` - The offset you want to move the blue channel by.
- * # Example
- * // For example, to add an offset to the green channel by 40 pixels.
- *
- * ```no_run
- * use photon_rs::effects::offset_blue;
- * use photon_rs::native::open_image;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * offset_blue(&mut img, 40_u32);
- * ```
- * @param {PhotonImage} img
- * @param {number} offset_amt
- */
-export function offset_blue(img, offset_amt) {
-    _assertClass(img, PhotonImage);
-    wasm.offset_blue(img.__wbg_ptr, offset_amt);
-}
-
-/**
- * Adds multiple offsets to the image by a certain number of pixels (on two channels).
- *
- * # Arguments
- * * `img` - A PhotonImage that contains a view into the image.
- * * `offset` - The offset is added to the pixels in the image.
- * # Example
- *
- * ```no_run
- * // For example, to add a 30-pixel offset to both the red and blue channels:
- * use photon_rs::effects::multiple_offsets;
- * use photon_rs::native::open_image;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * multiple_offsets(&mut img, 30_u32, 0_usize, 2_usize);
- * ```
- * @param {PhotonImage} photon_image
- * @param {number} offset
- * @param {number} channel_index
- * @param {number} channel_index2
- */
-export function multiple_offsets(photon_image, offset, channel_index, channel_index2) {
-    _assertClass(photon_image, PhotonImage);
-    wasm.multiple_offsets(photon_image.__wbg_ptr, offset, channel_index, channel_index2);
-}
-
-/**
- * Halftoning effect.
- *
- * # Arguments
- * * `img` - A PhotonImage that contains a view into the image.
- * # Example
- *
- * ```no_run
- * // For example:
- * use photon_rs::effects::halftone;
- * use photon_rs::native::open_image;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * halftone(&mut img);
- * ```
- * @param {PhotonImage} photon_image
- */
-export function halftone(photon_image) {
-    _assertClass(photon_image, PhotonImage);
-    wasm.halftone(photon_image.__wbg_ptr);
-}
-
-/**
- * Reduces an image to the primary colours.
- *
- * # Arguments
- * * `img` - A PhotonImage that contains a view into the image.
- * # Example
- *
- * ```no_run
- * // For example, to add a primary colour effect to an image of type `DynamicImage`:
- * use photon_rs::effects::primary;
- * use photon_rs::native::open_image;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * primary(&mut img);
- * ```
- * @param {PhotonImage} img
- */
-export function primary(img) {
-    _assertClass(img, PhotonImage);
-    wasm.primary(img.__wbg_ptr);
-}
-
-/**
- * Colorizes the green channels of the image.
- *
- * # Arguments
- * * `img` - A PhotonImage that contains a view into the image.
- * # Example
- *
- * ```no_run
- * // For example, to colorize an image of type `PhotonImage`:
- * use photon_rs::effects::colorize;
- * use photon_rs::native::open_image;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * colorize(&mut img);
- * ```
- * @param {PhotonImage} photon_image
- */
-export function colorize(photon_image) {
-    _assertClass(photon_image, PhotonImage);
-    wasm.colorize(photon_image.__wbg_ptr);
-}
-
-/**
- * Applies a solarizing effect to an image.
- *
- * # Arguments
- * * `img` - A PhotonImage that contains a view into the image.
- * # Example
- *
- * ```no_run
- * // For example, to colorize an image of type `PhotonImage`:
- * use photon_rs::effects::solarize;
- * use photon_rs::native::open_image;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * solarize(&mut img);
- * ```
- * @param {PhotonImage} photon_image
- */
-export function solarize(photon_image) {
-    _assertClass(photon_image, PhotonImage);
-    wasm.solarize(photon_image.__wbg_ptr);
-}
-
-/**
- * Applies a solarizing effect to an image and returns the resulting PhotonImage.
- *
- * # Arguments
- * * `img` - A PhotonImage that contains a view into the image.
- * # Example
- *
- * ```no_run
- * // For example, to solarize "retimg" an image of type `PhotonImage`:
- * use photon_rs::effects::solarize_retimg;
- * use photon_rs::native::open_image;
- * use photon_rs::PhotonImage;
- *
- * let img = open_image("img.jpg").expect("File should open");
- * let result: PhotonImage = solarize_retimg(&img);
- * ```
- * @param {PhotonImage} photon_image
- * @returns {PhotonImage}
- */
-export function solarize_retimg(photon_image) {
-    _assertClass(photon_image, PhotonImage);
-    const ret = wasm.solarize_retimg(photon_image.__wbg_ptr);
-    return PhotonImage.__wrap(ret);
-}
-
-/**
- * Adjust the brightness of an image by a factor.
- *
- * # Arguments
- * * `img` - A PhotonImage that contains a view into the image.
- * * `brightness` - A u8 to add or subtract to the brightness. To increase
- * the brightness, pass a positive number (up to 255). To decrease the brightness,
- * pass a negative number instead.
- * # Example
- *
- * ```no_run
- * use photon_rs::effects::adjust_brightness;
- * use photon_rs::native::open_image;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * adjust_brightness(&mut img, 10_i16);
- * ```
- * @param {PhotonImage} photon_image
- * @param {number} brightness
- */
-export function adjust_brightness(photon_image, brightness) {
-    _assertClass(photon_image, PhotonImage);
-    wasm.adjust_brightness(photon_image.__wbg_ptr, brightness);
-}
-
-/**
- * Increase the brightness of an image by a constant.
- *
- * # Arguments
- * * `img` - A PhotonImage that contains a view into the image.
- * * `brightness` - A u8 to add to the brightness.
- * # Example
- *
- * ```no_run
- * use photon_rs::effects::inc_brightness;
- * use photon_rs::native::open_image;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * inc_brightness(&mut img, 10_u8);
- * ```
- * @param {PhotonImage} photon_image
- * @param {number} brightness
- */
-export function inc_brightness(photon_image, brightness) {
-    _assertClass(photon_image, PhotonImage);
-    wasm.inc_brightness(photon_image.__wbg_ptr, brightness);
-}
-
-/**
- * Decrease the brightness of an image by a constant.
- *
- * # Arguments
- * * `img` - A PhotonImage that contains a view into the image.
- * * `brightness` - A u8 to subtract from the brightness. It should be a positive number,
- * and this value will then be subtracted from the brightness.
- * # Example
- *
- * ```no_run
- * use photon_rs::effects::dec_brightness;
- * use photon_rs::native::open_image;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * dec_brightness(&mut img, 10_u8);
- * ```
- * @param {PhotonImage} photon_image
- * @param {number} brightness
- */
-export function dec_brightness(photon_image, brightness) {
-    _assertClass(photon_image, PhotonImage);
-    wasm.dec_brightness(photon_image.__wbg_ptr, brightness);
-}
-
-/**
- * Adjust the contrast of an image by a factor.
- *
- * # Arguments
- * * `photon_image` - A PhotonImage that contains a view into the image.
- * * `contrast` - An f32 factor used to adjust contrast. Between [-255.0, 255.0]. The algorithm will
- * clamp results if passed factor is out of range.
- * # Example
- *
- * ```no_run
- * use photon_rs::effects::adjust_contrast;
- * use photon_rs::native::open_image;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * adjust_contrast(&mut img, 30_f32);
- * ```
- * @param {PhotonImage} photon_image
- * @param {number} contrast
- */
-export function adjust_contrast(photon_image, contrast) {
-    _assertClass(photon_image, PhotonImage);
-    wasm.adjust_contrast(photon_image.__wbg_ptr, contrast);
-}
-
-/**
- * Tint an image by adding an offset to averaged RGB channel values.
- *
- * # Arguments
- * * `img` - A PhotonImage that contains a view into the image.
- * * `r_offset` - The amount the R channel should be incremented by.
- * * `g_offset` - The amount the G channel should be incremented by.
- * * `b_offset` - The amount the B channel should be incremented by.
- * # Example
- *
- * ```no_run
- * // For example, to tint an image of type `PhotonImage`:
- * use photon_rs::effects::tint;
- * use photon_rs::native::open_image;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * tint(&mut img, 10_u32, 20_u32, 15_u32);
- * ```
- * @param {PhotonImage} photon_image
- * @param {number} r_offset
- * @param {number} g_offset
- * @param {number} b_offset
- */
-export function tint(photon_image, r_offset, g_offset, b_offset) {
-    _assertClass(photon_image, PhotonImage);
-    wasm.tint(photon_image.__wbg_ptr, r_offset, g_offset, b_offset);
-}
-
-/**
- * Horizontal strips. Divide an image into a series of equal-height strips, for an artistic effect.
- *
- * # Arguments
- * * `img` - A PhotonImage that contains a view into the image.
- * * `num_strips` - The number of strips
- * # Example
- *
- * ```no_run
- * // For example, to draw horizontal strips on a `PhotonImage`:
- * use photon_rs::effects::horizontal_strips;
- * use photon_rs::native::open_image;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * horizontal_strips(&mut img, 8u8);
- * ```
- * @param {PhotonImage} photon_image
- * @param {number} num_strips
- */
-export function horizontal_strips(photon_image, num_strips) {
-    _assertClass(photon_image, PhotonImage);
-    wasm.horizontal_strips(photon_image.__wbg_ptr, num_strips);
-}
-
-/**
- * Horizontal strips. Divide an image into a series of equal-width strips, for an artistic effect. Sepcify a color as well.
- *
- * # Arguments
- * * `img` - A PhotonImage that contains a view into the image.
- * * `num_strips` - The numbder of strips
- * * `color` - Color of strips.
- * # Example
- *
- * ```no_run
- * // For example, to draw blue horizontal strips on a `PhotonImage`:
- * use photon_rs::effects::color_horizontal_strips;
- * use photon_rs::native::open_image;
- * use photon_rs::Rgb;
- *
- * let color = Rgb::new(255u8, 0u8, 0u8);
- * let mut img = open_image("img.jpg").expect("File should open");
- * color_horizontal_strips(&mut img, 8u8, color);
- * ```
- * @param {PhotonImage} photon_image
- * @param {number} num_strips
- * @param {Rgb} color
- */
-export function color_horizontal_strips(photon_image, num_strips, color) {
-    _assertClass(photon_image, PhotonImage);
-    _assertClass(color, Rgb);
-    var ptr0 = color.__destroy_into_raw();
-    wasm.color_horizontal_strips(photon_image.__wbg_ptr, num_strips, ptr0);
-}
-
-/**
- * Vertical strips. Divide an image into a series of equal-width strips, for an artistic effect.
- *
- * # Arguments
- * * `img` - A PhotonImage that contains a view into the image.
- * * `num_strips` - The numbder of strips
- * # Example
- *
- * ```no_run
- * // For example, to draw vertical strips on a `PhotonImage`:
- * use photon_rs::effects::vertical_strips;
- * use photon_rs::native::open_image;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * vertical_strips(&mut img, 8u8);
- * ```
- * @param {PhotonImage} photon_image
- * @param {number} num_strips
- */
-export function vertical_strips(photon_image, num_strips) {
-    _assertClass(photon_image, PhotonImage);
-    wasm.vertical_strips(photon_image.__wbg_ptr, num_strips);
-}
-
-/**
- * Vertical strips. Divide an image into a series of equal-width strips, for an artistic effect. Sepcify a color as well.
- *
- * # Arguments
- * * `img` - A PhotonImage that contains a view into the image.
- * * `num_strips` - The numbder of strips
- * * `color` - Color of strips.
- * # Example
- *
- * ```no_run
- * // For example, to draw red vertical strips on a `PhotonImage`:
- * use photon_rs::effects::color_vertical_strips;
- * use photon_rs::native::open_image;
- * use photon_rs::Rgb;
- *
- * let color = Rgb::new(255u8, 0u8, 0u8);
- * let mut img = open_image("img.jpg").expect("File should open");
- * color_vertical_strips(&mut img, 8u8, color);
- * ```
- * @param {PhotonImage} photon_image
- * @param {number} num_strips
- * @param {Rgb} color
- */
-export function color_vertical_strips(photon_image, num_strips, color) {
-    _assertClass(photon_image, PhotonImage);
-    _assertClass(color, Rgb);
-    var ptr0 = color.__destroy_into_raw();
-    wasm.color_vertical_strips(photon_image.__wbg_ptr, num_strips, ptr0);
-}
-
-/**
- * Turn an image into an oil painting
- *
- * # Arguments
- * * `img` - A PhotonImage that contains a view into the image.
- * * `radius` - Radius of each paint particle
- * * `intesnity` - How artsy an Image should be
- * # Example
- *
- * ```no_run
- * // For example, to oil an image of type `PhotonImage`:
- * use photon_rs::effects::oil;
- * use photon_rs::native::open_image;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * oil(&mut img, 4i32, 55.0);
- * ```
- * @param {PhotonImage} photon_image
- * @param {number} radius
- * @param {number} intensity
- */
-export function oil(photon_image, radius, intensity) {
-    _assertClass(photon_image, PhotonImage);
-    wasm.oil(photon_image.__wbg_ptr, radius, intensity);
-}
-
-/**
- * Turn an image into an frosted glass see through
- *
- * # Arguments
- * * `img` - A PhotonImage that contains a view into the image.
- * # Example
- *
- * ```no_run
- * // For example, to turn an image of type `PhotonImage` into frosted glass see through:
- * use photon_rs::effects::frosted_glass;
- * use photon_rs::native::open_image;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * frosted_glass(&mut img);
- * ```
- * @param {PhotonImage} photon_image
- */
-export function frosted_glass(photon_image) {
-    _assertClass(photon_image, PhotonImage);
-    wasm.frosted_glass(photon_image.__wbg_ptr);
-}
-
-/**
- * Pixelize an image.
- *
- * # Arguments
- * * `photon_image` - A PhotonImage that contains a view into the image.
- * * `pixel_size` - Targeted pixel size of generated image.
- * # Example
- *
- * ```no_run
- * // For example, to turn an image of type `PhotonImage` into a pixelized image with 50 pixels blocks:
- * use photon_rs::effects::pixelize;
- * use photon_rs::native::open_image;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * pixelize(&mut img, 50);
- * ```
- * @param {PhotonImage} photon_image
- * @param {number} pixel_size
- */
-export function pixelize(photon_image, pixel_size) {
-    _assertClass(photon_image, PhotonImage);
-    wasm.pixelize(photon_image.__wbg_ptr, pixel_size);
-}
-
-/**
- * Normalizes an image by remapping its range of pixels values. Only RGB
- * channels are processed and each channel is stretched to \[0, 255\] range
- * independently. This process is also known as contrast stretching.
- * # Arguments
- * * `photon_image` - A PhotonImage that contains a view into the image.
- * # Example
- *
- * ```no_run
- * // For example, to turn an image of type `PhotonImage` into a normalized image:
- * use photon_rs::effects::normalize;
- * use photon_rs::native::open_image;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * normalize(&mut img);
- * ```
- * @param {PhotonImage} photon_image
- */
-export function normalize(photon_image) {
-    _assertClass(photon_image, PhotonImage);
-    wasm.normalize(photon_image.__wbg_ptr);
-}
-
-/**
- * Applies Floyd-Steinberg dithering to an image.
- * Only RGB channels are processed, alpha remains unchanged.
- * # Arguments
- * * `photon_image` - A PhotonImage that contains a view into the image.
- * * `depth` - bits per channel. Clamped between 1 and 8.
- * # Example
- *
- * ```no_run
- * // For example, to turn an image of type `PhotonImage` into a dithered image:
- * use photon_rs::effects::dither;
- * use photon_rs::native::open_image;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * let depth = 1;
- * dither(&mut img, depth);
- * ```
- * @param {PhotonImage} photon_image
- * @param {number} depth
- */
-export function dither(photon_image, depth) {
-    _assertClass(photon_image, PhotonImage);
-    wasm.dither(photon_image.__wbg_ptr, depth);
-}
-
-/**
- * @param {PhotonImage} photon_image
- * @param {Rgb} color_a
- * @param {Rgb} color_b
- */
-export function duotone(photon_image, color_a, color_b) {
-    _assertClass(photon_image, PhotonImage);
-    _assertClass(color_a, Rgb);
-    var ptr0 = color_a.__destroy_into_raw();
-    _assertClass(color_b, Rgb);
-    var ptr1 = color_b.__destroy_into_raw();
-    wasm.duotone(photon_image.__wbg_ptr, ptr0, ptr1);
-}
-
-/**
- * Solarization on the Blue channel.
- *
- * # Arguments
- * * `img` - A PhotonImage.
- * # Example
- *
- * ```no_run
- * use photon_rs::filters::neue;
- * use photon_rs::native::open_image;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * neue(&mut img);
- * ```
- * @param {PhotonImage} photon_image
- */
-export function neue(photon_image) {
-    _assertClass(photon_image, PhotonImage);
-    wasm.neue(photon_image.__wbg_ptr);
-}
-
-/**
- * Solarization on the Red and Green channels.
- *
- * # Arguments
- * * `img` - A PhotonImage.
- * # Example
- *
- * ```no_run
- * use photon_rs::filters::lix;
- * use photon_rs::native::open_image;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * lix(&mut img);
- * ```
- * @param {PhotonImage} photon_image
- */
-export function lix(photon_image) {
-    _assertClass(photon_image, PhotonImage);
-    wasm.lix(photon_image.__wbg_ptr);
-}
-
-/**
- * Solarization on the Red and Blue channels.
- *
- * # Arguments
- * * `img` - A PhotonImage.
- * # Example
- *
- * ```no_run
- * use photon_rs::filters::ryo;
- * use photon_rs::native::open_image;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * ryo(&mut img);
- * ```
- * @param {PhotonImage} photon_image
- */
-export function ryo(photon_image) {
-    _assertClass(photon_image, PhotonImage);
-    wasm.ryo(photon_image.__wbg_ptr);
-}
-
-/**
- * Apply a filter to an image. Over 20 filters are available.
- * The filters are as follows:
- * * **oceanic**: Add an aquamarine-tinted hue to an image.
- * * **islands**: Aquamarine tint.
- * * **marine**: Add a green/blue mixed hue to an image.
- * * **seagreen**: Dark green hue, with tones of blue.
- * * **flagblue**: Royal blue tint
- * * **liquid**: Blue-inspired tint.
- * * **diamante**: Custom filter with a blue/turquoise tint.
- * * **radio**: Fallout-style radio effect.
- * * **twenties**: Slight-blue tinted historical effect.
- * * **rosetint**: Rose-tinted filter.
- * * **mauve**: Purple-infused filter.
- * * **bluechrome**: Blue monochrome effect.
- * * **vintage**: Vintage filter with a red tint.
- * * **perfume**: Increase the blue channel, with moderate increases in the Red and Green channels.
- * * **serenity**: Custom filter with an increase in the Blue channel's values.
- * # Arguments
- * * `img` - A PhotonImage.
- * * `filter_name` - The filter's name. Choose from the selection above, eg: "oceanic"
- * # Example
- *
- * ```no_run
- * // For example, to add a filter called "vintage" to an image:
- * use photon_rs::filters::filter;
- * use photon_rs::native::open_image;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * filter(&mut img, "vintage");
- * ```
- * @param {PhotonImage} img
- * @param {string} filter_name
- */
-export function filter(img, filter_name) {
-    _assertClass(img, PhotonImage);
-    const ptr0 = passStringToWasm0(filter_name, wasm.__wbindgen_export_1, wasm.__wbindgen_export_3);
-    const len0 = WASM_VECTOR_LEN;
-    wasm.filter(img.__wbg_ptr, ptr0, len0);
-}
-
-/**
- * Apply a lofi effect to an image.
- *
- * # Arguments
- * * `img` - A PhotonImage.
- * # Example
- *
- * ```no_run
- * use photon_rs::filters::lofi;
- * use photon_rs::native::open_image;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * lofi(&mut img);
- * ```
- * @param {PhotonImage} img
- */
-export function lofi(img) {
-    _assertClass(img, PhotonImage);
-    wasm.lofi(img.__wbg_ptr);
-}
-
-/**
- * Apply a rose tint to an image.
- *
- * # Arguments
- * * `img` - A PhotonImage.
- * # Example
- *
- * ```no_run
- * use photon_rs::filters::pastel_pink;
- * use photon_rs::native::open_image;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * pastel_pink(&mut img);
- * ```
- * @param {PhotonImage} img
- */
-export function pastel_pink(img) {
-    _assertClass(img, PhotonImage);
-    wasm.pastel_pink(img.__wbg_ptr);
-}
-
-/**
- * Apply a vintage, golden hue to an image.
- *
- * # Arguments
- * * `img` - A PhotonImage.
- * # Example
- *
- * ```no_run
- * use photon_rs::filters::golden;
- * use photon_rs::native::open_image;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * golden(&mut img);
- * ```
- * @param {PhotonImage} img
- */
-export function golden(img) {
-    _assertClass(img, PhotonImage);
-    wasm.golden(img.__wbg_ptr);
-}
-
-/**
- * Increased contrast filter effect.
- *
- * # Arguments
- * * `img` - A PhotonImage.
- * # Example
- *
- * ```no_run
- * use photon_rs::filters::cali;
- * use photon_rs::native::open_image;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * cali(&mut img);
- * ```
- * @param {PhotonImage} img
- */
-export function cali(img) {
-    _assertClass(img, PhotonImage);
-    wasm.cali(img.__wbg_ptr);
-}
-
-/**
- * Greyscale effect with increased contrast.
- *
- * # Arguments
- * * `img` - A PhotonImage.
- * # Example
- *
- * ```no_run
- * use photon_rs::filters::dramatic;
- * use photon_rs::native::open_image;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * dramatic(&mut img);
- * ```
- * @param {PhotonImage} img
+ * Perceptual hashes of an image as 16-digit hex strings
  */
-export function dramatic(img) {
-    _assertClass(img, PhotonImage);
-    wasm.dramatic(img.__wbg_ptr);
+export class PerceptualHashes {
+    static __wrap(ptr) {
+        const obj = Object.create(PerceptualHashes.prototype);
+        obj.__wbg_ptr = ptr;
+        PerceptualHashesFinalization.register(obj, obj.__wbg_ptr, obj);
+        return obj;
+    }
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        PerceptualHashesFinalization.unregister(this);
+        return ptr;
+    }
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_perceptualhashes_free(ptr, 0);
+    }
+    /**
+     * @returns {string}
+     */
+    get ahash() {
+        let deferred1_0;
+        let deferred1_1;
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            wasm.__wbg_get_perceptualhashes_ahash(retptr, this.__wbg_ptr);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            deferred1_0 = r0;
+            deferred1_1 = r1;
+            return getStringFromWasm0(r0, r1);
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+            wasm.__wbindgen_export2(deferred1_0, deferred1_1, 1);
+        }
+    }
+    /**
+     * @returns {string}
+     */
+    get dhash() {
+        let deferred1_0;
+        let deferred1_1;
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            wasm.__wbg_get_perceptualhashes_dhash(retptr, this.__wbg_ptr);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            deferred1_0 = r0;
+            deferred1_1 = r1;
+            return getStringFromWasm0(r0, r1);
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+            wasm.__wbindgen_export2(deferred1_0, deferred1_1, 1);
+        }
+    }
+    /**
+     * @returns {string}
+     */
+    get phash() {
+        let deferred1_0;
+        let deferred1_1;
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            wasm.__wbg_get_perceptualhashes_phash(retptr, this.__wbg_ptr);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            deferred1_0 = r0;
+            deferred1_1 = r1;
+            return getStringFromWasm0(r0, r1);
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+            wasm.__wbindgen_export2(deferred1_0, deferred1_1, 1);
+        }
+    }
+    /**
+     * @param {string} arg0
+     */
+    set ahash(arg0) {
+        const ptr0 = passStringToWasm0(arg0, wasm.__wbindgen_export3, wasm.__wbindgen_export4);
+        const len0 = WASM_VECTOR_LEN;
+        wasm.__wbg_set_perceptualhashes_ahash(this.__wbg_ptr, ptr0, len0);
+    }
+    /**
+     * @param {string} arg0
+     */
+    set dhash(arg0) {
+        const ptr0 = passStringToWasm0(arg0, wasm.__wbindgen_export3, wasm.__wbindgen_export4);
+        const len0 = WASM_VECTOR_LEN;
+        wasm.__wbg_set_perceptualhashes_dhash(this.__wbg_ptr, ptr0, len0);
+    }
+    /**
+     * @param {string} arg0
+     */
+    set phash(arg0) {
+        const ptr0 = passStringToWasm0(arg0, wasm.__wbindgen_export3, wasm.__wbindgen_export4);
+        const len0 = WASM_VECTOR_LEN;
+        wasm.__wbg_set_perceptualhashes_phash(this.__wbg_ptr, ptr0, len0);
+    }
 }
+if (Symbol.dispose) PerceptualHashes.prototype[Symbol.dispose] = PerceptualHashes.prototype.free;
 
 /**
- * Monochrome tint effect with increased contrast
- *
- * # Arguments
- * * `img` - A PhotonImage.
- * * `rgb_color` - RGB color
- * # Example
+ * An image kept in WASM memory with its mipmap pyramid, for fast previews
  *
- * ```no_run
- * use photon_rs::filters::monochrome_tint;
- * use photon_rs::native::open_image;
- * use photon_rs::Rgb;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * let rgb_color = Rgb::new(12, 12, 10);
- * monochrome_tint(&mut img, rgb_color);
- * ```
- * @param {PhotonImage} img
- * @param {Rgb} rgb_color
+ * `render_preview` runs the edits on the smallest level that covers the
+ * display size, with blur radius and crop scaled to it; `render_full`
+ * runs them at full resolution for commit or export.
  */
-export function monochrome_tint(img, rgb_color) {
-    _assertClass(img, PhotonImage);
-    _assertClass(rgb_color, Rgb);
-    var ptr0 = rgb_color.__destroy_into_raw();
-    wasm.monochrome_tint(img.__wbg_ptr, ptr0);
+export class ProxyImage {
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        ProxyImageFinalization.unregister(this);
+        return ptr;
+    }
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_proxyimage_free(ptr, 0);
+    }
+    /**
+     * @returns {number}
+     */
+    level_count() {
+        const ret = wasm.proxyimage_level_count(this.__wbg_ptr);
+        return ret >>> 0;
+    }
+    /**
+     * @param {Uint8Array} image_data
+     * @param {number} width
+     * @param {number} height
+     */
+    constructor(image_data, width, height) {
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            const ptr0 = passArray8ToWasm0(image_data, wasm.__wbindgen_export3);
+            const len0 = WASM_VECTOR_LEN;
+            wasm.proxyimage_new(retptr, ptr0, len0, width, height);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+            if (r2) {
+                throw takeObject(r1);
+            }
+            this.__wbg_ptr = r0;
+            ProxyImageFinalization.register(this, this.__wbg_ptr, this);
+            return this;
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+        }
+    }
+    /**
+     * Render at full resolution
+     *
+     * on_progress, progress_every, cancel: as for `apply_blur`; totalRows
+     * counts the rows of every stage (6 x height for blur)
+     * @param {FilterSettings} settings
+     * @param {Function | null} [on_progress]
+     * @param {number | null} [progress_every]
+     * @param {AbortSignal | Int32Array | null} [cancel]
+     * @returns {RenderedImage}
+     */
+    render_full(settings, on_progress, progress_every, cancel) {
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            _assertClass(settings, FilterSettings);
+            wasm.proxyimage_render_full(retptr, this.__wbg_ptr, settings.__wbg_ptr, isLikeNone(on_progress) ? 0 : addHeapObject(on_progress), isLikeNone(progress_every) ? Number.MAX_SAFE_INTEGER : (progress_every) >>> 0, isLikeNone(cancel) ? 0 : addHeapObject(cancel));
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+            if (r2) {
+                throw takeObject(r1);
+            }
+            return RenderedImage.__wrap(r0);
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+        }
+    }
+    /**
+     * Render for a `max_width` x `max_height` display area
+     * @param {FilterSettings} settings
+     * @param {number} max_width
+     * @param {number} max_height
+     * @returns {RenderedImage}
+     */
+    render_preview(settings, max_width, max_height) {
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            _assertClass(settings, FilterSettings);
+            wasm.proxyimage_render_preview(retptr, this.__wbg_ptr, settings.__wbg_ptr, max_width, max_height);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+            if (r2) {
+                throw takeObject(r1);
+            }
+            return RenderedImage.__wrap(r0);
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+        }
+    }
 }
+if (Symbol.dispose) ProxyImage.prototype[Symbol.dispose] = ProxyImage.prototype.free;
 
 /**
- * Duotone effect with blue and purple tones.
- *
- * # Arguments
- * * `img` - A PhotonImage.
- * # Example
- *
- * ```no_run
- * use photon_rs::filters::duotone_violette;
- * use photon_rs::native::open_image;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * duotone_violette(&mut img);
- * ```
- * @param {PhotonImage} img
+ * Output of a pipeline render
  */
-export function duotone_violette(img) {
-    _assertClass(img, PhotonImage);
-    wasm.duotone_violette(img.__wbg_ptr);
+export class RenderedImage {
+    static __wrap(ptr) {
+        const obj = Object.create(RenderedImage.prototype);
+        obj.__wbg_ptr = ptr;
+        RenderedImageFinalization.register(obj, obj.__wbg_ptr, obj);
+        return obj;
+    }
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        RenderedImageFinalization.unregister(this);
+        return ptr;
+    }
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_renderedimage_free(ptr, 0);
+    }
+    /**
+     * RGBA pixels
+     * @returns {Uint8Array}
+     */
+    get data() {
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            wasm.__wbg_get_renderedimage_data(retptr, this.__wbg_ptr);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            var v1 = getArrayU8FromWasm0(r0, r1).slice();
+            wasm.__wbindgen_export2(r0, r1 * 1, 1);
+            return v1;
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+        }
+    }
+    /**
+     * @returns {number}
+     */
+    get height() {
+        const ret = wasm.__wbg_get_renderedimage_height(this.__wbg_ptr);
+        return ret >>> 0;
+    }
+    /**
+     * @returns {number}
+     */
+    get width() {
+        const ret = wasm.__wbg_get_renderedimage_width(this.__wbg_ptr);
+        return ret >>> 0;
+    }
+    /**
+     * RGBA pixels
+     * @param {Uint8Array} arg0
+     */
+    set data(arg0) {
+        const ptr0 = passArray8ToWasm0(arg0, wasm.__wbindgen_export3);
+        const len0 = WASM_VECTOR_LEN;
+        wasm.__wbg_set_renderedimage_data(this.__wbg_ptr, ptr0, len0);
+    }
+    /**
+     * @param {number} arg0
+     */
+    set height(arg0) {
+        wasm.__wbg_set_renderedimage_height(this.__wbg_ptr, arg0);
+    }
+    /**
+     * @param {number} arg0
+     */
+    set width(arg0) {
+        wasm.__wbg_set_renderedimage_width(this.__wbg_ptr, arg0);
+    }
 }
+if (Symbol.dispose) RenderedImage.prototype[Symbol.dispose] = RenderedImage.prototype.free;
 
 /**
- * Duotone effect with purple tones.
- *
- * # Arguments
- * * `img` - A PhotonImage.
- * # Example
+ * One tile of a tiled run, see `plan_tiles`
  *
- * ```no_run
- * use photon_rs::filters::duotone_horizon;
- * use photon_rs::native::open_image;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * duotone_horizon(&mut img);
- * ```
- * @param {PhotonImage} img
+ * `input_*` is the area to read from the source image, `core_*` the area
+ * this tile's result covers in the output image.
  */
-export function duotone_horizon(img) {
-    _assertClass(img, PhotonImage);
-    wasm.duotone_horizon(img.__wbg_ptr);
+export class Tile {
+    static __wrap(ptr) {
+        const obj = Object.create(Tile.prototype);
+        obj.__wbg_ptr = ptr;
+        TileFinalization.register(obj, obj.__wbg_ptr, obj);
+        return obj;
+    }
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        TileFinalization.unregister(this);
+        return ptr;
+    }
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_tile_free(ptr, 0);
+    }
+    /**
+     * @returns {number}
+     */
+    get core_height() {
+        const ret = wasm.__wbg_get_tile_core_height(this.__wbg_ptr);
+        return ret >>> 0;
+    }
+    /**
+     * @returns {number}
+     */
+    get core_width() {
+        const ret = wasm.__wbg_get_tile_core_width(this.__wbg_ptr);
+        return ret >>> 0;
+    }
+    /**
+     * @returns {number}
+     */
+    get core_x() {
+        const ret = wasm.__wbg_get_tile_core_x(this.__wbg_ptr);
+        return ret >>> 0;
+    }
+    /**
+     * @returns {number}
+     */
+    get core_y() {
+        const ret = wasm.__wbg_get_tile_core_y(this.__wbg_ptr);
+        return ret >>> 0;
+    }
+    /**
+     * @returns {number}
+     */
+    get input_height() {
+        const ret = wasm.__wbg_get_tile_input_height(this.__wbg_ptr);
+        return ret >>> 0;
+    }
+    /**
+     * @returns {number}
+     */
+    get input_width() {
+        const ret = wasm.__wbg_get_tile_input_width(this.__wbg_ptr);
+        return ret >>> 0;
+    }
+    /**
+     * @returns {number}
+     */
+    get input_x() {
+        const ret = wasm.__wbg_get_tile_input_x(this.__wbg_ptr);
+        return ret >>> 0;
+    }
+    /**
+     * @returns {number}
+     */
+    get input_y() {
+        const ret = wasm.__wbg_get_tile_input_y(this.__wbg_ptr);
+        return ret >>> 0;
+    }
+    /**
+     * @param {number} arg0
+     */
+    set core_height(arg0) {
+        wasm.__wbg_set_tile_core_height(this.__wbg_ptr, arg0);
+    }
+    /**
+     * @param {number} arg0
+     */
+    set core_width(arg0) {
+        wasm.__wbg_set_tile_core_width(this.__wbg_ptr, arg0);
+    }
+    /**
+     * @param {number} arg0
+     */
+    set core_x(arg0) {
+        wasm.__wbg_set_tile_core_x(this.__wbg_ptr, arg0);
+    }
+    /**
+     * @param {number} arg0
+     */
+    set core_y(arg0) {
+        wasm.__wbg_set_tile_core_y(this.__wbg_ptr, arg0);
+    }
+    /**
+     * @param {number} arg0
+     */
+    set input_height(arg0) {
+        wasm.__wbg_set_tile_input_height(this.__wbg_ptr, arg0);
+    }
+    /**
+     * @param {number} arg0
+     */
+    set input_width(arg0) {
+        wasm.__wbg_set_tile_input_width(this.__wbg_ptr, arg0);
+    }
+    /**
+     * @param {number} arg0
+     */
+    set input_x(arg0) {
+        wasm.__wbg_set_tile_input_x(this.__wbg_ptr, arg0);
+    }
+    /**
+     * @param {number} arg0
+     */
+    set input_y(arg0) {
+        wasm.__wbg_set_tile_input_y(this.__wbg_ptr, arg0);
+    }
 }
+if (Symbol.dispose) Tile.prototype[Symbol.dispose] = Tile.prototype.free;
 
 /**
- * A duotone filter with a user-specified color and a gray color
- *
- * # Arguments
- * * `img` - A PhotonImage.
- * * `rgb_color` - RGB color
- * # Example
- *
- * ```no_run
- * use photon_rs::filters::duotone_tint;
- * use photon_rs::native::open_image;
- * use photon_rs::Rgb;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * let rgb_color = Rgb::new(12, 12, 10);
- * duotone_tint(&mut img, rgb_color);
- * ```
- * @param {PhotonImage} img
- * @param {Rgb} rgb_color
+ * Timing of the most recent successful filter/analysis call
  */
-export function duotone_tint(img, rgb_color) {
-    _assertClass(img, PhotonImage);
-    _assertClass(rgb_color, Rgb);
-    var ptr0 = rgb_color.__destroy_into_raw();
-    wasm.duotone_tint(img.__wbg_ptr, ptr0);
+export class TimingReport {
+    static __wrap(ptr) {
+        const obj = Object.create(TimingReport.prototype);
+        obj.__wbg_ptr = ptr;
+        TimingReportFinalization.register(obj, obj.__wbg_ptr, obj);
+        return obj;
+    }
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        TimingReportFinalization.unregister(this);
+        return ptr;
+    }
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_timingreport_free(ptr, 0);
+    }
+    /**
+     * Wall time in milliseconds; `undefined` when no `performance` clock is available
+     * @returns {number | undefined}
+     */
+    get elapsed_ms() {
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            wasm.__wbg_get_timingreport_elapsed_ms(retptr, this.__wbg_ptr);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r2 = getDataViewMemory0().getFloat64(retptr + 8 * 1, true);
+            return r0 === 0 ? undefined : r2;
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+        }
+    }
+    /**
+     * @returns {number}
+     */
+    get height() {
+        const ret = wasm.__wbg_get_timingreport_height(this.__wbg_ptr);
+        return ret >>> 0;
+    }
+    /**
+     * Name of the exported function, e.g. `apply_blur`
+     * @returns {string}
+     */
+    get operation() {
+        let deferred1_0;
+        let deferred1_1;
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            wasm.__wbg_get_timingreport_operation(retptr, this.__wbg_ptr);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            deferred1_0 = r0;
+            deferred1_1 = r1;
+            return getStringFromWasm0(r0, r1);
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+            wasm.__wbindgen_export2(deferred1_0, deferred1_1, 1);
+        }
+    }
+    /**
+     * Input dimensions
+     * @returns {number}
+     */
+    get width() {
+        const ret = wasm.__wbg_get_timingreport_width(this.__wbg_ptr);
+        return ret >>> 0;
+    }
+    /**
+     * Wall time in milliseconds; `undefined` when no `performance` clock is available
+     * @param {number | null} [arg0]
+     */
+    set elapsed_ms(arg0) {
+        wasm.__wbg_set_timingreport_elapsed_ms(this.__wbg_ptr, !isLikeNone(arg0), isLikeNone(arg0) ? 0 : arg0);
+    }
+    /**
+     * @param {number} arg0
+     */
+    set height(arg0) {
+        wasm.__wbg_set_timingreport_height(this.__wbg_ptr, arg0);
+    }
+    /**
+     * Name of the exported function, e.g. `apply_blur`
+     * @param {string} arg0
+     */
+    set operation(arg0) {
+        const ptr0 = passStringToWasm0(arg0, wasm.__wbindgen_export3, wasm.__wbindgen_export4);
+        const len0 = WASM_VECTOR_LEN;
+        wasm.__wbg_set_timingreport_operation(this.__wbg_ptr, ptr0, len0);
+    }
+    /**
+     * Input dimensions
+     * @param {number} arg0
+     */
+    set width(arg0) {
+        wasm.__wbg_set_timingreport_width(this.__wbg_ptr, arg0);
+    }
 }
+if (Symbol.dispose) TimingReport.prototype[Symbol.dispose] = TimingReport.prototype.free;
 
 /**
- * Duotone effect with a lilac hue
- *
- * # Arguments
- * * `img` - A PhotonImage.
- * # Example
- *
- * ```no_run
- * use photon_rs::filters::duotone_lilac;
- * use photon_rs::native::open_image;
+ * An image or text mark for `apply_watermark`, rendered once and reusable across exports
  *
- * let mut img = open_image("img.jpg").expect("File should open");
- * duotone_lilac(&mut img);
- * ```
- * @param {PhotonImage} img
+ * Bottom-right with a 16px margin, fully opaque and not tiled until set.
  */
-export function duotone_lilac(img) {
-    _assertClass(img, PhotonImage);
-    wasm.duotone_lilac(img.__wbg_ptr);
+export class Watermark {
+    static __wrap(ptr) {
+        const obj = Object.create(Watermark.prototype);
+        obj.__wbg_ptr = ptr;
+        WatermarkFinalization.register(obj, obj.__wbg_ptr, obj);
+        return obj;
+    }
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        WatermarkFinalization.unregister(this);
+        return ptr;
+    }
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_watermark_free(ptr, 0);
+    }
+    /**
+     * RGBA `mark` scaled by `scale` and rotated clockwise by `rotation` degrees
+     * @param {Uint8Array} mark
+     * @param {number} width
+     * @param {number} height
+     * @param {number} scale
+     * @param {number} rotation
+     * @returns {Watermark}
+     */
+    static image(mark, width, height, scale, rotation) {
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            const ptr0 = passArray8ToWasm0(mark, wasm.__wbindgen_export3);
+            const len0 = WASM_VECTOR_LEN;
+            wasm.watermark_image(retptr, ptr0, len0, width, height, scale, rotation);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+            if (r2) {
+                throw takeObject(r1);
+            }
+            return Watermark.__wrap(r0);
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+        }
+    }
+    /**
+     * anchor: "top-left", "top", "top-right", "left", "center", "right",
+     * "bottom-left", "bottom" or "bottom-right"
+     * @param {string} anchor
+     */
+    set_anchor(anchor) {
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            const ptr0 = passStringToWasm0(anchor, wasm.__wbindgen_export3, wasm.__wbindgen_export4);
+            const len0 = WASM_VECTOR_LEN;
+            wasm.watermark_set_anchor(retptr, this.__wbg_ptr, ptr0, len0);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            if (r1) {
+                throw takeObject(r0);
+            }
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+        }
+    }
+    /**
+     * Distance in pixels from the edges next to the anchor
+     * @param {number} margin
+     */
+    set_margin(margin) {
+        wasm.watermark_set_margin(this.__wbg_ptr, margin);
+    }
+    /**
+     * opacity: 0.0 to 1.0
+     * @param {number} opacity
+     */
+    set_opacity(opacity) {
+        wasm.watermark_set_opacity(this.__wbg_ptr, opacity);
+    }
+    /**
+     * Repeat the mark over the whole image with `spacing` pixels between copies (default 0)
+     * @param {boolean} tile
+     * @param {number | null} [spacing]
+     */
+    set_tile(tile, spacing) {
+        wasm.watermark_set_tile(this.__wbg_ptr, tile, isLikeNone(spacing) ? Number.MAX_SAFE_INTEGER : (spacing) >>> 0);
+    }
+    /**
+     * Size of the rendered mark, [width, height]
+     * @returns {Uint32Array}
+     */
+    size() {
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            wasm.watermark_size(retptr, this.__wbg_ptr);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            var v1 = getArrayU32FromWasm0(r0, r1).slice();
+            wasm.__wbindgen_export2(r0, r1 * 4, 4);
+            return v1;
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+        }
+    }
 }
+if (Symbol.dispose) Watermark.prototype[Symbol.dispose] = Watermark.prototype.free;
 
 /**
- * A duotone ochre tint effect
- *
- * # Arguments
- * * `img` - A PhotonImage.
- * # Example
- *
- * ```no_run
- * use photon_rs::filters::duotone_ochre;
- * use photon_rs::native::open_image;
+ * Apply Gaussian blur
  *
- * let mut img = open_image("img.jpg").expect("File should open");
- * duotone_ochre(&mut img);
- * ```
- * @param {PhotonImage} img
+ * on_progress: optional `(doneRows, totalRows) => void`, called every `progress_every`
+ * rows (default 64); rows of all 6 blur passes count, so totalRows is 6 x height
+ * cancel: optional `AbortSignal` or `Int32Array` flag, checked between row chunks;
+ * throws `Cancelled` once aborted / once the flag's first element is non-zero.
+ * The call is synchronous, so an `AbortSignal` can only be aborted from inside
+ * `on_progress`; to cancel from another thread (e.g. the page while this runs in
+ * a worker), pass an `Int32Array` over a `SharedArrayBuffer` and `Atomics.store` 1 into it
+ * mask: optional selection, one byte per pixel (see `Mask.data`)
+ * @param {Uint8Array} image_data
+ * @param {number} width
+ * @param {number} height
+ * @param {number} radius
+ * @param {Function | null} [on_progress]
+ * @param {number | null} [progress_every]
+ * @param {AbortSignal | Int32Array | null} [cancel]
+ * @param {Uint8Array | null} [mask]
+ * @returns {Uint8Array}
  */
-export function duotone_ochre(img) {
-    _assertClass(img, PhotonImage);
-    wasm.duotone_ochre(img.__wbg_ptr);
+export function apply_blur(image_data, width, height, radius, on_progress, progress_every, cancel, mask) {
+    try {
+        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+        const ptr0 = passArray8ToWasm0(image_data, wasm.__wbindgen_export3);
+        const len0 = WASM_VECTOR_LEN;
+        var ptr1 = isLikeNone(mask) ? 0 : passArray8ToWasm0(mask, wasm.__wbindgen_export3);
+        var len1 = WASM_VECTOR_LEN;
+        wasm.apply_blur(retptr, ptr0, len0, width, height, radius, isLikeNone(on_progress) ? 0 : addHeapObject(on_progress), isLikeNone(progress_every) ? Number.MAX_SAFE_INTEGER : (progress_every) >>> 0, isLikeNone(cancel) ? 0 : addHeapObject(cancel), ptr1, len1);
+        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+        var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+        var r3 = getDataViewMemory0().getInt32(retptr + 4 * 3, true);
+        if (r3) {
+            throw takeObject(r2);
+        }
+        var v3 = getArrayU8FromWasm0(r0, r1).slice();
+        wasm.__wbindgen_export2(r0, r1 * 1, 1);
+        return v3;
+    } finally {
+        wasm.__wbindgen_add_to_stack_pointer(16);
+    }
 }
 
 /**
- * Apply a red hue, with increased contrast and brightness.
- *
- * # Arguments
- * * `img` - A PhotonImage.
- * # Example
+ * Blur one tile of a large image
  *
- * ```no_run
- * use photon_rs::filters::firenze;
- * use photon_rs::native::open_image;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * firenze(&mut img);
- * ```
- * @param {PhotonImage} img
+ * tile_data: RGBA pixels of the tile's input area
+ * Returns the RGBA pixels of the tile's core area, to be placed at (core_x, core_y)
+ * @param {Uint8Array} tile_data
+ * @param {Tile} tile
+ * @param {number} image_width
+ * @param {number} image_height
+ * @param {number} radius
+ * @returns {Uint8Array}
  */
-export function firenze(img) {
-    _assertClass(img, PhotonImage);
-    wasm.firenze(img.__wbg_ptr);
+export function apply_blur_tile(tile_data, tile, image_width, image_height, radius) {
+    try {
+        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+        const ptr0 = passArray8ToWasm0(tile_data, wasm.__wbindgen_export3);
+        const len0 = WASM_VECTOR_LEN;
+        _assertClass(tile, Tile);
+        wasm.apply_blur_tile(retptr, ptr0, len0, tile.__wbg_ptr, image_width, image_height, radius);
+        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+        var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+        var r3 = getDataViewMemory0().getInt32(retptr + 4 * 3, true);
+        if (r3) {
+            throw takeObject(r2);
+        }
+        var v2 = getArrayU8FromWasm0(r0, r1).slice();
+        wasm.__wbindgen_export2(r0, r1 * 1, 1);
+        return v2;
+    } finally {
+        wasm.__wbindgen_add_to_stack_pointer(16);
+    }
 }
 
 /**
- * Apply a greyscale effect with increased contrast.
- *
- * # Arguments
- * * `img` - A PhotonImage.
- * # Example
- *
- * ```no_run
- * use photon_rs::filters::obsidian;
- * use photon_rs::native::open_image;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * obsidian(&mut img);
- * ```
- * @param {PhotonImage} img
+ * Apply brightness adjustment
+ * adjustment: -255.0 (darker) to +255.0 (brighter)
+ * mask: optional selection, one byte per pixel (see `Mask.data`)
+ * @param {Uint8Array} image_data
+ * @param {number} width
+ * @param {number} height
+ * @param {number} adjustment
+ * @param {Uint8Array | null} [mask]
+ * @returns {Uint8Array}
  */
-export function obsidian(img) {
-    _assertClass(img, PhotonImage);
-    wasm.obsidian(img.__wbg_ptr);
+export function apply_brightness(image_data, width, height, adjustment, mask) {
+    try {
+        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+        const ptr0 = passArray8ToWasm0(image_data, wasm.__wbindgen_export3);
+        const len0 = WASM_VECTOR_LEN;
+        var ptr1 = isLikeNone(mask) ? 0 : passArray8ToWasm0(mask, wasm.__wbindgen_export3);
+        var len1 = WASM_VECTOR_LEN;
+        wasm.apply_brightness(retptr, ptr0, len0, width, height, adjustment, ptr1, len1);
+        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+        var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+        var r3 = getDataViewMemory0().getInt32(retptr + 4 * 3, true);
+        if (r3) {
+            throw takeObject(r2);
+        }
+        var v3 = getArrayU8FromWasm0(r0, r1).slice();
+        wasm.__wbindgen_export2(r0, r1 * 1, 1);
+        return v3;
+    } finally {
+        wasm.__wbindgen_add_to_stack_pointer(16);
+    }
 }
 
 /**
- * Apply a monochrome effect of a certain colour.
- *
- * It does so by averaging the R, G, and B values of a pixel, and then adding a
- * separate value to that averaged value for each channel to produce a tint.
- * # Arguments
- * * `photon_image` - A PhotonImage.
- * * `r_offset` - The value to add to the Red channel per pixel.
- * * `g_offset` - The value to add to the Green channel per pixel.
- * * `b_offset` - The value to add to the Blue channel per pixel.
- *
- * # Example
- *
- * ```no_run
- * // For example, to apply a monochrome effect to an image:
- * use photon_rs::monochrome::monochrome;
- * use photon_rs::native::open_image;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * monochrome(&mut img, 40_u32, 50_u32, 100_u32);
- * ```
- * @param {PhotonImage} img
- * @param {number} r_offset
- * @param {number} g_offset
- * @param {number} b_offset
+ * Crop image to specified rectangle
+ * mask: optional selection over the whole image; unselected pixels of the crop become transparent
+ * @param {Uint8Array} image_data
+ * @param {number} width
+ * @param {number} height
+ * @param {number} x
+ * @param {number} y
+ * @param {number} crop_width
+ * @param {number} crop_height
+ * @param {Uint8Array | null} [mask]
+ * @returns {Uint8Array}
  */
-export function monochrome(img, r_offset, g_offset, b_offset) {
-    _assertClass(img, PhotonImage);
-    wasm.monochrome(img.__wbg_ptr, r_offset, g_offset, b_offset);
+export function apply_crop(image_data, width, height, x, y, crop_width, crop_height, mask) {
+    try {
+        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+        const ptr0 = passArray8ToWasm0(image_data, wasm.__wbindgen_export3);
+        const len0 = WASM_VECTOR_LEN;
+        var ptr1 = isLikeNone(mask) ? 0 : passArray8ToWasm0(mask, wasm.__wbindgen_export3);
+        var len1 = WASM_VECTOR_LEN;
+        wasm.apply_crop(retptr, ptr0, len0, width, height, x, y, crop_width, crop_height, ptr1, len1);
+        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+        var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+        var r3 = getDataViewMemory0().getInt32(retptr + 4 * 3, true);
+        if (r3) {
+            throw takeObject(r2);
+        }
+        var v3 = getArrayU8FromWasm0(r0, r1).slice();
+        wasm.__wbindgen_export2(r0, r1 * 1, 1);
+        return v3;
+    } finally {
+        wasm.__wbindgen_add_to_stack_pointer(16);
+    }
 }
 
 /**
- * Convert an image to sepia.
- *
- * # Arguments
- * * `photon_image` - A PhotonImage.
- * # Example
- *
- * ```no_run
- * // For example, to sepia an image of type `PhotonImage`:
- * use photon_rs::monochrome::sepia;
- * use photon_rs::native::open_image;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * sepia(&mut img);
- * ```
- * @param {PhotonImage} img
+ * Apply horizontal flip (mirror left-right)
+ * mask: optional selection, mirrored within its bounding box
+ * @param {Uint8Array} image_data
+ * @param {number} width
+ * @param {number} height
+ * @param {Uint8Array | null} [mask]
+ * @returns {Uint8Array}
  */
-export function sepia(img) {
-    _assertClass(img, PhotonImage);
-    wasm.sepia(img.__wbg_ptr);
+export function apply_flip_horizontal(image_data, width, height, mask) {
+    try {
+        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+        const ptr0 = passArray8ToWasm0(image_data, wasm.__wbindgen_export3);
+        const len0 = WASM_VECTOR_LEN;
+        var ptr1 = isLikeNone(mask) ? 0 : passArray8ToWasm0(mask, wasm.__wbindgen_export3);
+        var len1 = WASM_VECTOR_LEN;
+        wasm.apply_flip_horizontal(retptr, ptr0, len0, width, height, ptr1, len1);
+        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+        var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+        var r3 = getDataViewMemory0().getInt32(retptr + 4 * 3, true);
+        if (r3) {
+            throw takeObject(r2);
+        }
+        var v3 = getArrayU8FromWasm0(r0, r1).slice();
+        wasm.__wbindgen_export2(r0, r1 * 1, 1);
+        return v3;
+    } finally {
+        wasm.__wbindgen_add_to_stack_pointer(16);
+    }
 }
 
 /**
- * Convert an image to grayscale using the conventional averaging algorithm.
- *
- * # Arguments
- * * `photon_image` - A PhotonImage.
- * # Example
- *
- * ```no_run
- * // For example, to convert an image of type `PhotonImage` to grayscale:
- * use photon_rs::monochrome::grayscale;
- * use photon_rs::native::open_image;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * grayscale(&mut img);
- * ```
- * @param {PhotonImage} img
+ * Apply horizontal flip in place on a caller-provided buffer (no output allocation)
+ * @param {Uint8Array} image_data
+ * @param {number} width
+ * @param {number} height
  */
-export function grayscale(img) {
-    _assertClass(img, PhotonImage);
-    wasm.grayscale(img.__wbg_ptr);
+export function apply_flip_horizontal_in_place(image_data, width, height) {
+    try {
+        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+        var ptr0 = passArray8ToWasm0(image_data, wasm.__wbindgen_export3);
+        var len0 = WASM_VECTOR_LEN;
+        wasm.apply_flip_horizontal_in_place(retptr, ptr0, len0, addHeapObject(image_data), width, height);
+        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+        if (r1) {
+            throw takeObject(r0);
+        }
+    } finally {
+        wasm.__wbindgen_add_to_stack_pointer(16);
+    }
 }
 
 /**
- * Convert an image to grayscale with a human corrected factor, to account for human vision.
- *
- * # Arguments
- * * `photon_image` - A PhotonImage.
- * # Example
- *
- * ```no_run
- * // For example, to convert an image of type `PhotonImage` to grayscale with a human corrected factor:
- * use photon_rs::monochrome::grayscale_human_corrected;
- * use photon_rs::native::open_image;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * grayscale_human_corrected(&mut img);
- * ```
- * @param {PhotonImage} img
+ * Apply vertical flip (mirror top-bottom)
+ * mask: optional selection, mirrored within its bounding box
+ * @param {Uint8Array} image_data
+ * @param {number} width
+ * @param {number} height
+ * @param {Uint8Array | null} [mask]
+ * @returns {Uint8Array}
  */
-export function grayscale_human_corrected(img) {
-    _assertClass(img, PhotonImage);
-    wasm.grayscale_human_corrected(img.__wbg_ptr);
+export function apply_flip_vertical(image_data, width, height, mask) {
+    try {
+        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+        const ptr0 = passArray8ToWasm0(image_data, wasm.__wbindgen_export3);
+        const len0 = WASM_VECTOR_LEN;
+        var ptr1 = isLikeNone(mask) ? 0 : passArray8ToWasm0(mask, wasm.__wbindgen_export3);
+        var len1 = WASM_VECTOR_LEN;
+        wasm.apply_flip_vertical(retptr, ptr0, len0, width, height, ptr1, len1);
+        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+        var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+        var r3 = getDataViewMemory0().getInt32(retptr + 4 * 3, true);
+        if (r3) {
+            throw takeObject(r2);
+        }
+        var v3 = getArrayU8FromWasm0(r0, r1).slice();
+        wasm.__wbindgen_export2(r0, r1 * 1, 1);
+        return v3;
+    } finally {
+        wasm.__wbindgen_add_to_stack_pointer(16);
+    }
 }
 
 /**
- * Desaturate an image by getting the min/max of each pixel's RGB values.
- *
- * # Arguments
- * * `photon_image` - A PhotonImage.
- * # Example
- *
- * ```no_run
- * // For example, to desaturate an image:
- * use photon_rs::monochrome::desaturate;
- * use photon_rs::native::open_image;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * desaturate(&mut img);
- * ```
- * @param {PhotonImage} img
+ * Apply vertical flip in place on a caller-provided buffer (no output allocation)
+ * @param {Uint8Array} image_data
+ * @param {number} width
+ * @param {number} height
  */
-export function desaturate(img) {
-    _assertClass(img, PhotonImage);
-    wasm.desaturate(img.__wbg_ptr);
+export function apply_flip_vertical_in_place(image_data, width, height) {
+    try {
+        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+        var ptr0 = passArray8ToWasm0(image_data, wasm.__wbindgen_export3);
+        var len0 = WASM_VECTOR_LEN;
+        wasm.apply_flip_vertical_in_place(retptr, ptr0, len0, addHeapObject(image_data), width, height);
+        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+        if (r1) {
+            throw takeObject(r0);
+        }
+    } finally {
+        wasm.__wbindgen_add_to_stack_pointer(16);
+    }
 }
 
 /**
- * Uses a min. decomposition algorithm to convert an image to greyscale.
- *
- * # Arguments
- * * `photon_image` - A PhotonImage.
- *
- * # Example
- *
- * ```no_run
- * // For example, to decompose an image with min decomposition:
- * use photon_rs::monochrome::decompose_min;
- * use photon_rs::native::open_image;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * decompose_min(&mut img);
- * ```
- * @param {PhotonImage} img
+ * Apply film grain; the same seed always gives the same grain
+ * amount: 0.0 to 1.0
+ * size: grain size in pixels, default 1.0
+ * roughness: 0.0 (soft) to 1.0 (rough), default 0.5
+ * seed: default 0
+ * @param {Uint8Array} image_data
+ * @param {number} width
+ * @param {number} height
+ * @param {number} amount
+ * @param {number | null} [size]
+ * @param {number | null} [roughness]
+ * @param {number | null} [seed]
+ * @returns {Uint8Array}
  */
-export function decompose_min(img) {
-    _assertClass(img, PhotonImage);
-    wasm.decompose_min(img.__wbg_ptr);
+export function apply_grain(image_data, width, height, amount, size, roughness, seed) {
+    try {
+        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+        const ptr0 = passArray8ToWasm0(image_data, wasm.__wbindgen_export3);
+        const len0 = WASM_VECTOR_LEN;
+        wasm.apply_grain(retptr, ptr0, len0, width, height, amount, isLikeNone(size) ? Number.MAX_SAFE_INTEGER : Math.fround(size), isLikeNone(roughness) ? Number.MAX_SAFE_INTEGER : Math.fround(roughness), isLikeNone(seed) ? Number.MAX_SAFE_INTEGER : (seed) >>> 0);
+        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+        var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+        var r3 = getDataViewMemory0().getInt32(retptr + 4 * 3, true);
+        if (r3) {
+            throw takeObject(r2);
+        }
+        var v2 = getArrayU8FromWasm0(r0, r1).slice();
+        wasm.__wbindgen_export2(r0, r1 * 1, 1);
+        return v2;
+    } finally {
+        wasm.__wbindgen_add_to_stack_pointer(16);
+    }
 }
 
 /**
- * Uses a max. decomposition algorithm to convert an image to greyscale.
- *
- * # Arguments
- * * `photon_image` - A PhotonImage.
- *
- * # Example
- *
- * ```no_run
- * // For example, to decompose an image with max decomposition:
- * use photon_rs::monochrome::decompose_max;
- * use photon_rs::native::open_image;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * decompose_max(&mut img);
- * ```
- * @param {PhotonImage} img
+ * Convert image to grayscale
+ * mask: optional selection, one byte per pixel (see `Mask.data`)
+ * @param {Uint8Array} image_data
+ * @param {number} width
+ * @param {number} height
+ * @param {Uint8Array | null} [mask]
+ * @returns {Uint8Array}
  */
-export function decompose_max(img) {
-    _assertClass(img, PhotonImage);
-    wasm.decompose_max(img.__wbg_ptr);
+export function apply_grayscale(image_data, width, height, mask) {
+    try {
+        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+        const ptr0 = passArray8ToWasm0(image_data, wasm.__wbindgen_export3);
+        const len0 = WASM_VECTOR_LEN;
+        var ptr1 = isLikeNone(mask) ? 0 : passArray8ToWasm0(mask, wasm.__wbindgen_export3);
+        var len1 = WASM_VECTOR_LEN;
+        wasm.apply_grayscale(retptr, ptr0, len0, width, height, ptr1, len1);
+        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+        var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+        var r3 = getDataViewMemory0().getInt32(retptr + 4 * 3, true);
+        if (r3) {
+            throw takeObject(r2);
+        }
+        var v3 = getArrayU8FromWasm0(r0, r1).slice();
+        wasm.__wbindgen_export2(r0, r1 * 1, 1);
+        return v3;
+    } finally {
+        wasm.__wbindgen_add_to_stack_pointer(16);
+    }
 }
 
 /**
- * Employ only a limited number of gray shades in an image.
- *
- * # Arguments
- * * `photon_image` - A PhotonImage.
- * * `num_shades` - The number of grayscale shades to be displayed in the image.
- *
- * # Example
- *
- * ```no_run
- * // For example, to limit an image to four shades of gray only:
- * use photon_rs::monochrome::grayscale_shades;
- * use photon_rs::native::open_image;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * grayscale_shades(&mut img, 4_u8);
- * ```
- * @param {PhotonImage} photon_image
- * @param {number} num_shades
+ * Map an image through a color lookup table
+ * interpolation: "tetrahedral" (default) or "trilinear"
+ * strength: 0.0 (original) to 1.0 (full look), default 1.0
+ * on_progress, progress_every, cancel: as for `apply_blur`; totalRows is the height
+ * @param {Uint8Array} image_data
+ * @param {number} width
+ * @param {number} height
+ * @param {Lut} lut
+ * @param {string | null} [interpolation]
+ * @param {number | null} [strength]
+ * @param {Function | null} [on_progress]
+ * @param {number | null} [progress_every]
+ * @param {AbortSignal | Int32Array | null} [cancel]
+ * @returns {Uint8Array}
  */
-export function grayscale_shades(photon_image, num_shades) {
-    _assertClass(photon_image, PhotonImage);
-    wasm.grayscale_shades(photon_image.__wbg_ptr, num_shades);
+export function apply_lut(image_data, width, height, lut, interpolation, strength, on_progress, progress_every, cancel) {
+    try {
+        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+        const ptr0 = passArray8ToWasm0(image_data, wasm.__wbindgen_export3);
+        const len0 = WASM_VECTOR_LEN;
+        _assertClass(lut, Lut);
+        var ptr1 = isLikeNone(interpolation) ? 0 : passStringToWasm0(interpolation, wasm.__wbindgen_export3, wasm.__wbindgen_export4);
+        var len1 = WASM_VECTOR_LEN;
+        wasm.apply_lut(retptr, ptr0, len0, width, height, lut.__wbg_ptr, ptr1, len1, isLikeNone(strength) ? Number.MAX_SAFE_INTEGER : Math.fround(strength), isLikeNone(on_progress) ? 0 : addHeapObject(on_progress), isLikeNone(progress_every) ? Number.MAX_SAFE_INTEGER : (progress_every) >>> 0, isLikeNone(cancel) ? 0 : addHeapObject(cancel));
+        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+        var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+        var r3 = getDataViewMemory0().getInt32(retptr + 4 * 3, true);
+        if (r3) {
+            throw takeObject(r2);
+        }
+        var v3 = getArrayU8FromWasm0(r0, r1).slice();
+        wasm.__wbindgen_export2(r0, r1 * 1, 1);
+        return v3;
+    } finally {
+        wasm.__wbindgen_add_to_stack_pointer(16);
+    }
 }
 
 /**
- * Convert an image to grayscale by setting a pixel's 3 RGB values to the Red channel's value.
- *
- * # Arguments
- * * `photon_image` - A PhotonImage.
- *
- * # Example
- *
- * ```no_run
- * use photon_rs::monochrome::r_grayscale;
- * use photon_rs::native::open_image;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * r_grayscale(&mut img);
- * ```
- * @param {PhotonImage} photon_image
+ * Rotate image 180 degrees
+ * mask: optional selection, turned within its bounding box
+ * @param {Uint8Array} image_data
+ * @param {number} width
+ * @param {number} height
+ * @param {Uint8Array | null} [mask]
+ * @returns {Uint8Array}
  */
-export function r_grayscale(photon_image) {
-    _assertClass(photon_image, PhotonImage);
-    wasm.r_grayscale(photon_image.__wbg_ptr);
+export function apply_rotate_180(image_data, width, height, mask) {
+    try {
+        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+        const ptr0 = passArray8ToWasm0(image_data, wasm.__wbindgen_export3);
+        const len0 = WASM_VECTOR_LEN;
+        var ptr1 = isLikeNone(mask) ? 0 : passArray8ToWasm0(mask, wasm.__wbindgen_export3);
+        var len1 = WASM_VECTOR_LEN;
+        wasm.apply_rotate_180(retptr, ptr0, len0, width, height, ptr1, len1);
+        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+        var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+        var r3 = getDataViewMemory0().getInt32(retptr + 4 * 3, true);
+        if (r3) {
+            throw takeObject(r2);
+        }
+        var v3 = getArrayU8FromWasm0(r0, r1).slice();
+        wasm.__wbindgen_export2(r0, r1 * 1, 1);
+        return v3;
+    } finally {
+        wasm.__wbindgen_add_to_stack_pointer(16);
+    }
 }
 
 /**
- * Convert an image to grayscale by setting a pixel's 3 RGB values to the Green channel's value.
- *
- * # Arguments
- * * `photon_image` - A PhotonImage.
- *
- * # Example
- *
- * ```no_run
- * use photon_rs::monochrome::g_grayscale;
- * use photon_rs::native::open_image;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * g_grayscale(&mut img);
- * ```
- * @param {PhotonImage} photon_image
+ * Rotate image 180 degrees in place on a caller-provided buffer (no output allocation)
+ * @param {Uint8Array} image_data
+ * @param {number} width
+ * @param {number} height
  */
-export function g_grayscale(photon_image) {
-    _assertClass(photon_image, PhotonImage);
-    wasm.g_grayscale(photon_image.__wbg_ptr);
+export function apply_rotate_180_in_place(image_data, width, height) {
+    try {
+        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+        var ptr0 = passArray8ToWasm0(image_data, wasm.__wbindgen_export3);
+        var len0 = WASM_VECTOR_LEN;
+        wasm.apply_rotate_180_in_place(retptr, ptr0, len0, addHeapObject(image_data), width, height);
+        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+        if (r1) {
+            throw takeObject(r0);
+        }
+    } finally {
+        wasm.__wbindgen_add_to_stack_pointer(16);
+    }
 }
 
 /**
- * Convert an image to grayscale by setting a pixel's 3 RGB values to the Blue channel's value.
- *
- * # Arguments
- * * `photon_image` - A PhotonImage.
- *
- * # Example
- *
- * ```no_run
- * use photon_rs::monochrome::b_grayscale;
- * use photon_rs::native::open_image;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * b_grayscale(&mut img);
- * ```
- * @param {PhotonImage} photon_image
+ * Rotate image 270 degrees clockwise (90 degrees counter-clockwise)
+ * mask: optional selection, turned within its bounding box, which must be square
+ * @param {Uint8Array} image_data
+ * @param {number} width
+ * @param {number} height
+ * @param {Uint8Array | null} [mask]
+ * @returns {Uint8Array}
  */
-export function b_grayscale(photon_image) {
-    _assertClass(photon_image, PhotonImage);
-    wasm.b_grayscale(photon_image.__wbg_ptr);
+export function apply_rotate_270_cw(image_data, width, height, mask) {
+    try {
+        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+        const ptr0 = passArray8ToWasm0(image_data, wasm.__wbindgen_export3);
+        const len0 = WASM_VECTOR_LEN;
+        var ptr1 = isLikeNone(mask) ? 0 : passArray8ToWasm0(mask, wasm.__wbindgen_export3);
+        var len1 = WASM_VECTOR_LEN;
+        wasm.apply_rotate_270_cw(retptr, ptr0, len0, width, height, ptr1, len1);
+        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+        var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+        var r3 = getDataViewMemory0().getInt32(retptr + 4 * 3, true);
+        if (r3) {
+            throw takeObject(r2);
+        }
+        var v3 = getArrayU8FromWasm0(r0, r1).slice();
+        wasm.__wbindgen_export2(r0, r1 * 1, 1);
+        return v3;
+    } finally {
+        wasm.__wbindgen_add_to_stack_pointer(16);
+    }
 }
 
 /**
- * Convert an image to grayscale by setting a pixel's 3 RGB values to a chosen channel's value.
- *
- * # Arguments
- * * `photon_image` - A PhotonImage.
- * * `channel` - A usize representing the channel from 0 to 2. O represents the Red channel, 1 the Green channel, and 2 the Blue channel.
- *
- * # Example
- * To grayscale using only values from the Red channel:
- * ```no_run
- * use photon_rs::monochrome::single_channel_grayscale;
- * use photon_rs::native::open_image;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * single_channel_grayscale(&mut img, 0_usize);
- * ```
- * @param {PhotonImage} photon_image
- * @param {number} channel
+ * Rotate image 90 degrees clockwise
+ * mask: optional selection, turned within its bounding box, which must be square
+ * @param {Uint8Array} image_data
+ * @param {number} width
+ * @param {number} height
+ * @param {Uint8Array | null} [mask]
+ * @returns {Uint8Array}
  */
-export function single_channel_grayscale(photon_image, channel) {
-    _assertClass(photon_image, PhotonImage);
-    wasm.single_channel_grayscale(photon_image.__wbg_ptr, channel);
+export function apply_rotate_90_cw(image_data, width, height, mask) {
+    try {
+        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+        const ptr0 = passArray8ToWasm0(image_data, wasm.__wbindgen_export3);
+        const len0 = WASM_VECTOR_LEN;
+        var ptr1 = isLikeNone(mask) ? 0 : passArray8ToWasm0(mask, wasm.__wbindgen_export3);
+        var len1 = WASM_VECTOR_LEN;
+        wasm.apply_rotate_90_cw(retptr, ptr0, len0, width, height, ptr1, len1);
+        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+        var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+        var r3 = getDataViewMemory0().getInt32(retptr + 4 * 3, true);
+        if (r3) {
+            throw takeObject(r2);
+        }
+        var v3 = getArrayU8FromWasm0(r0, r1).slice();
+        wasm.__wbindgen_export2(r0, r1 * 1, 1);
+        return v3;
+    } finally {
+        wasm.__wbindgen_add_to_stack_pointer(16);
+    }
 }
 
 /**
- * Threshold an image using a standard thresholding algorithm.
- *
- * # Arguments
- * * `photon_image` - A PhotonImage.
- * * `threshold` - The amount the image should be thresholded by from 0 to 255.
- * # Example
- *
- * ```no_run
- * // For example, to threshold an image of type `PhotonImage`:
- * use photon_rs::monochrome::threshold;
- * use photon_rs::native::open_image;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * threshold(&mut img, 30_u32);
- * ```
- * @param {PhotonImage} img
- * @param {number} threshold
+ * Apply a vignette
+ * amount: -1.0 (black edges) to 1.0 (white edges)
+ * midpoint: 0.0 (center) to 1.0 (corners), default 0.5
+ * roundness: -1.0 (rounded rectangle) to 1.0 (circle), default 0.0 (image aspect)
+ * feather: 0.0 (hard) to 1.0 (soft), default 0.5
+ * highlights: 0.0 to 1.0, how much bright pixels are spared from darkening, default 0.0
+ * @param {Uint8Array} image_data
+ * @param {number} width
+ * @param {number} height
+ * @param {number} amount
+ * @param {number | null} [midpoint]
+ * @param {number | null} [roundness]
+ * @param {number | null} [feather]
+ * @param {number | null} [highlights]
+ * @returns {Uint8Array}
  */
-export function threshold(img, threshold) {
-    _assertClass(img, PhotonImage);
-    wasm.threshold(img.__wbg_ptr, threshold);
+export function apply_vignette(image_data, width, height, amount, midpoint, roundness, feather, highlights) {
+    try {
+        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+        const ptr0 = passArray8ToWasm0(image_data, wasm.__wbindgen_export3);
+        const len0 = WASM_VECTOR_LEN;
+        wasm.apply_vignette(retptr, ptr0, len0, width, height, amount, isLikeNone(midpoint) ? Number.MAX_SAFE_INTEGER : Math.fround(midpoint), isLikeNone(roundness) ? Number.MAX_SAFE_INTEGER : Math.fround(roundness), isLikeNone(feather) ? Number.MAX_SAFE_INTEGER : Math.fround(feather), isLikeNone(highlights) ? Number.MAX_SAFE_INTEGER : Math.fround(highlights));
+        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+        var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+        var r3 = getDataViewMemory0().getInt32(retptr + 4 * 3, true);
+        if (r3) {
+            throw takeObject(r2);
+        }
+        var v2 = getArrayU8FromWasm0(r0, r1).slice();
+        wasm.__wbindgen_export2(r0, r1 * 1, 1);
+        return v2;
+    } finally {
+        wasm.__wbindgen_add_to_stack_pointer(16);
+    }
 }
 
 /**
- * Add a watermark to an image.
- *
- * # Arguments
- * * `img` - A DynamicImage that contains a view into the image.
- * * `watermark` - The watermark to be placed onto the `img` image.
- * * `x` - The x coordinate where the watermark's top corner should be positioned.
- * * `y` - The y coordinate where the watermark's top corner should be positioned.
- * # Example
- *
- * ```no_run
- * // For example, to add a watermark to an image at x: 30, y: 40:
- * use photon_rs::multiple::watermark;
- * use photon_rs::native::open_image;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * let water_mark = open_image("watermark.jpg").expect("File should open");
- * watermark(&mut img, &water_mark, 30_i64, 40_i64);
- * ```
- * @param {PhotonImage} img
- * @param {PhotonImage} watermark
- * @param {bigint} x
- * @param {bigint} y
+ * Overlay `watermark` on an image, e.g. as the last step before encoding an export
+ * @param {Uint8Array} image_data
+ * @param {number} width
+ * @param {number} height
+ * @param {Watermark} watermark
+ * @returns {Uint8Array}
  */
-export function watermark(img, watermark, x, y) {
-    _assertClass(img, PhotonImage);
-    _assertClass(watermark, PhotonImage);
-    wasm.watermark(img.__wbg_ptr, watermark.__wbg_ptr, x, y);
+export function apply_watermark(image_data, width, height, watermark) {
+    try {
+        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+        const ptr0 = passArray8ToWasm0(image_data, wasm.__wbindgen_export3);
+        const len0 = WASM_VECTOR_LEN;
+        _assertClass(watermark, Watermark);
+        wasm.apply_watermark(retptr, ptr0, len0, width, height, watermark.__wbg_ptr);
+        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+        var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+        var r3 = getDataViewMemory0().getInt32(retptr + 4 * 3, true);
+        if (r3) {
+            throw takeObject(r2);
+        }
+        var v2 = getArrayU8FromWasm0(r0, r1).slice();
+        wasm.__wbindgen_export2(r0, r1 * 1, 1);
+        return v2;
+    } finally {
+        wasm.__wbindgen_add_to_stack_pointer(16);
+    }
 }
 
 /**
- * Blend two images together.
- *
- * The `blend_mode` (3rd param) determines which blending mode to use; change this for varying effects.
- * The blend modes available include: `overlay`, `over`, `atop`, `xor`, `plus`, `multiply`, `burn`,
- * `difference`, `soft_light`, `screen`, `hard_light`, `dodge`, `exclusion`, `lighten`, `darken` (more to come)
- * NOTE: The first image must be smaller than the second image passed as params.
- * If the first image were larger than the second, then there would be overflowing pixels which would have no corresponding pixels
- * in the second image.
- * # Arguments
- * * `img` - A DynamicImage that contains a view into the image.
- * * `img2` - The 2nd DynamicImage to be blended with the first.
- * * `blend_mode` - The blending mode to use. See above for complete list of blend modes available.
- * # Example
- *
- * ```no_run
- * // For example, to blend two images with the `multiply` blend mode:
- * use photon_rs::multiple::blend;
- * use photon_rs::native::open_image;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * let img2 = open_image("img2.jpg").expect("File should open");
- * blend(&mut img, &img2, "multiply");
- * ```
- * @param {PhotonImage} photon_image
- * @param {PhotonImage} photon_image2
- * @param {string} blend_mode
+ * Correct white balance in linear light
+ * temperature: negative is cooler, positive warmer; 1.0 doubles red against blue
+ * tint: negative is greener, positive more magenta
+ * on_progress, progress_every, cancel: as for `apply_blur`; totalRows is the height
+ * @param {Uint8Array} image_data
+ * @param {number} width
+ * @param {number} height
+ * @param {number} temperature
+ * @param {number} tint
+ * @param {Function | null} [on_progress]
+ * @param {number | null} [progress_every]
+ * @param {AbortSignal | Int32Array | null} [cancel]
+ * @returns {Uint8Array}
  */
-export function blend(photon_image, photon_image2, blend_mode) {
-    _assertClass(photon_image, PhotonImage);
-    _assertClass(photon_image2, PhotonImage);
-    const ptr0 = passStringToWasm0(blend_mode, wasm.__wbindgen_export_1, wasm.__wbindgen_export_3);
-    const len0 = WASM_VECTOR_LEN;
-    wasm.blend(photon_image.__wbg_ptr, photon_image2.__wbg_ptr, ptr0, len0);
+export function apply_white_balance(image_data, width, height, temperature, tint, on_progress, progress_every, cancel) {
+    try {
+        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+        const ptr0 = passArray8ToWasm0(image_data, wasm.__wbindgen_export3);
+        const len0 = WASM_VECTOR_LEN;
+        wasm.apply_white_balance(retptr, ptr0, len0, width, height, temperature, tint, isLikeNone(on_progress) ? 0 : addHeapObject(on_progress), isLikeNone(progress_every) ? Number.MAX_SAFE_INTEGER : (progress_every) >>> 0, isLikeNone(cancel) ? 0 : addHeapObject(cancel));
+        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+        var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+        var r3 = getDataViewMemory0().getInt32(retptr + 4 * 3, true);
+        if (r3) {
+            throw takeObject(r2);
+        }
+        var v2 = getArrayU8FromWasm0(r0, r1).slice();
+        wasm.__wbindgen_export2(r0, r1 * 1, 1);
+        return v2;
+    } finally {
+        wasm.__wbindgen_add_to_stack_pointer(16);
+    }
 }
 
 /**
+ * Automatic white balance, measured on the unedited image
+ *
+ * Returns [temperature, tint] for `apply_white_balance` or the sliders.
+ * method: "gray-world" (the average color is gray) or "white-patch" (the brightest surfaces are white)
+ * @param {Uint8Array} image_data
  * @param {number} width
  * @param {number} height
- * @returns {PhotonImage}
- */
-export function create_gradient(width, height) {
-    const ret = wasm.create_gradient(width, height);
-    return PhotonImage.__wrap(ret);
-}
-
-/**
- * Apply a gradient to an image.
- * @param {PhotonImage} image
- */
-export function apply_gradient(image) {
-    _assertClass(image, PhotonImage);
-    wasm.apply_gradient(image.__wbg_ptr);
-}
-
-/**
- * Add randomized noise to an image.
- * This function adds a Gaussian Noise Sample to each pixel through incrementing each channel by a randomized offset.
- * This randomized offset is generated by creating a randomized thread pool.
- * **[WASM SUPPORT IS AVAILABLE]**: Randomized thread pools cannot be created with WASM, but
- * a workaround using js_sys::Math::random works now.
- * # Arguments
- * * `img` - A PhotonImage.
- *
- * # Example
- *
- * ```no_run
- * // For example:
- * use photon_rs::native::open_image;
- * use photon_rs::noise::add_noise_rand;
- * use photon_rs::PhotonImage;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * add_noise_rand(&mut img);
- * ```
- * @param {PhotonImage} photon_image
+ * @param {string} method
+ * @returns {Float32Array}
  */
-export function add_noise_rand(photon_image) {
-    _assertClass(photon_image, PhotonImage);
-    wasm.add_noise_rand(photon_image.__wbg_ptr);
+export function auto_white_balance(image_data, width, height, method) {
+    try {
+        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+        const ptr0 = passArray8ToWasm0(image_data, wasm.__wbindgen_export3);
+        const len0 = WASM_VECTOR_LEN;
+        const ptr1 = passStringToWasm0(method, wasm.__wbindgen_export3, wasm.__wbindgen_export4);
+        const len1 = WASM_VECTOR_LEN;
+        wasm.auto_white_balance(retptr, ptr0, len0, width, height, ptr1, len1);
+        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+        var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+        var r3 = getDataViewMemory0().getInt32(retptr + 4 * 3, true);
+        if (r3) {
+            throw takeObject(r2);
+        }
+        var v3 = getArrayF32FromWasm0(r0, r1).slice();
+        wasm.__wbindgen_export2(r0, r1 * 4, 4);
+        return v3;
+    } finally {
+        wasm.__wbindgen_add_to_stack_pointer(16);
+    }
 }
 
 /**
- * Add pink-tinted noise to an image.
- *
- * **[WASM SUPPORT IS AVAILABLE]**: Randomized thread pools cannot be created with WASM, but
- * a workaround using js_sys::Math::random works now.
- * # Arguments
- * * `name` - A PhotonImage that contains a view into the image.
- *
- * # Example
- *
- * ```no_run
- * // For example, to add pink-tinted noise to an image:
- * use photon_rs::native::open_image;
- * use photon_rs::noise::pink_noise;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * pink_noise(&mut img);
- * ```
- * @param {PhotonImage} photon_image
+ * Halo needed by `apply_blur_tile` for a seamless result
+ * @param {number} image_width
+ * @param {number} image_height
+ * @param {number} radius
+ * @returns {number}
  */
-export function pink_noise(photon_image) {
-    _assertClass(photon_image, PhotonImage);
-    wasm.pink_noise(photon_image.__wbg_ptr);
+export function blur_halo(image_width, image_height, radius) {
+    const ret = wasm.blur_halo(image_width, image_height, radius);
+    return ret >>> 0;
 }
 
 /**
- * Add bordered-text to an image.
- * The only font available as of now is Roboto.
- * Note: A graphic design/text-drawing library is currently being developed, so stay tuned.
- *
- * # Arguments
- * * `photon_image` - A PhotonImage.
- * * `text` - Text string to be drawn to the image.
- * * `x` - x-coordinate of where first letter's 1st pixel should be drawn.
- * * `y` - y-coordinate of where first letter's 1st pixel should be drawn.
- * * `font_size` - Font size in pixels of the text to be drawn.
- *
- * # Example
- *
- * ```no_run
- * // For example to draw the string "Welcome to Photon!" at 10, 10:
- * use photon_rs::native::open_image;
- * use photon_rs::text::draw_text_with_border;
+ * Compute R, G, B, luminance and alpha histograms in one pass
  *
- * // Open the image. A PhotonImage is returned.
- * let mut img = open_image("img.jpg").expect("File should open");
- * draw_text_with_border(&mut img, "Welcome to Photon!", 10_i32, 10_i32, 90_f32);
- * ```
- * @param {PhotonImage} photon_img
- * @param {string} text
- * @param {number} x
- * @param {number} y
- * @param {number} font_size
- */
-export function draw_text_with_border(photon_img, text, x, y, font_size) {
-    _assertClass(photon_img, PhotonImage);
-    const ptr0 = passStringToWasm0(text, wasm.__wbindgen_export_1, wasm.__wbindgen_export_3);
-    const len0 = WASM_VECTOR_LEN;
-    wasm.draw_text_with_border(photon_img.__wbg_ptr, ptr0, len0, x, y, font_size);
+ * Returns a `Uint32Array` of 5 x 256 bins in the order R, G, B, luminance, alpha.
+ * stride: sample every n-th pixel in both directions (default 1)
+ * x, y, roi_width, roi_height: optional region of interest, same rules as `apply_crop`
+ * @param {Uint8Array} image_data
+ * @param {number} width
+ * @param {number} height
+ * @param {number | null} [stride]
+ * @param {number | null} [x]
+ * @param {number | null} [y]
+ * @param {number | null} [roi_width]
+ * @param {number | null} [roi_height]
+ * @returns {Uint32Array}
+ */
+export function compute_histogram(image_data, width, height, stride, x, y, roi_width, roi_height) {
+    try {
+        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+        const ptr0 = passArray8ToWasm0(image_data, wasm.__wbindgen_export3);
+        const len0 = WASM_VECTOR_LEN;
+        wasm.compute_histogram(retptr, ptr0, len0, width, height, isLikeNone(stride) ? Number.MAX_SAFE_INTEGER : (stride) >>> 0, isLikeNone(x) ? Number.MAX_SAFE_INTEGER : (x) >>> 0, isLikeNone(y) ? Number.MAX_SAFE_INTEGER : (y) >>> 0, isLikeNone(roi_width) ? Number.MAX_SAFE_INTEGER : (roi_width) >>> 0, isLikeNone(roi_height) ? Number.MAX_SAFE_INTEGER : (roi_height) >>> 0);
+        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+        var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+        var r3 = getDataViewMemory0().getInt32(retptr + 4 * 3, true);
+        if (r3) {
+            throw takeObject(r2);
+        }
+        var v2 = getArrayU32FromWasm0(r0, r1).slice();
+        wasm.__wbindgen_export2(r0, r1 * 4, 4);
+        return v2;
+    } finally {
+        wasm.__wbindgen_add_to_stack_pointer(16);
+    }
 }
 
 /**
- * Add text to an image.
- * The only font available as of now is Roboto.
- * Note: A graphic design/text-drawing library is currently being developed, so stay tuned.
- *
- * # Arguments
- * * `photon_image` - A PhotonImage.
- * * `text` - Text string to be drawn to the image.
- * * `x` - x-coordinate of where first letter's 1st pixel should be drawn.
- * * `y` - y-coordinate of where first letter's 1st pixel should be drawn.
- * * `font_size` - Font size in pixels of the text to be drawn.
- *
- * # Example
- *
- * ```no_run
- * // For example to draw the string "Welcome to Photon!" at 10, 10:
- * use photon_rs::native::open_image;
- * use photon_rs::text::draw_text;
- *
- * // Open the image. A PhotonImage is returned.
- * let mut img = open_image("img.jpg").expect("File should open");
- * draw_text(&mut img, "Welcome to Photon!", 10_i32, 10_i32, 90_f32);
- * ```
- * @param {PhotonImage} photon_img
- * @param {string} text
- * @param {number} x
- * @param {number} y
- * @param {number} font_size
+ * Compute aHash, dHash and pHash for near-duplicate detection
+ * Matches the `hashes` field returned by the image-metadata service
+ * @param {Uint8Array} image_data
+ * @param {number} width
+ * @param {number} height
+ * @returns {PerceptualHashes}
  */
-export function draw_text(photon_img, text, x, y, font_size) {
-    _assertClass(photon_img, PhotonImage);
-    const ptr0 = passStringToWasm0(text, wasm.__wbindgen_export_1, wasm.__wbindgen_export_3);
-    const len0 = WASM_VECTOR_LEN;
-    wasm.draw_text(photon_img.__wbg_ptr, ptr0, len0, x, y, font_size);
+export function compute_perceptual_hashes(image_data, width, height) {
+    try {
+        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+        const ptr0 = passArray8ToWasm0(image_data, wasm.__wbindgen_export3);
+        const len0 = WASM_VECTOR_LEN;
+        wasm.compute_perceptual_hashes(retptr, ptr0, len0, width, height);
+        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+        var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+        if (r2) {
+            throw takeObject(r1);
+        }
+        return PerceptualHashes.__wrap(r0);
+    } finally {
+        wasm.__wbindgen_add_to_stack_pointer(16);
+    }
 }
 
 /**
- * Crop an image.
- *
- * # Arguments
- * * `img` - A PhotonImage.
+ * Arrow from (`x1`, `y1`) to a head at (`x2`, `y2`), drawn in place
  *
- * # Example
- *
- * ```no_run
- * // For example, to crop an image at (0, 0) to (500, 800)
- * use photon_rs::native::{open_image};
- * use photon_rs::transform::crop;
- * use photon_rs::PhotonImage;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * let cropped_img: PhotonImage = crop(&img, 0_u32, 0_u32, 500_u32, 800_u32);
- * // Write the contents of this image in JPG format.
- * ```
- * @param {PhotonImage} photon_image
+ * head_length: pixels (default 4 x line_width)
+ * @param {Uint8Array} image_data
+ * @param {number} width
+ * @param {number} height
  * @param {number} x1
  * @param {number} y1
  * @param {number} x2
  * @param {number} y2
- * @returns {PhotonImage}
+ * @param {number} color
+ * @param {number} line_width
+ * @param {number | null} [head_length]
  */
-export function crop(photon_image, x1, y1, x2, y2) {
-    _assertClass(photon_image, PhotonImage);
-    const ret = wasm.crop(photon_image.__wbg_ptr, x1, y1, x2, y2);
-    return PhotonImage.__wrap(ret);
+export function draw_arrow(image_data, width, height, x1, y1, x2, y2, color, line_width, head_length) {
+    try {
+        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+        var ptr0 = passArray8ToWasm0(image_data, wasm.__wbindgen_export3);
+        var len0 = WASM_VECTOR_LEN;
+        wasm.draw_arrow(retptr, ptr0, len0, addHeapObject(image_data), width, height, x1, y1, x2, y2, color, line_width, isLikeNone(head_length) ? Number.MAX_SAFE_INTEGER : Math.fround(head_length));
+        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+        if (r1) {
+            throw takeObject(r0);
+        }
+    } finally {
+        wasm.__wbindgen_add_to_stack_pointer(16);
+    }
 }
 
 /**
- * @param {HTMLCanvasElement} source_canvas
+ * Ellipse centred on (`cx`, `cy`), drawn in place
+ * @param {Uint8Array} image_data
  * @param {number} width
  * @param {number} height
- * @param {number} left
- * @param {number} top
- * @returns {HTMLCanvasElement}
- */
-export function crop_img_browser(source_canvas, width, height, left, top) {
-    const ret = wasm.crop_img_browser(addHeapObject(source_canvas), width, height, left, top);
-    return takeObject(ret);
-}
-
-/**
- * Flip an image horizontally.
- *
- * # Arguments
- * * `img` - A PhotonImage.
- *
- * # Example
- *
- * ```no_run
- * // For example, to flip an image horizontally:
- * use photon_rs::native::open_image;
- * use photon_rs::transform::fliph;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * fliph(&mut img);
- * ```
- * @param {PhotonImage} photon_image
+ * @param {number} cx
+ * @param {number} cy
+ * @param {number} rx
+ * @param {number} ry
+ * @param {DrawStyle} style
  */
-export function fliph(photon_image) {
-    _assertClass(photon_image, PhotonImage);
-    wasm.fliph(photon_image.__wbg_ptr);
+export function draw_ellipse(image_data, width, height, cx, cy, rx, ry, style) {
+    try {
+        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+        var ptr0 = passArray8ToWasm0(image_data, wasm.__wbindgen_export3);
+        var len0 = WASM_VECTOR_LEN;
+        _assertClass(style, DrawStyle);
+        wasm.draw_ellipse(retptr, ptr0, len0, addHeapObject(image_data), width, height, cx, cy, rx, ry, style.__wbg_ptr);
+        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+        if (r1) {
+            throw takeObject(r0);
+        }
+    } finally {
+        wasm.__wbindgen_add_to_stack_pointer(16);
+    }
 }
 
 /**
- * Flip an image vertically.
- *
- * # Arguments
- * * `img` - A PhotonImage.
- *
- * # Example
- *
- * ```no_run
- * // For example, to flip an image vertically:
- * use photon_rs::native::open_image;
- * use photon_rs::transform::flipv;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * flipv(&mut img);
- * ```
- * @param {PhotonImage} photon_image
+ * Anti-aliased line with round caps, drawn in place; color is 0xRRGGBBAA
+ * @param {Uint8Array} image_data
+ * @param {number} width
+ * @param {number} height
+ * @param {number} x1
+ * @param {number} y1
+ * @param {number} x2
+ * @param {number} y2
+ * @param {number} color
+ * @param {number} line_width
  */
-export function flipv(photon_image) {
-    _assertClass(photon_image, PhotonImage);
-    wasm.flipv(photon_image.__wbg_ptr);
+export function draw_line(image_data, width, height, x1, y1, x2, y2, color, line_width) {
+    try {
+        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+        var ptr0 = passArray8ToWasm0(image_data, wasm.__wbindgen_export3);
+        var len0 = WASM_VECTOR_LEN;
+        wasm.draw_line(retptr, ptr0, len0, addHeapObject(image_data), width, height, x1, y1, x2, y2, color, line_width);
+        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+        if (r1) {
+            throw takeObject(r0);
+        }
+    } finally {
+        wasm.__wbindgen_add_to_stack_pointer(16);
+    }
 }
 
 /**
- * Resize an image on the web.
- *
- * # Arguments
- * * `img` - A PhotonImage.
- * * `width` - New width.
- * * `height` - New height.
- * * `sampling_filter` - Nearest = 1, Triangle = 2, CatmullRom = 3, Gaussian = 4, Lanczos3 = 5
- * @param {PhotonImage} photon_img
+ * Closed polygon drawn in place; points are flat [x0, y0, x1, y1, ...]
+ * @param {Uint8Array} image_data
  * @param {number} width
  * @param {number} height
- * @param {SamplingFilter} sampling_filter
- * @returns {HTMLCanvasElement}
+ * @param {Float32Array} points
+ * @param {DrawStyle} style
  */
-export function resize_img_browser(photon_img, width, height, sampling_filter) {
-    _assertClass(photon_img, PhotonImage);
-    const ret = wasm.resize_img_browser(photon_img.__wbg_ptr, width, height, sampling_filter);
-    return takeObject(ret);
+export function draw_polygon(image_data, width, height, points, style) {
+    try {
+        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+        var ptr0 = passArray8ToWasm0(image_data, wasm.__wbindgen_export3);
+        var len0 = WASM_VECTOR_LEN;
+        const ptr1 = passArrayF32ToWasm0(points, wasm.__wbindgen_export3);
+        const len1 = WASM_VECTOR_LEN;
+        _assertClass(style, DrawStyle);
+        wasm.draw_polygon(retptr, ptr0, len0, addHeapObject(image_data), width, height, ptr1, len1, style.__wbg_ptr);
+        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+        if (r1) {
+            throw takeObject(r0);
+        }
+    } finally {
+        wasm.__wbindgen_add_to_stack_pointer(16);
+    }
 }
 
 /**
- * Resize an image.
- *
- * # Arguments
- * * `img` - A PhotonImage.
- * * `width` - New width.
- * * `height` - New height.
- * * `sampling_filter` - Nearest = 1, Triangle = 2, CatmullRom = 3, Gaussian = 4, Lanczos3 = 5
- * @param {PhotonImage} photon_img
+ * Rectangle with its top-left corner at (`x`, `y`), drawn in place
+ * @param {Uint8Array} image_data
  * @param {number} width
  * @param {number} height
- * @param {SamplingFilter} sampling_filter
- * @returns {PhotonImage}
+ * @param {number} x
+ * @param {number} y
+ * @param {number} rect_width
+ * @param {number} rect_height
+ * @param {DrawStyle} style
  */
-export function resize(photon_img, width, height, sampling_filter) {
-    _assertClass(photon_img, PhotonImage);
-    const ret = wasm.resize(photon_img.__wbg_ptr, width, height, sampling_filter);
-    return PhotonImage.__wrap(ret);
+export function draw_rect(image_data, width, height, x, y, rect_width, rect_height, style) {
+    try {
+        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+        var ptr0 = passArray8ToWasm0(image_data, wasm.__wbindgen_export3);
+        var len0 = WASM_VECTOR_LEN;
+        _assertClass(style, DrawStyle);
+        wasm.draw_rect(retptr, ptr0, len0, addHeapObject(image_data), width, height, x, y, rect_width, rect_height, style.__wbg_ptr);
+        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+        if (r1) {
+            throw takeObject(r0);
+        }
+    } finally {
+        wasm.__wbindgen_add_to_stack_pointer(16);
+    }
 }
 
 /**
- * Resize image using seam carver.
- * Resize only if new dimensions are smaller, than original image.
- * # NOTE: This is still experimental feature, and pretty slow.
- *
- * # Arguments
- * * `img` - A PhotonImage.
- * * `width` - New width.
- * * `height` - New height.
- *
- * # Example
- *
- * ```no_run
- * // For example, resize image using seam carver:
- * use photon_rs::native::open_image;
- * use photon_rs::transform::seam_carve;
- * use photon_rs::PhotonImage;
- *
- * let img = open_image("img.jpg").expect("File should open");
- * let result: PhotonImage = seam_carve(&img, 100_u32, 100_u32);
- * ```
- * @param {PhotonImage} img
- * @param {number} width
- * @param {number} height
- * @returns {PhotonImage}
+ * Get a greeting message (test function)
+ * @param {string} name
+ * @returns {string}
  */
-export function seam_carve(img, width, height) {
-    _assertClass(img, PhotonImage);
-    const ret = wasm.seam_carve(img.__wbg_ptr, width, height);
-    return PhotonImage.__wrap(ret);
+export function greet(name) {
+    let deferred2_0;
+    let deferred2_1;
+    try {
+        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+        const ptr0 = passStringToWasm0(name, wasm.__wbindgen_export3, wasm.__wbindgen_export4);
+        const len0 = WASM_VECTOR_LEN;
+        wasm.greet(retptr, ptr0, len0);
+        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+        deferred2_0 = r0;
+        deferred2_1 = r1;
+        return getStringFromWasm0(r0, r1);
+    } finally {
+        wasm.__wbindgen_add_to_stack_pointer(16);
+        wasm.__wbindgen_export2(deferred2_0, deferred2_1, 1);
+    }
 }
 
 /**
- * Shear the image along the X axis.
- * A sheared PhotonImage is returned.
- *
- * # Arguments
- * * `img` - A PhotonImage. See the PhotonImage struct for details.
- * * `shear` - Amount to shear.
- *
- * # Example
- *
- * ```no_run
- * // For example, to shear an image by 0.5:
- * use photon_rs::native::open_image;
- * use photon_rs::transform::shearx;
- *
- * let img = open_image("img.jpg").expect("File should open");
- * let sheared_img = shearx(&img, 0.5);
- * ```
- * @param {PhotonImage} photon_img
- * @param {number} shear
- * @returns {PhotonImage}
+ * Hamming distance (0-64) between two hex hashes of the same kind
+ * @param {string} hash_a
+ * @param {string} hash_b
+ * @returns {number}
  */
-export function shearx(photon_img, shear) {
-    _assertClass(photon_img, PhotonImage);
-    const ret = wasm.shearx(photon_img.__wbg_ptr, shear);
-    return PhotonImage.__wrap(ret);
+export function hamming_distance(hash_a, hash_b) {
+    try {
+        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+        const ptr0 = passStringToWasm0(hash_a, wasm.__wbindgen_export3, wasm.__wbindgen_export4);
+        const len0 = WASM_VECTOR_LEN;
+        const ptr1 = passStringToWasm0(hash_b, wasm.__wbindgen_export3, wasm.__wbindgen_export4);
+        const len1 = WASM_VECTOR_LEN;
+        wasm.hamming_distance(retptr, ptr0, len0, ptr1, len1);
+        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+        var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+        if (r2) {
+            throw takeObject(r1);
+        }
+        return r0 >>> 0;
+    } finally {
+        wasm.__wbindgen_add_to_stack_pointer(16);
+    }
 }
 
 /**
- * Shear the image along the Y axis.
- * A sheared PhotonImage is returned.
- *
- * # Arguments
- * * `img` - A PhotonImage. See the PhotonImage struct for details.
- * * `shear` - Amount to shear.
- *
- * # Example
- *
- * ```no_run
- * // For example, to shear an image by 0.5:
- * use photon_rs::native::open_image;
- * use photon_rs::transform::sheary;
- *
- * let img = open_image("img.jpg").expect("File should open");
- * let sheared_img = sheary(&img, 0.5);
- * ```
- * @param {PhotonImage} photon_img
- * @param {number} shear
- * @returns {PhotonImage}
+ * Initialize the WASM module
+ * This should be called once when the module is loaded
  */
-export function sheary(photon_img, shear) {
-    _assertClass(photon_img, PhotonImage);
-    const ret = wasm.sheary(photon_img.__wbg_ptr, shear);
-    return PhotonImage.__wrap(ret);
+export function init() {
+    wasm.init();
 }
 
 /**
- * Apply uniform padding around the PhotonImage
- * A padded PhotonImage is returned.
- * # Arguments
- * * `img` - A PhotonImage. See the PhotonImage struct for details.
- * * `padding` - The amount of padding to be applied to the PhotonImage.
- * * `padding_rgba` - Tuple containing the RGBA code for padding color.
- *
- * # Example
- *
- * ```no_run
- * // For example, to apply a padding of 10 pixels around a PhotonImage:
- * use photon_rs::transform::padding_uniform;
- * use photon_rs::native::open_image;
- * use photon_rs::Rgba;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * let rgba = Rgba::new(200_u8, 100_u8, 150_u8, 255_u8);
- * padding_uniform(&img, 10_u32, rgba);
- * ```
- * @param {PhotonImage} img
- * @param {number} padding
- * @param {Rgba} padding_rgba
- * @returns {PhotonImage}
+ * Whether two pHashes are close enough to flag as a near-duplicate upload
+ * @param {string} phash_a
+ * @param {string} phash_b
+ * @returns {boolean}
  */
-export function padding_uniform(img, padding, padding_rgba) {
-    _assertClass(img, PhotonImage);
-    _assertClass(padding_rgba, Rgba);
-    var ptr0 = padding_rgba.__destroy_into_raw();
-    const ret = wasm.padding_uniform(img.__wbg_ptr, padding, ptr0);
-    return PhotonImage.__wrap(ret);
+export function is_near_duplicate(phash_a, phash_b) {
+    try {
+        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+        const ptr0 = passStringToWasm0(phash_a, wasm.__wbindgen_export3, wasm.__wbindgen_export4);
+        const len0 = WASM_VECTOR_LEN;
+        const ptr1 = passStringToWasm0(phash_b, wasm.__wbindgen_export3, wasm.__wbindgen_export4);
+        const len1 = WASM_VECTOR_LEN;
+        wasm.is_near_duplicate(retptr, ptr0, len0, ptr1, len1);
+        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+        var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+        if (r2) {
+            throw takeObject(r1);
+        }
+        return r0 !== 0;
+    } finally {
+        wasm.__wbindgen_add_to_stack_pointer(16);
+    }
 }
 
 /**
- * Apply padding on the left side of the PhotonImage
- * A padded PhotonImage is returned.
- * # Arguments
- * * `img` - A PhotonImage. See the PhotonImage struct for details.
- * * `padding` - The amount of padding to be applied to the PhotonImage.
- * * `padding_rgba` - Tuple containing the RGBA code for padding color.
- *
- * # Example
- *
- * ```no_run
- * // For example, to apply a padding of 10 pixels on the left side of a PhotonImage:
- * use photon_rs::transform::padding_left;
- * use photon_rs::native::open_image;
- * use photon_rs::Rgba;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * let rgba = Rgba::new(200_u8, 100_u8, 150_u8, 255_u8);
- * padding_left(&img, 10_u32, rgba);
- * ```
- * @param {PhotonImage} img
- * @param {number} padding
- * @param {Rgba} padding_rgba
- * @returns {PhotonImage}
+ * Timing report of the last successful call on this thread (main thread or worker)
+ * @returns {TimingReport | undefined}
  */
-export function padding_left(img, padding, padding_rgba) {
-    _assertClass(img, PhotonImage);
-    _assertClass(padding_rgba, Rgba);
-    var ptr0 = padding_rgba.__destroy_into_raw();
-    const ret = wasm.padding_left(img.__wbg_ptr, padding, ptr0);
-    return PhotonImage.__wrap(ret);
+export function last_timing() {
+    const ret = wasm.last_timing();
+    return ret === 0 ? undefined : TimingReport.__wrap(ret);
 }
 
 /**
- * Apply padding on the left side of the PhotonImage
- * A padded PhotonImage is returned.
- * # Arguments
- * * `img` - A PhotonImage. See the PhotonImage struct for details.
- * * `padding` - The amount of padding to be applied to the PhotonImage.
- * * `padding_rgba` - Tuple containing the RGBA code for padding color.
- *
- * # Example
- *
- * ```no_run
- * // For example, to apply a padding of 10 pixels on the right side of a PhotonImage:
- * use photon_rs::transform::padding_right;
- * use photon_rs::native::open_image;
- * use photon_rs::Rgba;
+ * Magic wand: select pixels within `tolerance` of the color at (`x`, `y`)
  *
- * let mut img = open_image("img.jpg").expect("File should open");
- * let rgba = Rgba::new(200_u8, 100_u8, 150_u8, 255_u8);
- * padding_right(&img, 10_u32, rgba);
- * ```
- * @param {PhotonImage} img
- * @param {number} padding
- * @param {Rgba} padding_rgba
- * @returns {PhotonImage}
+ * contiguous: only pixels connected to the seed (flood fill), otherwise all matches
+ * color_space: "rgb" (default, tolerance 0-441) or "lab" (CIE76 delta E)
+ * @param {Uint8Array} image_data
+ * @param {number} width
+ * @param {number} height
+ * @param {number} x
+ * @param {number} y
+ * @param {number} tolerance
+ * @param {boolean} contiguous
+ * @param {string | null} [color_space]
+ * @returns {Mask}
  */
-export function padding_right(img, padding, padding_rgba) {
-    _assertClass(img, PhotonImage);
-    _assertClass(padding_rgba, Rgba);
-    var ptr0 = padding_rgba.__destroy_into_raw();
-    const ret = wasm.padding_right(img.__wbg_ptr, padding, ptr0);
-    return PhotonImage.__wrap(ret);
+export function magic_wand(image_data, width, height, x, y, tolerance, contiguous, color_space) {
+    try {
+        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+        const ptr0 = passArray8ToWasm0(image_data, wasm.__wbindgen_export3);
+        const len0 = WASM_VECTOR_LEN;
+        var ptr1 = isLikeNone(color_space) ? 0 : passStringToWasm0(color_space, wasm.__wbindgen_export3, wasm.__wbindgen_export4);
+        var len1 = WASM_VECTOR_LEN;
+        wasm.magic_wand(retptr, ptr0, len0, width, height, x, y, tolerance, contiguous, ptr1, len1);
+        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+        var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+        if (r2) {
+            throw takeObject(r1);
+        }
+        return Mask.__wrap(r0);
+    } finally {
+        wasm.__wbindgen_add_to_stack_pointer(16);
+    }
 }
 
 /**
- * Apply padding on the left side of the PhotonImage
- * A padded PhotonImage is returned.
- * # Arguments
- * * `img` - A PhotonImage. See the PhotonImage struct for details.
- * * `padding` - The amount of padding to be applied to the PhotonImage.
- * * `padding_rgba` - Tuple containing the RGBA code for padding color.
- *
- * # Example
+ * Split an image into tiles for processing pieces of it at a time
  *
- * ```no_run
- * // For example, to apply a padding of 10 pixels on the top of a PhotonImage:
- * use photon_rs::transform::padding_top;
- * use photon_rs::native::open_image;
- * use photon_rs::Rgba;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * let rgba = Rgba::new(200_u8, 100_u8, 150_u8, 255_u8);
- * padding_top(&img, 10_u32, rgba);
- * ```
- * @param {PhotonImage} img
- * @param {number} padding
- * @param {Rgba} padding_rgba
- * @returns {PhotonImage}
+ * halo: context pixels per side, e.g. from `blur_halo`; 0 for per-pixel filters
+ * @param {number} width
+ * @param {number} height
+ * @param {number} tile_size
+ * @param {number} halo
+ * @returns {Tile[]}
  */
-export function padding_top(img, padding, padding_rgba) {
-    _assertClass(img, PhotonImage);
-    _assertClass(padding_rgba, Rgba);
-    var ptr0 = padding_rgba.__destroy_into_raw();
-    const ret = wasm.padding_top(img.__wbg_ptr, padding, ptr0);
-    return PhotonImage.__wrap(ret);
+export function plan_tiles(width, height, tile_size, halo) {
+    try {
+        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+        wasm.plan_tiles(retptr, width, height, tile_size, halo);
+        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+        var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+        var r3 = getDataViewMemory0().getInt32(retptr + 4 * 3, true);
+        if (r3) {
+            throw takeObject(r2);
+        }
+        var v1 = getArrayJsValueFromWasm0(r0, r1);
+        wasm.__wbindgen_export2(r0, r1 * 4, 4);
+        return v1;
+    } finally {
+        wasm.__wbindgen_add_to_stack_pointer(16);
+    }
 }
 
 /**
- * Apply padding on the left side of the PhotonImage
- * A padded PhotonImage is returned.
- * # Arguments
- * * `img` - A PhotonImage. See the PhotonImage struct for details.
- * * `padding` - The amount of padding to be applied to the PhotonImage.
- * * `padding_rgba` - Tuple containing the RGBA code for padding color.
- *
- * # Example
- *
- * ```no_run
- * // For example, to apply a padding of 10 pixels on the bottom of a PhotonImage:
- * use photon_rs::transform::padding_bottom;
- * use photon_rs::native::open_image;
- * use photon_rs::Rgba;
- *
- * let mut img = open_image("img.jpg").expect("File should open");
- * let rgba = Rgba::new(200_u8, 100_u8, 150_u8, 255_u8);
- * padding_bottom(&img, 10_u32, rgba);
- * ```
- * @param {PhotonImage} img
- * @param {number} padding
- * @param {Rgba} padding_rgba
- * @returns {PhotonImage}
+ * Select pixels by hue, saturation and lightness, soft edged with `fuzziness`
+ * @param {Uint8Array} image_data
+ * @param {number} width
+ * @param {number} height
+ * @param {ColorRange} range
+ * @returns {Mask}
  */
-export function padding_bottom(img, padding, padding_rgba) {
-    _assertClass(img, PhotonImage);
-    _assertClass(padding_rgba, Rgba);
-    var ptr0 = padding_rgba.__destroy_into_raw();
-    const ret = wasm.padding_bottom(img.__wbg_ptr, padding, ptr0);
-    return PhotonImage.__wrap(ret);
+export function select_color_range(image_data, width, height, range) {
+    try {
+        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+        const ptr0 = passArray8ToWasm0(image_data, wasm.__wbindgen_export3);
+        const len0 = WASM_VECTOR_LEN;
+        _assertClass(range, ColorRange);
+        wasm.select_color_range(retptr, ptr0, len0, width, height, range.__wbg_ptr);
+        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+        var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+        if (r2) {
+            throw takeObject(r1);
+        }
+        return Mask.__wrap(r0);
+    } finally {
+        wasm.__wbindgen_add_to_stack_pointer(16);
+    }
 }
 
 /**
- * Rotate the PhotonImage on an arbitrary angle
- * A rotated PhotonImage is returned.
- *
- * # Arguments
- * * `img` - A PhotonImage. See the PhotonImage struct for details.
- * * `angle` - Rotation angle in degrees.
- *
- * # Example
- *
- * ```no_run
- * // For example, to rotate a PhotonImage by 30 degrees:
- * use photon_rs::native::open_image;
- * use photon_rs::transform::rotate;
- *
- * let img = open_image("img.jpg").expect("File should open");
- * let rotated_img = rotate(&img, 30.0);
- * ```
- * @param {PhotonImage} photon_img
- * @param {number} angle
- * @returns {PhotonImage}
+ * Change the console log level at runtime
+ * level: "off", "error", "warn", "info", "debug" or "trace" (case-insensitive)
+ * @param {string} level
  */
-export function rotate(photon_img, angle) {
-    _assertClass(photon_img, PhotonImage);
-    const ret = wasm.rotate(photon_img.__wbg_ptr, angle);
-    return PhotonImage.__wrap(ret);
+export function set_log_level(level) {
+    try {
+        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+        const ptr0 = passStringToWasm0(level, wasm.__wbindgen_export3, wasm.__wbindgen_export4);
+        const len0 = WASM_VECTOR_LEN;
+        wasm.set_log_level(retptr, ptr0, len0);
+        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+        if (r1) {
+            throw takeObject(r0);
+        }
+    } finally {
+        wasm.__wbindgen_add_to_stack_pointer(16);
+    }
 }
 
 /**
- * Resample the PhotonImage.
- *
- * # Arguments
- * * `img` - A PhotonImage. See the PhotonImage struct for details.
- * * `dst_width` - Target width.
- * * `dst_height` - Target height.
+ * Eyedropper: white balance that makes the area around (x, y) neutral gray
  *
- * # Example
- *
- * ```no_run
- * // For example, to resample a PhotonImage to 1920x1080 size:
- * use photon_rs::native::open_image;
- * use photon_rs::transform::resample;
- *
- * let img = open_image("img.jpg").expect("File should open");
- * let rotated_img = resample(&img, 1920, 1080);
- * ```
- * @param {PhotonImage} img
- * @param {number} dst_width
- * @param {number} dst_height
- * @returns {PhotonImage}
+ * Returns [temperature, tint] for `apply_white_balance` or the sliders.
+ * radius: pixels averaged around the point in each direction (default 2, a 5x5 sample)
+ * @param {Uint8Array} image_data
+ * @param {number} width
+ * @param {number} height
+ * @param {number} x
+ * @param {number} y
+ * @param {number | null} [radius]
+ * @returns {Float32Array}
  */
-export function resample(img, dst_width, dst_height) {
-    _assertClass(img, PhotonImage);
-    const ret = wasm.resample(img.__wbg_ptr, dst_width, dst_height);
-    return PhotonImage.__wrap(ret);
+export function white_balance_from_point(image_data, width, height, x, y, radius) {
+    try {
+        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+        const ptr0 = passArray8ToWasm0(image_data, wasm.__wbindgen_export3);
+        const len0 = WASM_VECTOR_LEN;
+        wasm.white_balance_from_point(retptr, ptr0, len0, width, height, x, y, isLikeNone(radius) ? Number.MAX_SAFE_INTEGER : (radius) >>> 0);
+        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+        var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+        var r3 = getDataViewMemory0().getInt32(retptr + 4 * 3, true);
+        if (r3) {
+            throw takeObject(r2);
+        }
+        var v2 = getArrayF32FromWasm0(r0, r1).slice();
+        wasm.__wbindgen_export2(r0, r1 * 4, 4);
+        return v2;
+    } finally {
+        wasm.__wbindgen_add_to_stack_pointer(16);
+    }
+}
+function __wbg_get_imports() {
+    const import0 = {
+        __proto__: null,
+        __wbg___wbindgen_copy_to_typed_array_88899a52af046901: function(arg0, arg1, arg2) {
+            new Uint8Array(getObject(arg2).buffer, getObject(arg2).byteOffset, getObject(arg2).byteLength).set(getArrayU8FromWasm0(arg0, arg1));
+        },
+        __wbg___wbindgen_is_undefined_8865fb403f8fe9d8: function(arg0) {
+            const ret = getObject(arg0) === undefined;
+            return ret;
+        },
+        __wbg___wbindgen_throw_41e9ee4f547fc59a: function(arg0, arg1) {
+            throw new Error(getStringFromWasm0(arg0, arg1));
+        },
+        __wbg_aborted_5b2b5f06b5e59fde: function(arg0) {
+            const ret = getObject(arg0).aborted;
+            return ret;
+        },
+        __wbg_call_1875a20c43a36133: function() { return handleError(function (arg0, arg1, arg2, arg3) {
+            const ret = getObject(arg0).call(getObject(arg1), getObject(arg2), getObject(arg3));
+            return addHeapObject(ret);
+        }, arguments); },
+        __wbg_cancelled_new: function(arg0) {
+            const ret = Cancelled.__wrap(arg0);
+            return addHeapObject(ret);
+        },
+        __wbg_debug_05be7ad0a1e623f8: function(arg0, arg1, arg2, arg3) {
+            console.debug(getObject(arg0), getObject(arg1), getObject(arg2), getObject(arg3));
+        },
+        __wbg_error_6614f5677eeead43: function(arg0, arg1, arg2, arg3) {
+            console.error(getObject(arg0), getObject(arg1), getObject(arg2), getObject(arg3));
+        },
+        __wbg_error_757e9472f8410341: function(arg0, arg1) {
+            let deferred0_0;
+            let deferred0_1;
+            try {
+                deferred0_0 = arg0;
+                deferred0_1 = arg1;
+                console.error(getStringFromWasm0(arg0, arg1));
+            } finally {
+                wasm.__wbindgen_export2(deferred0_0, deferred0_1, 1);
+            }
+        },
+        __wbg_error_c9cf3fc2064683a9: function(arg0) {
+            console.error(getObject(arg0));
+        },
+        __wbg_info_b68ad4a35d1670c2: function(arg0, arg1, arg2, arg3) {
+            console.info(getObject(arg0), getObject(arg1), getObject(arg2), getObject(arg3));
+        },
+        __wbg_instanceof_Int32Array_4b9a86620726269b: function(arg0) {
+            let result;
+            try {
+                result = getObject(arg0) instanceof Int32Array;
+            } catch (_) {
+                result = false;
+            }
+            const ret = result;
+            return ret;
+        },
+        __wbg_instanceof_Window_82d71df4eddf88bc: function(arg0) {
+            let result;
+            try {
+                result = getObject(arg0) instanceof Window;
+            } catch (_) {
+                result = false;
+            }
+            const ret = result;
+            return ret;
+        },
+        __wbg_instanceof_WorkerGlobalScope_8966e756dd00e8b7: function(arg0) {
+            let result;
+            try {
+                result = getObject(arg0) instanceof WorkerGlobalScope;
+            } catch (_) {
+                result = false;
+            }
+            const ret = result;
+            return ret;
+        },
+        __wbg_load_07055e23cc1692bf: function() { return handleError(function (arg0, arg1) {
+            const ret = Atomics.load(getObject(arg0), arg1 >>> 0);
+            return ret;
+        }, arguments); },
+        __wbg_log_19b94368315af0fb: function(arg0, arg1, arg2, arg3) {
+            console.log(getObject(arg0), getObject(arg1), getObject(arg2), getObject(arg3));
+        },
+        __wbg_new_227d7c05414eb861: function() {
+            const ret = new Error();
+            return addHeapObject(ret);
+        },
+        __wbg_now_d0b7f4bea9f38490: function(arg0) {
+            const ret = getObject(arg0).now();
+            return ret;
+        },
+        __wbg_performance_d0d7b03fc649b694: function(arg0) {
+            const ret = getObject(arg0).performance;
+            return isLikeNone(ret) ? 0 : addHeapObject(ret);
+        },
+        __wbg_performance_f994e3615154faeb: function(arg0) {
+            const ret = getObject(arg0).performance;
+            return isLikeNone(ret) ? 0 : addHeapObject(ret);
+        },
+        __wbg_stack_3b0d974bbf31e44f: function(arg0, arg1) {
+            const ret = getObject(arg1).stack;
+            const ptr1 = passStringToWasm0(ret, wasm.__wbindgen_export3, wasm.__wbindgen_export4);
+            const len1 = WASM_VECTOR_LEN;
+            getDataViewMemory0().setInt32(arg0 + 4 * 1, len1, true);
+            getDataViewMemory0().setInt32(arg0 + 4 * 0, ptr1, true);
+        },
+        __wbg_static_accessor_GLOBAL_266715b9d96ba635: function() {
+            const ret = typeof global === 'undefined' ? null : global;
+            return isLikeNone(ret) ? 0 : addHeapObject(ret);
+        },
+        __wbg_static_accessor_GLOBAL_THIS_10fb7dc1ae063179: function() {
+            const ret = typeof globalThis === 'undefined' ? null : globalThis;
+            return isLikeNone(ret) ? 0 : addHeapObject(ret);
+        },
+        __wbg_static_accessor_SELF_0b583911f537483a: function() {
+            const ret = typeof self === 'undefined' ? null : self;
+            return isLikeNone(ret) ? 0 : addHeapObject(ret);
+        },
+        __wbg_static_accessor_WINDOW_d7f903d1508cbdc4: function() {
+            const ret = typeof window === 'undefined' ? null : window;
+            return isLikeNone(ret) ? 0 : addHeapObject(ret);
+        },
+        __wbg_tile_new: function(arg0) {
+            const ret = Tile.__wrap(arg0);
+            return addHeapObject(ret);
+        },
+        __wbg_warn_88929063e36ba285: function(arg0, arg1, arg2, arg3) {
+            console.warn(getObject(arg0), getObject(arg1), getObject(arg2), getObject(arg3));
+        },
+        __wbindgen_generic_0000000000000001: function(arg0) {
+            // Cast intrinsic for `F64 -> Externref`.
+            const ret = arg0;
+            return addHeapObject(ret);
+        },
+        __wbindgen_generic_0000000000000002: function(arg0, arg1) {
+            // Cast intrinsic for `Ref(String) -> Externref`.
+            const ret = getStringFromWasm0(arg0, arg1);
+            return addHeapObject(ret);
+        },
+        __wbindgen_object_clone_ref: function(arg0) {
+            const ret = getObject(arg0);
+            return addHeapObject(ret);
+        },
+        __wbindgen_object_drop_ref: function(arg0) {
+            takeObject(arg0);
+        },
+    };
+    return {
+        __proto__: null,
+        "./pixlab_wasm_bg.js": import0,
+    };
 }
 
-/**
- * @enum {1 | 2 | 3 | 4 | 5}
- */
-export const SamplingFilter = Object.freeze({
-    Nearest: 1, "1": "Nearest",
-    Triangle: 2, "2": "Triangle",
-    CatmullRom: 3, "3": "CatmullRom",
-    Gaussian: 4, "4": "Gaussian",
-    Lanczos3: 5, "5": "Lanczos3",
-});
-
-const PhotonImageFinalization = (typeof FinalizationRegistry === 'undefined')
+const CancelledFinalization = (typeof FinalizationRegistry === 'undefined')
     ? { register: () => {}, unregister: () => {} }
-    : new FinalizationRegistry(ptr => wasm.__wbg_photonimage_free(ptr >>> 0, 1));
-/**
- * Provides the image's height, width, and contains the image's raw pixels.
- * For use when communicating between JS and WASM, and also natively.
- */
-export class PhotonImage {
+    : new FinalizationRegistry(ptr => wasm.__wbg_cancelled_free(ptr, 1));
+const ColorRangeFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_colorrange_free(ptr, 1));
+const DrawStyleFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_drawstyle_free(ptr, 1));
+const FilterSettingsFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_filtersettings_free(ptr, 1));
+const IncrementalRendererFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_incrementalrenderer_free(ptr, 1));
+const LayerStackFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_layerstack_free(ptr, 1));
+const LutFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_lut_free(ptr, 1));
+const MaskFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_mask_free(ptr, 1));
+const PerceptualHashesFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_perceptualhashes_free(ptr, 1));
+const ProxyImageFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_proxyimage_free(ptr, 1));
+const RenderedImageFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_renderedimage_free(ptr, 1));
+const TileFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_tile_free(ptr, 1));
+const TimingReportFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_timingreport_free(ptr, 1));
+const WatermarkFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_watermark_free(ptr, 1));
 
-    static __wrap(ptr) {
-        ptr = ptr >>> 0;
-        const obj = Object.create(PhotonImage.prototype);
-        obj.__wbg_ptr = ptr;
-        PhotonImageFinalization.register(obj, obj.__wbg_ptr, obj);
-        return obj;
-    }
+function addHeapObject(obj) {
+    if (heap_next === heap.length) heap.push(heap.length + 1);
+    const idx = heap_next;
+    heap_next = heap[idx];
 
-    __destroy_into_raw() {
-        const ptr = this.__wbg_ptr;
-        this.__wbg_ptr = 0;
-        PhotonImageFinalization.unregister(this);
-        return ptr;
-    }
+    heap[idx] = obj;
+    return idx;
+}
 
-    free() {
-        const ptr = this.__destroy_into_raw();
-        wasm.__wbg_photonimage_free(ptr, 0);
-    }
-    /**
-     * Create a new PhotonImage from a Vec of u8s, which represent raw pixels.
-     * @param {Uint8Array} raw_pixels
-     * @param {number} width
-     * @param {number} height
-     */
-    constructor(raw_pixels, width, height) {
-        const ptr0 = passArray8ToWasm0(raw_pixels, wasm.__wbindgen_export_1);
-        const len0 = WASM_VECTOR_LEN;
-        const ret = wasm.photonimage_new(ptr0, len0, width, height);
-        this.__wbg_ptr = ret >>> 0;
-        PhotonImageFinalization.register(this, this.__wbg_ptr, this);
-        return this;
-    }
-    /**
-     * Create a new PhotonImage from a base64 string.
-     * @param {string} base64
-     * @returns {PhotonImage}
-     */
-    static new_from_base64(base64) {
-        const ptr0 = passStringToWasm0(base64, wasm.__wbindgen_export_1, wasm.__wbindgen_export_3);
-        const len0 = WASM_VECTOR_LEN;
-        const ret = wasm.base64_to_image(ptr0, len0);
-        return PhotonImage.__wrap(ret);
-    }
-    /**
-     * Create a new PhotonImage from a byteslice.
-     * @param {Uint8Array} vec
-     * @returns {PhotonImage}
-     */
-    static new_from_byteslice(vec) {
-        const ptr0 = passArray8ToWasm0(vec, wasm.__wbindgen_export_1);
-        const len0 = WASM_VECTOR_LEN;
-        const ret = wasm.photonimage_new_from_byteslice(ptr0, len0);
-        return PhotonImage.__wrap(ret);
-    }
-    /**
-     * Create a new PhotonImage from a Blob/File.
-     * @param {Blob} blob
-     * @returns {PhotonImage}
-     */
-    static new_from_blob(blob) {
-        const ret = wasm.photonimage_new_from_blob(addHeapObject(blob));
-        return PhotonImage.__wrap(ret);
-    }
-    /**
-     * Create a new PhotonImage from a HTMLImageElement
-     * @param {HTMLImageElement} image
-     * @returns {PhotonImage}
-     */
-    static new_from_image(image) {
-        const ret = wasm.photonimage_new_from_image(addHeapObject(image));
-        return PhotonImage.__wrap(ret);
-    }
-    /**
-     * Get the width of the PhotonImage.
-     * @returns {number}
-     */
-    get_width() {
-        const ret = wasm.photonimage_get_width(this.__wbg_ptr);
-        return ret >>> 0;
-    }
-    /**
-     * Get the PhotonImage's pixels as a Vec of u8s.
-     * @returns {Uint8Array}
-     */
-    get_raw_pixels() {
-        try {
-            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
-            wasm.photonimage_get_raw_pixels(retptr, this.__wbg_ptr);
-            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
-            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
-            var v1 = getArrayU8FromWasm0(r0, r1).slice();
-            wasm.__wbindgen_export_2(r0, r1 * 1, 1);
-            return v1;
-        } finally {
-            wasm.__wbindgen_add_to_stack_pointer(16);
-        }
-    }
-    /**
-     * Get the height of the PhotonImage.
-     * @returns {number}
-     */
-    get_height() {
-        const ret = wasm.photonimage_get_height(this.__wbg_ptr);
-        return ret >>> 0;
-    }
-    /**
-     * Convert the PhotonImage to base64.
-     * @returns {string}
-     */
-    get_base64() {
-        let deferred1_0;
-        let deferred1_1;
-        try {
-            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
-            wasm.photonimage_get_base64(retptr, this.__wbg_ptr);
-            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
-            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
-            deferred1_0 = r0;
-            deferred1_1 = r1;
-            return getStringFromWasm0(r0, r1);
-        } finally {
-            wasm.__wbindgen_add_to_stack_pointer(16);
-            wasm.__wbindgen_export_2(deferred1_0, deferred1_1, 1);
-        }
-    }
-    /**
-     * Convert the PhotonImage to raw bytes. Returns PNG.
-     * @returns {Uint8Array}
-     */
-    get_bytes() {
-        try {
-            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
-            wasm.photonimage_get_bytes(retptr, this.__wbg_ptr);
-            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
-            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
-            var v1 = getArrayU8FromWasm0(r0, r1).slice();
-            wasm.__wbindgen_export_2(r0, r1 * 1, 1);
-            return v1;
-        } finally {
-            wasm.__wbindgen_add_to_stack_pointer(16);
-        }
-    }
-    /**
-     * Convert the PhotonImage to raw bytes. Returns a JPEG.
-     * @param {number} quality
-     * @returns {Uint8Array}
-     */
-    get_bytes_jpeg(quality) {
-        try {
-            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
-            wasm.photonimage_get_bytes_jpeg(retptr, this.__wbg_ptr, quality);
-            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
-            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
-            var v1 = getArrayU8FromWasm0(r0, r1).slice();
-            wasm.__wbindgen_export_2(r0, r1 * 1, 1);
-            return v1;
-        } finally {
-            wasm.__wbindgen_add_to_stack_pointer(16);
-        }
-    }
-    /**
-     * Convert the PhotonImage to raw bytes. Returns a WEBP.
-     * @returns {Uint8Array}
-     */
-    get_bytes_webp() {
-        try {
-            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
-            wasm.photonimage_get_bytes_webp(retptr, this.__wbg_ptr);
-            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
-            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
-            var v1 = getArrayU8FromWasm0(r0, r1).slice();
-            wasm.__wbindgen_export_2(r0, r1 * 1, 1);
-            return v1;
-        } finally {
-            wasm.__wbindgen_add_to_stack_pointer(16);
-        }
-    }
-    /**
-     * Convert the PhotonImage's raw pixels to JS-compatible ImageData.
-     * @returns {ImageData}
-     */
-    get_image_data() {
-        const ret = wasm.photonimage_get_image_data(this.__wbg_ptr);
-        return takeObject(ret);
-    }
-    /**
-     * Convert ImageData to raw pixels, and update the PhotonImage's raw pixels to this.
-     * @param {ImageData} img_data
-     */
-    set_imgdata(img_data) {
-        wasm.photonimage_set_imgdata(this.__wbg_ptr, addHeapObject(img_data));
-    }
-    /**
-     * Calculates estimated filesize and returns number of bytes
-     * @returns {bigint}
-     */
-    get_estimated_filesize() {
-        const ret = wasm.photonimage_get_estimated_filesize(this.__wbg_ptr);
-        return BigInt.asUintN(64, ret);
+function _assertClass(instance, klass) {
+    if (!(instance instanceof klass)) {
+        throw new Error(`expected instance of ${klass.name}`);
     }
 }
-if (Symbol.dispose) PhotonImage.prototype[Symbol.dispose] = PhotonImage.prototype.free;
 
-const RgbFinalization = (typeof FinalizationRegistry === 'undefined')
-    ? { register: () => {}, unregister: () => {} }
-    : new FinalizationRegistry(ptr => wasm.__wbg_rgb_free(ptr >>> 0, 1));
-/**
- * RGB color type.
- */
-export class Rgb {
+function dropObject(idx) {
+    if (idx < 1028) return;
+    heap[idx] = heap_next;
+    heap_next = idx;
+}
 
-    __destroy_into_raw() {
-        const ptr = this.__wbg_ptr;
-        this.__wbg_ptr = 0;
-        RgbFinalization.unregister(this);
-        return ptr;
-    }
+function getArrayF32FromWasm0(ptr, len) {
+    ptr = ptr >>> 0;
+    return getFloat32ArrayMemory0().subarray(ptr / 4, ptr / 4 + len);
+}
 
-    free() {
-        const ptr = this.__destroy_into_raw();
-        wasm.__wbg_rgb_free(ptr, 0);
-    }
-    /**
-     * Create a new RGB struct.
-     * @param {number} r
-     * @param {number} g
-     * @param {number} b
-     */
-    constructor(r, g, b) {
-        const ret = wasm.rgb_new(r, g, b);
-        this.__wbg_ptr = ret >>> 0;
-        RgbFinalization.register(this, this.__wbg_ptr, this);
-        return this;
-    }
-    /**
-     * Set the Red value.
-     * @param {number} r
-     */
-    set_red(r) {
-        wasm.rgb_set_red(this.__wbg_ptr, r);
-    }
-    /**
-     * Get the Green value.
-     * @param {number} g
-     */
-    set_green(g) {
-        wasm.rgb_set_green(this.__wbg_ptr, g);
-    }
-    /**
-     * Set the Blue value.
-     * @param {number} b
-     */
-    set_blue(b) {
-        wasm.rgb_set_blue(this.__wbg_ptr, b);
-    }
-    /**
-     * Get the Red value.
-     * @returns {number}
-     */
-    get_red() {
-        const ret = wasm.rgb_get_red(this.__wbg_ptr);
-        return ret;
-    }
-    /**
-     * Get the Green value.
-     * @returns {number}
-     */
-    get_green() {
-        const ret = wasm.rgb_get_green(this.__wbg_ptr);
-        return ret;
-    }
-    /**
-     * Get the Blue value.
-     * @returns {number}
-     */
-    get_blue() {
-        const ret = wasm.rgb_get_blue(this.__wbg_ptr);
-        return ret;
+function getArrayJsValueFromWasm0(ptr, len) {
+    ptr = ptr >>> 0;
+    const mem = getDataViewMemory0();
+    const result = [];
+    for (let i = ptr; i < ptr + 4 * len; i += 4) {
+        result.push(takeObject(mem.getUint32(i, true)));
     }
+    return result;
 }
-if (Symbol.dispose) Rgb.prototype[Symbol.dispose] = Rgb.prototype.free;
 
-const RgbaFinalization = (typeof FinalizationRegistry === 'undefined')
-    ? { register: () => {}, unregister: () => {} }
-    : new FinalizationRegistry(ptr => wasm.__wbg_rgba_free(ptr >>> 0, 1));
-/**
- * RGBA color type.
- */
-export class Rgba {
+function getArrayU32FromWasm0(ptr, len) {
+    ptr = ptr >>> 0;
+    return getUint32ArrayMemory0().subarray(ptr / 4, ptr / 4 + len);
+}
 
-    __destroy_into_raw() {
-        const ptr = this.__wbg_ptr;
-        this.__wbg_ptr = 0;
-        RgbaFinalization.unregister(this);
-        return ptr;
-    }
+function getArrayU8FromWasm0(ptr, len) {
+    ptr = ptr >>> 0;
+    return getUint8ArrayMemory0().subarray(ptr / 1, ptr / 1 + len);
+}
 
-    free() {
-        const ptr = this.__destroy_into_raw();
-        wasm.__wbg_rgba_free(ptr, 0);
-    }
-    /**
-     * Create a new RGBA struct.
-     * @param {number} r
-     * @param {number} g
-     * @param {number} b
-     * @param {number} a
-     */
-    constructor(r, g, b, a) {
-        const ret = wasm.rgba_new(r, g, b, a);
-        this.__wbg_ptr = ret >>> 0;
-        RgbaFinalization.register(this, this.__wbg_ptr, this);
-        return this;
+let cachedDataViewMemory0 = null;
+function getDataViewMemory0() {
+    if (cachedDataViewMemory0 === null || cachedDataViewMemory0.buffer.detached === true || (cachedDataViewMemory0.buffer.detached === undefined && cachedDataViewMemory0.buffer !== wasm.memory.buffer)) {
+        cachedDataViewMemory0 = new DataView(wasm.memory.buffer);
     }
-    /**
-     * Set the Red value.
-     * @param {number} r
-     */
-    set_red(r) {
-        wasm.rgb_set_red(this.__wbg_ptr, r);
+    return cachedDataViewMemory0;
+}
+
+let cachedFloat32ArrayMemory0 = null;
+function getFloat32ArrayMemory0() {
+    if (cachedFloat32ArrayMemory0 === null || cachedFloat32ArrayMemory0.byteLength === 0) {
+        cachedFloat32ArrayMemory0 = new Float32Array(wasm.memory.buffer);
     }
-    /**
-     * Get the Green value.
-     * @param {number} g
-     */
-    set_green(g) {
-        wasm.rgb_set_green(this.__wbg_ptr, g);
+    return cachedFloat32ArrayMemory0;
+}
+
+function getStringFromWasm0(ptr, len) {
+    return decodeText(ptr >>> 0, len);
+}
+
+let cachedUint32ArrayMemory0 = null;
+function getUint32ArrayMemory0() {
+    if (cachedUint32ArrayMemory0 === null || cachedUint32ArrayMemory0.byteLength === 0) {
+        cachedUint32ArrayMemory0 = new Uint32Array(wasm.memory.buffer);
     }
-    /**
-     * Set the Blue value.
-     * @param {number} b
-     */
-    set_blue(b) {
-        wasm.rgb_set_blue(this.__wbg_ptr, b);
+    return cachedUint32ArrayMemory0;
+}
+
+let cachedUint8ArrayMemory0 = null;
+function getUint8ArrayMemory0() {
+    if (cachedUint8ArrayMemory0 === null || cachedUint8ArrayMemory0.byteLength === 0) {
+        cachedUint8ArrayMemory0 = new Uint8Array(wasm.memory.buffer);
     }
-    /**
-     * Set the alpha value.
-     * @param {number} a
-     */
-    set_alpha(a) {
-        wasm.rgba_set_alpha(this.__wbg_ptr, a);
+    return cachedUint8ArrayMemory0;
+}
+
+function getObject(idx) { return heap[idx]; }
+
+function handleError(f, args) {
+    try {
+        return f.apply(this, args);
+    } catch (e) {
+        wasm.__wbindgen_export(addHeapObject(e));
     }
-    /**
-     * Get the Red value.
-     * @returns {number}
-     */
-    get_red() {
-        const ret = wasm.rgb_get_red(this.__wbg_ptr);
-        return ret;
+}
+
+let heap = new Array(1024).fill(undefined);
+heap.push(undefined, null, true, false);
+
+let heap_next = heap.length;
+
+function isLikeNone(x) {
+    return x === undefined || x === null;
+}
+
+function passArray8ToWasm0(arg, malloc) {
+    const ptr = malloc(arg.length * 1, 1) >>> 0;
+    getUint8ArrayMemory0().set(arg, ptr / 1);
+    WASM_VECTOR_LEN = arg.length;
+    return ptr;
+}
+
+function passArrayF32ToWasm0(arg, malloc) {
+    const ptr = malloc(arg.length * 4, 4) >>> 0;
+    getFloat32ArrayMemory0().set(arg, ptr / 4);
+    WASM_VECTOR_LEN = arg.length;
+    return ptr;
+}
+
+function passStringToWasm0(arg, malloc, realloc) {
+    if (realloc === undefined) {
+        const buf = cachedTextEncoder.encode(arg);
+        const ptr = malloc(buf.length, 1) >>> 0;
+        getUint8ArrayMemory0().subarray(ptr, ptr + buf.length).set(buf);
+        WASM_VECTOR_LEN = buf.length;
+        return ptr;
     }
-    /**
-     * Get the Green value.
-     * @returns {number}
-     */
-    get_green() {
-        const ret = wasm.rgb_get_green(this.__wbg_ptr);
-        return ret;
+
+    let len = arg.length;
+    let ptr = malloc(len, 1) >>> 0;
+
+    const mem = getUint8ArrayMemory0();
+
+    let offset = 0;
+
+    for (; offset < len; offset++) {
+        const code = arg.charCodeAt(offset);
+        if (code > 0x7F) break;
+        mem[ptr + offset] = code;
     }
-    /**
-     * Get the Blue value.
-     * @returns {number}
-     */
-    get_blue() {
-        const ret = wasm.rgb_get_blue(this.__wbg_ptr);
-        return ret;
+    if (offset !== len) {
+        if (offset !== 0) {
+            arg = arg.slice(offset);
+        }
+        ptr = realloc(ptr, len, len = offset + arg.length * 3, 1) >>> 0;
+        const view = getUint8ArrayMemory0().subarray(ptr + offset, ptr + len);
+        const ret = cachedTextEncoder.encodeInto(arg, view);
+
+        offset += ret.written;
+        ptr = realloc(ptr, len, offset, 1) >>> 0;
     }
-    /**
-     * Get the alpha value for this color.
-     * @returns {number}
-     */
-    get_alpha() {
-        const ret = wasm.rgba_get_alpha(this.__wbg_ptr);
-        return ret;
+
+    WASM_VECTOR_LEN = offset;
+    return ptr;
+}
+
+function takeObject(idx) {
+    const ret = getObject(idx);
+    dropObject(idx);
+    return ret;
+}
+
+let cachedTextDecoder = new TextDecoder('utf-8', { ignoreBOM: true, fatal: true });
+cachedTextDecoder.decode();
+const MAX_SAFARI_DECODE_BYTES = 2146435072;
+let numBytesDecoded = 0;
+function decodeText(ptr, len) {
+    numBytesDecoded += len;
+    if (numBytesDecoded >= MAX_SAFARI_DECODE_BYTES) {
+        cachedTextDecoder = new TextDecoder('utf-8', { ignoreBOM: true, fatal: true });
+        cachedTextDecoder.decode();
+        numBytesDecoded = len;
     }
+    return cachedTextDecoder.decode(getUint8ArrayMemory0().subarray(ptr, ptr + len));
 }
-if (Symbol.dispose) Rgba.prototype[Symbol.dispose] = Rgba.prototype.free;
 
-const EXPECTED_RESPONSE_TYPES = new Set(['basic', 'cors', 'default']);
+const cachedTextEncoder = new TextEncoder();
+
+if (!('encodeInto' in cachedTextEncoder)) {
+    cachedTextEncoder.encodeInto = function (arg, view) {
+        const buf = cachedTextEncoder.encode(arg);
+        view.set(buf);
+        return {
+            read: arg.length,
+            written: buf.length
+        };
+    };
+}
+
+let WASM_VECTOR_LEN = 0;
+
+let wasmModule, wasmInstance, wasm;
+function __wbg_finalize_init(instance, module) {
+    wasmInstance = instance;
+    wasm = instance.exports;
+    wasmModule = module;
+    cachedDataViewMemory0 = null;
+    cachedFloat32ArrayMemory0 = null;
+    cachedUint32ArrayMemory0 = null;
+    cachedUint8ArrayMemory0 = null;
+    wasm.__wbindgen_start();
+    return wasm;
+}
 
 async function __wbg_load(module, imports) {
     if (typeof Response === 'function' && module instanceof Response) {
+        if (!module.ok) {
+            throw new Error(`failed to fetch Wasm: ${module.status} ${module.statusText} fetching '${module.url}'`);
+        }
+
         if (typeof WebAssembly.instantiateStreaming === 'function') {
             try {
                 return await WebAssembly.instantiateStreaming(module, imports);
-
             } catch (e) {
-                const validResponse = module.ok && EXPECTED_RESPONSE_TYPES.has(module.type);
+                const validResponse = expectedResponseType(module.type);
 
                 if (validResponse && module.headers.get('Content-Type') !== 'application/wasm') {
                     console.warn("`WebAssembly.instantiateStreaming` failed because your server does not serve Wasm with `application/wasm` MIME type. Falling back to `WebAssembly.instantiate` which is slower. Original error:\n", e);
 
-                } else {
-                    throw e;
-                }
+                } else { throw e; }
             }
         }
 
         const bytes = await module.arrayBuffer();
         return await WebAssembly.instantiate(bytes, imports);
-
     } else {
         const instance = await WebAssembly.instantiate(module, imports);
 
         if (instance instanceof WebAssembly.Instance) {
             return { instance, module };
-
         } else {
             return instance;
         }
     }
-}
 
-function __wbg_get_imports() {
-    const imports = {};
-    imports.wbg = {};
-    imports.wbg.__wbg_appendChild_87a6cc0aeb132c06 = function() { return handleError(function (arg0, arg1) {
-        const ret = getObject(arg0).appendChild(getObject(arg1));
-        return addHeapObject(ret);
-    }, arguments) };
-    imports.wbg.__wbg_body_8822ca55cb3730d2 = function(arg0) {
-        const ret = getObject(arg0).body;
-        return isLikeNone(ret) ? 0 : addHeapObject(ret);
-    };
-    imports.wbg.__wbg_call_13410aac570ffff7 = function() { return handleError(function (arg0, arg1) {
-        const ret = getObject(arg0).call(getObject(arg1));
-        return addHeapObject(ret);
-    }, arguments) };
-    imports.wbg.__wbg_createElement_4909dfa2011f2abe = function() { return handleError(function (arg0, arg1, arg2) {
-        const ret = getObject(arg0).createElement(getStringFromWasm0(arg1, arg2));
-        return addHeapObject(ret);
-    }, arguments) };
-    imports.wbg.__wbg_data_ba67edb3bdb00513 = function(arg0, arg1) {
-        const ret = getObject(arg1).data;
-        const ptr1 = passArray8ToWasm0(ret, wasm.__wbindgen_export_1);
-        const len1 = WASM_VECTOR_LEN;
-        getDataViewMemory0().setInt32(arg0 + 4 * 1, len1, true);
-        getDataViewMemory0().setInt32(arg0 + 4 * 0, ptr1, true);
-    };
-    imports.wbg.__wbg_debug_7f3000e7358ea482 = function(arg0, arg1, arg2, arg3) {
-        console.debug(getObject(arg0), getObject(arg1), getObject(arg2), getObject(arg3));
-    };
-    imports.wbg.__wbg_document_7d29d139bd619045 = function(arg0) {
-        const ret = getObject(arg0).document;
-        return isLikeNone(ret) ? 0 : addHeapObject(ret);
-    };
-    imports.wbg.__wbg_drawImage_461ed27d3fd639ff = function() { return handleError(function (arg0, arg1, arg2, arg3) {
-        getObject(arg0).drawImage(getObject(arg1), arg2, arg3);
-    }, arguments) };
-    imports.wbg.__wbg_drawImage_fb90fd38ae1ef51e = function() { return handleError(function (arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9) {
-        getObject(arg0).drawImage(getObject(arg1), arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9);
-    }, arguments) };
-    imports.wbg.__wbg_error_0889f151acea569e = function(arg0, arg1, arg2, arg3) {
-        console.error(getObject(arg0), getObject(arg1), getObject(arg2), getObject(arg3));
-    };
-    imports.wbg.__wbg_error_7534b8e9a36f1ab4 = function(arg0, arg1) {
-        let deferred0_0;
-        let deferred0_1;
-        try {
-            deferred0_0 = arg0;
-            deferred0_1 = arg1;
-            console.error(getStringFromWasm0(arg0, arg1));
-        } finally {
-            wasm.__wbindgen_export_2(deferred0_0, deferred0_1, 1);
-        }
-    };
-    imports.wbg.__wbg_error_99981e16d476aa5c = function(arg0) {
-        console.error(getObject(arg0));
-    };
-    imports.wbg.__wbg_getContext_15e158d04230a6f6 = function() { return handleError(function (arg0, arg1, arg2) {
-        const ret = getObject(arg0).getContext(getStringFromWasm0(arg1, arg2));
-        return isLikeNone(ret) ? 0 : addHeapObject(ret);
-    }, arguments) };
-    imports.wbg.__wbg_getImageData_bdd94c9b04e5217d = function() { return handleError(function (arg0, arg1, arg2, arg3, arg4) {
-        const ret = getObject(arg0).getImageData(arg1, arg2, arg3, arg4);
-        return addHeapObject(ret);
-    }, arguments) };
-    imports.wbg.__wbg_height_228fe8a75d4d09d6 = function(arg0) {
-        const ret = getObject(arg0).height;
-        return ret;
-    };
-    imports.wbg.__wbg_height_dcede88ab14fa083 = function(arg0) {
-        const ret = getObject(arg0).height;
-        return ret;
-    };
-    imports.wbg.__wbg_height_e7fbbd0922af3e64 = function(arg0) {
-        const ret = getObject(arg0).height;
-        return ret;
-    };
-    imports.wbg.__wbg_info_15c3631232fceddb = function(arg0, arg1, arg2, arg3) {
-        console.info(getObject(arg0), getObject(arg1), getObject(arg2), getObject(arg3));
-    };
-    imports.wbg.__wbg_instanceof_CanvasRenderingContext2d_8c616198ec03b12f = function(arg0) {
-        let result;
-        try {
-            result = getObject(arg0) instanceof CanvasRenderingContext2D;
-        } catch (_) {
-            result = false;
+    function expectedResponseType(type) {
+        switch (type) {
+            case 'basic': case 'cors': case 'default': return true;
         }
-        const ret = result;
-        return ret;
-    };
-    imports.wbg.__wbg_instanceof_HtmlCanvasElement_299c60950dbb3428 = function(arg0) {
-        let result;
-        try {
-            result = getObject(arg0) instanceof HTMLCanvasElement;
-        } catch (_) {
-            result = false;
-        }
-        const ret = result;
-        return ret;
-    };
-    imports.wbg.__wbg_instanceof_Window_12d20d558ef92592 = function(arg0) {
-        let result;
-        try {
-            result = getObject(arg0) instanceof Window;
-        } catch (_) {
-            result = false;
-        }
-        const ret = result;
-        return ret;
-    };
-    imports.wbg.__wbg_length_6bb7e81f9d7713e4 = function(arg0) {
-        const ret = getObject(arg0).length;
-        return ret;
-    };
-    imports.wbg.__wbg_log_ddbf5bc3d4dae44c = function(arg0, arg1, arg2, arg3) {
-        console.log(getObject(arg0), getObject(arg1), getObject(arg2), getObject(arg3));
-    };
-    imports.wbg.__wbg_new_638ebfaedbf32a5e = function(arg0) {
-        const ret = new Uint8Array(getObject(arg0));
-        return addHeapObject(ret);
-    };
-    imports.wbg.__wbg_new_8a6f238a6ece86ea = function() {
-        const ret = new Error();
-        return addHeapObject(ret);
-    };
-    imports.wbg.__wbg_newnoargs_254190557c45b4ec = function(arg0, arg1) {
-        const ret = new Function(getStringFromWasm0(arg0, arg1));
-        return addHeapObject(ret);
-    };
-    imports.wbg.__wbg_newwithu8clampedarrayandsh_132382d049b78e28 = function() { return handleError(function (arg0, arg1, arg2, arg3) {
-        const ret = new ImageData(getClampedArrayU8FromWasm0(arg0, arg1), arg2 >>> 0, arg3 >>> 0);
-        return addHeapObject(ret);
-    }, arguments) };
-    imports.wbg.__wbg_now_886b39d7ec380719 = function(arg0) {
-        const ret = getObject(arg0).now();
-        return ret;
-    };
-    imports.wbg.__wbg_performance_a221af8decc752fb = function(arg0) {
-        const ret = getObject(arg0).performance;
-        return isLikeNone(ret) ? 0 : addHeapObject(ret);
-    };
-    imports.wbg.__wbg_prototypesetcall_3d4a26c1ed734349 = function(arg0, arg1, arg2) {
-        Uint8Array.prototype.set.call(getArrayU8FromWasm0(arg0, arg1), getObject(arg2));
-    };
-    imports.wbg.__wbg_putImageData_0324b5d1f2cb58d9 = function() { return handleError(function (arg0, arg1, arg2, arg3) {
-        getObject(arg0).putImageData(getObject(arg1), arg2, arg3);
-    }, arguments) };
-    imports.wbg.__wbg_setheight_4fce583024b2d088 = function(arg0, arg1) {
-        getObject(arg0).height = arg1 >>> 0;
-    };
-    imports.wbg.__wbg_settextContent_b55fe2f5f1399466 = function(arg0, arg1, arg2) {
-        getObject(arg0).textContent = arg1 === 0 ? undefined : getStringFromWasm0(arg1, arg2);
-    };
-    imports.wbg.__wbg_setwidth_40a6ed203b92839d = function(arg0, arg1) {
-        getObject(arg0).width = arg1 >>> 0;
-    };
-    imports.wbg.__wbg_stack_0ed75d68575b0f3c = function(arg0, arg1) {
-        const ret = getObject(arg1).stack;
-        const ptr1 = passStringToWasm0(ret, wasm.__wbindgen_export_1, wasm.__wbindgen_export_3);
-        const len1 = WASM_VECTOR_LEN;
-        getDataViewMemory0().setInt32(arg0 + 4 * 1, len1, true);
-        getDataViewMemory0().setInt32(arg0 + 4 * 0, ptr1, true);
-    };
-    imports.wbg.__wbg_static_accessor_GLOBAL_8921f820c2ce3f12 = function() {
-        const ret = typeof global === 'undefined' ? null : global;
-        return isLikeNone(ret) ? 0 : addHeapObject(ret);
-    };
-    imports.wbg.__wbg_static_accessor_GLOBAL_THIS_f0a4409105898184 = function() {
-        const ret = typeof globalThis === 'undefined' ? null : globalThis;
-        return isLikeNone(ret) ? 0 : addHeapObject(ret);
-    };
-    imports.wbg.__wbg_static_accessor_SELF_995b214ae681ff99 = function() {
-        const ret = typeof self === 'undefined' ? null : self;
-        return isLikeNone(ret) ? 0 : addHeapObject(ret);
-    };
-    imports.wbg.__wbg_static_accessor_WINDOW_cde3890479c675ea = function() {
-        const ret = typeof window === 'undefined' ? null : window;
-        return isLikeNone(ret) ? 0 : addHeapObject(ret);
-    };
-    imports.wbg.__wbg_warn_90eb15d986910fe9 = function(arg0, arg1, arg2, arg3) {
-        console.warn(getObject(arg0), getObject(arg1), getObject(arg2), getObject(arg3));
-    };
-    imports.wbg.__wbg_wbindgendebugstring_99ef257a3ddda34d = function(arg0, arg1) {
-        const ret = debugString(getObject(arg1));
-        const ptr1 = passStringToWasm0(ret, wasm.__wbindgen_export_1, wasm.__wbindgen_export_3);
-        const len1 = WASM_VECTOR_LEN;
-        getDataViewMemory0().setInt32(arg0 + 4 * 1, len1, true);
-        getDataViewMemory0().setInt32(arg0 + 4 * 0, ptr1, true);
-    };
-    imports.wbg.__wbg_wbindgenisundefined_c4b71d073b92f3c5 = function(arg0) {
-        const ret = getObject(arg0) === undefined;
-        return ret;
-    };
-    imports.wbg.__wbg_wbindgenthrow_451ec1a8469d7eb6 = function(arg0, arg1) {
-        throw new Error(getStringFromWasm0(arg0, arg1));
-    };
-    imports.wbg.__wbg_width_a4fb6e3c48314b28 = function(arg0) {
-        const ret = getObject(arg0).width;
-        return ret;
-    };
-    imports.wbg.__wbg_width_c7a070ac56976582 = function(arg0) {
-        const ret = getObject(arg0).width;
-        return ret;
-    };
-    imports.wbg.__wbg_width_cb2a94a03e71098c = function(arg0) {
-        const ret = getObject(arg0).width;
-        return ret;
-    };
-    imports.wbg.__wbindgen_cast_2241b6af4c4b2941 = function(arg0, arg1) {
-        // Cast intrinsic for `Ref(String) -> Externref`.
-        const ret = getStringFromWasm0(arg0, arg1);
-        return addHeapObject(ret);
-    };
-    imports.wbg.__wbindgen_object_clone_ref = function(arg0) {
-        const ret = getObject(arg0);
-        return addHeapObject(ret);
-    };
-    imports.wbg.__wbindgen_object_drop_ref = function(arg0) {
-        takeObject(arg0);
-    };
-
-    return imports;
-}
-
-function __wbg_init_memory(imports, memory) {
-
-}
-
-function __wbg_finalize_init(instance, module) {
-    wasm = instance.exports;
-    __wbg_init.__wbindgen_wasm_module = module;
-    cachedDataViewMemory0 = null;
-    cachedUint8ArrayMemory0 = null;
-    cachedUint8ClampedArrayMemory0 = null;
-
-
-    wasm.__wbindgen_start();
-    return wasm;
+        return false;
+    }
 }
 
 function initSync(module) {
     if (wasm !== undefined) return wasm;
 
 
-    if (typeof module !== 'undefined') {
+    if (module !== undefined) {
         if (Object.getPrototypeOf(module) === Object.prototype) {
             ({module} = module)
         } else {
@@ -4945,15 +3114,10 @@ function initSync(module) {
     }
 
     const imports = __wbg_get_imports();
-
-    __wbg_init_memory(imports);
-
     if (!(module instanceof WebAssembly.Module)) {
         module = new WebAssembly.Module(module);
     }
-
     const instance = new WebAssembly.Instance(module, imports);
-
     return __wbg_finalize_init(instance, module);
 }
 
@@ -4961,7 +3125,7 @@ async function __wbg_init(module_or_path) {
     if (wasm !== undefined) return wasm;
 
 
-    if (typeof module_or_path !== 'undefined') {
+    if (module_or_path !== undefined) {
         if (Object.getPrototypeOf(module_or_path) === Object.prototype) {
             ({module_or_path} = module_or_path)
         } else {
@@ -4969,7 +3133,7 @@ async function __wbg_init(module_or_path) {
         }
     }
 
-    if (typeof module_or_path === 'undefined') {
+    if (module_or_path === undefined) {
         module_or_path = new URL('pixlab_wasm_bg.wasm', import.meta.url);
     }
     const imports = __wbg_get_imports();
@@ -4978,12 +3142,9 @@ async function __wbg_init(module_or_path) {
         module_or_path = fetch(module_or_path);
     }
 
-    __wbg_init_memory(imports);
-
     const { instance, module } = await __wbg_load(await module_or_path, imports);
 
     return __wbg_finalize_init(instance, module);
 }
 
-export { initSync };
-export default __wbg_init;
+export { initSync, __wbg_init as default };
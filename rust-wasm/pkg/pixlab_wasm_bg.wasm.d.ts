@@ -1,202 +1,182 @@
 /* tslint:disable */
 /* eslint-disable */
 export const memory: WebAssembly.Memory;
-export const init: () => void;
+export const __wbg_cancelled_free: (a: number, b: number) => void;
+export const __wbg_colorrange_free: (a: number, b: number) => void;
+export const __wbg_drawstyle_free: (a: number, b: number) => void;
+export const __wbg_filtersettings_free: (a: number, b: number) => void;
+export const __wbg_get_cancelled_operation: (a: number, b: number) => void;
+export const __wbg_get_colorrange_fuzziness: (a: number) => number;
+export const __wbg_get_colorrange_hue: (a: number) => number;
+export const __wbg_get_colorrange_hue_tolerance: (a: number) => number;
+export const __wbg_get_colorrange_max_lightness: (a: number) => number;
+export const __wbg_get_colorrange_max_saturation: (a: number) => number;
+export const __wbg_get_colorrange_min_lightness: (a: number) => number;
+export const __wbg_get_colorrange_min_saturation: (a: number) => number;
+export const __wbg_get_drawstyle_fill: (a: number) => number;
+export const __wbg_get_drawstyle_stroke: (a: number) => number;
+export const __wbg_get_drawstyle_stroke_width: (a: number) => number;
+export const __wbg_get_filtersettings_blur: (a: number) => number;
+export const __wbg_get_filtersettings_brightness: (a: number) => number;
+export const __wbg_get_filtersettings_flip_horizontal: (a: number) => number;
+export const __wbg_get_filtersettings_flip_vertical: (a: number) => number;
+export const __wbg_get_filtersettings_grayscale: (a: number) => number;
+export const __wbg_get_filtersettings_rotation: (a: number) => number;
+export const __wbg_get_filtersettings_temperature: (a: number) => number;
+export const __wbg_get_filtersettings_tint: (a: number) => number;
+export const __wbg_get_perceptualhashes_ahash: (a: number, b: number) => void;
+export const __wbg_get_perceptualhashes_dhash: (a: number, b: number) => void;
+export const __wbg_get_perceptualhashes_phash: (a: number, b: number) => void;
+export const __wbg_get_renderedimage_data: (a: number, b: number) => void;
+export const __wbg_get_renderedimage_height: (a: number) => number;
+export const __wbg_get_renderedimage_width: (a: number) => number;
+export const __wbg_get_tile_core_height: (a: number) => number;
+export const __wbg_get_tile_core_width: (a: number) => number;
+export const __wbg_get_tile_core_x: (a: number) => number;
+export const __wbg_get_tile_core_y: (a: number) => number;
+export const __wbg_get_tile_input_height: (a: number) => number;
+export const __wbg_get_tile_input_width: (a: number) => number;
+export const __wbg_get_tile_input_x: (a: number) => number;
+export const __wbg_get_tile_input_y: (a: number) => number;
+export const __wbg_get_timingreport_elapsed_ms: (a: number, b: number) => void;
+export const __wbg_get_timingreport_height: (a: number) => number;
+export const __wbg_get_timingreport_operation: (a: number, b: number) => void;
+export const __wbg_get_timingreport_width: (a: number) => number;
+export const __wbg_incrementalrenderer_free: (a: number, b: number) => void;
+export const __wbg_layerstack_free: (a: number, b: number) => void;
+export const __wbg_lut_free: (a: number, b: number) => void;
+export const __wbg_mask_free: (a: number, b: number) => void;
+export const __wbg_perceptualhashes_free: (a: number, b: number) => void;
+export const __wbg_proxyimage_free: (a: number, b: number) => void;
+export const __wbg_renderedimage_free: (a: number, b: number) => void;
+export const __wbg_set_cancelled_operation: (a: number, b: number, c: number) => void;
+export const __wbg_set_colorrange_fuzziness: (a: number, b: number) => void;
+export const __wbg_set_colorrange_hue: (a: number, b: number) => void;
+export const __wbg_set_colorrange_hue_tolerance: (a: number, b: number) => void;
+export const __wbg_set_colorrange_max_lightness: (a: number, b: number) => void;
+export const __wbg_set_colorrange_max_saturation: (a: number, b: number) => void;
+export const __wbg_set_colorrange_min_lightness: (a: number, b: number) => void;
+export const __wbg_set_colorrange_min_saturation: (a: number, b: number) => void;
+export const __wbg_set_drawstyle_fill: (a: number, b: number) => void;
+export const __wbg_set_drawstyle_stroke: (a: number, b: number) => void;
+export const __wbg_set_drawstyle_stroke_width: (a: number, b: number) => void;
+export const __wbg_set_filtersettings_blur: (a: number, b: number) => void;
+export const __wbg_set_filtersettings_brightness: (a: number, b: number) => void;
+export const __wbg_set_filtersettings_flip_horizontal: (a: number, b: number) => void;
+export const __wbg_set_filtersettings_flip_vertical: (a: number, b: number) => void;
+export const __wbg_set_filtersettings_grayscale: (a: number, b: number) => void;
+export const __wbg_set_filtersettings_rotation: (a: number, b: number) => void;
+export const __wbg_set_filtersettings_temperature: (a: number, b: number) => void;
+export const __wbg_set_filtersettings_tint: (a: number, b: number) => void;
+export const __wbg_set_perceptualhashes_ahash: (a: number, b: number, c: number) => void;
+export const __wbg_set_perceptualhashes_dhash: (a: number, b: number, c: number) => void;
+export const __wbg_set_perceptualhashes_phash: (a: number, b: number, c: number) => void;
+export const __wbg_set_renderedimage_data: (a: number, b: number, c: number) => void;
+export const __wbg_set_renderedimage_height: (a: number, b: number) => void;
+export const __wbg_set_renderedimage_width: (a: number, b: number) => void;
+export const __wbg_set_tile_core_height: (a: number, b: number) => void;
+export const __wbg_set_tile_core_width: (a: number, b: number) => void;
+export const __wbg_set_tile_core_x: (a: number, b: number) => void;
+export const __wbg_set_tile_core_y: (a: number, b: number) => void;
+export const __wbg_set_tile_input_height: (a: number, b: number) => void;
+export const __wbg_set_tile_input_width: (a: number, b: number) => void;
+export const __wbg_set_tile_input_x: (a: number, b: number) => void;
+export const __wbg_set_tile_input_y: (a: number, b: number) => void;
+export const __wbg_set_timingreport_elapsed_ms: (a: number, b: number, c: number) => void;
+export const __wbg_set_timingreport_height: (a: number, b: number) => void;
+export const __wbg_set_timingreport_operation: (a: number, b: number, c: number) => void;
+export const __wbg_set_timingreport_width: (a: number, b: number) => void;
+export const __wbg_tile_free: (a: number, b: number) => void;
+export const __wbg_timingreport_free: (a: number, b: number) => void;
+export const __wbg_watermark_free: (a: number, b: number) => void;
+export const apply_blur: (a: number, b: number, c: number, d: number, e: number, f: number, g: number, h: number, i: number, j: number, k: number) => void;
+export const apply_blur_tile: (a: number, b: number, c: number, d: number, e: number, f: number, g: number) => void;
+export const apply_brightness: (a: number, b: number, c: number, d: number, e: number, f: number, g: number, h: number) => void;
+export const apply_crop: (a: number, b: number, c: number, d: number, e: number, f: number, g: number, h: number, i: number, j: number, k: number) => void;
+export const apply_flip_horizontal: (a: number, b: number, c: number, d: number, e: number, f: number, g: number) => void;
+export const apply_flip_horizontal_in_place: (a: number, b: number, c: number, d: number, e: number, f: number) => void;
+export const apply_flip_vertical: (a: number, b: number, c: number, d: number, e: number, f: number, g: number) => void;
+export const apply_flip_vertical_in_place: (a: number, b: number, c: number, d: number, e: number, f: number) => void;
+export const apply_grain: (a: number, b: number, c: number, d: number, e: number, f: number, g: number, h: number, i: number) => void;
+export const apply_grayscale: (a: number, b: number, c: number, d: number, e: number, f: number, g: number) => void;
+export const apply_lut: (a: number, b: number, c: number, d: number, e: number, f: number, g: number, h: number, i: number, j: number, k: number, l: number) => void;
+export const apply_rotate_180: (a: number, b: number, c: number, d: number, e: number, f: number, g: number) => void;
+export const apply_rotate_180_in_place: (a: number, b: number, c: number, d: number, e: number, f: number) => void;
+export const apply_rotate_270_cw: (a: number, b: number, c: number, d: number, e: number, f: number, g: number) => void;
+export const apply_rotate_90_cw: (a: number, b: number, c: number, d: number, e: number, f: number, g: number) => void;
+export const apply_vignette: (a: number, b: number, c: number, d: number, e: number, f: number, g: number, h: number, i: number, j: number) => void;
+export const apply_watermark: (a: number, b: number, c: number, d: number, e: number, f: number) => void;
+export const apply_white_balance: (a: number, b: number, c: number, d: number, e: number, f: number, g: number, h: number, i: number, j: number) => void;
+export const auto_white_balance: (a: number, b: number, c: number, d: number, e: number, f: number, g: number) => void;
+export const blur_halo: (a: number, b: number, c: number) => number;
+export const colorrange_new: () => number;
+export const compute_histogram: (a: number, b: number, c: number, d: number, e: number, f: number, g: number, h: number, i: number, j: number) => void;
+export const compute_perceptual_hashes: (a: number, b: number, c: number, d: number, e: number) => void;
+export const draw_arrow: (a: number, b: number, c: number, d: number, e: number, f: number, g: number, h: number, i: number, j: number, k: number, l: number, m: number) => void;
+export const draw_ellipse: (a: number, b: number, c: number, d: number, e: number, f: number, g: number, h: number, i: number, j: number, k: number) => void;
+export const draw_line: (a: number, b: number, c: number, d: number, e: number, f: number, g: number, h: number, i: number, j: number, k: number, l: number) => void;
+export const draw_polygon: (a: number, b: number, c: number, d: number, e: number, f: number, g: number, h: number, i: number) => void;
+export const draw_rect: (a: number, b: number, c: number, d: number, e: number, f: number, g: number, h: number, i: number, j: number, k: number) => void;
+export const drawstyle_new: () => number;
+export const filtersettings_clear_crop: (a: number) => void;
+export const filtersettings_new: () => number;
+export const filtersettings_set_crop: (a: number, b: number, c: number, d: number, e: number) => void;
+export const filtersettings_set_grain: (a: number, b: number, c: number, d: number, e: number) => void;
+export const filtersettings_set_vignette: (a: number, b: number, c: number, d: number, e: number, f: number) => void;
 export const greet: (a: number, b: number, c: number) => void;
-export const apply_grayscale: (a: number, b: number, c: number, d: number, e: number) => void;
-export const apply_blur: (a: number, b: number, c: number, d: number, e: number, f: number) => void;
-export const apply_brightness: (a: number, b: number, c: number, d: number, e: number, f: number) => void;
-export const apply_flip_horizontal: (a: number, b: number, c: number, d: number, e: number) => void;
-export const apply_flip_vertical: (a: number, b: number, c: number, d: number, e: number) => void;
-export const apply_rotate_90_cw: (a: number, b: number, c: number, d: number, e: number) => void;
-export const apply_rotate_180: (a: number, b: number, c: number, d: number, e: number) => void;
-export const apply_rotate_270_cw: (a: number, b: number, c: number, d: number, e: number) => void;
-export const apply_crop: (a: number, b: number, c: number, d: number, e: number, f: number, g: number, h: number, i: number) => void;
-export const __wbg_photonimage_free: (a: number, b: number) => void;
-export const photonimage_new: (a: number, b: number, c: number, d: number) => number;
-export const photonimage_new_from_byteslice: (a: number, b: number) => number;
-export const photonimage_new_from_blob: (a: number) => number;
-export const photonimage_new_from_image: (a: number) => number;
-export const photonimage_get_width: (a: number) => number;
-export const photonimage_get_raw_pixels: (a: number, b: number) => void;
-export const photonimage_get_height: (a: number) => number;
-export const photonimage_get_base64: (a: number, b: number) => void;
-export const photonimage_get_bytes: (a: number, b: number) => void;
-export const photonimage_get_bytes_jpeg: (a: number, b: number, c: number) => void;
-export const photonimage_get_bytes_webp: (a: number, b: number) => void;
-export const photonimage_get_image_data: (a: number) => number;
-export const photonimage_set_imgdata: (a: number, b: number) => void;
-export const photonimage_get_estimated_filesize: (a: number) => bigint;
-export const __wbg_rgb_free: (a: number, b: number) => void;
-export const rgb_new: (a: number, b: number, c: number) => number;
-export const rgb_set_red: (a: number, b: number) => void;
-export const rgb_set_green: (a: number, b: number) => void;
-export const rgb_set_blue: (a: number, b: number) => void;
-export const rgb_get_red: (a: number) => number;
-export const rgb_get_green: (a: number) => number;
-export const rgb_get_blue: (a: number) => number;
-export const rgba_new: (a: number, b: number, c: number, d: number) => number;
-export const rgba_set_alpha: (a: number, b: number) => void;
-export const rgba_get_alpha: (a: number) => number;
-export const run: (a: number) => void;
-export const get_image_data: (a: number, b: number) => number;
-export const putImageData: (a: number, b: number, c: number) => void;
-export const open_image: (a: number, b: number) => number;
-export const to_raw_pixels: (a: number, b: number) => void;
-export const base64_to_image: (a: number, b: number) => number;
-export const base64_to_vec: (a: number, b: number, c: number) => void;
-export const to_image_data: (a: number) => number;
-export const alter_channel: (a: number, b: number, c: number) => void;
-export const alter_red_channel: (a: number, b: number) => void;
-export const alter_green_channel: (a: number, b: number) => void;
-export const alter_blue_channel: (a: number, b: number) => void;
-export const alter_two_channels: (a: number, b: number, c: number, d: number, e: number) => void;
-export const alter_channels: (a: number, b: number, c: number, d: number) => void;
-export const remove_channel: (a: number, b: number, c: number) => void;
-export const remove_red_channel: (a: number, b: number) => void;
-export const remove_green_channel: (a: number, b: number) => void;
-export const remove_blue_channel: (a: number, b: number) => void;
-export const swap_channels: (a: number, b: number, c: number) => void;
-export const invert: (a: number) => void;
-export const selective_hue_rotate: (a: number, b: number, c: number) => void;
-export const selective_color_convert: (a: number, b: number, c: number, d: number) => void;
-export const selective_lighten: (a: number, b: number, c: number) => void;
-export const selective_desaturate: (a: number, b: number, c: number) => void;
-export const selective_saturate: (a: number, b: number, c: number) => void;
-export const selective_greyscale: (a: number, b: number) => void;
-export const gamma_correction: (a: number, b: number, c: number, d: number) => void;
-export const hsluv: (a: number, b: number, c: number, d: number) => void;
-export const lch: (a: number, b: number, c: number, d: number) => void;
-export const hsl: (a: number, b: number, c: number, d: number) => void;
-export const hsv: (a: number, b: number, c: number, d: number) => void;
-export const hue_rotate_hsl: (a: number, b: number) => void;
-export const hue_rotate_hsv: (a: number, b: number) => void;
-export const hue_rotate_lch: (a: number, b: number) => void;
-export const hue_rotate_hsluv: (a: number, b: number) => void;
-export const saturate_hsl: (a: number, b: number) => void;
-export const saturate_lch: (a: number, b: number) => void;
-export const saturate_hsluv: (a: number, b: number) => void;
-export const saturate_hsv: (a: number, b: number) => void;
-export const lighten_lch: (a: number, b: number) => void;
-export const lighten_hsluv: (a: number, b: number) => void;
-export const lighten_hsl: (a: number, b: number) => void;
-export const lighten_hsv: (a: number, b: number) => void;
-export const darken_lch: (a: number, b: number) => void;
-export const darken_hsluv: (a: number, b: number) => void;
-export const darken_hsl: (a: number, b: number) => void;
-export const darken_hsv: (a: number, b: number) => void;
-export const desaturate_hsv: (a: number, b: number) => void;
-export const desaturate_hsl: (a: number, b: number) => void;
-export const desaturate_lch: (a: number, b: number) => void;
-export const desaturate_hsluv: (a: number, b: number) => void;
-export const mix_with_colour: (a: number, b: number, c: number) => void;
-export const noise_reduction: (a: number) => void;
-export const sharpen: (a: number) => void;
-export const edge_detection: (a: number) => void;
-export const identity: (a: number) => void;
-export const box_blur: (a: number) => void;
-export const gaussian_blur: (a: number, b: number) => void;
-export const detect_horizontal_lines: (a: number) => void;
-export const detect_vertical_lines: (a: number) => void;
-export const detect_45_deg_lines: (a: number) => void;
-export const detect_135_deg_lines: (a: number) => void;
-export const laplace: (a: number) => void;
-export const edge_one: (a: number) => void;
-export const emboss: (a: number) => void;
-export const sobel_horizontal: (a: number) => void;
-export const prewitt_horizontal: (a: number) => void;
-export const sobel_vertical: (a: number) => void;
-export const sobel_global: (a: number) => void;
-export const offset: (a: number, b: number, c: number) => void;
-export const offset_red: (a: number, b: number) => void;
-export const offset_green: (a: number, b: number) => void;
-export const offset_blue: (a: number, b: number) => void;
-export const multiple_offsets: (a: number, b: number, c: number, d: number) => void;
-export const halftone: (a: number) => void;
-export const primary: (a: number) => void;
-export const colorize: (a: number) => void;
-export const solarize: (a: number) => void;
-export const solarize_retimg: (a: number) => number;
-export const adjust_brightness: (a: number, b: number) => void;
-export const inc_brightness: (a: number, b: number) => void;
-export const dec_brightness: (a: number, b: number) => void;
-export const adjust_contrast: (a: number, b: number) => void;
-export const tint: (a: number, b: number, c: number, d: number) => void;
-export const horizontal_strips: (a: number, b: number) => void;
-export const color_horizontal_strips: (a: number, b: number, c: number) => void;
-export const vertical_strips: (a: number, b: number) => void;
-export const color_vertical_strips: (a: number, b: number, c: number) => void;
-export const oil: (a: number, b: number, c: number) => void;
-export const frosted_glass: (a: number) => void;
-export const pixelize: (a: number, b: number) => void;
-export const normalize: (a: number) => void;
-export const dither: (a: number, b: number) => void;
-export const duotone: (a: number, b: number, c: number) => void;
-export const neue: (a: number) => void;
-export const lix: (a: number) => void;
-export const ryo: (a: number) => void;
-export const filter: (a: number, b: number, c: number) => void;
-export const lofi: (a: number) => void;
-export const pastel_pink: (a: number) => void;
-export const golden: (a: number) => void;
-export const cali: (a: number) => void;
-export const dramatic: (a: number) => void;
-export const monochrome_tint: (a: number, b: number) => void;
-export const duotone_violette: (a: number) => void;
-export const duotone_horizon: (a: number) => void;
-export const duotone_tint: (a: number, b: number) => void;
-export const duotone_lilac: (a: number) => void;
-export const duotone_ochre: (a: number) => void;
-export const firenze: (a: number) => void;
-export const obsidian: (a: number) => void;
-export const monochrome: (a: number, b: number, c: number, d: number) => void;
-export const sepia: (a: number) => void;
-export const grayscale: (a: number) => void;
-export const grayscale_human_corrected: (a: number) => void;
-export const desaturate: (a: number) => void;
-export const decompose_min: (a: number) => void;
-export const decompose_max: (a: number) => void;
-export const grayscale_shades: (a: number, b: number) => void;
-export const r_grayscale: (a: number) => void;
-export const g_grayscale: (a: number) => void;
-export const b_grayscale: (a: number) => void;
-export const single_channel_grayscale: (a: number, b: number) => void;
-export const threshold: (a: number, b: number) => void;
-export const watermark: (a: number, b: number, c: bigint, d: bigint) => void;
-export const blend: (a: number, b: number, c: number, d: number) => void;
-export const create_gradient: (a: number, b: number) => number;
-export const apply_gradient: (a: number) => void;
-export const add_noise_rand: (a: number) => void;
-export const pink_noise: (a: number) => void;
-export const draw_text_with_border: (a: number, b: number, c: number, d: number, e: number, f: number) => void;
-export const draw_text: (a: number, b: number, c: number, d: number, e: number, f: number) => void;
-export const crop: (a: number, b: number, c: number, d: number, e: number) => number;
-export const crop_img_browser: (a: number, b: number, c: number, d: number, e: number) => number;
-export const fliph: (a: number) => void;
-export const flipv: (a: number) => void;
-export const resize_img_browser: (a: number, b: number, c: number, d: number) => number;
-export const resize: (a: number, b: number, c: number, d: number) => number;
-export const seam_carve: (a: number, b: number, c: number) => number;
-export const shearx: (a: number, b: number) => number;
-export const sheary: (a: number, b: number) => number;
-export const padding_uniform: (a: number, b: number, c: number) => number;
-export const padding_left: (a: number, b: number, c: number) => number;
-export const padding_right: (a: number, b: number, c: number) => number;
-export const padding_top: (a: number, b: number, c: number) => number;
-export const padding_bottom: (a: number, b: number, c: number) => number;
-export const rotate: (a: number, b: number) => number;
-export const resample: (a: number, b: number, c: number) => number;
-export const rgba_get_red: (a: number) => number;
-export const rgba_get_green: (a: number) => number;
-export const rgba_get_blue: (a: number) => number;
-export const photonimage_new_from_base64: (a: number, b: number) => number;
-export const rgba_set_green: (a: number, b: number) => void;
-export const rgba_set_blue: (a: number, b: number) => void;
-export const rgba_set_red: (a: number, b: number) => void;
-export const __wbg_rgba_free: (a: number, b: number) => void;
-export const __wbindgen_export_0: (a: number) => void;
-export const __wbindgen_export_1: (a: number, b: number) => number;
-export const __wbindgen_export_2: (a: number, b: number, c: number) => void;
-export const __wbindgen_export_3: (a: number, b: number, c: number, d: number) => number;
+export const hamming_distance: (a: number, b: number, c: number, d: number, e: number) => void;
+export const incrementalrenderer_cached_bytes: (a: number) => number;
+export const incrementalrenderer_clear: (a: number) => void;
+export const incrementalrenderer_new: (a: number, b: number, c: number, d: number, e: number, f: number) => void;
+export const incrementalrenderer_render: (a: number, b: number, c: number, d: number, e: number, f: number) => void;
+export const incrementalrenderer_set_budget: (a: number, b: number) => void;
+export const incrementalrenderer_set_source: (a: number, b: number, c: number, d: number, e: number, f: number) => void;
+export const init: () => void;
+export const is_near_duplicate: (a: number, b: number, c: number, d: number, e: number) => void;
+export const last_timing: () => number;
+export const layerstack_add_layer: (a: number, b: number, c: number, d: number, e: number, f: number) => void;
+export const layerstack_composite: (a: number) => number;
+export const layerstack_layer_count: (a: number) => number;
+export const layerstack_move_layer: (a: number, b: number, c: number, d: number) => void;
+export const layerstack_new: (a: number, b: number) => number;
+export const layerstack_remove_layer: (a: number, b: number, c: number) => void;
+export const layerstack_set_blend_mode: (a: number, b: number, c: number, d: number, e: number) => void;
+export const layerstack_set_layer_data: (a: number, b: number, c: number, d: number, e: number, f: number, g: number) => void;
+export const layerstack_set_offset: (a: number, b: number, c: number, d: number, e: number) => void;
+export const layerstack_set_opacity: (a: number, b: number, c: number, d: number) => void;
+export const layerstack_set_visible: (a: number, b: number, c: number, d: number) => void;
+export const lut_new: (a: number, b: number, c: number) => void;
+export const lut_title: (a: number, b: number) => void;
+export const magic_wand: (a: number, b: number, c: number, d: number, e: number, f: number, g: number, h: number, i: number, j: number, k: number) => void;
+export const mask_bounds: (a: number, b: number) => void;
+export const mask_data: (a: number, b: number) => void;
+export const mask_ellipse: (a: number, b: number, c: number, d: number, e: number, f: number) => number;
+export const mask_feather: (a: number, b: number) => number;
+export const mask_full: (a: number, b: number) => number;
+export const mask_intersect: (a: number, b: number, c: number) => void;
+export const mask_invert: (a: number) => void;
+export const mask_new: (a: number, b: number) => number;
+export const mask_polygon: (a: number, b: number, c: number, d: number, e: number) => void;
+export const mask_rect: (a: number, b: number, c: number, d: number, e: number, f: number) => number;
+export const mask_subtract: (a: number, b: number, c: number) => void;
+export const mask_union: (a: number, b: number, c: number) => void;
+export const plan_tiles: (a: number, b: number, c: number, d: number, e: number) => void;
+export const proxyimage_level_count: (a: number) => number;
+export const proxyimage_new: (a: number, b: number, c: number, d: number, e: number) => void;
+export const proxyimage_render_full: (a: number, b: number, c: number, d: number, e: number, f: number) => void;
+export const proxyimage_render_preview: (a: number, b: number, c: number, d: number, e: number) => void;
+export const select_color_range: (a: number, b: number, c: number, d: number, e: number, f: number) => void;
+export const set_log_level: (a: number, b: number, c: number) => void;
+export const watermark_image: (a: number, b: number, c: number, d: number, e: number, f: number, g: number) => void;
+export const watermark_set_anchor: (a: number, b: number, c: number, d: number) => void;
+export const watermark_set_margin: (a: number, b: number) => void;
+export const watermark_set_opacity: (a: number, b: number) => void;
+export const watermark_set_tile: (a: number, b: number, c: number) => void;
+export const watermark_size: (a: number, b: number) => void;
+export const white_balance_from_point: (a: number, b: number, c: number, d: number, e: number, f: number, g: number, h: number) => void;
+export const __wbindgen_export: (a: number) => void;
+export const __wbindgen_export2: (a: number, b: number, c: number) => void;
+export const __wbindgen_export3: (a: number, b: number) => number;
+export const __wbindgen_export4: (a: number, b: number, c: number, d: number) => number;
 export const __wbindgen_add_to_stack_pointer: (a: number) => number;
 export const __wbindgen_start: () => void;
@@ -0,0 +1,187 @@
+use crate::filters::crop;
+
+/// Number of bins per channel
+pub const BINS: usize = 256;
+
+/// Per-channel histograms gathered in a single pass
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Histogram {
+    pub red: [u32; BINS],
+    pub green: [u32; BINS],
+    pub blue: [u32; BINS],
+    /// Rec.709 luminance
+    pub luminance: [u32; BINS],
+    pub alpha: [u32; BINS],
+    /// Number of pixels that were sampled
+    pub samples: u32,
+}
+
+/// Rectangle to restrict the histogram to, same semantics as `crop::apply`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Region {
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl Histogram {
+    /// Flatten into R, G, B, luminance, alpha (256 bins each, in that order)
+    pub fn to_flat(&self) -> Vec<u32> {
+        let mut flat = Vec::with_capacity(BINS * 5);
+        flat.extend_from_slice(&self.red);
+        flat.extend_from_slice(&self.green);
+        flat.extend_from_slice(&self.blue);
+        flat.extend_from_slice(&self.luminance);
+        flat.extend_from_slice(&self.alpha);
+        flat
+    }
+}
+
+/// Compute R, G, B, luminance and alpha histograms of RGBA image data
+///
+/// # Arguments
+/// * `stride` - Sample every `stride`-th pixel in both directions (1 = every pixel)
+/// * `region` - Optional region of interest; `None` covers the whole image
+pub fn compute(
+    image_data: &[u8],
+    width: u32,
+    height: u32,
+    stride: u32,
+    region: Option<Region>,
+) -> Result<Histogram, String> {
+    // Validate input
+    let expected_len = (width * height * 4) as usize;
+    if image_data.len() != expected_len {
+        return Err(format!(
+            "Invalid image data length: expected {}, got {}",
+            expected_len,
+            image_data.len()
+        ));
+    }
+
+    if stride == 0 {
+        return Err("Stride must be at least 1".to_string());
+    }
+
+    let region = region.unwrap_or(Region { x: 0, y: 0, width, height });
+    crop::validate_area(width, height, region.x, region.y, region.width, region.height)?;
+
+    let width = width as usize;
+    let stride = stride as usize;
+
+    let mut hist = Histogram {
+        red: [0; BINS],
+        green: [0; BINS],
+        blue: [0; BINS],
+        luminance: [0; BINS],
+        alpha: [0; BINS],
+        samples: 0,
+    };
+
+    let (x0, y0) = (region.x as usize, region.y as usize);
+    let (x1, y1) = (x0 + region.width as usize, y0 + region.height as usize);
+
+    for y in (y0..y1).step_by(stride) {
+        let row = &image_data[(y * width + x0) * 4..(y * width + x1) * 4];
+
+        for pixel in row.chunks_exact(4).step_by(stride) {
+            let (r, g, b, a) = (pixel[0], pixel[1], pixel[2], pixel[3]);
+
+            hist.red[r as usize] += 1;
+            hist.green[g as usize] += 1;
+            hist.blue[b as usize] += 1;
+            hist.alpha[a as usize] += 1;
+            hist.luminance[luminance(r, g, b) as usize] += 1;
+            hist.samples += 1;
+        }
+    }
+
+    Ok(hist)
+}
+
+/// Rec.709 luminance in integer math (weights x10000, rounded)
+fn luminance(r: u8, g: u8, b: u8) -> u8 {
+    ((2126 * r as u32 + 7152 * g as u32 + 722 * b as u32 + 5000) / 10000) as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_histogram_counts() {
+        // 2x2 RGBA image: red, green, blue, transparent white
+        let data = vec![
+            255, 0, 0, 255,
+            0, 255, 0, 255,
+            0, 0, 255, 255,
+            255, 255, 255, 0,
+        ];
+
+        let hist = compute(&data, 2, 2, 1, None).unwrap();
+        assert_eq!(hist.samples, 4);
+        assert_eq!(hist.red[255], 2);
+        assert_eq!(hist.red[0], 2);
+        assert_eq!(hist.alpha[255], 3);
+        assert_eq!(hist.alpha[0], 1);
+        assert_eq!(hist.luminance[255], 1); // White
+        assert_eq!(hist.luminance[54], 1); // Red: 0.2126 * 255
+        assert_eq!(hist.luminance.iter().sum::<u32>(), 4);
+    }
+
+    #[test]
+    fn test_histogram_stride() {
+        // 4x4 gray image, stride 2 samples (0,0), (2,0), (0,2), (2,2)
+        let data = vec![128u8; 4 * 4 * 4];
+        let hist = compute(&data, 4, 4, 2, None).unwrap();
+        assert_eq!(hist.samples, 4);
+        assert_eq!(hist.red[128], 4);
+    }
+
+    #[test]
+    fn test_histogram_region() {
+        // 2x2 image, region is the bottom-right pixel only
+        let data = vec![
+            0, 0, 0, 255,
+            0, 0, 0, 255,
+            0, 0, 0, 255,
+            200, 100, 50, 255,
+        ];
+
+        let region = Region { x: 1, y: 1, width: 1, height: 1 };
+        let hist = compute(&data, 2, 2, 1, Some(region)).unwrap();
+        assert_eq!(hist.samples, 1);
+        assert_eq!(hist.red[200], 1);
+        assert_eq!(hist.green[100], 1);
+        assert_eq!(hist.blue[50], 1);
+    }
+
+    #[test]
+    fn test_histogram_flat_layout() {
+        let data = vec![10, 20, 30, 40];
+        let flat = compute(&data, 1, 1, 1, None).unwrap().to_flat();
+        assert_eq!(flat.len(), BINS * 5);
+        assert_eq!(flat[10], 1);
+        assert_eq!(flat[BINS + 20], 1);
+        assert_eq!(flat[2 * BINS + 30], 1);
+        assert_eq!(flat[4 * BINS + 40], 1);
+    }
+
+    #[test]
+    fn test_histogram_invalid_region() {
+        let data = vec![0u8; 2 * 2 * 4];
+        let region = Region { x: 1, y: 0, width: 2, height: 1 };
+        let result = compute(&data, 2, 2, 1, Some(region));
+        assert!(result.unwrap_err().contains("exceeds image width"));
+
+        let result = compute(&data, 2, 2, 0, None);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_invalid_dimensions() {
+        let data = vec![255, 0, 0, 255];
+        assert!(compute(&data, 2, 2, 1, None).is_err());
+    }
+}
@@ -0,0 +1,2 @@
+pub mod histogram;
+pub use pixlab_phash as phash;
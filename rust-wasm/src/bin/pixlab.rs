@@ -0,0 +1,328 @@
+//! `pixlab` - apply the web editor's filters to image files
+//!
+//! Filters always run in the editor's pipeline order (see `pipeline::apply`),
+//! whatever order the flags are given in, so the output matches the browser.
+
+use pixlab_wasm::filters::lut::{self, Interpolation, Lut};
+use pixlab_wasm::filters::white_balance::{AutoWhiteBalance, WhiteBalance};
+use pixlab_wasm::pipeline::{self, CropArea, FilterState, Image, Rotation};
+use pixlab_wasm::watermark::{Anchor, Placement, Watermark};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+const USAGE: &str = "\
+Usage: pixlab <INPUT> [OPTIONS] -o <OUTPUT>
+
+Options:
+  --temperature <N>     White balance, negative cooler, positive warmer
+  --tint <N>            White balance, negative greener, positive more magenta
+  --auto-white-balance <METHOD>
+                        Measure temperature and tint from the input:
+                        gray-world or white-patch
+  --grayscale           Convert to grayscale
+  --brightness <N>      Brightness adjustment, -255 to 255
+  --flip-horizontal     Mirror left-right
+  --flip-vertical       Mirror top-bottom
+  --rotate <DEG>        Rotate clockwise by 90, 180 or 270 degrees
+  --blur <RADIUS>       Gaussian blur radius
+  --crop <X,Y,W,H>      Crop after rotating, in rotated coordinates
+  --vignette <N>        Vignette, -1.0 (black edges) to 1.0 (white edges)
+  --grain <N>           Film grain, 0.0 to 1.0
+  --grain-size <PX>     Grain size in pixels (default 1.0)
+  --grain-seed <N>      Grain pattern; the same seed gives the same grain (default 0)
+  --lut <PATH>          Apply a .cube color lookup table after the filters
+  --lut-strength <N>    0.0 (original) to 1.0 (full look, default)
+  --lut-trilinear       Trilinear instead of tetrahedral interpolation
+  --watermark <PATH>    Overlay an image (e.g. a logo PNG) after the filters
+  --watermark-anchor <NAME>
+                        top-left, top, top-right, left, center, right,
+                        bottom-left, bottom or bottom-right (default)
+  --watermark-margin <PX>
+                        Distance from the edges next to the anchor (default 16)
+  --watermark-opacity <N>
+                        0.0 to 1.0 (default 1.0)
+  --watermark-scale <N> Scale the watermark image (default 1.0)
+  --watermark-rotate <DEG>
+                        Rotate the watermark clockwise, any angle
+  --watermark-tile <GAP>
+                        Repeat the watermark over the image, GAP pixels apart
+  -o, --output <PATH>   Output file (.png or .jpg)
+  -h, --help            Show this help";
+
+/// Parsed command line
+#[derive(Debug, PartialEq)]
+struct Args {
+    input: PathBuf,
+    output: PathBuf,
+    state: FilterState,
+    /// Replaces `--temperature` and `--tint` when set
+    auto_white_balance: Option<AutoWhiteBalance>,
+    lut: LutArgs,
+    watermark: WatermarkArgs,
+}
+
+/// `--lut*` options
+#[derive(Debug, PartialEq)]
+struct LutArgs {
+    path: Option<PathBuf>,
+    strength: f32,
+    interpolation: Interpolation,
+}
+
+impl Default for LutArgs {
+    fn default() -> Self {
+        LutArgs { path: None, strength: 1.0, interpolation: Interpolation::default() }
+    }
+}
+
+/// `--watermark*` options
+#[derive(Debug, PartialEq)]
+struct WatermarkArgs {
+    path: Option<PathBuf>,
+    scale: f32,
+    rotation: f32,
+    placement: Placement,
+}
+
+impl Default for WatermarkArgs {
+    fn default() -> Self {
+        WatermarkArgs { path: None, scale: 1.0, rotation: 0.0, placement: Placement::default() }
+    }
+}
+
+fn main() -> ExitCode {
+    let args = match parse_args(std::env::args().skip(1)) {
+        Ok(Some(args)) => args,
+        Ok(None) => {
+            println!("{}", USAGE);
+            return ExitCode::SUCCESS;
+        }
+        Err(e) => {
+            eprintln!("pixlab: {}\n\n{}", e, USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match run(&args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("pixlab: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(args: &Args) -> Result<(), String> {
+    let input = read_image(&args.input)?;
+    let mut state = args.state.clone();
+    if let Some(method) = args.auto_white_balance {
+        state.white_balance = WhiteBalance::auto(&input.data, input.width, input.height, method)?;
+    }
+    let mut output = pipeline::apply(&input, &state)?;
+
+    if let Some(path) = &args.lut.path {
+        let text = std::fs::read_to_string(path).map_err(|e| format!("Cannot read {}: {}", path.display(), e))?;
+        let table = Lut::parse_cube(&text).map_err(|e| format!("{}: {}", path.display(), e))?;
+        output.data = lut::apply(&output.data, output.width, output.height, &table, args.lut.interpolation, args.lut.strength)?;
+    }
+
+    if let Some(path) = &args.watermark.path {
+        let mut watermark = Watermark::from_image(&read_image(path)?, args.watermark.scale, args.watermark.rotation)?;
+        watermark.placement = args.watermark.placement;
+        output = watermark.apply(&output);
+    }
+
+    let rgba = image::RgbaImage::from_raw(output.width, output.height, output.data)
+        .ok_or("Filter output does not match its dimensions")?;
+
+    // JPEG has no alpha channel
+    let result = match image::ImageFormat::from_path(&args.output) {
+        Ok(image::ImageFormat::Jpeg) => image::DynamicImage::ImageRgba8(rgba).to_rgb8().save(&args.output),
+        _ => rgba.save(&args.output),
+    };
+
+    result.map_err(|e| format!("Cannot write {}: {}", args.output.display(), e))
+}
+
+fn read_image(path: &PathBuf) -> Result<Image, String> {
+    let decoded = image::open(path)
+        .map_err(|e| format!("Cannot read {}: {}", path.display(), e))?
+        .to_rgba8();
+    let (width, height) = decoded.dimensions();
+
+    Image::new(width, height, decoded.into_raw())
+}
+
+/// Parse arguments (without the program name); `None` when help was requested
+fn parse_args(args: impl IntoIterator<Item = String>) -> Result<Option<Args>, String> {
+    let mut args = args.into_iter();
+    let mut input = None;
+    let mut output = None;
+    let mut state = FilterState::default();
+    let mut auto_white_balance = None;
+    let mut lut = LutArgs::default();
+    let mut watermark = WatermarkArgs::default();
+
+    while let Some(arg) = args.next() {
+        let mut value = |name: &str| args.next().ok_or(format!("{} needs a value", name));
+
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(None),
+            "--temperature" => state.white_balance.temperature = parse_number(&value(&arg)?, &arg)?,
+            "--tint" => state.white_balance.tint = parse_number(&value(&arg)?, &arg)?,
+            "--auto-white-balance" => auto_white_balance = Some(AutoWhiteBalance::from_name(&value(&arg)?)?),
+            "--grayscale" => state.grayscale = true,
+            "--flip-horizontal" => state.flip_horizontal = true,
+            "--flip-vertical" => state.flip_vertical = true,
+            "--brightness" => state.brightness = parse_number(&value(&arg)?, &arg)?,
+            "--blur" => state.blur = parse_number(&value(&arg)?, &arg)?,
+            "--rotate" => state.rotation = Rotation::from_degrees(parse_number(&value(&arg)?, &arg)?)?,
+            "--crop" => state.crop = Some(parse_crop(&value(&arg)?)?),
+            "--vignette" => state.vignette.amount = parse_number(&value(&arg)?, &arg)?,
+            "--grain" => state.grain.amount = parse_number(&value(&arg)?, &arg)?,
+            "--grain-size" => state.grain.size = parse_number(&value(&arg)?, &arg)?,
+            "--grain-seed" => state.grain.seed = parse_number(&value(&arg)?, &arg)?,
+            "--lut" => lut.path = Some(PathBuf::from(value(&arg)?)),
+            "--lut-strength" => lut.strength = parse_number(&value(&arg)?, &arg)?,
+            "--lut-trilinear" => lut.interpolation = Interpolation::Trilinear,
+            "--watermark" => watermark.path = Some(PathBuf::from(value(&arg)?)),
+            "--watermark-anchor" => watermark.placement.anchor = Anchor::from_name(&value(&arg)?)?,
+            "--watermark-margin" => watermark.placement.margin = parse_number(&value(&arg)?, &arg)?,
+            "--watermark-opacity" => watermark.placement.opacity = parse_number(&value(&arg)?, &arg)?,
+            "--watermark-scale" => watermark.scale = parse_number(&value(&arg)?, &arg)?,
+            "--watermark-rotate" => watermark.rotation = parse_number(&value(&arg)?, &arg)?,
+            "--watermark-tile" => {
+                watermark.placement.tile = true;
+                watermark.placement.spacing = parse_number(&value(&arg)?, &arg)?;
+            }
+            "-o" | "--output" => output = Some(PathBuf::from(value(&arg)?)),
+            _ if arg.starts_with('-') => return Err(format!("Unknown option {}", arg)),
+            _ if input.is_none() => input = Some(PathBuf::from(arg)),
+            _ => return Err(format!("Unexpected argument {}", arg)),
+        }
+    }
+
+    if lut.path.is_none() && lut != LutArgs::default() {
+        return Err("--lut-* options need --lut".to_string());
+    }
+    if watermark.path.is_none() && watermark != WatermarkArgs::default() {
+        return Err("--watermark-* options need --watermark".to_string());
+    }
+
+    Ok(Some(Args {
+        input: input.ok_or("Missing input file")?,
+        output: output.ok_or("Missing output file (-o)")?,
+        state,
+        auto_white_balance,
+        lut,
+        watermark,
+    }))
+}
+
+fn parse_number<T: std::str::FromStr>(value: &str, name: &str) -> Result<T, String> {
+    value.parse().map_err(|_| format!("Invalid value for {}: {}", name, value))
+}
+
+/// `X,Y,W,H`
+fn parse_crop(value: &str) -> Result<CropArea, String> {
+    let parts = value
+        .split(',')
+        .map(|part| parse_number(part.trim(), "--crop"))
+        .collect::<Result<Vec<u32>, _>>()?;
+
+    match parts[..] {
+        [x, y, width, height] => Ok(CropArea { x, y, width, height }),
+        _ => Err(format!("--crop expects X,Y,W,H, got {}", value)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pixlab_wasm::filters::effects::{Grain, Vignette};
+
+    fn parse(args: &[&str]) -> Result<Option<Args>, String> {
+        parse_args(args.iter().map(|s| s.to_string()))
+    }
+
+    #[test]
+    fn test_parse_args() {
+        let args = parse(&[
+            "in.jpg", "--rotate", "90", "--grayscale", "--crop", "1,2,30,40", "--vignette", "-0.4", "--grain", "0.2",
+            "--grain-seed", "7", "--temperature", "0.3", "-o", "out.png",
+        ])
+        .unwrap()
+        .unwrap();
+
+        assert_eq!(args.input, PathBuf::from("in.jpg"));
+        assert_eq!(args.output, PathBuf::from("out.png"));
+        assert_eq!(
+            args.state,
+            FilterState {
+                white_balance: WhiteBalance { temperature: 0.3, tint: 0.0 },
+                grayscale: true,
+                rotation: Rotation::Cw90,
+                crop: Some(CropArea { x: 1, y: 2, width: 30, height: 40 }),
+                vignette: Vignette { amount: -0.4, ..Vignette::default() },
+                grain: Grain { amount: 0.2, seed: 7, ..Grain::default() },
+                ..FilterState::default()
+            }
+        );
+        assert_eq!(args.auto_white_balance, None);
+        assert_eq!(args.lut, LutArgs::default());
+        assert_eq!(args.watermark, WatermarkArgs::default());
+    }
+
+    #[test]
+    fn test_parse_watermark_args() {
+        let args = parse(&[
+            "in.jpg", "-o", "out.jpg", "--watermark", "logo.png", "--watermark-anchor", "top-left",
+            "--watermark-opacity", "0.4", "--watermark-scale", "0.5", "--watermark-rotate", "-30", "--watermark-tile", "24",
+        ])
+        .unwrap()
+        .unwrap();
+
+        assert_eq!(
+            args.watermark,
+            WatermarkArgs {
+                path: Some(PathBuf::from("logo.png")),
+                scale: 0.5,
+                rotation: -30.0,
+                placement: Placement { anchor: Anchor::TopLeft, opacity: 0.4, tile: true, spacing: 24, ..Placement::default() },
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_lut_args() {
+        let args = parse(&["in.jpg", "--lut", "film.cube", "--lut-strength", "0.6", "--lut-trilinear", "-o", "out.png"])
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(
+            args.lut,
+            LutArgs { path: Some(PathBuf::from("film.cube")), strength: 0.6, interpolation: Interpolation::Trilinear }
+        );
+    }
+
+    #[test]
+    fn test_parse_auto_white_balance() {
+        let args = parse(&["in.jpg", "--auto-white-balance", "white-patch", "-o", "out.png"]).unwrap().unwrap();
+        assert_eq!(args.auto_white_balance, Some(AutoWhiteBalance::WhitePatch));
+        assert!(args.state.white_balance.is_identity());
+    }
+
+    #[test]
+    fn test_parse_args_errors() {
+        assert!(parse(&["in.jpg"]).is_err());
+        assert!(parse(&["in.jpg", "-o"]).is_err());
+        assert!(parse(&["in.jpg", "--rotate", "45", "-o", "out.png"]).is_err());
+        assert!(parse(&["in.jpg", "--crop", "1,2,3", "-o", "out.png"]).is_err());
+        assert!(parse(&["in.jpg", "--sharpen", "-o", "out.png"]).is_err());
+        assert!(parse(&["in.jpg", "--lut-strength", "0.5", "-o", "out.png"]).is_err());
+        assert!(parse(&["in.jpg", "--auto-white-balance", "average", "-o", "out.png"]).is_err());
+        assert!(parse(&["in.jpg", "--watermark-tile", "4", "-o", "out.png"]).is_err());
+        assert!(parse(&["in.jpg", "--watermark", "logo.png", "--watermark-anchor", "middle", "-o", "out.png"]).is_err());
+        assert_eq!(parse(&["--help"]), Ok(None));
+    }
+}
@@ -0,0 +1,396 @@
+//! Drawing shapes and text onto RGBA images
+//!
+//! A [`Canvas`] wraps an image buffer and rasterizes lines, rectangles,
+//! ellipses, polygons and arrows into it with exact-area anti-aliasing.
+//! Every shape is turned into closed outlines first; all outlines of one
+//! color are filled in a single pass, so overlapping parts of a translucent
+//! stroke are not painted twice. Pixels are composited source-over with the
+//! same math as [`crate::layers`].
+
+#[cfg(feature = "text")]
+pub mod text;
+
+use crate::layers::{self, BlendMode};
+use ab_glyph_rasterizer::{point, Rasterizer};
+use std::f32::consts::PI;
+
+/// (x, y) in pixels; (0, 0) is the top-left corner of the image
+pub type Point = (f32, f32);
+
+/// Straight (non-premultiplied) RGBA
+pub type Color = [u8; 4];
+
+/// Largest distance between a flattened curve and the true curve, in pixels
+const FLATTEN_TOLERANCE: f32 = 0.1;
+
+/// Fill and outline of a shape
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Style {
+    pub fill: Option<Color>,
+    pub stroke: Option<Color>,
+    /// Stroke width in pixels, centred on the shape's edge
+    pub stroke_width: f32,
+}
+
+/// Line segment of an outline
+type Segment = (Point, Point);
+
+/// RGBA image to draw on
+pub struct Canvas<'a> {
+    data: &'a mut [u8],
+    width: u32,
+    height: u32,
+}
+
+impl<'a> Canvas<'a> {
+    pub fn new(data: &'a mut [u8], width: u32, height: u32) -> Result<Self, String> {
+        let expected_len = (width * height * 4) as usize;
+        if data.len() != expected_len {
+            return Err(format!(
+                "Invalid image data length: expected {}, got {}",
+                expected_len,
+                data.len()
+            ));
+        }
+
+        Ok(Canvas { data, width, height })
+    }
+
+    /// Straight line with round caps
+    pub fn line(&mut self, from: Point, to: Point, color: Color, width: f32) {
+        self.fill_segments(&stroke_polyline(&[from, to], false, width), color);
+    }
+
+    pub fn rect(&mut self, x: f32, y: f32, width: f32, height: f32, style: &Style) {
+        let corners = |grow: f32| {
+            vec![(x - grow, y - grow), (x + width + grow, y - grow), (x + width + grow, y + height + grow), (x - grow, y + height + grow)]
+        };
+
+        if let Some(fill) = style.fill {
+            self.fill_segments(&closed(&corners(0.0)), fill);
+        }
+        if let Some(stroke) = style.stroke {
+            // Outer rectangle with the inner one cut out gives mitred corners
+            let half = style.stroke_width / 2.0;
+            let mut segments = closed(&corners(half));
+            if width > style.stroke_width && height > style.stroke_width {
+                segments.extend(reversed(&closed(&corners(-half))));
+            }
+            self.fill_segments(&segments, stroke);
+        }
+    }
+
+    /// Ellipse centred on (`cx`, `cy`) with radii `rx` and `ry`
+    pub fn ellipse(&mut self, cx: f32, cy: f32, rx: f32, ry: f32, style: &Style) {
+        if let Some(fill) = style.fill {
+            self.fill_segments(&closed(&ellipse_points(cx, cy, rx, ry)), fill);
+        }
+        if let Some(stroke) = style.stroke {
+            let half = style.stroke_width / 2.0;
+            let mut segments = closed(&ellipse_points(cx, cy, rx + half, ry + half));
+            if rx > half && ry > half {
+                segments.extend(reversed(&closed(&ellipse_points(cx, cy, rx - half, ry - half))));
+            }
+            self.fill_segments(&segments, stroke);
+        }
+    }
+
+    /// Closed polygon through `points`; filled with the non-zero rule, stroked with round joins
+    pub fn polygon(&mut self, points: &[Point], style: &Style) {
+        if points.len() < 2 {
+            return;
+        }
+
+        if let Some(fill) = style.fill {
+            self.fill_segments(&closed(points), fill);
+        }
+        if let Some(stroke) = style.stroke {
+            self.fill_segments(&stroke_polyline(points, true, style.stroke_width), stroke);
+        }
+    }
+
+    /// Line from `from` to `to` ending in a filled triangular head of `head_length` pixels
+    pub fn arrow(&mut self, from: Point, to: Point, color: Color, width: f32, head_length: f32) {
+        let (dx, dy) = (to.0 - from.0, to.1 - from.1);
+        let length = (dx * dx + dy * dy).sqrt();
+        if length == 0.0 {
+            return;
+        }
+
+        let (ux, uy) = (dx / length, dy / length);
+        let head_length = head_length.min(length);
+        let half_base = (head_length / 2.0).max(width);
+        let base = (to.0 - ux * head_length, to.1 - uy * head_length);
+
+        // The shaft stops inside the head so its round cap stays hidden
+        let shaft_end = (to.0 - ux * head_length / 2.0, to.1 - uy * head_length / 2.0);
+        let mut segments = if head_length < length { stroke_polyline(&[from, shaft_end], false, width) } else { Vec::new() };
+        segments.extend(oriented(closed(&[
+            to,
+            (base.0 - uy * half_base, base.1 + ux * half_base),
+            (base.0 + uy * half_base, base.1 - ux * half_base),
+        ])));
+
+        self.fill_segments(&segments, color);
+    }
+
+    /// Fill the outlines made of `segments` with `color`, non-zero winding
+    pub(crate) fn fill_segments(&mut self, segments: &[Segment], color: Color) {
+        let Some((x0, y0, x1, y1)) = self.clipped_bounds(segments) else {
+            return;
+        };
+
+        // Two spare columns take what lies right of the area, see `clip_x`
+        let (area_w, area_h) = (x1 - x0, y1 - y0);
+        let mut raster = Rasterizer::new(area_w + 2, area_h);
+        let (ox, oy) = (x0 as f32, y0 as f32);
+        let right = area_w as f32 + 1.0;
+
+        for &((ax, ay), (bx, by)) in segments {
+            for (a, b) in clip_x(((ax - ox, ay - oy), (bx - ox, by - oy)), right) {
+                raster.draw_line(point(a.0, a.1), point(b.0, b.1));
+            }
+        }
+
+        let row_len = self.width as usize * 4;
+        raster.for_each_pixel_2d(|x, y, coverage| {
+            let x = x as usize;
+            if x < area_w && coverage > 0.0 {
+                let i = (y0 + y as usize) * row_len + (x0 + x) * 4;
+                // Overlapping outlines add up past 1
+                layers::composite_pixel(&mut self.data[i..i + 4], &color, coverage.min(1.0), BlendMode::Normal);
+            }
+        });
+    }
+
+    /// Pixel bounds of `segments` within the image: (x0, y0, x1, y1), end exclusive
+    fn clipped_bounds(&self, segments: &[Segment]) -> Option<(usize, usize, usize, usize)> {
+        let points = || segments.iter().flat_map(|&(a, b)| [a, b]);
+        let min_x = points().map(|p| p.0).fold(f32::INFINITY, f32::min);
+        let min_y = points().map(|p| p.1).fold(f32::INFINITY, f32::min);
+        let max_x = points().map(|p| p.0).fold(f32::NEG_INFINITY, f32::max);
+        let max_y = points().map(|p| p.1).fold(f32::NEG_INFINITY, f32::max);
+        if !(min_x.is_finite() && min_y.is_finite() && max_x.is_finite() && max_y.is_finite()) {
+            return None;
+        }
+
+        let x0 = min_x.floor().clamp(0.0, self.width as f32) as usize;
+        let y0 = min_y.floor().clamp(0.0, self.height as f32) as usize;
+        let x1 = max_x.ceil().clamp(0.0, self.width as f32) as usize;
+        let y1 = max_y.ceil().clamp(0.0, self.height as f32) as usize;
+
+        (x0 < x1 && y0 < y1).then_some((x0, y0, x1, y1))
+    }
+}
+
+/// Split a segment at x = 0 and x = `right`, moving the outside parts onto those lines
+///
+/// The rasterizer accumulates coverage along each row, so an edge left of
+/// the area counts the same when pushed onto its left border. Edges to the
+/// right only affect pixels further right and land in the spare columns.
+fn clip_x(segment: Segment, right: f32) -> Vec<Segment> {
+    let (a, b) = segment;
+    let mut points = vec![a];
+
+    for edge in [0.0, right] {
+        let (lo, hi) = if a.0 < b.0 { (a.0, b.0) } else { (b.0, a.0) };
+        if lo < edge && edge < hi {
+            let t = (edge - a.0) / (b.0 - a.0);
+            points.push((edge, a.1 + t * (b.1 - a.1)));
+        }
+    }
+    points.push(b);
+
+    // Crossings in order from a to b
+    if a.0 > b.0 {
+        let last = points.len() - 1;
+        points[1..last].reverse();
+    }
+
+    points
+        .windows(2)
+        .map(|w| ((w[0].0.clamp(0.0, right), w[0].1), (w[1].0.clamp(0.0, right), w[1].1)))
+        .collect()
+}
+
+/// Edges of the closed outline through `points`
+fn closed(points: &[Point]) -> Vec<Segment> {
+    (0..points.len()).map(|i| (points[i], points[(i + 1) % points.len()])).collect()
+}
+
+fn reversed(segments: &[Segment]) -> Vec<Segment> {
+    segments.iter().rev().map(|&(a, b)| (b, a)).collect()
+}
+
+/// Clockwise on screen (positive shoelace area with y down), so overlapping pieces add up
+fn oriented(segments: Vec<Segment>) -> Vec<Segment> {
+    let area: f32 = segments.iter().map(|&(a, b)| a.0 * b.1 - b.0 * a.1).sum();
+    if area < 0.0 {
+        reversed(&segments)
+    } else {
+        segments
+    }
+}
+
+/// Points along an ellipse, close enough that the edges stay within `FLATTEN_TOLERANCE`
+fn ellipse_points(cx: f32, cy: f32, rx: f32, ry: f32) -> Vec<Point> {
+    let radius = rx.max(ry);
+    if radius <= 0.0 {
+        return Vec::new();
+    }
+
+    // Sagitta of a chord spanning angle a is r * (1 - cos(a / 2))
+    let step = 2.0 * (1.0 - (FLATTEN_TOLERANCE / radius).min(1.0)).acos();
+    let count = ((2.0 * PI / step).ceil() as usize).clamp(8, 4096);
+
+    // Vertices slightly outside the curve, so the polygon has the ellipse's area
+    let step = 2.0 * PI / count as f32;
+    let grow = (step / step.sin()).sqrt();
+
+    (0..count)
+        .map(|i| {
+            let angle = step * i as f32;
+            (cx + rx * grow * angle.cos(), cy + ry * grow * angle.sin())
+        })
+        .collect()
+}
+
+/// Outline of a `width` pixel stroke along `points`, with round joins and caps
+///
+/// One rectangle per segment and one disc per vertex, all oriented alike so
+/// the non-zero fill merges them.
+fn stroke_polyline(points: &[Point], closed_path: bool, width: f32) -> Vec<Segment> {
+    let half = width / 2.0;
+    if half <= 0.0 {
+        return Vec::new();
+    }
+
+    let edges = if closed_path { points.len() } else { points.len() - 1 };
+    let mut segments = Vec::new();
+
+    for i in 0..edges {
+        let (a, b) = (points[i], points[(i + 1) % points.len()]);
+        let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+        let length = (dx * dx + dy * dy).sqrt();
+        if length == 0.0 {
+            continue;
+        }
+
+        let (nx, ny) = (-dy / length * half, dx / length * half);
+        segments.extend(oriented(closed(&[
+            (a.0 + nx, a.1 + ny),
+            (b.0 + nx, b.1 + ny),
+            (b.0 - nx, b.1 - ny),
+            (a.0 - nx, a.1 - ny),
+        ])));
+    }
+
+    for &(x, y) in points {
+        segments.extend(oriented(closed(&ellipse_points(x, y, half, half))));
+    }
+
+    segments
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RED: Color = [255, 0, 0, 255];
+
+    fn blank(width: u32, height: u32) -> Vec<u8> {
+        [255, 255, 255, 255].repeat((width * height) as usize)
+    }
+
+    fn pixel(data: &[u8], width: u32, x: u32, y: u32) -> [u8; 4] {
+        let i = ((y * width + x) * 4) as usize;
+        data[i..i + 4].try_into().unwrap()
+    }
+
+    /// Total red coverage, in pixels
+    fn red_area(data: &[u8]) -> f32 {
+        data.chunks_exact(4).map(|px| (255 - px[1]) as f32 / 255.0).sum()
+    }
+
+    #[test]
+    fn test_rect_fill_and_stroke() {
+        let mut data = blank(20, 20);
+        let mut canvas = Canvas::new(&mut data, 20, 20).unwrap();
+        canvas.rect(2.0, 3.0, 10.0, 5.0, &Style { fill: Some(RED), ..Style::default() });
+        assert_eq!(red_area(&data), 50.0);
+        assert_eq!(pixel(&data, 20, 2, 3), RED);
+        assert_eq!(pixel(&data, 20, 12, 3), [255, 255, 255, 255]);
+
+        // A 2px stroke on pixel edges: 1px either side of the outline
+        let mut data = blank(20, 20);
+        let mut canvas = Canvas::new(&mut data, 20, 20).unwrap();
+        canvas.rect(4.0, 4.0, 10.0, 10.0, &Style { stroke: Some(RED), stroke_width: 2.0, ..Style::default() });
+        assert_eq!(red_area(&data), 12.0 * 12.0 - 8.0 * 8.0);
+        assert_eq!(pixel(&data, 20, 8, 8), [255, 255, 255, 255]);
+    }
+
+    #[test]
+    fn test_anti_aliased_ellipse() {
+        let mut data = blank(64, 64);
+        let mut canvas = Canvas::new(&mut data, 64, 64).unwrap();
+        canvas.ellipse(32.0, 32.0, 20.0, 12.0, &Style { fill: Some(RED), ..Style::default() });
+
+        let expected = PI * 20.0 * 12.0;
+        assert!((red_area(&data) - expected).abs() < 0.5, "area {}", red_area(&data));
+        assert_eq!(pixel(&data, 64, 32, 32), RED);
+        // Partial coverage on the edge
+        let edge = pixel(&data, 64, 32, 20);
+        assert!(edge[1] > 0 && edge[1] < 255, "edge {:?}", edge);
+    }
+
+    #[test]
+    fn test_translucent_stroke_is_painted_once() {
+        // Joins overlap, but the stroke must not get darker there
+        let mut data = blank(40, 40);
+        let mut canvas = Canvas::new(&mut data, 40, 40).unwrap();
+        let points = [(5.0, 5.0), (30.0, 5.0), (30.0, 30.0)];
+        canvas.polygon(&points, &Style { stroke: Some([0, 0, 0, 128]), stroke_width: 4.0, ..Style::default() });
+
+        let darkest = data.chunks_exact(4).map(|px| px[0]).min().unwrap();
+        assert_eq!(darkest, 127);
+        assert_eq!(pixel(&data, 40, 30, 5)[0], 127);
+    }
+
+    #[test]
+    fn test_shapes_outside_the_image() {
+        let mut data = blank(10, 10);
+        let mut canvas = Canvas::new(&mut data, 10, 10).unwrap();
+
+        // Clipped on every side, the visible part is filled exactly
+        canvas.rect(-5.0, -5.0, 20.0, 20.0, &Style { fill: Some(RED), ..Style::default() });
+        assert_eq!(red_area(&data), 100.0);
+
+        let mut data = blank(10, 10);
+        let mut canvas = Canvas::new(&mut data, 10, 10).unwrap();
+        canvas.line((-50.0, 5.0), (50.0, 5.0), RED, 2.0);
+        canvas.ellipse(100.0, 100.0, 5.0, 5.0, &Style { fill: Some(RED), ..Style::default() });
+        assert_eq!(red_area(&data), 20.0);
+
+        assert!(Canvas::new(&mut [0; 3], 1, 1).is_err());
+    }
+
+    #[test]
+    fn test_arrow() {
+        let mut data = blank(60, 20);
+        let mut canvas = Canvas::new(&mut data, 60, 20).unwrap();
+        canvas.arrow((5.0, 10.0), (55.0, 10.0), RED, 2.0, 12.0);
+
+        // Shaft 2px high, head wider near its base
+        assert_eq!(pixel(&data, 60, 20, 10), RED);
+        assert_eq!(pixel(&data, 60, 20, 13), [255, 255, 255, 255]);
+        assert_eq!(pixel(&data, 60, 45, 13), RED);
+        assert_eq!(pixel(&data, 60, 57, 10), [255, 255, 255, 255]);
+    }
+
+    #[test]
+    fn test_clip_x() {
+        // Crossing both borders from right to left
+        let parts = clip_x(((12.0, 0.0), (-2.0, 7.0)), 10.0);
+        assert_eq!(parts, vec![((10.0, 0.0), (10.0, 1.0)), ((10.0, 1.0), (0.0, 6.0)), ((0.0, 6.0), (0.0, 7.0))]);
+    }
+}
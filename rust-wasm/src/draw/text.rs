@@ -0,0 +1,260 @@
+//! Text from TrueType/OpenType fonts (`text` feature)
+//!
+//! Glyph outlines are laid out with the font's advances and kerning, then
+//! placed, rotated and filled like any other [`Canvas`] shape. No shaping:
+//! one glyph per character, left to right.
+
+use super::{Canvas, Color, Point, Segment};
+use ab_glyph::{Font as _, FontVec, OutlineCurve, PxScale, PxScaleFont, ScaleFont};
+
+type Scaled<'a> = PxScaleFont<&'a FontVec>;
+
+/// Horizontal alignment of each line relative to the anchor point
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Align {
+    #[default]
+    Left,
+    Center,
+    Right,
+}
+
+impl Align {
+    /// "left", "center" or "right"
+    pub fn from_name(name: &str) -> Result<Self, String> {
+        match name {
+            "left" => Ok(Align::Left),
+            "center" => Ok(Align::Center),
+            "right" => Ok(Align::Right),
+            _ => Err(format!("Unknown alignment: {}", name)),
+        }
+    }
+}
+
+/// How text is drawn
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TextStyle {
+    /// Font size in pixels, from the highest ascender to the lowest descender
+    pub size: f32,
+    pub color: Color,
+    pub align: Align,
+    /// Clockwise degrees around the anchor point
+    pub rotation: f32,
+}
+
+impl Default for TextStyle {
+    fn default() -> Self {
+        TextStyle { size: 16.0, color: [0, 0, 0, 255], align: Align::Left, rotation: 0.0 }
+    }
+}
+
+/// A parsed TTF or OTF font
+pub struct Font {
+    font: FontVec,
+}
+
+impl Font {
+    /// Parse the contents of a .ttf or .otf file (the first face of a collection)
+    pub fn from_bytes(data: Vec<u8>) -> Result<Self, String> {
+        let font = FontVec::try_from_vec(data).map_err(|e| format!("Invalid font: {}", e))?;
+        Ok(Font { font })
+    }
+
+    /// Width of the widest line and height of all lines at `size` pixels
+    pub fn measure(&self, text: &str, size: f32) -> (f32, f32) {
+        let scaled = self.font.as_scaled(PxScale::from(size));
+        let width = text.lines().map(|line| line_width(&scaled, line)).fold(0.0, f32::max);
+        let lines = text.lines().count().max(1) as f32;
+
+        (width, scaled.height() + (lines - 1.0) * line_advance(&scaled))
+    }
+
+    /// Distance from the top of a line to its baseline at `size` pixels
+    pub fn ascent(&self, size: f32) -> f32 {
+        self.font.as_scaled(PxScale::from(size)).ascent()
+    }
+
+    /// Outline segments of `text` laid out with the first baseline at y = 0
+    /// and aligned at x = 0, before rotation
+    fn outline(&self, text: &str, size: f32, align: Align) -> Vec<Segment> {
+        let scaled = self.font.as_scaled(PxScale::from(size));
+        let (sx, sy) = (scaled.h_scale_factor(), scaled.v_scale_factor());
+        let mut segments = Vec::new();
+
+        for (row, line) in text.lines().enumerate() {
+            let baseline = row as f32 * line_advance(&scaled);
+            let mut pen = match align {
+                Align::Left => 0.0,
+                Align::Center => -line_width(&scaled, line) / 2.0,
+                Align::Right => -line_width(&scaled, line),
+            };
+            let mut previous = None;
+
+            for c in line.chars() {
+                let id = scaled.glyph_id(c);
+                if let Some(prev) = previous {
+                    pen += scaled.kern(prev, id);
+                }
+                previous = Some(id);
+
+                if let Some(outline) = self.font.outline(id) {
+                    // Font units have y up
+                    let place = |p: ab_glyph::Point| (pen + p.x * sx, baseline - p.y * sy);
+                    for curve in &outline.curves {
+                        flatten(curve, &place, &mut segments);
+                    }
+                }
+                pen += scaled.h_advance(id);
+            }
+        }
+
+        segments
+    }
+}
+
+/// Distance from one baseline to the next
+fn line_advance(scaled: &Scaled) -> f32 {
+    scaled.height() + scaled.line_gap()
+}
+
+fn line_width(scaled: &Scaled, line: &str) -> f32 {
+    let mut width = 0.0;
+    let mut previous = None;
+
+    for c in line.chars() {
+        let id = scaled.glyph_id(c);
+        if let Some(prev) = previous {
+            width += scaled.kern(prev, id);
+        }
+        width += scaled.h_advance(id);
+        previous = Some(id);
+    }
+
+    width
+}
+
+/// Append `curve` as line segments, in pixel coordinates from `place`
+fn flatten(curve: &OutlineCurve, place: &impl Fn(ab_glyph::Point) -> Point, segments: &mut Vec<Segment>) {
+    let mut polyline = |points: &[Point], at: &dyn Fn(f32) -> Point| {
+        // Control polygon length bounds the curve length; ~2px per step is smooth at any size
+        let length: f32 = points.windows(2).map(|w| ((w[1].0 - w[0].0).powi(2) + (w[1].1 - w[0].1).powi(2)).sqrt()).sum();
+        let steps = ((length / 2.0).ceil() as usize).clamp(1, 64);
+
+        let mut last = points[0];
+        for i in 1..=steps {
+            let next = at(i as f32 / steps as f32);
+            segments.push((last, next));
+            last = next;
+        }
+    };
+
+    match *curve {
+        OutlineCurve::Line(a, b) => segments.push((place(a), place(b))),
+        OutlineCurve::Quad(a, b, c) => {
+            let (a, b, c) = (place(a), place(b), place(c));
+            polyline(&[a, b, c], &|t| {
+                let u = 1.0 - t;
+                let (wa, wb, wc) = (u * u, 2.0 * u * t, t * t);
+                (wa * a.0 + wb * b.0 + wc * c.0, wa * a.1 + wb * b.1 + wc * c.1)
+            });
+        }
+        OutlineCurve::Cubic(a, b, c, d) => {
+            let (a, b, c, d) = (place(a), place(b), place(c), place(d));
+            polyline(&[a, b, c, d], &|t| {
+                let u = 1.0 - t;
+                let (wa, wb, wc, wd) = (u * u * u, 3.0 * u * u * t, 3.0 * u * t * t, t * t * t);
+                (wa * a.0 + wb * b.0 + wc * c.0 + wd * d.0, wa * a.1 + wb * b.1 + wc * c.1 + wd * d.1)
+            });
+        }
+    }
+}
+
+impl Canvas<'_> {
+    /// Draw `text` with its first baseline through (`x`, `y`), aligned and rotated around that point
+    ///
+    /// Lines are separated by `\n`.
+    pub fn text(&mut self, font: &Font, text: &str, x: f32, y: f32, style: &TextStyle) {
+        let (sin, cos) = style.rotation.to_radians().sin_cos();
+        let transform = |(px, py): Point| (x + px * cos - py * sin, y + px * sin + py * cos);
+
+        let segments: Vec<Segment> = font
+            .outline(text, style.size, style.align)
+            .into_iter()
+            .map(|(a, b)| (transform(a), transform(b)))
+            .collect();
+
+        self.fill_segments(&segments, style.color);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn roboto() -> Font {
+        Font::from_bytes(include_bytes!("../../tests/fixtures/Roboto-Regular.ttf").to_vec()).unwrap()
+    }
+
+    /// Bounding box (x0, y0, x1, y1) of pixels that are not white
+    fn ink_bounds(data: &[u8], width: u32) -> (u32, u32, u32, u32) {
+        let mut bounds = (u32::MAX, u32::MAX, 0, 0);
+        for (i, px) in data.chunks_exact(4).enumerate() {
+            if px[..3] != [255, 255, 255] {
+                let (x, y) = (i as u32 % width, i as u32 / width);
+                bounds = (bounds.0.min(x), bounds.1.min(y), bounds.2.max(x + 1), bounds.3.max(y + 1));
+            }
+        }
+        bounds
+    }
+
+    fn draw(text: &str, x: f32, y: f32, style: &TextStyle) -> Vec<u8> {
+        let mut data = [255, 255, 255, 255].repeat(200 * 100);
+        Canvas::new(&mut data, 200, 100).unwrap().text(&roboto(), text, x, y, style);
+        data
+    }
+
+    #[test]
+    fn test_text_is_drawn_on_the_baseline() {
+        let style = TextStyle { size: 40.0, ..TextStyle::default() };
+        let data = draw("Hxg", 20.0, 50.0, &style);
+        let (x0, y0, x1, y1) = ink_bounds(&data, 200);
+
+        // Cap height above the baseline, descender of the g below it
+        assert!((20..24).contains(&x0), "x0 {}", x0);
+        assert!(y0 > 15 && y0 < 30, "y0 {}", y0);
+        assert!(y1 > 55 && y1 < 62, "y1 {}", y1);
+        assert!(x1 < 20 + roboto().measure("Hxg", 40.0).0.ceil() as u32 + 1);
+
+        // Anti-aliased: gray levels along the edges
+        assert!(data.chunks_exact(4).any(|px| px[0] > 0 && px[0] < 255));
+    }
+
+    #[test]
+    fn test_alignment() {
+        let font = roboto();
+        let (width, _) = font.measure("Wide", 30.0);
+        let left = ink_bounds(&draw("Wide", 100.0, 50.0, &TextStyle { size: 30.0, ..TextStyle::default() }), 200);
+        let right = ink_bounds(&draw("Wide", 100.0, 50.0, &TextStyle { size: 30.0, align: Align::Right, ..TextStyle::default() }), 200);
+        let center = ink_bounds(&draw("Wide", 100.0, 50.0, &TextStyle { size: 30.0, align: Align::Center, ..TextStyle::default() }), 200);
+
+        assert!(left.0 >= 100 && right.2 <= 101);
+        assert!(center.0 < 100 && center.2 > 100);
+        assert!(((left.0 as f32 - right.0 as f32) - width).abs() <= 1.0);
+        assert!(Align::from_name("justify").is_err());
+    }
+
+    #[test]
+    fn test_rotation_and_lines() {
+        // Rotated a quarter turn clockwise, the text runs downwards with its tops to the right
+        let style = TextStyle { size: 20.0, rotation: 90.0, ..TextStyle::default() };
+        let (x0, y0, x1, y1) = ink_bounds(&draw("Long text", 100.0, 5.0, &style), 200);
+        assert!(y1 - y0 > 3 * (x1 - x0), "bounds {:?}", (x0, y0, x1, y1));
+        assert!(x0 >= 94 && x1 > 110, "bounds {:?}", (x0, y0, x1, y1));
+
+        let font = roboto();
+        let (one_w, one_h) = font.measure("ab", 20.0);
+        let (two_w, two_h) = font.measure("ab\nabcd", 20.0);
+        assert!(two_w > one_w && two_h > 1.5 * one_h);
+
+        assert!(Font::from_bytes(vec![0; 16]).is_err());
+    }
+}
@@ -1,6 +1,24 @@
+use super::exec::Exec;
+use super::mask::{self, Mask};
+
 /// Apply brightness adjustment to image data
 /// Adjustment range: -255.0 (darker) to +255.0 (brighter)
 pub fn apply(image_data: &[u8], width: u32, height: u32, adjustment: f32) -> Result<Vec<u8>, String> {
+    apply_with(Exec::preferred(), image_data, width, height, adjustment)
+}
+
+/// [`apply`] only where `mask` selects, blending along soft edges
+pub fn apply_masked(
+    image_data: &[u8],
+    width: u32,
+    height: u32,
+    adjustment: f32,
+    mask: Option<&Mask>,
+) -> Result<Vec<u8>, String> {
+    mask::select(image_data, apply(image_data, width, height, adjustment)?, width, height, mask)
+}
+
+pub(crate) fn apply_with(exec: Exec, image_data: &[u8], width: u32, height: u32, adjustment: f32) -> Result<Vec<u8>, String> {
     // Validate input
     let expected_len = (width * height * 4) as usize;
     if image_data.len() != expected_len {
@@ -15,27 +33,36 @@ pub fn apply(image_data: &[u8], width: u32, height: u32, adjustment: f32) -> Res
     let adjustment = adjustment.clamp(-255.0, 255.0);
 
     // Create output buffer
-    let mut output = Vec::with_capacity(image_data.len());
+    let row_len = width as usize * 4;
+    let mut output = vec![0u8; image_data.len()];
+
+    // Process each row
+    exec.rows(&mut output, row_len, |y, row| {
+        adjust_row(&image_data[y * row_len..(y + 1) * row_len], row, adjustment);
+    });
+
+    Ok(output)
+}
+
+/// Adjust one row, using the SIMD kernel where available
+fn adjust_row(src: &[u8], dst: &mut [u8], adjustment: f32) {
+    #[cfg(all(feature = "simd", target_arch = "wasm32", target_feature = "simd128"))]
+    let done = super::simd::brightness(src, dst, adjustment);
+    #[cfg(not(all(feature = "simd", target_arch = "wasm32", target_feature = "simd128")))]
+    let done = 0;
 
-    // Process each pixel
-    for chunk in image_data.chunks_exact(4) {
-        let r = chunk[0];
-        let g = chunk[1];
-        let b = chunk[2];
-        let a = chunk[3];
+    adjust_pixels(&src[done..], &mut dst[done..], adjustment);
+}
 
+/// Scalar kernel; also handles the tail the SIMD kernel leaves over
+pub(crate) fn adjust_pixels(src: &[u8], dst: &mut [u8], adjustment: f32) {
+    for (dst, chunk) in dst.chunks_exact_mut(4).zip(src.chunks_exact(4)) {
         // Apply brightness adjustment with clamping
-        let new_r = ((r as f32 + adjustment).clamp(0.0, 255.0)) as u8;
-        let new_g = ((g as f32 + adjustment).clamp(0.0, 255.0)) as u8;
-        let new_b = ((b as f32 + adjustment).clamp(0.0, 255.0)) as u8;
-
-        output.push(new_r);
-        output.push(new_g);
-        output.push(new_b);
-        output.push(a); // Alpha channel unchanged
+        dst[0] = ((chunk[0] as f32 + adjustment).clamp(0.0, 255.0)) as u8;
+        dst[1] = ((chunk[1] as f32 + adjustment).clamp(0.0, 255.0)) as u8;
+        dst[2] = ((chunk[2] as f32 + adjustment).clamp(0.0, 255.0)) as u8;
+        dst[3] = chunk[3]; // Alpha channel unchanged
     }
-
-    Ok(output)
 }
 
 #[cfg(test)]
@@ -102,4 +129,13 @@ mod tests {
         let result = apply(&data, 2, 2, 0.0); // Wrong dimensions
         assert!(result.is_err());
     }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_parallel_matches_serial() {
+        let data = crate::filters::test_image(67, 45);
+        let serial = apply_with(Exec::Serial, &data, 67, 45, 37.5).unwrap();
+        let parallel = apply_with(Exec::Parallel, &data, 67, 45, 37.5).unwrap();
+        assert_eq!(serial, parallel);
+    }
 }
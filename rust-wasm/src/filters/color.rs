@@ -0,0 +1,109 @@
+//! Color space conversions shared by the filters
+//!
+//! sRGB values are bytes; linear, Lab and HSL values are `f32`.
+
+/// sRGB byte to linear light, 0.0 to 1.0
+pub(crate) fn srgb_to_linear(value: u8) -> f32 {
+    let v = value as f32 / 255.0;
+    if v <= 0.04045 {
+        v / 12.92
+    } else {
+        ((v + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Linear light to sRGB byte; the inverse of [`srgb_to_linear`], clamped to 0.0 to 1.0
+pub(crate) fn linear_to_srgb(value: f32) -> u8 {
+    let v = value.clamp(0.0, 1.0);
+    let encoded = if v <= 0.003_130_8 {
+        v * 12.92
+    } else {
+        1.055 * v.powf(1.0 / 2.4) - 0.055
+    };
+    (encoded * 255.0).round() as u8
+}
+
+/// sRGB to CIE L*a*b* (D65 white), L in 0.0 to 100.0
+pub(crate) fn rgb_to_lab(r: u8, g: u8, b: u8) -> [f32; 3] {
+    let (r, g, b) = (srgb_to_linear(r), srgb_to_linear(g), srgb_to_linear(b));
+
+    // Linear sRGB to XYZ, relative to the D65 white point
+    let x = (0.412_456_4 * r + 0.357_576_1 * g + 0.180_437_5 * b) / 0.950_47;
+    let y = 0.212_672_9 * r + 0.715_152_2 * g + 0.072_175 * b;
+    let z = (0.019_333_9 * r + 0.119_192 * g + 0.950_304_1 * b) / 1.088_83;
+
+    let f = |t: f32| {
+        if t > 216.0 / 24389.0 {
+            t.cbrt()
+        } else {
+            (24389.0 / 27.0 * t + 16.0) / 116.0
+        }
+    };
+    let (fx, fy, fz) = (f(x), f(y), f(z));
+
+    [116.0 * fy - 16.0, 500.0 * (fx - fy), 200.0 * (fy - fz)]
+}
+
+/// sRGB to hue in degrees (0.0 to 360.0), saturation and lightness (0.0 to 1.0)
+///
+/// Grays have hue 0 and saturation 0, as in CSS `hsl()`.
+pub(crate) fn rgb_to_hsl(r: u8, g: u8, b: u8) -> [f32; 3] {
+    let (r, g, b) = (r as f32 / 255.0, g as f32 / 255.0, b as f32 / 255.0);
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let lightness = (max + min) / 2.0;
+    let chroma = max - min;
+
+    if chroma == 0.0 {
+        return [0.0, 0.0, lightness];
+    }
+
+    let saturation = chroma / (1.0 - (2.0 * lightness - 1.0).abs());
+    let hue = if max == r {
+        ((g - b) / chroma).rem_euclid(6.0)
+    } else if max == g {
+        (b - r) / chroma + 2.0
+    } else {
+        (r - g) / chroma + 4.0
+    };
+
+    [hue * 60.0, saturation.min(1.0), lightness]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: [f32; 3], b: [f32; 3], tolerance: f32) -> bool {
+        a.iter().zip(&b).all(|(x, y)| (x - y).abs() <= tolerance)
+    }
+
+    #[test]
+    fn test_srgb_to_linear() {
+        assert_eq!(srgb_to_linear(0), 0.0);
+        assert_eq!(srgb_to_linear(255), 1.0);
+        assert!((srgb_to_linear(128) - 0.2158).abs() < 1e-3);
+
+        assert!((0..=255).all(|v| linear_to_srgb(srgb_to_linear(v)) == v));
+        assert_eq!(linear_to_srgb(-0.5), 0);
+        assert_eq!(linear_to_srgb(2.0), 255);
+    }
+
+    #[test]
+    fn test_lab() {
+        assert!(close(rgb_to_lab(255, 255, 255), [100.0, 0.0, 0.0], 0.01));
+        assert!(close(rgb_to_lab(0, 0, 0), [0.0, 0.0, 0.0], 0.01));
+        // Reference values for sRGB red and a mid gray
+        assert!(close(rgb_to_lab(255, 0, 0), [53.24, 80.09, 67.20], 0.05));
+        assert!(close(rgb_to_lab(128, 128, 128), [53.59, 0.0, 0.0], 0.05));
+    }
+
+    #[test]
+    fn test_hsl() {
+        assert!(close(rgb_to_hsl(255, 0, 0), [0.0, 1.0, 0.5], 1e-4));
+        assert!(close(rgb_to_hsl(0, 255, 0), [120.0, 1.0, 0.5], 1e-4));
+        assert!(close(rgb_to_hsl(0, 0, 128), [240.0, 1.0, 128.0 / 510.0], 1e-4));
+        assert!(close(rgb_to_hsl(255, 0, 128), [329.88, 1.0, 0.5], 0.01));
+        assert!(close(rgb_to_hsl(200, 200, 200), [0.0, 0.0, 200.0 / 255.0], 1e-4));
+    }
+}
@@ -1,3 +1,6 @@
+use super::exec::Exec;
+use super::mask::Mask;
+
 /// Crop image to specified rectangle
 ///
 /// # Arguments
@@ -19,6 +22,59 @@ pub fn apply(
     y: u32,
     crop_width: u32,
     crop_height: u32,
+) -> Result<Vec<u8>, String> {
+    apply_with(Exec::preferred(), image_data, orig_width, orig_height, x, y, crop_width, crop_height)
+}
+
+/// Crop, then clear what lies outside the selection; a plain crop without a mask
+///
+/// `mask` covers the whole input image. Its coverage scales the alpha of the
+/// cropped pixels, so unselected pixels become fully transparent and feathered
+/// edges fade out. `Mask::bounds` gives the tightest rectangle for a selection.
+#[allow(clippy::too_many_arguments)]
+pub fn apply_masked(
+    image_data: &[u8],
+    orig_width: u32,
+    orig_height: u32,
+    x: u32,
+    y: u32,
+    crop_width: u32,
+    crop_height: u32,
+    mask: Option<&Mask>,
+) -> Result<Vec<u8>, String> {
+    let mut output = apply(image_data, orig_width, orig_height, x, y, crop_width, crop_height)?;
+    let Some(mask) = mask else {
+        return Ok(output);
+    };
+    mask.check_size(orig_width, orig_height)?;
+
+    let (orig_width, crop_width) = (orig_width as usize, crop_width as usize);
+    for (row, dst) in output.chunks_exact_mut(crop_width * 4).enumerate() {
+        let start = (y as usize + row) * orig_width + x as usize;
+        let coverage = &mask.data()[start..start + crop_width];
+
+        for (pixel, &c) in dst.chunks_exact_mut(4).zip(coverage) {
+            match c {
+                0 => pixel.fill(0),
+                255 => {}
+                _ => pixel[3] = ((pixel[3] as u32 * c as u32 + 127) / 255) as u8,
+            }
+        }
+    }
+
+    Ok(output)
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn apply_with(
+    exec: Exec,
+    image_data: &[u8],
+    orig_width: u32,
+    orig_height: u32,
+    x: u32,
+    y: u32,
+    crop_width: u32,
+    crop_height: u32,
 ) -> Result<Vec<u8>, String> {
     // Validate input dimensions
     let expected_len = (orig_width * orig_height * 4) as usize;
@@ -30,15 +86,49 @@ pub fn apply(
         ));
     }
 
-    // Validate crop area is within bounds
-    if x + crop_width > orig_width {
+    validate_area(orig_width, orig_height, x, y, crop_width, crop_height)?;
+
+    let orig_width = orig_width as usize;
+    let crop_width = crop_width as usize;
+    let crop_height = crop_height as usize;
+    let x = x as usize;
+    let y = y as usize;
+
+    // Allocate output buffer
+    let mut output = vec![0u8; crop_width * crop_height * 4];
+
+    // Copy pixels row by row (cache-efficient)
+    exec.rows(&mut output, crop_width * 4, |row, dst| {
+        let src_start = ((y + row) * orig_width + x) * 4;
+        let src_end = src_start + crop_width * 4;
+
+        dst.copy_from_slice(&image_data[src_start..src_end]);
+    });
+
+    Ok(output)
+}
+
+/// Check that a rectangle is non-empty and lies within an image
+///
+/// Shared by every function that takes a region of interest, so they all
+/// accept exactly the rectangles `apply` accepts.
+pub fn validate_area(
+    orig_width: u32,
+    orig_height: u32,
+    x: u32,
+    y: u32,
+    crop_width: u32,
+    crop_height: u32,
+) -> Result<(), String> {
+    // Validate crop area is within bounds (the sums can overflow for values from JS)
+    if x.checked_add(crop_width).is_none_or(|right| right > orig_width) {
         return Err(format!(
             "Crop area exceeds image width: x({}) + width({}) > {}",
             x, crop_width, orig_width
         ));
     }
 
-    if y + crop_height > orig_height {
+    if y.checked_add(crop_height).is_none_or(|bottom| bottom > orig_height) {
         return Err(format!(
             "Crop area exceeds image height: y({}) + height({}) > {}",
             y, crop_height, orig_height
@@ -53,26 +143,7 @@ pub fn apply(
         ));
     }
 
-    let orig_width = orig_width as usize;
-    let crop_width = crop_width as usize;
-    let crop_height = crop_height as usize;
-    let x = x as usize;
-    let y = y as usize;
-
-    // Allocate output buffer
-    let mut output = vec![0u8; crop_width * crop_height * 4];
-
-    // Copy pixels row by row (cache-efficient)
-    for row in 0..crop_height {
-        let src_start = ((y + row) * orig_width + x) * 4;
-        let src_end = src_start + crop_width * 4;
-        let dst_start = row * crop_width * 4;
-        let dst_end = dst_start + crop_width * 4;
-
-        output[dst_start..dst_end].copy_from_slice(&image_data[src_start..src_end]);
-    }
-
-    Ok(output)
+    Ok(())
 }
 
 #[cfg(test)]
@@ -152,6 +223,16 @@ mod tests {
         assert!(result.unwrap_err().contains("exceeds image height"));
     }
 
+    #[test]
+    fn test_crop_area_overflow() {
+        // x + width and y + height wrap around to 0 in u32
+        let data = vec![255, 0, 0, 255];
+        let result = apply(&data, 1, 1, 1, 0, u32::MAX, 1);
+        assert!(result.unwrap_err().contains("exceeds image width"));
+        let result = apply(&data, 1, 1, 0, 1, 1, u32::MAX);
+        assert!(result.unwrap_err().contains("exceeds image height"));
+    }
+
     #[test]
     fn test_crop_zero_dimensions() {
         let data = vec![255, 0, 0, 255];
@@ -160,6 +241,35 @@ mod tests {
         assert!(result.unwrap_err().contains("must be non-zero"));
     }
 
+    #[test]
+    fn test_crop_masked() {
+        use crate::filters::tiled::Rect;
+
+        let data = crate::filters::test_image(8, 6);
+        let plain = apply(&data, 8, 6, 2, 1, 4, 3).unwrap();
+        assert_eq!(apply_masked(&data, 8, 6, 2, 1, 4, 3, None).unwrap(), plain);
+
+        // Selection covers the left half of the crop, plus one half-selected pixel
+        let mut mask = Mask::rect(8, 6, Rect { x: 0, y: 0, width: 4, height: 6 }).into_data();
+        mask[8 + 4] = 128;
+        let mask = Mask::from_data(8, 6, mask).unwrap();
+        let result = apply_masked(&data, 8, 6, 2, 1, 4, 3, Some(&mask)).unwrap();
+
+        for (i, (out, src)) in result.chunks_exact(4).zip(plain.chunks_exact(4)).enumerate() {
+            let (col, row) = (i % 4, i / 4);
+            match (col, row) {
+                (0 | 1, _) => assert_eq!(out, src),
+                (2, 0) => {
+                    assert_eq!(out[..3], src[..3]);
+                    assert_eq!(out[3], ((src[3] as u32 * 128 + 127) / 255) as u8);
+                }
+                _ => assert_eq!(out, [0, 0, 0, 0]),
+            }
+        }
+
+        assert!(apply_masked(&data, 8, 6, 2, 1, 4, 3, Some(&Mask::full(6, 8))).is_err());
+    }
+
     #[test]
     fn test_invalid_data_length() {
         let data = vec![255, 0, 0]; // Only 3 bytes instead of 4
@@ -167,4 +277,13 @@ mod tests {
         assert!(result.is_err());
         assert!(result.unwrap_err().contains("Invalid image data length"));
     }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_parallel_matches_serial() {
+        let data = crate::filters::test_image(67, 45);
+        let serial = apply_with(Exec::Serial, &data, 67, 45, 5, 7, 50, 31).unwrap();
+        let parallel = apply_with(Exec::Parallel, &data, 67, 45, 5, 7, 50, 31).unwrap();
+        assert_eq!(serial, parallel);
+    }
 }
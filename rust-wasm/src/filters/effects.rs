@@ -0,0 +1,309 @@
+//! Creative effects: vignette and film grain
+//!
+//! Both are functions of the pixel position only, never of the order pixels
+//! are visited in, so tiled, parallel and serial runs give identical bytes.
+//! Grain comes from a hash of the lattice position and a seed rather than a
+//! random number generator: the same seed always gives the same grain.
+
+use super::exec::Exec;
+
+/// Darkening or lightening towards the edges of the frame
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Vignette {
+    /// -1.0 (black edges) to 1.0 (white edges), 0.0 = off
+    pub amount: f32,
+    /// 0.0 to 1.0: where the falloff is centred, from the middle of the image
+    /// (0.0) to its corners (1.0)
+    pub midpoint: f32,
+    /// -1.0 (rounded rectangle) to 1.0 (circle); 0.0 follows the image's aspect ratio
+    pub roundness: f32,
+    /// 0.0 (hard edge) to 1.0 (widest falloff)
+    pub feather: f32,
+    /// 0.0 to 1.0: how much bright pixels are spared from darkening
+    pub highlights: f32,
+}
+
+impl Default for Vignette {
+    fn default() -> Self {
+        Vignette { amount: 0.0, midpoint: 0.5, roundness: 0.0, feather: 0.5, highlights: 0.0 }
+    }
+}
+
+/// Monochrome film grain
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Grain {
+    /// 0.0 (off) to 1.0
+    pub amount: f32,
+    /// Grain size in pixels, 1.0 = one grain per pixel
+    pub size: f32,
+    /// 0.0 (soft clumps) to 1.0 (adds detail at half the size)
+    pub roughness: f32,
+    pub seed: u32,
+}
+
+impl Default for Grain {
+    fn default() -> Self {
+        Grain { amount: 0.0, size: 1.0, roughness: 0.5, seed: 0 }
+    }
+}
+
+/// Largest change grain makes to a channel, at amount 1.0
+const GRAIN_STRENGTH: f32 = 64.0;
+
+pub fn vignette(image_data: &[u8], width: u32, height: u32, settings: &Vignette) -> Result<Vec<u8>, String> {
+    vignette_with(Exec::preferred(), image_data, width, height, settings)
+}
+
+pub(crate) fn vignette_with(
+    exec: Exec,
+    image_data: &[u8],
+    width: u32,
+    height: u32,
+    settings: &Vignette,
+) -> Result<Vec<u8>, String> {
+    validate(image_data, width, height)?;
+
+    let amount = settings.amount.clamp(-1.0, 1.0);
+    let roundness = settings.roundness.clamp(-1.0, 1.0);
+    let feather = settings.feather.clamp(0.0, 1.0);
+    let highlights = settings.highlights.clamp(0.0, 1.0);
+
+    // Normalized coordinates: the image edges sit at +-1, its corners at
+    // distance sqrt(2) for every roundness
+    let (half_w, half_h) = (width as f32 / 2.0, height as f32 / 2.0);
+    let half_diagonal = (half_w * half_w + half_h * half_h).sqrt() / std::f32::consts::SQRT_2;
+    let circle = roundness.max(0.0);
+    let (scale_x, scale_y) = (lerp(1.0 / half_w, 1.0 / half_diagonal, circle), lerp(1.0 / half_h, 1.0 / half_diagonal, circle));
+    // Superellipse exponent: 2 is an ellipse, higher is squarer
+    let exponent = 2.0 - 6.0 * roundness.min(0.0);
+
+    let middle = settings.midpoint.clamp(0.0, 1.0) * std::f32::consts::SQRT_2;
+    let spread = 0.01 + 0.7 * feather;
+    let (inner, outer) = (middle - spread, middle + spread);
+
+    let row_len = width as usize * 4;
+    let mut output = image_data.to_vec();
+    exec.rows(&mut output, row_len, |y, row| {
+        let dy = ((y as f32 + 0.5 - half_h) * scale_y).abs();
+        for (x, px) in row.chunks_exact_mut(4).enumerate() {
+            let dx = ((x as f32 + 0.5 - half_w) * scale_x).abs();
+            let distance = if exponent == 2.0 {
+                (dx * dx + dy * dy).sqrt()
+            } else {
+                (dx.powf(exponent) + dy.powf(exponent)).powf(1.0 / exponent)
+            };
+
+            let weight = smoothstep(inner, outer, distance) * amount;
+            if weight < 0.0 {
+                // Darken; with highlight protection bright pixels keep more of their light
+                let luma = (0.299 * px[0] as f32 + 0.587 * px[1] as f32 + 0.114 * px[2] as f32) / 255.0;
+                let factor = 1.0 + weight * (1.0 - highlights * smoothstep(0.5, 1.0, luma));
+                for c in &mut px[..3] {
+                    *c = (*c as f32 * factor).round() as u8;
+                }
+            } else if weight > 0.0 {
+                for c in &mut px[..3] {
+                    *c = (*c as f32 + (255.0 - *c as f32) * weight).round() as u8;
+                }
+            }
+        }
+    });
+
+    Ok(output)
+}
+
+pub fn grain(image_data: &[u8], width: u32, height: u32, settings: &Grain) -> Result<Vec<u8>, String> {
+    grain_with(Exec::preferred(), image_data, width, height, settings)
+}
+
+pub(crate) fn grain_with(exec: Exec, image_data: &[u8], width: u32, height: u32, settings: &Grain) -> Result<Vec<u8>, String> {
+    validate(image_data, width, height)?;
+
+    let size = if settings.size > 0.0 { settings.size } else { 1.0 };
+    let roughness = settings.roughness.clamp(0.0, 1.0);
+    // Grains smaller than a pixel average out; a preview of large image sees less of them
+    let strength = settings.amount.clamp(0.0, 1.0) * GRAIN_STRENGTH * size.min(1.0);
+
+    let row_len = width as usize * 4;
+    let mut output = image_data.to_vec();
+    exec.rows(&mut output, row_len, |y, row| {
+        // Pixel corners, so that at size 1 every pixel gets its own lattice value
+        let fy = y as f32;
+        for (x, px) in row.chunks_exact_mut(4).enumerate() {
+            let fx = x as f32;
+            let coarse = value_noise(fx / size, fy / size, settings.seed);
+            let fine = value_noise(2.0 * fx / size, 2.0 * fy / size, settings.seed ^ 0x9e37_79b9);
+            let noise = lerp(coarse, fine, roughness) * strength;
+
+            // Same offset on every channel: grain in luminance, not color
+            for c in &mut px[..3] {
+                *c = (*c as f32 + noise).round().clamp(0.0, 255.0) as u8;
+            }
+        }
+    });
+
+    Ok(output)
+}
+
+/// Smoothly interpolated lattice noise in -1.0..=1.0 at (`x`, `y`) in lattice units
+fn value_noise(x: f32, y: f32, seed: u32) -> f32 {
+    let (x0, y0) = (x.floor(), y.floor());
+    let (tx, ty) = (smooth(x - x0), smooth(y - y0));
+    let (ix, iy) = (x0 as i32, y0 as i32);
+
+    let top = lerp(lattice(ix, iy, seed), lattice(ix + 1, iy, seed), tx);
+    let bottom = lerp(lattice(ix, iy + 1, seed), lattice(ix + 1, iy + 1, seed), tx);
+    lerp(top, bottom, ty)
+}
+
+/// Pseudo-random value in -1.0..=1.0 for a lattice point
+fn lattice(x: i32, y: i32, seed: u32) -> f32 {
+    // Integer hash (lowbias32 finalizer) of the combined coordinates
+    let mut h = (x as u32).wrapping_mul(0x8da6_b343) ^ (y as u32).wrapping_mul(0xd816_3841) ^ seed.wrapping_mul(0xcb1a_b31f);
+    h ^= h >> 16;
+    h = h.wrapping_mul(0x7feb_352d);
+    h ^= h >> 15;
+    h = h.wrapping_mul(0x846c_a68b);
+    h ^= h >> 16;
+
+    h as f32 / u32::MAX as f32 * 2.0 - 1.0
+}
+
+fn smooth(t: f32) -> f32 {
+    t * t * (3.0 - 2.0 * t)
+}
+
+fn smoothstep(edge0: f32, edge1: f32, x: f32) -> f32 {
+    smooth(((x - edge0) / (edge1 - edge0)).clamp(0.0, 1.0))
+}
+
+fn lerp(a: f32, b: f32, t: f32) -> f32 {
+    a + (b - a) * t
+}
+
+fn validate(image_data: &[u8], width: u32, height: u32) -> Result<(), String> {
+    let expected_len = (width * height * 4) as usize;
+    if image_data.len() != expected_len {
+        return Err(format!(
+            "Invalid image data length: expected {}, got {}",
+            expected_len,
+            image_data.len()
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::filters::test_image;
+
+    fn gray(width: u32, height: u32, value: u8) -> Vec<u8> {
+        [value, value, value, 255].repeat((width * height) as usize)
+    }
+
+    fn at(data: &[u8], width: u32, x: u32, y: u32) -> u8 {
+        data[((y * width + x) * 4) as usize]
+    }
+
+    #[test]
+    fn test_default_settings_leave_the_image_unchanged() {
+        let data = test_image(31, 17);
+        assert_eq!(vignette(&data, 31, 17, &Vignette::default()).unwrap(), data);
+        assert_eq!(grain(&data, 31, 17, &Grain::default()).unwrap(), data);
+        assert!(vignette(&data, 30, 17, &Vignette::default()).is_err());
+    }
+
+    #[test]
+    fn test_vignette_darkens_the_corners() {
+        let data = gray(60, 40, 200);
+        let result = vignette(&data, 60, 40, &Vignette { amount: -0.8, ..Vignette::default() }).unwrap();
+
+        assert_eq!(at(&result, 60, 30, 20), 200);
+        assert!(at(&result, 60, 0, 0) < 70, "corner {}", at(&result, 60, 0, 0));
+        // Darker towards the corner along the diagonal, alpha untouched
+        let diagonal: Vec<u8> = (0..20).map(|i| at(&result, 60, 30 - i * 3 / 2, 20 - i)).collect();
+        assert!(diagonal.windows(2).all(|w| w[0] >= w[1]));
+        assert!(result.chunks_exact(4).all(|px| px[3] == 255));
+
+        let light = vignette(&data, 60, 40, &Vignette { amount: 0.8, ..Vignette::default() }).unwrap();
+        assert!(at(&light, 60, 0, 0) > 240);
+    }
+
+    #[test]
+    fn test_vignette_shape() {
+        let data = gray(80, 40, 200);
+        let settings = Vignette { amount: -1.0, midpoint: 0.3, feather: 0.2, ..Vignette::default() };
+
+        // Following the aspect ratio, the edge midpoints are darkened alike
+        let ellipse = vignette(&data, 80, 40, &settings).unwrap();
+        assert!((at(&ellipse, 80, 0, 20) as i32 - at(&ellipse, 80, 40, 0) as i32).abs() <= 2);
+
+        // A circle reaches further along the long axis than the short one
+        let circle = vignette(&data, 80, 40, &Vignette { roundness: 1.0, ..settings }).unwrap();
+        assert!(at(&circle, 80, 10, 20) < at(&circle, 80, 40, 10));
+
+        // Squarer shapes darken less of the area between edge and corner
+        let square = vignette(&data, 80, 40, &Vignette { roundness: -1.0, ..settings }).unwrap();
+        let sum = |data: &[u8]| data.chunks_exact(4).map(|px| px[0] as u32).sum::<u32>();
+        assert!(sum(&square) > sum(&ellipse));
+    }
+
+    #[test]
+    fn test_vignette_protects_highlights() {
+        let mut data = gray(40, 40, 250);
+        data[..4].copy_from_slice(&[60, 60, 60, 255]);
+        let settings = Vignette { amount: -1.0, highlights: 1.0, ..Vignette::default() };
+        let result = vignette(&data, 40, 40, &settings).unwrap();
+
+        assert!(at(&result, 40, 39, 39) > 200);
+        assert!(at(&result, 40, 0, 0) < 20);
+    }
+
+    #[test]
+    fn test_grain_is_seeded() {
+        let data = gray(64, 64, 128);
+        let settings = Grain { amount: 0.5, seed: 7, ..Grain::default() };
+
+        let first = grain(&data, 64, 64, &settings).unwrap();
+        assert_eq!(grain(&data, 64, 64, &settings).unwrap(), first);
+        assert_ne!(grain(&data, 64, 64, &Grain { seed: 8, ..settings }).unwrap(), first);
+
+        // Monochrome, centred on the original value
+        assert!(first.chunks_exact(4).all(|px| px[0] == px[1] && px[1] == px[2] && px[3] == 255));
+        let mean = first.chunks_exact(4).map(|px| px[0] as f32).sum::<f32>() / 4096.0;
+        assert!((mean - 128.0).abs() < 2.0, "mean {}", mean);
+    }
+
+    #[test]
+    fn test_grain_size_and_roughness() {
+        let data = gray(128, 128, 128);
+        // Mean absolute difference between horizontal neighbours
+        let roughness = |settings: Grain| {
+            let result = grain(&data, 128, 128, &settings).unwrap();
+            let pixels: Vec<i32> = result.chunks_exact(4).map(|px| px[0] as i32).collect();
+            pixels.windows(2).map(|w| (w[0] - w[1]).abs()).sum::<i32>() as f32 / pixels.len() as f32
+        };
+
+        let base = Grain { amount: 1.0, size: 1.0, roughness: 0.0, seed: 1 };
+        assert!(roughness(Grain { size: 4.0, ..base }) < roughness(base) / 2.0);
+        assert!(roughness(Grain { size: 4.0, roughness: 1.0, ..base }) > roughness(Grain { size: 4.0, ..base }));
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_parallel_matches_serial() {
+        let data = test_image(67, 45);
+        let settings = Vignette { amount: -0.6, roundness: -0.5, highlights: 0.5, ..Vignette::default() };
+        assert_eq!(
+            vignette_with(Exec::Serial, &data, 67, 45, &settings).unwrap(),
+            vignette_with(Exec::Parallel, &data, 67, 45, &settings).unwrap()
+        );
+
+        let settings = Grain { amount: 0.7, size: 2.5, roughness: 0.3, seed: 42 };
+        assert_eq!(
+            grain_with(Exec::Serial, &data, 67, 45, &settings).unwrap(),
+            grain_with(Exec::Parallel, &data, 67, 45, &settings).unwrap()
+        );
+    }
+}
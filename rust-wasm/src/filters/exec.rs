@@ -0,0 +1,183 @@
+//! Row scheduling shared by the filters
+//!
+//! Filters write their output row by row through [`Exec`], so the same loop
+//! body runs either on the calling thread or across the rayon thread pool
+//! (`parallel` feature). Both strategies produce byte-identical output.
+
+use super::progress::{Cancelled, Progress};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// How a filter walks over its output rows
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Exec {
+    /// One row after another on the calling thread
+    #[cfg_attr(feature = "parallel", allow(dead_code))]
+    Serial,
+    /// Rows split across the rayon thread pool
+    #[cfg(feature = "parallel")]
+    Parallel,
+}
+
+impl Exec {
+    /// Parallel when built with the `parallel` feature, serial otherwise
+    pub fn preferred() -> Self {
+        #[cfg(feature = "parallel")]
+        {
+            Exec::Parallel
+        }
+        #[cfg(not(feature = "parallel"))]
+        {
+            Exec::Serial
+        }
+    }
+
+    /// Call `f(y, row)` for every `row_len`-byte row of `output`
+    pub fn rows<F>(self, output: &mut [u8], row_len: usize, f: F)
+    where
+        F: Fn(usize, &mut [u8]) + Sync + Send,
+    {
+        if row_len == 0 {
+            return;
+        }
+
+        match self {
+            Exec::Serial => output.chunks_mut(row_len).enumerate().for_each(|(y, row)| f(y, row)),
+            #[cfg(feature = "parallel")]
+            Exec::Parallel => output.par_chunks_mut(row_len).enumerate().for_each(|(y, row)| f(y, row)),
+        }
+    }
+
+    /// Call `f(first_y, band)` for contiguous bands of whole rows
+    ///
+    /// For passes that carry state from one row to the next (e.g. a vertical
+    /// sliding window), where each band has to be primed independently.
+    /// Serial execution uses a single band covering the whole image.
+    pub fn bands<F>(self, output: &mut [u8], row_len: usize, f: F)
+    where
+        F: Fn(usize, &mut [u8]) + Sync + Send,
+    {
+        if row_len == 0 || output.is_empty() {
+            return;
+        }
+
+        match self {
+            Exec::Serial => f(0, output),
+            #[cfg(feature = "parallel")]
+            Exec::Parallel => {
+                // A few bands per thread keeps workers busy without paying
+                // the priming cost too often
+                let height = output.len() / row_len;
+                let bands = rayon::current_num_threads() * 4;
+                let rows_per_band = height.div_ceil(bands).max(1);
+
+                output
+                    .par_chunks_mut(rows_per_band * row_len)
+                    .enumerate()
+                    .for_each(|(i, band)| f(i * rows_per_band, band));
+            }
+        }
+    }
+
+    /// [`Exec::rows`] in chunks of `progress.chunk_rows()` rows, advancing
+    /// `progress` after each chunk
+    pub fn rows_tracked<F>(self, output: &mut [u8], row_len: usize, progress: &mut Progress, f: F) -> Result<(), Cancelled>
+    where
+        F: Fn(usize, &mut [u8]) + Sync + Send,
+    {
+        self.chunked(output, row_len, progress, |first_y, chunk| {
+            self.rows(chunk, row_len, |y, row| f(first_y + y, row));
+        })
+    }
+
+    /// [`Exec::bands`] in chunks of `progress.chunk_rows()` rows, advancing
+    /// `progress` after each chunk; every chunk is primed as its own band(s)
+    pub fn bands_tracked<F>(self, output: &mut [u8], row_len: usize, progress: &mut Progress, f: F) -> Result<(), Cancelled>
+    where
+        F: Fn(usize, &mut [u8]) + Sync + Send,
+    {
+        self.chunked(output, row_len, progress, |first_y, chunk| {
+            self.bands(chunk, row_len, |y, band| f(first_y + y, band));
+        })
+    }
+
+    fn chunked<G>(self, output: &mut [u8], row_len: usize, progress: &mut Progress, mut g: G) -> Result<(), Cancelled>
+    where
+        G: FnMut(usize, &mut [u8]),
+    {
+        if row_len == 0 {
+            return Ok(());
+        }
+
+        let chunk_rows = progress.chunk_rows();
+        for (i, chunk) in output.chunks_mut(chunk_rows.saturating_mul(row_len)).enumerate() {
+            g(i * chunk_rows, chunk);
+            progress.advance(chunk.len() / row_len)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rows_visits_every_row() {
+        let mut data = vec![0u8; 3 * 8];
+        Exec::Serial.rows(&mut data, 8, |y, row| row.fill(y as u8 + 1));
+        assert_eq!(&data[0..8], &[1; 8]);
+        assert_eq!(&data[16..24], &[3; 8]);
+    }
+
+    #[test]
+    fn test_bands_serial_is_single_band() {
+        let mut data = vec![0u8; 3 * 8];
+        Exec::Serial.bands(&mut data, 8, |y, band| {
+            assert_eq!(y, 0);
+            assert_eq!(band.len(), 24);
+        });
+    }
+
+    #[test]
+    fn test_empty_rows() {
+        let mut data: Vec<u8> = Vec::new();
+        Exec::preferred().rows(&mut data, 0, |_, _| panic!("no rows"));
+        Exec::preferred().bands(&mut data, 0, |_, _| panic!("no bands"));
+    }
+
+    #[test]
+    fn test_tracked_rows_in_chunks() {
+        let mut data = vec![0u8; 5 * 4];
+        let mut chunks = Vec::new();
+        let mut progress = Progress::new(2).on_progress(|done, _| chunks.push(done));
+        progress.begin(5).unwrap();
+
+        Exec::preferred()
+            .bands_tracked(&mut data, 4, &mut progress, |y0, band| {
+                for (i, row) in band.chunks_mut(4).enumerate() {
+                    row.fill((y0 + i) as u8);
+                }
+            })
+            .unwrap();
+        drop(progress);
+
+        assert_eq!(chunks, vec![2, 4, 5]);
+        assert_eq!(data.chunks(4).map(|row| row[0]).collect::<Vec<_>>(), vec![0, 1, 2, 3, 4]);
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_parallel_bands_cover_all_rows() {
+        let mut data = vec![0u8; 1000 * 4];
+        Exec::Parallel.bands(&mut data, 4, |y0, band| {
+            for (i, row) in band.chunks_mut(4).enumerate() {
+                row.copy_from_slice(&((y0 + i) as u32).to_le_bytes());
+            }
+        });
+        for (y, row) in data.chunks(4).enumerate() {
+            assert_eq!(u32::from_le_bytes(row.try_into().unwrap()), y as u32);
+        }
+    }
+}
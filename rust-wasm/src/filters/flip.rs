@@ -1,5 +1,20 @@
+use super::exec::Exec;
+use super::mask::{self, Mask};
+
 /// Apply horizontal flip (mirror left-right) to image data
 pub fn apply_horizontal(image_data: &[u8], width: u32, height: u32) -> Result<Vec<u8>, String> {
+    apply_horizontal_with(Exec::preferred(), image_data, width, height)
+}
+
+/// Mirror the selection left-right within its bounding box; the whole image without a mask
+pub fn apply_horizontal_masked(image_data: &[u8], width: u32, height: u32, mask: Option<&Mask>) -> Result<Vec<u8>, String> {
+    match mask {
+        Some(mask) => mask::transform_selection(image_data, width, height, mask, apply_horizontal),
+        None => apply_horizontal(image_data, width, height),
+    }
+}
+
+pub(crate) fn apply_horizontal_with(exec: Exec, image_data: &[u8], width: u32, height: u32) -> Result<Vec<u8>, String> {
     // Validate input
     let expected_len = (width * height * 4) as usize;
     if image_data.len() != expected_len {
@@ -10,26 +25,32 @@ pub fn apply_horizontal(image_data: &[u8], width: u32, height: u32) -> Result<Ve
         ));
     }
 
-    let width = width as usize;
-    let height = height as usize;
+    let row_len = width as usize * 4;
     let mut output = vec![0u8; image_data.len()];
 
-    // Flip each row horizontally
-    for y in 0..height {
-        for x in 0..width {
-            let src_idx = (y * width + x) * 4;
-            let dst_idx = (y * width + (width - 1 - x)) * 4;
-
-            // Copy RGBA pixel
-            output[dst_idx..dst_idx + 4].copy_from_slice(&image_data[src_idx..src_idx + 4]);
-        }
-    }
+    // Copy each row whole, then reverse its pixel order
+    exec.rows(&mut output, row_len, |y, row| {
+        row.copy_from_slice(&image_data[y * row_len..(y + 1) * row_len]);
+        row.as_chunks_mut::<4>().0.reverse();
+    });
 
     Ok(output)
 }
 
 /// Apply vertical flip (mirror top-bottom) to image data
 pub fn apply_vertical(image_data: &[u8], width: u32, height: u32) -> Result<Vec<u8>, String> {
+    apply_vertical_with(Exec::preferred(), image_data, width, height)
+}
+
+/// Mirror the selection top-bottom within its bounding box; the whole image without a mask
+pub fn apply_vertical_masked(image_data: &[u8], width: u32, height: u32, mask: Option<&Mask>) -> Result<Vec<u8>, String> {
+    match mask {
+        Some(mask) => mask::transform_selection(image_data, width, height, mask, apply_vertical),
+        None => apply_vertical(image_data, width, height),
+    }
+}
+
+pub(crate) fn apply_vertical_with(exec: Exec, image_data: &[u8], width: u32, height: u32) -> Result<Vec<u8>, String> {
     // Validate input
     let expected_len = (width * height * 4) as usize;
     if image_data.len() != expected_len {
@@ -40,22 +61,65 @@ pub fn apply_vertical(image_data: &[u8], width: u32, height: u32) -> Result<Vec<
         ));
     }
 
-    let width = width as usize;
+    let row_len = width as usize * 4;
     let height = height as usize;
     let mut output = vec![0u8; image_data.len()];
 
-    // Flip each column vertically
-    for y in 0..height {
-        for x in 0..width {
-            let src_idx = (y * width + x) * 4;
-            let dst_idx = ((height - 1 - y) * width + x) * 4;
+    // Each output row is a source row counted from the bottom
+    exec.rows(&mut output, row_len, |y, row| {
+        let src_start = (height - 1 - y) * row_len;
+        row.copy_from_slice(&image_data[src_start..src_start + row_len]);
+    });
 
-            // Copy RGBA pixel
-            output[dst_idx..dst_idx + 4].copy_from_slice(&image_data[src_idx..src_idx + 4]);
-        }
+    Ok(output)
+}
+
+/// Mirror left-right in place, without allocating an output buffer
+pub fn apply_horizontal_in_place(image_data: &mut [u8], width: u32, height: u32) -> Result<(), String> {
+    // Validate input
+    let expected_len = (width * height * 4) as usize;
+    if image_data.len() != expected_len {
+        return Err(format!(
+            "Invalid image data length: expected {}, got {}",
+            expected_len,
+            image_data.len()
+        ));
     }
 
-    Ok(output)
+    // Reverse the pixel order of each row
+    Exec::preferred().rows(image_data, width as usize * 4, |_, row| {
+        row.as_chunks_mut::<4>().0.reverse();
+    });
+
+    Ok(())
+}
+
+/// Mirror top-bottom in place, without allocating an output buffer
+pub fn apply_vertical_in_place(image_data: &mut [u8], width: u32, height: u32) -> Result<(), String> {
+    // Validate input
+    let expected_len = (width * height * 4) as usize;
+    if image_data.len() != expected_len {
+        return Err(format!(
+            "Invalid image data length: expected {}, got {}",
+            expected_len,
+            image_data.len()
+        ));
+    }
+
+    let row_len = width as usize * 4;
+    let half = height as usize / 2 * row_len;
+
+    // Swap each top row with its mirror in the bottom half
+    // (an odd middle row stays where it is)
+    let (top, rest) = image_data.split_at_mut(half);
+    let middle = rest.len() - half;
+    let bottom = &mut rest[middle..];
+
+    for (top_row, bottom_row) in top.chunks_exact_mut(row_len).zip(bottom.chunks_exact_mut(row_len).rev()) {
+        top_row.swap_with_slice(bottom_row);
+    }
+
+    Ok(())
 }
 
 #[cfg(test)]
@@ -130,4 +194,40 @@ mod tests {
         let result = apply_vertical(&data, 2, 2); // Wrong dimensions
         assert!(result.is_err());
     }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_parallel_matches_serial() {
+        let data = crate::filters::test_image(67, 45);
+
+        let serial = apply_horizontal_with(Exec::Serial, &data, 67, 45).unwrap();
+        let parallel = apply_horizontal_with(Exec::Parallel, &data, 67, 45).unwrap();
+        assert_eq!(serial, parallel);
+
+        let serial = apply_vertical_with(Exec::Serial, &data, 67, 45).unwrap();
+        let parallel = apply_vertical_with(Exec::Parallel, &data, 67, 45).unwrap();
+        assert_eq!(serial, parallel);
+    }
+
+    #[test]
+    fn test_in_place_matches_copying() {
+        for (width, height) in [(1, 1), (2, 1), (1, 2), (3, 3), (67, 45), (8, 6)] {
+            let data = crate::filters::test_image(width, height);
+
+            let mut in_place = data.clone();
+            apply_horizontal_in_place(&mut in_place, width, height).unwrap();
+            assert_eq!(in_place, apply_horizontal(&data, width, height).unwrap());
+
+            let mut in_place = data.clone();
+            apply_vertical_in_place(&mut in_place, width, height).unwrap();
+            assert_eq!(in_place, apply_vertical(&data, width, height).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_in_place_invalid_dimensions() {
+        let mut data = vec![255, 0, 0, 255];
+        assert!(apply_horizontal_in_place(&mut data, 2, 2).is_err());
+        assert!(apply_vertical_in_place(&mut data, 2, 2).is_err());
+    }
 }
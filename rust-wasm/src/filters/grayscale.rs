@@ -1,18 +1,57 @@
-use photon_rs::PhotonImage;
-use photon_rs::monochrome::grayscale as photon_grayscale;
+use super::exec::Exec;
+use super::mask::{self, Mask};
 
 /// Apply grayscale filter to image data
-/// Optimized: Uses Vec::from to reduce overhead, in-place mutation
+/// Channel average (r + g + b) / 3, same as `photon_rs::monochrome::grayscale`
 pub fn apply(image_data: &[u8], width: u32, height: u32) -> Result<Vec<u8>, String> {
-    // Create PhotonImage - Vec::from is slightly more optimized than to_vec()
-    // PhotonImage requires ownership of the data for in-place mutation
-    let mut img = PhotonImage::new(Vec::from(image_data), width, height);
+    apply_with(Exec::preferred(), image_data, width, height)
+}
+
+/// [`apply`] only where `mask` selects, blending along soft edges
+pub fn apply_masked(image_data: &[u8], width: u32, height: u32, mask: Option<&Mask>) -> Result<Vec<u8>, String> {
+    mask::select(image_data, apply(image_data, width, height)?, width, height, mask)
+}
+
+pub(crate) fn apply_with(exec: Exec, image_data: &[u8], width: u32, height: u32) -> Result<Vec<u8>, String> {
+    // Validate input
+    let expected_len = (width * height * 4) as usize;
+    if image_data.len() != expected_len {
+        return Err(format!(
+            "Invalid image data length: expected {}, got {}",
+            expected_len,
+            image_data.len()
+        ));
+    }
+
+    let row_len = width as usize * 4;
+    let mut output = vec![0u8; image_data.len()];
 
-    // Apply grayscale filter (in-place mutation)
-    photon_grayscale(&mut img);
+    exec.rows(&mut output, row_len, |y, row| {
+        gray_row(&image_data[y * row_len..(y + 1) * row_len], row);
+    });
 
-    // Return raw bytes (moves ownership, no copy)
-    Ok(img.get_raw_pixels())
+    Ok(output)
+}
+
+/// Convert one row, using the SIMD kernel where available
+fn gray_row(src: &[u8], dst: &mut [u8]) {
+    #[cfg(all(feature = "simd", target_arch = "wasm32", target_feature = "simd128"))]
+    let done = super::simd::grayscale(src, dst);
+    #[cfg(not(all(feature = "simd", target_arch = "wasm32", target_feature = "simd128")))]
+    let done = 0;
+
+    gray_pixels(&src[done..], &mut dst[done..]);
+}
+
+/// Scalar kernel; also handles the tail the SIMD kernel leaves over
+pub(crate) fn gray_pixels(src: &[u8], dst: &mut [u8]) {
+    for (dst, chunk) in dst.chunks_exact_mut(4).zip(src.chunks_exact(4)) {
+        let avg = ((chunk[0] as u32 + chunk[1] as u32 + chunk[2] as u32) / 3) as u8;
+        dst[0] = avg;
+        dst[1] = avg;
+        dst[2] = avg;
+        dst[3] = chunk[3]; // Alpha channel unchanged
+    }
 }
 
 #[cfg(test)]
@@ -33,4 +72,32 @@ mod tests {
         assert!(result.is_ok());
         assert_eq!(result.unwrap().len(), data.len());
     }
+
+    #[cfg(feature = "photon")]
+    #[test]
+    fn test_grayscale_matches_photon() {
+        use photon_rs::PhotonImage;
+        use photon_rs::monochrome::grayscale as photon_grayscale;
+
+        let data = crate::filters::test_image(67, 45);
+        let mut img = PhotonImage::new(data.clone(), 67, 45);
+        photon_grayscale(&mut img);
+
+        assert_eq!(apply(&data, 67, 45).unwrap(), img.get_raw_pixels());
+    }
+
+    #[test]
+    fn test_invalid_dimensions() {
+        let data = vec![255, 0, 0, 255];
+        assert!(apply(&data, 2, 2).is_err());
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_parallel_matches_serial() {
+        let data = crate::filters::test_image(67, 45);
+        let serial = apply_with(Exec::Serial, &data, 67, 45).unwrap();
+        let parallel = apply_with(Exec::Parallel, &data, 67, 45).unwrap();
+        assert_eq!(serial, parallel);
+    }
 }
@@ -0,0 +1,465 @@
+//! Color lookup tables from `.cube` files
+//!
+//! [`Lut::parse_cube`] reads the Adobe and Resolve `.cube` formats: a 1D
+//! table, a 3D table, or a 1D shaper followed by a 3D table. [`apply`] maps
+//! the sRGB values of every pixel through them, with trilinear or
+//! tetrahedral interpolation in the 3D cube, and mixes the result with the
+//! original by `strength`. Alpha is left alone.
+
+use super::exec::Exec;
+use super::progress::{Error, Progress};
+
+/// Largest table sizes accepted, as in the Adobe specification
+const MAX_1D_SIZE: usize = 65536;
+const MAX_3D_SIZE: usize = 256;
+
+/// How colors between the points of a 3D table are computed
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Interpolation {
+    /// Blend of the 8 corners of the surrounding cell
+    Trilinear,
+    /// Blend of the 4 corners of the tetrahedron around the color; keeps
+    /// grays on the neutral axis and is what grading tools use
+    #[default]
+    Tetrahedral,
+}
+
+impl Interpolation {
+    /// "trilinear" or "tetrahedral"
+    pub fn from_name(name: &str) -> Result<Self, String> {
+        match name {
+            "trilinear" => Ok(Interpolation::Trilinear),
+            "tetrahedral" => Ok(Interpolation::Tetrahedral),
+            _ => Err(format!("Unknown interpolation: {}", name)),
+        }
+    }
+}
+
+/// Input range of a table: values are mapped from `min..=max` onto its entries
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Domain {
+    min: [f32; 3],
+    max: [f32; 3],
+}
+
+impl Default for Domain {
+    fn default() -> Self {
+        Domain { min: [0.0; 3], max: [1.0; 3] }
+    }
+}
+
+impl Domain {
+    /// Position of `value` in channel `c`, 0.0 to 1.0
+    fn normalize(&self, value: f32, c: usize) -> f32 {
+        ((value - self.min[c]) / (self.max[c] - self.min[c])).clamp(0.0, 1.0)
+    }
+}
+
+/// Per-channel curves
+#[derive(Debug, Clone, PartialEq)]
+struct Table1d {
+    domain: Domain,
+    entries: Vec<[f32; 3]>,
+}
+
+/// Color cube; red varies fastest, then green, then blue
+#[derive(Debug, Clone, PartialEq)]
+struct Table3d {
+    domain: Domain,
+    size: usize,
+    entries: Vec<[f32; 3]>,
+}
+
+/// A parsed `.cube` file
+#[derive(Debug, Clone, PartialEq)]
+pub struct Lut {
+    title: Option<String>,
+    shaper: Option<Table1d>,
+    cube: Option<Table3d>,
+}
+
+impl Lut {
+    /// Parse the text of a `.cube` file
+    pub fn parse_cube(text: &str) -> Result<Self, String> {
+        let mut title = None;
+        let (mut size_1d, mut size_3d) = (None, None);
+        let mut domain = None;
+        let (mut range_1d, mut range_3d) = (None, None);
+        let mut entries = Vec::new();
+
+        for (number, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let error = |message: &str| format!("Line {}: {}", number + 1, message);
+
+            let (keyword, rest) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+            let rest = rest.trim();
+            match keyword {
+                "TITLE" => title = Some(rest.trim_matches('"').to_string()),
+                "LUT_1D_SIZE" => size_1d = Some(parse_size(rest, MAX_1D_SIZE).map_err(|e| error(&e))?),
+                "LUT_3D_SIZE" => size_3d = Some(parse_size(rest, MAX_3D_SIZE).map_err(|e| error(&e))?),
+                "DOMAIN_MIN" => domain.get_or_insert(Domain::default()).min = parse_triple(rest).map_err(|e| error(&e))?,
+                "DOMAIN_MAX" => domain.get_or_insert(Domain::default()).max = parse_triple(rest).map_err(|e| error(&e))?,
+                "LUT_1D_INPUT_RANGE" => range_1d = Some(parse_range(rest).map_err(|e| error(&e))?),
+                "LUT_3D_INPUT_RANGE" => range_3d = Some(parse_range(rest).map_err(|e| error(&e))?),
+                _ if keyword.starts_with(|c: char| c.is_ascii_digit() || c == '-' || c == '.') => {
+                    entries.push(parse_triple(line).map_err(|e| error(&e))?);
+                }
+                // Other keywords (e.g. LUT_IN_VIDEO_RANGE) do not change the mapping
+                _ => {}
+            }
+        }
+
+        let domain = domain.unwrap_or_default();
+        if domain.min.iter().zip(&domain.max).any(|(min, max)| min >= max) {
+            return Err("DOMAIN_MIN must be below DOMAIN_MAX".to_string());
+        }
+
+        // A 1D shaper comes first, then the cube
+        let len_1d = size_1d.unwrap_or(0);
+        let len_3d = size_3d.map_or(0, |n| n * n * n);
+        if size_1d.is_none() && size_3d.is_none() {
+            return Err("Missing LUT_1D_SIZE or LUT_3D_SIZE".to_string());
+        }
+        if entries.len() != len_1d + len_3d {
+            return Err(format!("Expected {} table entries, got {}", len_1d + len_3d, entries.len()));
+        }
+
+        let cube_entries = entries.split_off(len_1d);
+        let shaper = size_1d.map(|_| Table1d { domain: range_1d.unwrap_or(domain), entries });
+        let cube = size_3d.map(|size| Table3d { domain: range_3d.unwrap_or(domain), size, entries: cube_entries });
+
+        Ok(Lut { title, shaper, cube })
+    }
+
+    /// 3D table of `size` points per axis that maps every color to itself
+    pub fn identity(size: usize) -> Result<Self, String> {
+        Self::from_fn(size, |rgb| rgb)
+    }
+
+    /// 3D table of `size` points per axis sampled from `f`, which maps
+    /// normalized RGB to normalized RGB
+    pub fn from_fn(size: usize, f: impl Fn([f32; 3]) -> [f32; 3]) -> Result<Self, String> {
+        parse_size(&size.to_string(), MAX_3D_SIZE)?;
+
+        let step = 1.0 / (size - 1) as f32;
+        let entries = (0..size * size * size)
+            .map(|i| f([(i % size) as f32 * step, (i / size % size) as f32 * step, (i / (size * size)) as f32 * step]))
+            .collect();
+
+        Ok(Lut { title: None, shaper: None, cube: Some(Table3d { domain: Domain::default(), size, entries }) })
+    }
+
+    pub fn title(&self) -> Option<&str> {
+        self.title.as_deref()
+    }
+
+    /// Look up one color, channels normalized to 0.0..=1.0
+    pub fn map(&self, rgb: [f32; 3], interpolation: Interpolation) -> [f32; 3] {
+        let mut rgb = rgb;
+        if let Some(shaper) = &self.shaper {
+            rgb = shaper.map(rgb);
+        }
+        if let Some(cube) = &self.cube {
+            rgb = cube.map(rgb, interpolation);
+        }
+        rgb
+    }
+}
+
+impl Table1d {
+    fn map(&self, rgb: [f32; 3]) -> [f32; 3] {
+        let last = (self.entries.len() - 1) as f32;
+        let mut out = [0.0; 3];
+
+        for c in 0..3 {
+            let position = self.domain.normalize(rgb[c], c) * last;
+            let i = (position as usize).min(self.entries.len() - 2);
+            let t = position - i as f32;
+            out[c] = lerp(self.entries[i][c], self.entries[i + 1][c], t);
+        }
+
+        out
+    }
+}
+
+impl Table3d {
+    fn map(&self, rgb: [f32; 3], interpolation: Interpolation) -> [f32; 3] {
+        let n = self.size;
+        let last = (n - 1) as f32;
+
+        // Cell corner and position within the cell on each axis
+        let mut base = [0usize; 3];
+        let mut f = [0.0f32; 3];
+        for c in 0..3 {
+            let position = self.domain.normalize(rgb[c], c) * last;
+            base[c] = (position as usize).min(n - 2);
+            f[c] = position - base[c] as f32;
+        }
+
+        let at = |dr: usize, dg: usize, db: usize| self.entries[(base[0] + dr) + (base[1] + dg) * n + (base[2] + db) * n * n];
+        let (c000, c111) = (at(0, 0, 0), at(1, 1, 1));
+        let [fr, fg, fb] = f;
+
+        match interpolation {
+            Interpolation::Trilinear => {
+                let (c100, c010, c001) = (at(1, 0, 0), at(0, 1, 0), at(0, 0, 1));
+                let (c110, c101, c011) = (at(1, 1, 0), at(1, 0, 1), at(0, 1, 1));
+                std::array::from_fn(|c| {
+                    let c00 = lerp(c000[c], c100[c], fr);
+                    let c10 = lerp(c010[c], c110[c], fr);
+                    let c01 = lerp(c001[c], c101[c], fr);
+                    let c11 = lerp(c011[c], c111[c], fr);
+                    lerp(lerp(c00, c10, fg), lerp(c01, c11, fg), fb)
+                })
+            }
+            Interpolation::Tetrahedral => {
+                // The cell splits into six tetrahedra along its main diagonal;
+                // walk from c000 to c111 through the corners of the one holding the color
+                let (first, second, (t1, t2, t3)) = if fr > fg {
+                    if fg > fb {
+                        (at(1, 0, 0), at(1, 1, 0), (fr, fg, fb))
+                    } else if fr > fb {
+                        (at(1, 0, 0), at(1, 0, 1), (fr, fb, fg))
+                    } else {
+                        (at(0, 0, 1), at(1, 0, 1), (fb, fr, fg))
+                    }
+                } else if fb > fg {
+                    (at(0, 0, 1), at(0, 1, 1), (fb, fg, fr))
+                } else if fb > fr {
+                    (at(0, 1, 0), at(0, 1, 1), (fg, fb, fr))
+                } else {
+                    (at(0, 1, 0), at(1, 1, 0), (fg, fr, fb))
+                };
+
+                std::array::from_fn(|c| {
+                    c000[c] + t1 * (first[c] - c000[c]) + t2 * (second[c] - first[c]) + t3 * (c111[c] - second[c])
+                })
+            }
+        }
+    }
+}
+
+/// Map every pixel through `lut` and mix with the original by `strength`
+/// (0.0 = original, 1.0 = full look)
+pub fn apply(
+    image_data: &[u8],
+    width: u32,
+    height: u32,
+    lut: &Lut,
+    interpolation: Interpolation,
+    strength: f32,
+) -> Result<Vec<u8>, String> {
+    apply_with(Exec::preferred(), image_data, width, height, lut, interpolation, strength, &mut Progress::none())
+        .map_err(|e| e.to_string())
+}
+
+/// [`apply`] with progress reporting and cancellation; progress runs up to `height`
+pub fn apply_tracked(
+    image_data: &[u8],
+    width: u32,
+    height: u32,
+    lut: &Lut,
+    interpolation: Interpolation,
+    strength: f32,
+    progress: &mut Progress,
+) -> Result<Vec<u8>, Error> {
+    apply_with(Exec::preferred(), image_data, width, height, lut, interpolation, strength, progress)
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn apply_with(
+    exec: Exec,
+    image_data: &[u8],
+    width: u32,
+    height: u32,
+    lut: &Lut,
+    interpolation: Interpolation,
+    strength: f32,
+    progress: &mut Progress,
+) -> Result<Vec<u8>, Error> {
+    let expected_len = (width * height * 4) as usize;
+    if image_data.len() != expected_len {
+        return Err(Error::Invalid(format!(
+            "Invalid image data length: expected {}, got {}",
+            expected_len,
+            image_data.len()
+        )));
+    }
+
+    let strength = strength.clamp(0.0, 1.0);
+    let row_len = width as usize * 4;
+    let mut output = image_data.to_vec();
+
+    progress.begin(height as usize)?;
+    exec.rows_tracked(&mut output, row_len, progress, |_, row| {
+        for px in row.chunks_exact_mut(4) {
+            let rgb = [px[0] as f32 / 255.0, px[1] as f32 / 255.0, px[2] as f32 / 255.0];
+            let mapped = lut.map(rgb, interpolation);
+            for c in 0..3 {
+                let value = lerp(rgb[c], mapped[c].clamp(0.0, 1.0), strength);
+                px[c] = (value * 255.0).round() as u8;
+            }
+        }
+    })?;
+
+    Ok(output)
+}
+
+fn parse_size(value: &str, max: usize) -> Result<usize, String> {
+    match value.parse::<usize>() {
+        Ok(size) if (2..=max).contains(&size) => Ok(size),
+        _ => Err(format!("Table size must be 2 to {}, got {}", max, value)),
+    }
+}
+
+fn parse_triple(value: &str) -> Result<[f32; 3], String> {
+    let numbers = value
+        .split_whitespace()
+        .map(|part| part.parse::<f32>().map_err(|_| format!("Invalid number: {}", part)))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    match numbers[..] {
+        [r, g, b] => Ok([r, g, b]),
+        _ => Err(format!("Expected 3 numbers, got {}", numbers.len())),
+    }
+}
+
+/// `LUT_*_INPUT_RANGE min max`: the same range on every channel
+fn parse_range(value: &str) -> Result<Domain, String> {
+    let numbers = value
+        .split_whitespace()
+        .map(|part| part.parse::<f32>().map_err(|_| format!("Invalid number: {}", part)))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    match numbers[..] {
+        [min, max] if min < max => Ok(Domain { min: [min; 3], max: [max; 3] }),
+        _ => Err(format!("Expected an input range min max, got {}", value)),
+    }
+}
+
+fn lerp(a: f32, b: f32, t: f32) -> f32 {
+    a + (b - a) * t
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::filters::test_image;
+
+    const BOTH: [Interpolation; 2] = [Interpolation::Trilinear, Interpolation::Tetrahedral];
+
+    #[test]
+    fn test_identity_leaves_the_image_unchanged() {
+        let data = test_image(31, 17);
+        for size in [2, 17, 33] {
+            let lut = Lut::identity(size).unwrap();
+            for interpolation in BOTH {
+                assert_eq!(apply(&data, 31, 17, &lut, interpolation, 1.0).unwrap(), data);
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_cube() {
+        // Inverts red; written with red varying fastest
+        let text = "# Exported look\n\
+                    TITLE \"Red flip\"\n\
+                    LUT_3D_SIZE 2\n\
+                    DOMAIN_MIN 0 0 0\n\
+                    DOMAIN_MAX 1 1 1\n\
+                    \n\
+                    1 0 0\n0 0 0\n1 1 0\n0 1 0\n1 0 1\n0 0 1\n1 1 1\n0 1 1\n";
+        let lut = Lut::parse_cube(text).unwrap();
+        assert_eq!(lut.title(), Some("Red flip"));
+
+        let data = [255, 64, 0, 255, 0, 200, 128, 7];
+        for interpolation in BOTH {
+            let result = apply(&data, 2, 1, &lut, interpolation, 1.0).unwrap();
+            assert_eq!(result, [0, 64, 0, 255, 255, 200, 128, 7]);
+        }
+
+        // Half strength meets in the middle
+        let half = apply(&[255, 0, 0, 255], 1, 1, &lut, Interpolation::Tetrahedral, 0.5).unwrap();
+        assert_eq!(half, [128, 0, 0, 255]);
+        assert_eq!(apply(&data, 2, 1, &lut, Interpolation::Trilinear, 0.0).unwrap(), data);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(Lut::parse_cube("0 0 0\n1 1 1\n").is_err());
+        assert!(Lut::parse_cube("LUT_3D_SIZE 2\n0 0 0\n").is_err());
+        assert!(Lut::parse_cube("LUT_3D_SIZE 1\n0 0 0\n").is_err());
+        assert!(Lut::parse_cube("LUT_1D_SIZE 2\n0 0 0\n1 1 x\n").unwrap_err().starts_with("Line 3"));
+        assert!(Lut::parse_cube("LUT_1D_SIZE 2\nDOMAIN_MIN 1 0 0\n0 0 0\n1 1 1\n").is_err());
+        assert!(Interpolation::from_name("cubic").is_err());
+    }
+
+    #[test]
+    fn test_1d_and_shaper() {
+        // Squares each channel on a 0..2 input range
+        let curve = "LUT_1D_SIZE 3\nLUT_1D_INPUT_RANGE 0 2\n0 0 0\n0.25 0.25 0.25\n1 1 1\n";
+        let lut = Lut::parse_cube(curve).unwrap();
+        let result = apply(&[0, 255, 255, 255], 1, 1, &lut, Interpolation::Tetrahedral, 1.0).unwrap();
+        assert_eq!(result, [0, 64, 64, 255]);
+
+        // The same shaper followed by a cube swapping red and blue
+        let mut text = curve.replace("LUT_1D_INPUT_RANGE 0 2\n", "LUT_1D_INPUT_RANGE 0 2\nLUT_3D_SIZE 2\n");
+        for i in 0..8 {
+            text += &format!("{} {} {}\n", i >> 2 & 1, i >> 1 & 1, i & 1);
+        }
+        let lut = Lut::parse_cube(&text).unwrap();
+        let result = apply(&[255, 0, 0, 255], 1, 1, &lut, Interpolation::Trilinear, 1.0).unwrap();
+        assert_eq!(result, [0, 0, 64, 255]);
+    }
+
+    #[test]
+    fn test_interpolations() {
+        // A nonlinear look: both interpolations hit it exactly on lattice points
+        let look = |[r, g, b]: [f32; 3]| [r * r, (g + b) / 2.0, b.sqrt()];
+        let lut = Lut::from_fn(5, look).unwrap();
+        for interpolation in BOTH {
+            let mapped = lut.map([0.25, 0.5, 0.75], interpolation);
+            let expected = look([0.25, 0.5, 0.75]);
+            assert!(mapped.iter().zip(&expected).all(|(a, b)| (a - b).abs() < 1e-6));
+        }
+
+        // Between lattice points they differ, and tetrahedral keeps grays on the diagonal
+        let tilted = Lut::from_fn(2, |[r, g, b]| [r * g * b, r * g * b, r * g * b]).unwrap();
+        let gray = tilted.map([0.5; 3], Interpolation::Tetrahedral);
+        assert!((gray[0] - 0.5).abs() < 1e-6);
+        assert!((tilted.map([0.5; 3], Interpolation::Trilinear)[0] - 0.125).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_tracked_matches_untracked() {
+        let data = test_image(19, 7);
+        let lut = Lut::from_fn(5, |[r, g, b]| [b, r, g]).unwrap();
+        let mut last = (0, 0);
+        let mut progress = Progress::new(2).on_progress(|done, total| last = (done, total));
+        let result = apply_tracked(&data, 19, 7, &lut, Interpolation::Tetrahedral, 1.0, &mut progress).unwrap();
+        drop(progress);
+
+        assert_eq!(result, apply(&data, 19, 7, &lut, Interpolation::Tetrahedral, 1.0).unwrap());
+        assert_eq!(last, (7, 7));
+
+        let mut progress = Progress::new(2).cancel_when(|| true);
+        let result = apply_tracked(&data, 19, 7, &lut, Interpolation::Tetrahedral, 1.0, &mut progress);
+        assert_eq!(result, Err(Error::Cancelled));
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_parallel_matches_serial() {
+        let data = test_image(67, 45);
+        let lut = Lut::from_fn(9, |[r, g, b]| [g, b * b, (r + b) / 2.0]).unwrap();
+        for interpolation in BOTH {
+            assert_eq!(
+                apply_with(Exec::Serial, &data, 67, 45, &lut, interpolation, 0.8, &mut Progress::none()).unwrap(),
+                apply_with(Exec::Parallel, &data, 67, 45, &lut, interpolation, 0.8, &mut Progress::none()).unwrap()
+            );
+        }
+    }
+}
@@ -0,0 +1,448 @@
+//! Selection masks
+//!
+//! A [`Mask`] holds one 8-bit coverage value per pixel: 0 leaves the pixel
+//! alone, 255 takes the filter's result, values in between mix the two. Shape
+//! edges are anti-aliased with 4x4 supersampling and [`Mask::feather`] softens
+//! them further, so masked filters fade in smoothly along the selection.
+
+use super::blur;
+use super::exec::Exec;
+use super::tiled::{self, Rect};
+
+/// Subsamples per pixel along each axis for anti-aliased shape edges
+const SUBSAMPLES: u32 = 4;
+
+/// 8-bit coverage per pixel, row-major
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mask {
+    width: u32,
+    height: u32,
+    data: Vec<u8>,
+}
+
+impl Mask {
+    /// Nothing selected
+    pub fn empty(width: u32, height: u32) -> Self {
+        Mask { width, height, data: vec![0; (width * height) as usize] }
+    }
+
+    /// Everything selected
+    pub fn full(width: u32, height: u32) -> Self {
+        Mask { width, height, data: vec![255; (width * height) as usize] }
+    }
+
+    /// Wrap coverage values, one byte per pixel
+    pub fn from_data(width: u32, height: u32, data: Vec<u8>) -> Result<Self, String> {
+        let expected_len = (width * height) as usize;
+        if data.len() != expected_len {
+            return Err(format!("Invalid mask length: expected {}, got {}", expected_len, data.len()));
+        }
+
+        Ok(Mask { width, height, data })
+    }
+
+    /// Rectangle selection; parts outside the image are ignored
+    pub fn rect(width: u32, height: u32, rect: Rect) -> Self {
+        let mut mask = Mask::empty(width, height);
+        let x1 = rect.x.saturating_add(rect.width).min(width);
+        let y1 = rect.y.saturating_add(rect.height).min(height);
+
+        for y in rect.y.min(y1)..y1 {
+            let row = (y * width) as usize;
+            mask.data[row + rect.x.min(x1) as usize..row + x1 as usize].fill(255);
+        }
+
+        mask
+    }
+
+    /// Ellipse selection centred on (`cx`, `cy`) with radii `rx` and `ry`, in pixels
+    pub fn ellipse(width: u32, height: u32, cx: f32, cy: f32, rx: f32, ry: f32) -> Self {
+        let mut mask = Mask::empty(width, height);
+        if rx <= 0.0 || ry <= 0.0 {
+            return mask;
+        }
+
+        let x0 = (cx - rx).floor().max(0.0) as u32;
+        let y0 = (cy - ry).floor().max(0.0) as u32;
+        let x1 = ((cx + rx).ceil().max(0.0) as u32).min(width);
+        let y1 = ((cy + ry).ceil().max(0.0) as u32).min(height);
+        let step = 1.0 / SUBSAMPLES as f32;
+
+        for y in y0..y1 {
+            for x in x0..x1 {
+                let mut count = 0;
+                for sy in 0..SUBSAMPLES {
+                    let dy = (y as f32 + (sy as f32 + 0.5) * step - cy) / ry;
+                    for sx in 0..SUBSAMPLES {
+                        let dx = (x as f32 + (sx as f32 + 0.5) * step - cx) / rx;
+                        count += (dx * dx + dy * dy <= 1.0) as u32;
+                    }
+                }
+                mask.data[(y * width + x) as usize] = coverage(count);
+            }
+        }
+
+        mask
+    }
+
+    /// Polygon selection through `points` (x, y), closed automatically, even-odd fill
+    pub fn polygon(width: u32, height: u32, points: &[(f32, f32)]) -> Self {
+        let mut mask = Mask::empty(width, height);
+        if points.len() < 3 {
+            return mask;
+        }
+
+        let min_y = points.iter().map(|p| p.1).fold(f32::INFINITY, f32::min);
+        let max_y = points.iter().map(|p| p.1).fold(f32::NEG_INFINITY, f32::max);
+        let y0 = min_y.floor().max(0.0) as u32;
+        let y1 = (max_y.ceil().max(0.0) as u32).min(height);
+
+        let columns = (width * SUBSAMPLES) as i64;
+        let mut counts = vec![0u32; width as usize];
+        let mut crossings = Vec::new();
+
+        for y in y0..y1 {
+            counts.fill(0);
+
+            for sy in 0..SUBSAMPLES {
+                let yy = y as f32 + (sy as f32 + 0.5) / SUBSAMPLES as f32;
+
+                crossings.clear();
+                for (i, &(ax, ay)) in points.iter().enumerate() {
+                    let (bx, by) = points[(i + 1) % points.len()];
+                    if (ay <= yy) != (by <= yy) {
+                        crossings.push(ax + (yy - ay) * (bx - ax) / (by - ay));
+                    }
+                }
+                crossings.sort_by(f32::total_cmp);
+
+                // Subsample column j is centred on (j + 0.5) / SUBSAMPLES
+                for span in crossings.chunks_exact(2) {
+                    let first = ((span[0] * SUBSAMPLES as f32 - 0.5).ceil() as i64).clamp(0, columns);
+                    let end = ((span[1] * SUBSAMPLES as f32 - 0.5).ceil() as i64).clamp(0, columns);
+                    for j in first..end {
+                        counts[j as usize / SUBSAMPLES as usize] += 1;
+                    }
+                }
+            }
+
+            let row = (y * width) as usize;
+            for (value, &count) in mask.data[row..row + width as usize].iter_mut().zip(&counts) {
+                *value = coverage(count);
+            }
+        }
+
+        mask
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    pub fn into_data(self) -> Vec<u8> {
+        self.data
+    }
+
+    /// Add `other` to the selection (per-pixel maximum)
+    pub fn union(&mut self, other: &Mask) -> Result<(), String> {
+        self.combine(other, |a, b| a.max(b))
+    }
+
+    /// Remove `other` from the selection
+    pub fn subtract(&mut self, other: &Mask) -> Result<(), String> {
+        self.combine(other, |a, b| a.min(255 - b))
+    }
+
+    /// Keep only what is also in `other` (per-pixel minimum)
+    pub fn intersect(&mut self, other: &Mask) -> Result<(), String> {
+        self.combine(other, |a, b| a.min(b))
+    }
+
+    pub fn invert(&mut self) {
+        self.data.iter_mut().for_each(|v| *v = 255 - *v);
+    }
+
+    /// Soften the edges with a Gaussian blur of `radius` pixels
+    pub fn feather(&self, radius: f32) -> Mask {
+        if radius <= 0.0 || self.data.is_empty() {
+            return self.clone();
+        }
+
+        // Run the image blur on the coverage as an opaque gray image
+        let gray: Vec<u8> = self.data.iter().flat_map(|&v| [v, v, v, 255]).collect();
+        let blurred = blur::apply(&gray, self.width, self.height, radius).expect("gray image matches mask size");
+        let data = blurred.chunks_exact(4).map(|px| px[0]).collect();
+
+        Mask { width: self.width, height: self.height, data }
+    }
+
+    /// Smallest rectangle holding every selected pixel, `None` if nothing is selected
+    pub fn bounds(&self) -> Option<Rect> {
+        let width = self.width as usize;
+        let rows: Vec<usize> = (0..self.height as usize)
+            .filter(|&y| self.data[y * width..(y + 1) * width].iter().any(|&v| v > 0))
+            .collect();
+        let (&y0, &y1) = (rows.first()?, rows.last()?);
+
+        let selected = |x: usize| (y0..=y1).any(|y| self.data[y * width + x] > 0);
+        let x0 = (0..width).find(|&x| selected(x))?;
+        let x1 = (0..width).rev().find(|&x| selected(x))?;
+
+        Some(Rect { x: x0 as u32, y: y0 as u32, width: (x1 - x0 + 1) as u32, height: (y1 - y0 + 1) as u32 })
+    }
+
+    fn combine(&mut self, other: &Mask, f: impl Fn(u8, u8) -> u8) -> Result<(), String> {
+        if (other.width, other.height) != (self.width, self.height) {
+            return Err(format!(
+                "Mask size {}x{} does not match {}x{}",
+                other.width, other.height, self.width, self.height
+            ));
+        }
+
+        for (a, &b) in self.data.iter_mut().zip(&other.data) {
+            *a = f(*a, b);
+        }
+        Ok(())
+    }
+
+    pub(crate) fn check_size(&self, width: u32, height: u32) -> Result<(), String> {
+        if (self.width, self.height) != (width, height) {
+            return Err(format!(
+                "Mask size {}x{} does not match image size {}x{}",
+                self.width, self.height, width, height
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Coverage of a pixel with `count` of its subsamples inside the shape
+fn coverage(count: u32) -> u8 {
+    let samples = SUBSAMPLES * SUBSAMPLES;
+    ((count * 255 + samples / 2) / samples) as u8
+}
+
+/// Mix `filtered` into `original` by mask coverage, all channels including alpha
+pub fn blend(original: &[u8], filtered: &[u8], width: u32, height: u32, mask: &Mask) -> Result<Vec<u8>, String> {
+    blend_with(Exec::preferred(), original, filtered, width, height, mask)
+}
+
+pub(crate) fn blend_with(
+    exec: Exec,
+    original: &[u8],
+    filtered: &[u8],
+    width: u32,
+    height: u32,
+    mask: &Mask,
+) -> Result<Vec<u8>, String> {
+    let expected_len = (width * height * 4) as usize;
+    if original.len() != expected_len || filtered.len() != expected_len {
+        return Err(format!(
+            "Invalid image data length: expected {}, got {} and {}",
+            expected_len,
+            original.len(),
+            filtered.len()
+        ));
+    }
+    mask.check_size(width, height)?;
+
+    let row_len = width as usize * 4;
+    let mut output = vec![0u8; expected_len];
+
+    exec.rows(&mut output, row_len, |y, row| {
+        let start = y * row_len;
+        let coverage = &mask.data[y * width as usize..(y + 1) * width as usize];
+
+        for (x, &m) in coverage.iter().enumerate() {
+            let (m, keep) = (m as u32, 255 - m as u32);
+            for c in x * 4..x * 4 + 4 {
+                row[c] = ((original[start + c] as u32 * keep + filtered[start + c] as u32 * m + 127) / 255) as u8;
+            }
+        }
+    });
+
+    Ok(output)
+}
+
+/// `filtered` where `mask` selects, `original` elsewhere; `filtered` as is without a mask
+pub(crate) fn select(
+    original: &[u8],
+    filtered: Vec<u8>,
+    width: u32,
+    height: u32,
+    mask: Option<&Mask>,
+) -> Result<Vec<u8>, String> {
+    match mask {
+        Some(mask) => blend(original, &filtered, width, height, mask),
+        None => Ok(filtered),
+    }
+}
+
+/// Run a size-preserving `transform` on the selection's bounding box only
+///
+/// For flips and half turns: the selected content is mirrored or turned in
+/// place instead of being replaced by pixels from across the image.
+pub(crate) fn transform_selection<F>(
+    image_data: &[u8],
+    width: u32,
+    height: u32,
+    mask: &Mask,
+    transform: F,
+) -> Result<Vec<u8>, String>
+where
+    F: Fn(&[u8], u32, u32) -> Result<Vec<u8>, String>,
+{
+    let expected_len = (width * height * 4) as usize;
+    if image_data.len() != expected_len {
+        return Err(format!(
+            "Invalid image data length: expected {}, got {}",
+            expected_len,
+            image_data.len()
+        ));
+    }
+    mask.check_size(width, height)?;
+
+    let Some(bounds) = mask.bounds() else {
+        return Ok(image_data.to_vec());
+    };
+
+    let block = transform(&tiled::extract(image_data, width, bounds), bounds.width, bounds.height)?;
+    let mut transformed = image_data.to_vec();
+    tiled::paste(&mut transformed, width, &block, bounds);
+
+    blend(image_data, &transformed, width, height, mask)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::filters::{brightness, flip, grayscale, test_image};
+
+    fn area(mask: &Mask) -> f32 {
+        mask.data().iter().map(|&v| v as f32 / 255.0).sum()
+    }
+
+    #[test]
+    fn test_shapes() {
+        let rect = Mask::rect(10, 8, Rect { x: 7, y: 2, width: 10, height: 3 });
+        assert_eq!(area(&rect), 9.0);
+        assert_eq!(rect.bounds(), Some(Rect { x: 7, y: 2, width: 3, height: 3 }));
+
+        let ellipse = Mask::ellipse(64, 64, 32.0, 32.0, 20.0, 10.0);
+        let expected = std::f32::consts::PI * 20.0 * 10.0;
+        assert!((area(&ellipse) - expected).abs() < expected * 0.01, "area {}", area(&ellipse));
+        // Anti-aliased edge, solid inside
+        assert!(ellipse.data().iter().any(|&v| v > 0 && v < 255));
+        assert_eq!(ellipse.data()[32 * 64 + 32], 255);
+
+        // Right triangle with legs 20 and 10, one edge on a diagonal
+        let triangle = Mask::polygon(32, 32, &[(2.0, 3.0), (22.0, 3.0), (2.0, 13.0)]);
+        assert!((area(&triangle) - 100.0).abs() < 1.0, "area {}", area(&triangle));
+        assert_eq!(triangle.bounds(), Some(Rect { x: 2, y: 3, width: 20, height: 10 }));
+
+        // Axis-aligned polygon matches the rectangle exactly
+        let square = Mask::polygon(10, 8, &[(7.0, 2.0), (17.0, 2.0), (17.0, 5.0), (7.0, 5.0)]);
+        assert_eq!(square, rect);
+
+        assert_eq!(Mask::empty(4, 4).bounds(), None);
+        assert!(Mask::from_data(2, 2, vec![0; 3]).is_err());
+    }
+
+    #[test]
+    fn test_combine_and_invert() {
+        let left = Mask::rect(8, 4, Rect { x: 0, y: 0, width: 5, height: 4 });
+        let right = Mask::rect(8, 4, Rect { x: 3, y: 0, width: 5, height: 4 });
+
+        let mut union = left.clone();
+        union.union(&right).unwrap();
+        assert_eq!(union, Mask::full(8, 4));
+
+        let mut overlap = left.clone();
+        overlap.intersect(&right).unwrap();
+        assert_eq!(overlap, Mask::rect(8, 4, Rect { x: 3, y: 0, width: 2, height: 4 }));
+
+        let mut only_left = left.clone();
+        only_left.subtract(&right).unwrap();
+        assert_eq!(only_left, Mask::rect(8, 4, Rect { x: 0, y: 0, width: 3, height: 4 }));
+
+        let mut inverted = only_left.clone();
+        inverted.invert();
+        assert_eq!(inverted, Mask::rect(8, 4, Rect { x: 3, y: 0, width: 5, height: 4 }));
+
+        assert!(union.union(&Mask::empty(4, 4)).is_err());
+    }
+
+    #[test]
+    fn test_feather() {
+        let mask = Mask::rect(40, 40, Rect { x: 10, y: 10, width: 20, height: 20 });
+        let feathered = mask.feather(3.0);
+
+        // Soft ramp across the edge, solid in the middle
+        let row = &feathered.data()[20 * 40..21 * 40];
+        assert_eq!(row[20], 255);
+        assert_eq!(row[0], 0);
+        assert!(row[5..15].windows(2).all(|w| w[0] <= w[1]));
+        assert!(row[10] > 0 && row[10] < 255);
+        assert_eq!(mask.feather(0.0), mask);
+    }
+
+    #[test]
+    fn test_masked_filters() {
+        let (w, h) = (16, 12);
+        let data = test_image(w, h);
+        let mask = Mask::rect(w, h, Rect { x: 4, y: 3, width: 8, height: 5 });
+        let gray = grayscale::apply(&data, w, h).unwrap();
+
+        let result = grayscale::apply_masked(&data, w, h, Some(&mask)).unwrap();
+        for (i, &m) in mask.data().iter().enumerate() {
+            let expected = if m == 255 { &gray } else { &data };
+            assert_eq!(result[i * 4..i * 4 + 4], expected[i * 4..i * 4 + 4], "pixel {}", i);
+        }
+
+        // No mask: the plain filter
+        assert_eq!(grayscale::apply_masked(&data, w, h, None).unwrap(), gray);
+
+        // Half coverage mixes half way
+        let half = Mask::from_data(1, 1, vec![128]).unwrap();
+        let result = brightness::apply_masked(&[100, 100, 100, 255], 1, 1, 100.0, Some(&half)).unwrap();
+        assert_eq!(result, [150, 150, 150, 255]);
+
+        assert!(grayscale::apply_masked(&data, w, h, Some(&Mask::full(4, 4))).is_err());
+    }
+
+    #[test]
+    fn test_transform_selection() {
+        let (w, h) = (16, 12);
+        let data = test_image(w, h);
+        let bounds = Rect { x: 4, y: 3, width: 8, height: 5 };
+        let mask = Mask::rect(w, h, bounds);
+
+        // The selected block is mirrored in place, everything else is untouched
+        let mut expected = data.clone();
+        let block = flip::apply_horizontal(&tiled::extract(&data, w, bounds), bounds.width, bounds.height).unwrap();
+        tiled::paste(&mut expected, w, &block, bounds);
+
+        assert_eq!(flip::apply_horizontal_masked(&data, w, h, Some(&mask)).unwrap(), expected);
+        assert_eq!(flip::apply_horizontal_masked(&data, w, h, Some(&Mask::empty(w, h))).unwrap(), data);
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_parallel_matches_serial() {
+        let data = test_image(67, 45);
+        let filtered = grayscale::apply(&data, 67, 45).unwrap();
+        let mask = Mask::ellipse(67, 45, 30.0, 20.0, 25.0, 15.0).feather(2.0);
+
+        let serial = blend_with(Exec::Serial, &data, &filtered, 67, 45, &mask).unwrap();
+        let parallel = blend_with(Exec::Parallel, &data, &filtered, 67, 45, &mask).unwrap();
+        assert_eq!(serial, parallel);
+    }
+}
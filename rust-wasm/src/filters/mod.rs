@@ -2,5 +2,33 @@ pub mod grayscale;
 pub mod blur;
 pub mod brightness;
 pub mod flip;
+pub mod lut;
 pub mod rotate;
 pub mod crop;
+pub mod effects;
+pub mod mask;
+pub mod progress;
+pub mod selection;
+pub mod tiled;
+pub mod transform;
+pub mod white_balance;
+
+mod color;
+pub(crate) mod exec;
+#[cfg(all(feature = "simd", target_arch = "wasm32", target_feature = "simd128"))]
+mod simd;
+
+/// Deterministic pseudo-random RGBA image for tests
+#[cfg(test)]
+pub(crate) fn test_image(width: u32, height: u32) -> Vec<u8> {
+    let mut state: u32 = 0x1234_5678 ^ (width << 16) ^ height;
+    (0..width * height * 4)
+        .map(|_| {
+            // xorshift32
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            (state >> 24) as u8
+        })
+        .collect()
+}
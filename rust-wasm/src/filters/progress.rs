@@ -0,0 +1,212 @@
+//! Progress reporting and cancellation for long-running filters
+//!
+//! Filters that accept a [`Progress`] process their rows in chunks of
+//! `every_rows` and, between chunks, report how far they got and check for
+//! cancellation. Both happen on the calling thread, so the callbacks do not
+//! need to be `Send` (e.g. JS functions) even with the `parallel` feature.
+//!
+//! A run of several filters (see [`crate::pipeline::apply_tracked`]) wraps
+//! them in [`Progress::begin_stages`] and [`Progress::end_stage`], so one
+//! count runs over the rows of all its stages instead of restarting.
+
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Flag to stop a running filter at the next chunk boundary
+#[derive(Debug, Default)]
+pub struct CancelToken(AtomicBool);
+
+impl CancelToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// The filter stopped because it was cancelled
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cancelled;
+
+/// Error of a filter run with [`Progress`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// Invalid input, same message as the plain filter returns
+    Invalid(String),
+    Cancelled,
+}
+
+impl From<String> for Error {
+    fn from(message: String) -> Self {
+        Error::Invalid(message)
+    }
+}
+
+impl From<Cancelled> for Error {
+    fn from(_: Cancelled) -> Self {
+        Error::Cancelled
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Invalid(message) => f.write_str(message),
+            Error::Cancelled => f.write_str("Cancelled"),
+        }
+    }
+}
+
+/// Progress callback and cancellation check for one filter call
+pub struct Progress<'a> {
+    every_rows: usize,
+    done_rows: usize,
+    total_rows: usize,
+    /// Rows of the finished stages and of the whole run, for multi-stage runs
+    stages: Option<(usize, usize)>,
+    on_progress: Option<Box<dyn FnMut(usize, usize) + 'a>>,
+    is_cancelled: Option<Box<dyn Fn() -> bool + 'a>>,
+}
+
+impl<'a> Progress<'a> {
+    /// No reporting, no cancellation; rows are processed in one chunk
+    pub fn none() -> Self {
+        Self::new(usize::MAX)
+    }
+
+    /// Report and check for cancellation every `every_rows` rows
+    pub fn new(every_rows: usize) -> Self {
+        Progress {
+            every_rows: every_rows.max(1),
+            done_rows: 0,
+            total_rows: 0,
+            stages: None,
+            on_progress: None,
+            is_cancelled: None,
+        }
+    }
+
+    /// Call `f(done_rows, total_rows)` after every chunk
+    ///
+    /// Multi-pass filters count the rows of every pass, so `total_rows` can
+    /// be a multiple of the image height; multi-stage runs count the rows of
+    /// every stage.
+    pub fn on_progress(mut self, f: impl FnMut(usize, usize) + 'a) -> Self {
+        self.on_progress = Some(Box::new(f));
+        self
+    }
+
+    /// Stop with [`Cancelled`] once `token` is cancelled
+    pub fn cancel_token(self, token: &'a CancelToken) -> Self {
+        self.cancel_when(move || token.is_cancelled())
+    }
+
+    /// Stop with [`Cancelled`] once `f` returns true (e.g. an `AbortSignal`)
+    pub fn cancel_when(mut self, f: impl Fn() -> bool + 'a) -> Self {
+        self.is_cancelled = Some(Box::new(f));
+        self
+    }
+
+    /// Start a run of several filters that will process `total_rows` rows in all
+    pub(crate) fn begin_stages(&mut self, total_rows: usize) -> Result<(), Cancelled> {
+        self.stages = Some((0, total_rows));
+        self.begin(0)
+    }
+
+    /// Finish one filter of a multi-stage run; its rows count as done
+    pub(crate) fn end_stage(&mut self) {
+        if let Some((finished, _)) = self.stages.as_mut() {
+            *finished += self.total_rows;
+        }
+        self.done_rows = 0;
+        self.total_rows = 0;
+    }
+
+    /// Start a filter that will process `total_rows` rows in all
+    pub(crate) fn begin(&mut self, total_rows: usize) -> Result<(), Cancelled> {
+        self.done_rows = 0;
+        self.total_rows = total_rows;
+        self.check()
+    }
+
+    /// Rows to process before the next call to `advance`
+    pub(crate) fn chunk_rows(&self) -> usize {
+        self.every_rows
+    }
+
+    /// Record `rows` more finished rows, then report and check for cancellation
+    pub(crate) fn advance(&mut self, rows: usize) -> Result<(), Cancelled> {
+        self.done_rows = (self.done_rows + rows).min(self.total_rows);
+
+        let (done, total) = match self.stages {
+            Some((finished, total)) => ((finished + self.done_rows).min(total), total),
+            None => (self.done_rows, self.total_rows),
+        };
+        if let Some(f) = self.on_progress.as_mut() {
+            f(done, total);
+        }
+
+        self.check()
+    }
+
+    fn check(&self) -> Result<(), Cancelled> {
+        match &self.is_cancelled {
+            Some(f) if f() => Err(Cancelled),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_advance_reports_progress() {
+        let mut reports = Vec::new();
+        let mut progress = Progress::new(10).on_progress(|done, total| reports.push((done, total)));
+
+        progress.begin(25).unwrap();
+        progress.advance(10).unwrap();
+        progress.advance(10).unwrap();
+        progress.advance(5).unwrap();
+        drop(progress);
+
+        assert_eq!(reports, vec![(10, 25), (20, 25), (25, 25)]);
+    }
+
+    #[test]
+    fn test_stages_count_towards_one_total() {
+        let mut reports = Vec::new();
+        let mut progress = Progress::new(10).on_progress(|done, total| reports.push((done, total)));
+
+        progress.begin_stages(35).unwrap();
+        progress.begin(20).unwrap();
+        progress.advance(10).unwrap();
+        progress.advance(10).unwrap();
+        progress.end_stage();
+        progress.begin(15).unwrap();
+        progress.advance(15).unwrap();
+        progress.end_stage();
+        drop(progress);
+
+        assert_eq!(reports, vec![(10, 35), (20, 35), (35, 35)]);
+    }
+
+    #[test]
+    fn test_cancel_token() {
+        let token = CancelToken::new();
+        let mut progress = Progress::new(1).cancel_token(&token);
+
+        assert_eq!(progress.begin(3), Ok(()));
+        token.cancel();
+        assert_eq!(progress.advance(1), Err(Cancelled));
+        assert_eq!(progress.begin(3), Err(Cancelled));
+    }
+}
@@ -1,5 +1,22 @@
-/// Rotate image 90 degrees clockwise
+use super::exec::Exec;
+use super::mask::{self, Mask};
+
+/// Rotate image 90 degrees clockwise: the left column becomes the top row
 pub fn rotate_90_cw(image_data: &[u8], width: u32, height: u32) -> Result<Vec<u8>, String> {
+    rotate_90_cw_with(Exec::preferred(), image_data, width, height)
+}
+
+/// Turn the selection 90 degrees clockwise within its bounding box; the whole image without a mask
+///
+/// The image keeps its size, so the bounding box must be square.
+pub fn rotate_90_cw_masked(image_data: &[u8], width: u32, height: u32, mask: Option<&Mask>) -> Result<Vec<u8>, String> {
+    match mask {
+        Some(mask) => quarter_turn_selection(image_data, width, height, mask, rotate_90_cw),
+        None => rotate_90_cw(image_data, width, height),
+    }
+}
+
+pub(crate) fn rotate_90_cw_with(exec: Exec, image_data: &[u8], width: u32, height: u32) -> Result<Vec<u8>, String> {
     // Validate input
     let expected_len = (width * height * 4) as usize;
     if image_data.len() != expected_len {
@@ -15,25 +32,28 @@ pub fn rotate_90_cw(image_data: &[u8], width: u32, height: u32) -> Result<Vec<u8
     let mut output = vec![0u8; image_data.len()];
 
     // New dimensions: width and height are swapped
-    // For 90° CW: new_pixel[y][new_width - 1 - x] = old_pixel[x][y]
-    // In 1D array: (x, y) -> (y, height - 1 - x) with swapped dimensions
-    for y in 0..height {
-        for x in 0..width {
-            let src_idx = (y * width + x) * 4;
-            let new_x = y;
-            let new_y = width - 1 - x;
-            let dst_idx = (new_y * height + new_x) * 4;
-
-            // Copy RGBA pixel
-            output[dst_idx..dst_idx + 4].copy_from_slice(&image_data[src_idx..src_idx + 4]);
-        }
-    }
+    // For 90° CW: new_pixel(new_x, new_y) = old_pixel(new_y, height - 1 - new_x)
+    transpose_tiled(exec, image_data, &mut output, height, |new_x, new_y| {
+        (height - 1 - new_x) * width + new_y
+    });
 
     Ok(output)
 }
 
 /// Rotate image 180 degrees
 pub fn rotate_180(image_data: &[u8], width: u32, height: u32) -> Result<Vec<u8>, String> {
+    rotate_180_with(Exec::preferred(), image_data, width, height)
+}
+
+/// Turn the selection 180 degrees within its bounding box; the whole image without a mask
+pub fn rotate_180_masked(image_data: &[u8], width: u32, height: u32, mask: Option<&Mask>) -> Result<Vec<u8>, String> {
+    match mask {
+        Some(mask) => mask::transform_selection(image_data, width, height, mask, rotate_180),
+        None => rotate_180(image_data, width, height),
+    }
+}
+
+pub(crate) fn rotate_180_with(exec: Exec, image_data: &[u8], width: u32, height: u32) -> Result<Vec<u8>, String> {
     // Validate input
     let expected_len = (width * height * 4) as usize;
     if image_data.len() != expected_len {
@@ -49,23 +69,68 @@ pub fn rotate_180(image_data: &[u8], width: u32, height: u32) -> Result<Vec<u8>,
     let mut output = vec![0u8; image_data.len()];
 
     // For 180°: (x, y) -> (width - 1 - x, height - 1 - y)
-    for y in 0..height {
-        for x in 0..width {
-            let src_idx = (y * width + x) * 4;
-            let new_x = width - 1 - x;
-            let new_y = height - 1 - y;
-            let dst_idx = (new_y * width + new_x) * 4;
-
-            // Copy RGBA pixel
-            output[dst_idx..dst_idx + 4].copy_from_slice(&image_data[src_idx..src_idx + 4]);
-        }
-    }
+    // Each output row is a source row from the bottom, pixels reversed
+    let row_len = width * 4;
+    exec.rows(&mut output, row_len, |new_y, row| {
+        let src_start = (height - 1 - new_y) * row_len;
+        row.copy_from_slice(&image_data[src_start..src_start + row_len]);
+        row.as_chunks_mut::<4>().0.reverse();
+    });
 
     Ok(output)
 }
 
-/// Rotate image 270 degrees clockwise (= 90 degrees counter-clockwise)
+/// Rotate image 180 degrees in place, without allocating an output buffer
+pub fn rotate_180_in_place(image_data: &mut [u8], width: u32, height: u32) -> Result<(), String> {
+    // Validate input
+    let expected_len = (width * height * 4) as usize;
+    if image_data.len() != expected_len {
+        return Err(format!(
+            "Invalid image data length: expected {}, got {}",
+            expected_len,
+            image_data.len()
+        ));
+    }
+
+    // (x, y) -> (width - 1 - x, height - 1 - y) is reversing the pixel order
+    image_data.as_chunks_mut::<4>().0.reverse();
+
+    Ok(())
+}
+
+/// Rotate image 270 degrees clockwise (= 90 degrees counter-clockwise): the right column becomes the top row
 pub fn rotate_270_cw(image_data: &[u8], width: u32, height: u32) -> Result<Vec<u8>, String> {
+    rotate_270_cw_with(Exec::preferred(), image_data, width, height)
+}
+
+/// Turn the selection 270 degrees clockwise within its bounding box; the whole image without a mask
+///
+/// The image keeps its size, so the bounding box must be square.
+pub fn rotate_270_cw_masked(image_data: &[u8], width: u32, height: u32, mask: Option<&Mask>) -> Result<Vec<u8>, String> {
+    match mask {
+        Some(mask) => quarter_turn_selection(image_data, width, height, mask, rotate_270_cw),
+        None => rotate_270_cw(image_data, width, height),
+    }
+}
+
+/// A quarter turn of a square block keeps its size, so it can go through `transform_selection`
+fn quarter_turn_selection<F>(image_data: &[u8], width: u32, height: u32, mask: &Mask, turn: F) -> Result<Vec<u8>, String>
+where
+    F: Fn(&[u8], u32, u32) -> Result<Vec<u8>, String>,
+{
+    if let Some(bounds) = mask.bounds() {
+        if bounds.width != bounds.height {
+            return Err(format!(
+                "Quarter turns of a selection need a square bounding box, got {}x{}",
+                bounds.width, bounds.height
+            ));
+        }
+    }
+
+    mask::transform_selection(image_data, width, height, mask, turn)
+}
+
+pub(crate) fn rotate_270_cw_with(exec: Exec, image_data: &[u8], width: u32, height: u32) -> Result<Vec<u8>, String> {
     // Validate input
     let expected_len = (width * height * 4) as usize;
     if image_data.len() != expected_len {
@@ -81,22 +146,47 @@ pub fn rotate_270_cw(image_data: &[u8], width: u32, height: u32) -> Result<Vec<u
     let mut output = vec![0u8; image_data.len()];
 
     // New dimensions: width and height are swapped
-    // For 270° CW (= 90° CCW): (x, y) -> (height - 1 - y, x) with swapped dimensions
-    for y in 0..height {
-        for x in 0..width {
-            let src_idx = (y * width + x) * 4;
-            let new_x = height - 1 - y;
-            let new_y = x;
-            let dst_idx = (new_y * height + new_x) * 4;
-
-            // Copy RGBA pixel
-            output[dst_idx..dst_idx + 4].copy_from_slice(&image_data[src_idx..src_idx + 4]);
-        }
-    }
+    // For 270° CW (= 90° CCW): new_pixel(new_x, new_y) = old_pixel(width - 1 - new_y, new_x)
+    transpose_tiled(exec, image_data, &mut output, height, |new_x, new_y| {
+        new_x * width + (width - 1 - new_y)
+    });
 
     Ok(output)
 }
 
+/// Tile edge in pixels; a 32x32 RGBA tile is 4KB, so one source and one
+/// destination tile stay in L1 while the strided side is walked
+const TILE: usize = 32;
+
+/// Fill a rotated (width/height swapped) image tile by tile
+///
+/// The output is `new_width` (= source height) pixels wide.
+/// `src_index(new_x, new_y)` maps an output pixel to its source pixel index.
+/// Walking small square tiles instead of whole rows keeps the strided side
+/// of the transpose within a few cache lines.
+pub(super) fn transpose_tiled<F>(exec: Exec, image_data: &[u8], output: &mut [u8], new_width: usize, src_index: F)
+where
+    F: Fn(usize, usize) -> usize + Sync + Send,
+{
+    let src = image_data.as_chunks::<4>().0;
+
+    exec.bands(output, new_width * 4, |y0, band| {
+        let dst = band.as_chunks_mut::<4>().0;
+        let rows = dst.len() / new_width;
+
+        for tile_y in (0..rows).step_by(TILE) {
+            for tile_x in (0..new_width).step_by(TILE) {
+                for dy in tile_y..(tile_y + TILE).min(rows) {
+                    let dst_row = &mut dst[dy * new_width..(dy + 1) * new_width];
+                    for new_x in tile_x..(tile_x + TILE).min(new_width) {
+                        dst_row[new_x] = src[src_index(new_x, y0 + dy)];
+                    }
+                }
+            }
+        }
+    });
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -114,10 +204,10 @@ mod tests {
 
         let output = result.unwrap();
         // After 90° CW rotation, becomes 1x2 (height x width):
-        // [Blue]
         // [Red]
-        assert_eq!(&output[0..4], &[0, 0, 255, 255]); // Blue
-        assert_eq!(&output[4..8], &[255, 0, 0, 255]); // Red
+        // [Blue]
+        assert_eq!(&output[0..4], &[255, 0, 0, 255]); // Red
+        assert_eq!(&output[4..8], &[0, 0, 255, 255]); // Blue
     }
 
     #[test]
@@ -150,10 +240,10 @@ mod tests {
 
         let output = result.unwrap();
         // After 270° CW (= 90° CCW), becomes 1x2:
-        // [Red]
         // [Blue]
-        assert_eq!(&output[0..4], &[255, 0, 0, 255]); // Red
-        assert_eq!(&output[4..8], &[0, 0, 255, 255]); // Blue
+        // [Red]
+        assert_eq!(&output[0..4], &[0, 0, 255, 255]); // Blue
+        assert_eq!(&output[4..8], &[255, 0, 0, 255]); // Red
     }
 
     #[test]
@@ -191,6 +281,91 @@ mod tests {
         assert_eq!(&output[12..16], &[255, 0, 0, 255]); // R
     }
 
+    #[test]
+    fn test_rotation_direction_on_non_square_image() {
+        // 3x2 image, one distinct pixel per letter:
+        // [A][B][C]
+        // [D][E][F]
+        let data: Vec<u8> = (0..6u8).flat_map(|i| [i * 40, 0, 0, 255]).collect();
+        let letters = |buf: &[u8]| buf.chunks_exact(4).map(|px| (b'A' + px[0] / 40) as char).collect::<String>();
+
+        // Clockwise: the left column becomes the top row, read bottom to top
+        // [D][A]
+        // [E][B]
+        // [F][C]
+        assert_eq!(letters(&rotate_90_cw(&data, 3, 2).unwrap()), "DAEBFC");
+
+        // Counter-clockwise: the right column becomes the top row
+        // [C][F]
+        // [B][E]
+        // [A][D]
+        assert_eq!(letters(&rotate_270_cw(&data, 3, 2).unwrap()), "CFBEAD");
+
+        assert_eq!(letters(&rotate_180(&data, 3, 2).unwrap()), "FEDCBA");
+    }
+
+    #[test]
+    fn test_quarter_turn_of_selection() {
+        use crate::filters::tiled::{self, Rect};
+
+        let (w, h) = (16, 12);
+        let data = crate::filters::test_image(w, h);
+        let bounds = Rect { x: 4, y: 3, width: 5, height: 5 };
+        let mask = Mask::rect(w, h, bounds);
+
+        // The selected block turns in place, everything else is untouched
+        let block = tiled::extract(&data, w, bounds);
+        let turned = |block: Vec<u8>| {
+            let mut expected = data.clone();
+            tiled::paste(&mut expected, w, &block, bounds);
+            expected
+        };
+        let cw = turned(rotate_90_cw(&block, 5, 5).unwrap());
+        let ccw = turned(rotate_270_cw(&block, 5, 5).unwrap());
+        assert_eq!(rotate_90_cw_masked(&data, w, h, Some(&mask)).unwrap(), cw);
+        assert_eq!(rotate_270_cw_masked(&data, w, h, Some(&mask)).unwrap(), ccw);
+
+        assert_eq!(rotate_90_cw_masked(&data, w, h, None).unwrap(), rotate_90_cw(&data, w, h).unwrap());
+        assert_eq!(rotate_90_cw_masked(&data, w, h, Some(&Mask::empty(w, h))).unwrap(), data);
+
+        // A non-square selection would not fit back into its own box
+        let wide = Mask::rect(w, h, Rect { x: 4, y: 3, width: 6, height: 5 });
+        assert!(rotate_90_cw_masked(&data, w, h, Some(&wide)).is_err());
+        assert!(rotate_270_cw_masked(&data, w, h, Some(&wide)).is_err());
+    }
+
+    #[test]
+    fn test_rotate_180_in_place() {
+        for (width, height) in [(1, 1), (2, 1), (3, 3), (67, 45)] {
+            let data = crate::filters::test_image(width, height);
+            let mut in_place = data.clone();
+            rotate_180_in_place(&mut in_place, width, height).unwrap();
+            assert_eq!(in_place, rotate_180(&data, width, height).unwrap());
+        }
+
+        let mut data = vec![255, 0, 0, 255];
+        assert!(rotate_180_in_place(&mut data, 2, 2).is_err());
+    }
+
+    #[test]
+    fn test_rotate_spans_several_tiles() {
+        // Larger than one tile in both directions, with partial edge tiles
+        let (width, height) = (TILE * 2 + 5, TILE + 3);
+        let data = crate::filters::test_image(width as u32, height as u32);
+        let pixel = |buf: &[u8], w: usize, x: usize, y: usize| buf[(y * w + x) * 4..(y * w + x) * 4 + 4].to_vec();
+
+        let cw = rotate_90_cw(&data, width as u32, height as u32).unwrap();
+        let ccw = rotate_270_cw(&data, width as u32, height as u32).unwrap();
+
+        for y in 0..height {
+            for x in 0..width {
+                let src = pixel(&data, width, x, y);
+                assert_eq!(pixel(&cw, height, height - 1 - y, x), src);
+                assert_eq!(pixel(&ccw, height, y, width - 1 - x), src);
+            }
+        }
+    }
+
     #[test]
     fn test_invalid_dimensions() {
         let data = vec![255, 0, 0, 255];
@@ -203,4 +378,22 @@ mod tests {
         let result = rotate_270_cw(&data, 2, 2);
         assert!(result.is_err());
     }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_parallel_matches_serial() {
+        let data = crate::filters::test_image(67, 45);
+
+        let serial = rotate_90_cw_with(Exec::Serial, &data, 67, 45).unwrap();
+        let parallel = rotate_90_cw_with(Exec::Parallel, &data, 67, 45).unwrap();
+        assert_eq!(serial, parallel);
+
+        let serial = rotate_180_with(Exec::Serial, &data, 67, 45).unwrap();
+        let parallel = rotate_180_with(Exec::Parallel, &data, 67, 45).unwrap();
+        assert_eq!(serial, parallel);
+
+        let serial = rotate_270_cw_with(Exec::Serial, &data, 67, 45).unwrap();
+        let parallel = rotate_270_cw_with(Exec::Parallel, &data, 67, 45).unwrap();
+        assert_eq!(serial, parallel);
+    }
 }
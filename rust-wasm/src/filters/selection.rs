@@ -0,0 +1,316 @@
+//! Selections from image content: magic wand and color range
+//!
+//! Both return a [`Mask`] the size of the image, to display as a selection
+//! or to pass to the masked filters.
+
+use super::color;
+use super::exec::Exec;
+use super::mask::Mask;
+
+/// Color space in which [`magic_wand`] measures color distances
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ColorSpace {
+    /// Euclidean distance of the R, G, B bytes, 0 to 441
+    #[default]
+    Rgb,
+    /// CIE76 delta E in L*a*b*, closer to perceived difference
+    Lab,
+}
+
+impl ColorSpace {
+    /// "rgb" or "lab"
+    pub fn from_name(name: &str) -> Result<Self, String> {
+        match name {
+            "rgb" => Ok(ColorSpace::Rgb),
+            "lab" => Ok(ColorSpace::Lab),
+            _ => Err(format!("Unknown color space: {}", name)),
+        }
+    }
+}
+
+/// Select pixels whose color is within `tolerance` of the pixel at (`x`, `y`)
+///
+/// `contiguous` limits the selection to pixels connected to the seed through
+/// selected neighbours (4-connected flood fill); otherwise every matching
+/// pixel in the image is selected. Alpha is not compared. The mask is hard
+/// edged; [`Mask::feather`] softens it.
+#[allow(clippy::too_many_arguments)]
+pub fn magic_wand(
+    image_data: &[u8],
+    width: u32,
+    height: u32,
+    x: u32,
+    y: u32,
+    tolerance: f32,
+    contiguous: bool,
+    space: ColorSpace,
+) -> Result<Mask, String> {
+    magic_wand_with(Exec::preferred(), image_data, width, height, x, y, tolerance, contiguous, space)
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn magic_wand_with(
+    exec: Exec,
+    image_data: &[u8],
+    width: u32,
+    height: u32,
+    x: u32,
+    y: u32,
+    tolerance: f32,
+    contiguous: bool,
+    space: ColorSpace,
+) -> Result<Mask, String> {
+    validate(image_data, width, height)?;
+    if x >= width || y >= height {
+        return Err(format!("Seed ({}, {}) is outside the {}x{} image", x, y, width, height));
+    }
+
+    let seed = ((y * width + x) * 4) as usize;
+    let reference = to_space(space, &image_data[seed..seed + 3]);
+    let max_distance_sq = tolerance.max(0.0) * tolerance.max(0.0);
+
+    // 255 for every pixel within tolerance, anywhere in the image
+    let mut matches = vec![0u8; (width * height) as usize];
+    exec.rows(&mut matches, width as usize, |row_y, row| {
+        let start = row_y * width as usize * 4;
+        for (i, value) in row.iter_mut().enumerate() {
+            let px = &image_data[start + i * 4..start + i * 4 + 3];
+            let c = to_space(space, px);
+            let distance_sq: f32 = (0..3).map(|k| (c[k] - reference[k]) * (c[k] - reference[k])).sum();
+            *value = if distance_sq <= max_distance_sq { 255 } else { 0 };
+        }
+    });
+
+    if !contiguous {
+        return Mask::from_data(width, height, matches);
+    }
+
+    // Flood fill from the seed through matching pixels
+    let (w, h) = (width as usize, height as usize);
+    let mut selected = vec![0u8; w * h];
+    let mut stack = vec![y as usize * w + x as usize];
+    selected[stack[0]] = 255;
+
+    while let Some(i) = stack.pop() {
+        let (px, py) = (i % w, i / w);
+        let neighbours = [
+            (px > 0).then(|| i - 1),
+            (px + 1 < w).then(|| i + 1),
+            (py > 0).then(|| i - w),
+            (py + 1 < h).then(|| i + w),
+        ];
+
+        for n in neighbours.into_iter().flatten() {
+            if matches[n] == 255 && selected[n] == 0 {
+                selected[n] = 255;
+                stack.push(n);
+            }
+        }
+    }
+
+    Mask::from_data(width, height, selected)
+}
+
+fn to_space(space: ColorSpace, rgb: &[u8]) -> [f32; 3] {
+    match space {
+        ColorSpace::Rgb => [rgb[0] as f32, rgb[1] as f32, rgb[2] as f32],
+        ColorSpace::Lab => color::rgb_to_lab(rgb[0], rgb[1], rgb[2]),
+    }
+}
+
+/// Hue, saturation and lightness bounds for [`color_range`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HslRange {
+    /// Center hue in degrees: 0 red, 120 green, 240 blue
+    pub hue: f32,
+    /// Hues up to this many degrees either side of `hue` are selected; 180 selects all
+    pub hue_tolerance: f32,
+    /// Saturation bounds, 0.0 to 1.0
+    pub min_saturation: f32,
+    pub max_saturation: f32,
+    /// Lightness bounds, 0.0 to 1.0
+    pub min_lightness: f32,
+    pub max_lightness: f32,
+    /// Soft falloff outside the bounds, 0.0 (hard edge) to 1.0; a share of
+    /// 180 degrees for hue and of the full range for saturation and lightness
+    pub fuzziness: f32,
+}
+
+impl Default for HslRange {
+    /// Every color, hard edged
+    fn default() -> Self {
+        HslRange {
+            hue: 0.0,
+            hue_tolerance: 180.0,
+            min_saturation: 0.0,
+            max_saturation: 1.0,
+            min_lightness: 0.0,
+            max_lightness: 1.0,
+            fuzziness: 0.0,
+        }
+    }
+}
+
+/// Select pixels by hue, saturation and lightness
+///
+/// Pixels inside all three bounds get full coverage; with `fuzziness`,
+/// coverage falls off linearly outside them.
+pub fn color_range(image_data: &[u8], width: u32, height: u32, range: &HslRange) -> Result<Mask, String> {
+    color_range_with(Exec::preferred(), image_data, width, height, range)
+}
+
+pub(crate) fn color_range_with(
+    exec: Exec,
+    image_data: &[u8],
+    width: u32,
+    height: u32,
+    range: &HslRange,
+) -> Result<Mask, String> {
+    validate(image_data, width, height)?;
+
+    let fuzziness = range.fuzziness.clamp(0.0, 1.0);
+    let hue_center = range.hue.rem_euclid(360.0);
+    let mut coverage = vec![0u8; (width * height) as usize];
+
+    exec.rows(&mut coverage, width as usize, |y, row| {
+        let start = y * width as usize * 4;
+        for (i, value) in row.iter_mut().enumerate() {
+            let px = &image_data[start + i * 4..start + i * 4 + 3];
+            let [hue, saturation, lightness] = color::rgb_to_hsl(px[0], px[1], px[2]);
+
+            // Distance around the hue circle
+            let hue_distance = (hue - hue_center).abs();
+            let hue_distance = hue_distance.min(360.0 - hue_distance);
+
+            let weight = falloff(hue_distance - range.hue_tolerance, fuzziness * 180.0)
+                * falloff(outside(saturation, range.min_saturation, range.max_saturation), fuzziness)
+                * falloff(outside(lightness, range.min_lightness, range.max_lightness), fuzziness);
+            *value = (weight * 255.0).round() as u8;
+        }
+    });
+
+    Mask::from_data(width, height, coverage)
+}
+
+/// How far `value` lies outside `min..=max`, 0 or negative inside
+fn outside(value: f32, min: f32, max: f32) -> f32 {
+    (min - value).max(value - max)
+}
+
+/// 1 inside (`distance` <= 0), falling linearly to 0 at `width` outside
+fn falloff(distance: f32, width: f32) -> f32 {
+    if distance <= 0.0 {
+        1.0
+    } else if width <= 0.0 {
+        0.0
+    } else {
+        (1.0 - distance / width).max(0.0)
+    }
+}
+
+fn validate(image_data: &[u8], width: u32, height: u32) -> Result<(), String> {
+    let expected_len = (width * height * 4) as usize;
+    if image_data.len() != expected_len {
+        return Err(format!(
+            "Invalid image data length: expected {}, got {}",
+            expected_len,
+            image_data.len()
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 6x4 image: left half red, right half blue, with a red pixel in the blue half
+    fn two_halves() -> Vec<u8> {
+        let mut data = Vec::new();
+        for y in 0..4 {
+            for x in 0..6 {
+                let red = x < 3 || (x, y) == (5, 3);
+                data.extend_from_slice(if red { &[250, 10, 10, 255] } else { &[10, 10, 250, 255] });
+            }
+        }
+        data
+    }
+
+    fn selected(mask: &Mask) -> usize {
+        mask.data().iter().filter(|&&v| v == 255).count()
+    }
+
+    #[test]
+    fn test_magic_wand_contiguous_and_global() {
+        let data = two_halves();
+
+        let contiguous = magic_wand(&data, 6, 4, 0, 0, 10.0, true, ColorSpace::Rgb).unwrap();
+        assert_eq!(selected(&contiguous), 12);
+        assert_eq!(contiguous.data()[3 * 6 + 5], 0);
+
+        let global = magic_wand(&data, 6, 4, 0, 0, 10.0, false, ColorSpace::Rgb).unwrap();
+        assert_eq!(selected(&global), 13);
+        assert_eq!(global.data()[3 * 6 + 5], 255);
+
+        // A tolerance spanning red to blue selects everything
+        let all = magic_wand(&data, 6, 4, 0, 0, 400.0, true, ColorSpace::Rgb).unwrap();
+        assert_eq!(all, Mask::full(6, 4));
+    }
+
+    #[test]
+    fn test_magic_wand_tolerance_in_lab() {
+        // A gray ramp: each step is about 0.4 delta E at mid gray
+        let data: Vec<u8> = (0..16u8).flat_map(|i| [120 + i, 120 + i, 120 + i, 255]).collect();
+
+        let narrow = magic_wand(&data, 16, 1, 0, 0, 1.0, true, ColorSpace::Lab).unwrap();
+        let wide = magic_wand(&data, 16, 1, 0, 0, 3.0, true, ColorSpace::Lab).unwrap();
+        assert!(selected(&narrow) >= 2 && selected(&narrow) < selected(&wide));
+        assert!(selected(&wide) < 16);
+
+        assert!(magic_wand(&data, 16, 1, 16, 0, 3.0, true, ColorSpace::Lab).is_err());
+        assert!(ColorSpace::from_name("hsv").is_err());
+    }
+
+    #[test]
+    fn test_color_range() {
+        let data = two_halves();
+        let reds = HslRange { hue: 0.0, hue_tolerance: 20.0, min_saturation: 0.5, ..HslRange::default() };
+        let mask = color_range(&data, 6, 4, &reds).unwrap();
+        assert_eq!(selected(&mask), 13);
+        assert!(mask.data().iter().all(|&v| v == 0 || v == 255));
+
+        // Hue wraps around: 350 +- 20 still covers red
+        let wrapped = HslRange { hue: 350.0, ..reds };
+        assert_eq!(color_range(&data, 6, 4, &wrapped).unwrap(), mask);
+
+        assert_eq!(color_range(&data, 6, 4, &HslRange::default()).unwrap(), Mask::full(6, 4));
+    }
+
+    #[test]
+    fn test_color_range_fuzziness() {
+        // Hues 0, 30, 60 (red, orange, yellow)
+        let data = [255, 0, 0, 255, 255, 128, 0, 255, 255, 255, 0, 255];
+        let range = HslRange { hue: 0.0, hue_tolerance: 10.0, fuzziness: 0.25, ..HslRange::default() };
+        let mask = color_range(&data, 3, 1, &range).unwrap();
+
+        // 45 degrees of falloff: orange is 20 degrees past the bound
+        let [red, orange, yellow] = [mask.data()[0], mask.data()[1], mask.data()[2]];
+        assert_eq!(red, 255);
+        assert!(orange > 100 && orange < 160, "orange {}", orange);
+        assert_eq!(yellow, 0);
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_parallel_matches_serial() {
+        let data = crate::filters::test_image(67, 45);
+        let serial = magic_wand_with(Exec::Serial, &data, 67, 45, 10, 10, 120.0, true, ColorSpace::Lab).unwrap();
+        let parallel = magic_wand_with(Exec::Parallel, &data, 67, 45, 10, 10, 120.0, true, ColorSpace::Lab).unwrap();
+        assert_eq!(serial, parallel);
+
+        let range = HslRange { hue: 200.0, hue_tolerance: 40.0, fuzziness: 0.2, ..HslRange::default() };
+        let serial = color_range_with(Exec::Serial, &data, 67, 45, &range).unwrap();
+        let parallel = color_range_with(Exec::Parallel, &data, 67, 45, &range).unwrap();
+        assert_eq!(serial, parallel);
+    }
+}
@@ -0,0 +1,133 @@
+//! WASM SIMD128 kernels for the per-pixel filters (`simd` feature)
+//!
+//! Each kernel handles whole 16-byte blocks (4 RGBA pixels, one per u32 lane)
+//! and returns how many bytes it processed; the caller finishes the tail with
+//! the scalar kernel. Results are bit-identical to the scalar kernels.
+//!
+//! The tests below compare each kernel with its scalar counterpart. They
+//! need wasm32 + simd128 and run in Node with `cargo test --features simd`
+//! (`npm run test:simd`), never in a native `cargo test`.
+
+use core::arch::wasm32::*;
+
+/// Split 4 RGBA pixels into R, G, B, A lanes (0-255 in each u32)
+#[inline(always)]
+fn unpack(px: v128) -> (v128, v128, v128, v128) {
+    let mask = u32x4_splat(0xff);
+    (
+        v128_and(px, mask),
+        v128_and(u32x4_shr(px, 8), mask),
+        v128_and(u32x4_shr(px, 16), mask),
+        u32x4_shr(px, 24),
+    )
+}
+
+/// Inverse of [`unpack`]; every lane must already be in 0-255
+#[inline(always)]
+fn pack(r: v128, g: v128, b: v128, a: v128) -> v128 {
+    v128_or(
+        v128_or(r, i32x4_shl(g, 8)),
+        v128_or(i32x4_shl(b, 16), i32x4_shl(a, 24)),
+    )
+}
+
+/// Grayscale as (r + g + b) / 3, see `grayscale::gray_pixels`
+pub(crate) fn grayscale(src: &[u8], dst: &mut [u8]) -> usize {
+    let len = src.len().min(dst.len()) / 16 * 16;
+
+    // x / 3 == (x * 43691) >> 17 for every x <= 765
+    let third = u32x4_splat(43691);
+
+    for i in (0..len).step_by(16) {
+        // SAFETY: i + 16 <= len, which is within both slices; v128 loads
+        // and stores have no alignment requirement
+        let px = unsafe { v128_load(src.as_ptr().add(i) as *const v128) };
+
+        let (r, g, b, a) = unpack(px);
+        let sum = i32x4_add(i32x4_add(r, g), b);
+        let avg = u32x4_shr(i32x4_mul(sum, third), 17);
+
+        unsafe { v128_store(dst.as_mut_ptr().add(i) as *mut v128, pack(avg, avg, avg, a)) };
+    }
+
+    len
+}
+
+/// Brightness as clamp(c + adjustment, 0, 255), see `brightness::adjust_pixels`
+pub(crate) fn brightness(src: &[u8], dst: &mut [u8], adjustment: f32) -> usize {
+    let len = src.len().min(dst.len()) / 16 * 16;
+
+    let adjustment = f32x4_splat(adjustment);
+    let lo = f32x4_splat(0.0);
+    let hi = f32x4_splat(255.0);
+
+    // Same steps as the scalar `(c as f32 + adj).clamp(0.0, 255.0) as u8`,
+    // including NaN turning into 0 on the saturating truncation
+    let adjust = |c: v128| {
+        let v = f32x4_add(f32x4_convert_u32x4(c), adjustment);
+        i32x4_trunc_sat_f32x4(f32x4_min(f32x4_max(v, lo), hi))
+    };
+
+    for i in (0..len).step_by(16) {
+        // SAFETY: see `grayscale`
+        let px = unsafe { v128_load(src.as_ptr().add(i) as *const v128) };
+
+        let (r, g, b, a) = unpack(px);
+        let out = pack(adjust(r), adjust(g), adjust(b), a);
+
+        unsafe { v128_store(dst.as_mut_ptr().add(i) as *mut v128, out) };
+    }
+
+    len
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::filters::{brightness::adjust_pixels, grayscale::gray_pixels, test_image};
+    use wasm_bindgen_test::*;
+
+    /// Sizes chosen to leave every possible tail length (0-3 pixels)
+    const SIZES: [(u32, u32); 6] = [(1, 1), (3, 2), (5, 3), (16, 9), (67, 45), (255, 7)];
+
+    /// Every byte value in every channel position
+    fn all_values() -> Vec<u8> {
+        (0..=255u8).flat_map(|v| [v, 255 - v, v.wrapping_mul(7), v]).collect()
+    }
+
+    #[wasm_bindgen_test]
+    fn test_grayscale_matches_scalar() {
+        let inputs = SIZES.iter().map(|&(w, h)| test_image(w, h)).chain([all_values()]);
+
+        for data in inputs {
+            let mut simd = vec![0u8; data.len()];
+            let done = grayscale(&data, &mut simd);
+            gray_pixels(&data[done..], &mut simd[done..]);
+
+            let mut scalar = vec![0u8; data.len()];
+            gray_pixels(&data, &mut scalar);
+
+            assert_eq!(simd, scalar);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn test_brightness_matches_scalar() {
+        let adjustments = [-255.0, -100.5, -0.3, 0.0, 0.7, 42.25, 254.99, 255.0, f32::NAN];
+
+        for adjustment in adjustments {
+            let inputs = SIZES.iter().map(|&(w, h)| test_image(w, h)).chain([all_values()]);
+
+            for data in inputs {
+                let mut simd = vec![0u8; data.len()];
+                let done = brightness(&data, &mut simd, adjustment);
+                adjust_pixels(&data[done..], &mut simd[done..], adjustment);
+
+                let mut scalar = vec![0u8; data.len()];
+                adjust_pixels(&data, &mut scalar, adjustment);
+
+                assert_eq!(simd, scalar, "adjustment {}", adjustment);
+            }
+        }
+    }
+}
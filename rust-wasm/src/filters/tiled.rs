@@ -0,0 +1,199 @@
+//! Tiled processing for images too large to hold in memory at once
+//!
+//! The image is split into a grid of core tiles. Each tile is processed
+//! from its input rectangle, the core grown by the filter's halo (clamped
+//! to the image), and only the core of the result is kept. With a halo at
+//! least as wide as the filter's reach, the reassembled tiles are identical
+//! to filtering the whole image; see `blur::halo` and `blur::apply_tile`.
+//!
+//! Per-pixel filters (grayscale, brightness) need no halo and can be run on
+//! each tile directly.
+
+/// Rectangle in image pixel coordinates
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rect {
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+}
+
+/// One tile: the pixels it produces and the pixels it needs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Tile {
+    /// Output area; the cores of all tiles cover the image exactly once
+    pub core: Rect,
+    /// Input area; `core` grown by the halo, clamped to the image
+    pub input: Rect,
+}
+
+impl Rect {
+    /// Right and bottom edges (exclusive), `None` if they overflow
+    fn end(&self) -> Option<(u32, u32)> {
+        Some((self.x.checked_add(self.width)?, self.y.checked_add(self.height)?))
+    }
+}
+
+impl Tile {
+    /// Position of the core inside the input rectangle
+    pub fn core_offset(&self) -> (u32, u32) {
+        (self.core.x - self.input.x, self.core.y - self.input.y)
+    }
+}
+
+/// Split a `width` x `height` image into tiles of (at most) `tile_size` square
+/// with `halo` pixels of context on every side, row by row
+pub fn plan(width: u32, height: u32, tile_size: u32, halo: u32) -> Result<Vec<Tile>, String> {
+    if tile_size == 0 {
+        return Err("Tile size must be at least 1".to_string());
+    }
+
+    let mut tiles = Vec::new();
+
+    for y in (0..height).step_by(tile_size as usize) {
+        for x in (0..width).step_by(tile_size as usize) {
+            let core = Rect {
+                x,
+                y,
+                width: tile_size.min(width - x),
+                height: tile_size.min(height - y),
+            };
+
+            let x0 = x.saturating_sub(halo);
+            let y0 = y.saturating_sub(halo);
+            let x1 = (core.x + core.width).saturating_add(halo).min(width);
+            let y1 = (core.y + core.height).saturating_add(halo).min(height);
+
+            let input = Rect { x: x0, y: y0, width: x1 - x0, height: y1 - y0 };
+            tiles.push(Tile { core, input });
+        }
+    }
+
+    Ok(tiles)
+}
+
+/// Check that `tile` lies within the image and `tile_data` holds its input area
+pub(crate) fn validate(tile_data: &[u8], tile: &Tile, image_width: u32, image_height: u32) -> Result<(), String> {
+    let Tile { core, input } = tile;
+
+    // JS builds tiles itself, so the edges may not fit in u32
+    let Some((input_right, input_bottom)) = input.end().filter(|&(right, bottom)| right <= image_width && bottom <= image_height)
+    else {
+        return Err(format!(
+            "Tile input {}x{} at ({},{}) exceeds image size {}x{}",
+            input.width, input.height, input.x, input.y, image_width, image_height
+        ));
+    };
+
+    let core_inside = core.end().is_some_and(|(right, bottom)| {
+        core.x >= input.x && core.y >= input.y && right <= input_right && bottom <= input_bottom
+    });
+    if !core_inside {
+        return Err("Tile core must lie within its input area".to_string());
+    }
+
+    let expected_len = (input.width as usize).checked_mul(input.height as usize).and_then(|pixels| pixels.checked_mul(4));
+    if expected_len != Some(tile_data.len()) {
+        return Err(format!(
+            "Invalid tile data length: expected {}x{}x4, got {}",
+            input.width,
+            input.height,
+            tile_data.len()
+        ));
+    }
+
+    Ok(())
+}
+
+/// Copy `rect` out of a full RGBA image
+pub fn extract(image_data: &[u8], width: u32, rect: Rect) -> Vec<u8> {
+    let width = width as usize;
+    let (x, w) = (rect.x as usize, rect.width as usize);
+
+    (rect.y as usize..(rect.y + rect.height) as usize)
+        .flat_map(|y| &image_data[(y * width + x) * 4..(y * width + x + w) * 4])
+        .copied()
+        .collect()
+}
+
+/// Copy a `rect`-sized RGBA block into a full image at `rect`'s position
+pub fn paste(image_data: &mut [u8], width: u32, block: &[u8], rect: Rect) {
+    let width = width as usize;
+    let (x, w) = (rect.x as usize, rect.width as usize);
+
+    for (i, row) in block.chunks_exact(w * 4).enumerate() {
+        let y = rect.y as usize + i;
+        image_data[(y * width + x) * 4..(y * width + x + w) * 4].copy_from_slice(row);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cores_cover_image_once() {
+        let tiles = plan(67, 45, 16, 5).unwrap();
+        assert_eq!(tiles.len(), 5 * 3);
+
+        let mut covered = vec![0u8; 67 * 45];
+        for tile in &tiles {
+            for y in tile.core.y..tile.core.y + tile.core.height {
+                for x in tile.core.x..tile.core.x + tile.core.width {
+                    covered[(y * 67 + x) as usize] += 1;
+                }
+            }
+        }
+        assert!(covered.iter().all(|&n| n == 1));
+    }
+
+    #[test]
+    fn test_halo_is_clamped_to_image() {
+        let tiles = plan(40, 40, 16, 5).unwrap();
+
+        // Top-left tile only grows right and down
+        assert_eq!(tiles[0].input, Rect { x: 0, y: 0, width: 21, height: 21 });
+        // Middle tile grows on every side
+        assert_eq!(tiles[4].input, Rect { x: 11, y: 11, width: 26, height: 26 });
+        assert_eq!(tiles[4].core_offset(), (5, 5));
+        // Bottom-right tile is smaller than tile_size
+        assert_eq!(tiles[8].core, Rect { x: 32, y: 32, width: 8, height: 8 });
+    }
+
+    #[test]
+    fn test_extract_paste_roundtrip() {
+        let data = crate::filters::test_image(9, 7);
+        let mut copy = vec![0u8; data.len()];
+
+        for tile in plan(9, 7, 4, 0).unwrap() {
+            paste(&mut copy, 9, &extract(&data, 9, tile.core), tile.core);
+        }
+        assert_eq!(copy, data);
+    }
+
+    #[test]
+    fn test_invalid_plan() {
+        assert!(plan(10, 10, 0, 1).is_err());
+        assert!(plan(0, 10, 4, 1).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_validate_rejects_overflowing_rects() {
+        let rect = |x, y, width, height| Rect { x, y, width, height };
+        let tile = |core, input| Tile { core, input };
+        let data = [0; 4 * 4 * 4];
+
+        assert!(validate(&data, &tile(rect(1, 1, 2, 2), rect(0, 0, 4, 4)), 4, 4).is_ok());
+
+        // x + width and y + height wrap around to 3
+        let err = validate(&data, &tile(rect(0, 0, 1, 1), rect(u32::MAX, 0, 4, 4)), 4, 4).unwrap_err();
+        assert!(err.contains("exceeds image size"), "{}", err);
+        let err = validate(&data, &tile(rect(0, 0, 1, 1), rect(0, u32::MAX, 4, 4)), 4, 4).unwrap_err();
+        assert!(err.contains("exceeds image size"), "{}", err);
+
+        let err = validate(&data, &tile(rect(1, 0, u32::MAX, 1), rect(0, 0, 4, 4)), 4, 4).unwrap_err();
+        assert_eq!(err, "Tile core must lie within its input area");
+        let err = validate(&data, &tile(rect(0, 1, 1, u32::MAX), rect(0, 0, 4, 4)), 4, 4).unwrap_err();
+        assert_eq!(err, "Tile core must lie within its input area");
+    }
+}
@@ -0,0 +1,362 @@
+//! Flips and right-angle rotations folded into a single pass
+//!
+//! Any sequence of flips and quarter-turn rotations is one of the 8
+//! symmetries of a rectangle ([`Dihedral`]). [`compose`] folds the sequence,
+//! and [`apply`] produces the result, optionally cropped, in one pass over
+//! the source, reading only the pixels inside the crop.
+
+use super::crop;
+use super::exec::Exec;
+use super::rotate::transpose_tiled;
+use super::tiled::Rect;
+
+/// A single geometric edit
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Step {
+    FlipHorizontal,
+    FlipVertical,
+    Rotate90Cw,
+    Rotate180,
+    Rotate270Cw,
+}
+
+/// One of the 8 symmetries of a rectangle
+///
+/// Stored as the signed permutation matrix mapping source to output
+/// coordinates; the translation follows from the image size, since the
+/// image always maps onto `[0, width) x [0, height)` of the output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Dihedral {
+    m: [[i8; 2]; 2],
+}
+
+impl Dihedral {
+    pub const IDENTITY: Dihedral = Dihedral { m: [[1, 0], [0, 1]] };
+
+    pub fn from_step(step: Step) -> Self {
+        let m = match step {
+            Step::FlipHorizontal => [[-1, 0], [0, 1]],
+            Step::FlipVertical => [[1, 0], [0, -1]],
+            // new_x = height - 1 - y, new_y = x
+            Step::Rotate90Cw => [[0, -1], [1, 0]],
+            Step::Rotate180 => [[-1, 0], [0, -1]],
+            // new_x = y, new_y = width - 1 - x
+            Step::Rotate270Cw => [[0, 1], [-1, 0]],
+        };
+        Dihedral { m }
+    }
+
+    /// `self` followed by `step`
+    pub fn then(self, step: Step) -> Self {
+        let s = Dihedral::from_step(step).m;
+        let m = self.m;
+        let mut out = [[0; 2]; 2];
+        for (i, row) in out.iter_mut().enumerate() {
+            for (j, cell) in row.iter_mut().enumerate() {
+                *cell = s[i][0] * m[0][j] + s[i][1] * m[1][j];
+            }
+        }
+        Dihedral { m: out }
+    }
+
+    pub fn is_identity(&self) -> bool {
+        *self == Self::IDENTITY
+    }
+
+    /// Whether output x runs along source y (a quarter turn is involved)
+    pub fn swaps_axes(&self) -> bool {
+        self.m[0][0] == 0
+    }
+
+    /// Output size for a `width` x `height` source
+    pub fn output_size(&self, width: u32, height: u32) -> (u32, u32) {
+        if self.swaps_axes() {
+            (height, width)
+        } else {
+            (width, height)
+        }
+    }
+
+    /// Output position of source pixel (x, y)
+    pub fn map_point(&self, x: u32, y: u32, width: u32, height: u32) -> (u32, u32) {
+        let source = [x as i64, y as i64];
+        let size = [width as i64, height as i64];
+
+        let axis = |row: [i8; 2]| {
+            (0..2)
+                .map(|j| match row[j] {
+                    1 => source[j],
+                    -1 => size[j] - 1 - source[j],
+                    _ => 0,
+                })
+                .sum::<i64>() as u32
+        };
+
+        (axis(self.m[0]), axis(self.m[1]))
+    }
+
+    /// Source pixel that ends up at output position (x, y)
+    pub fn source_point(&self, x: u32, y: u32, width: u32, height: u32) -> (u32, u32) {
+        let (out_width, out_height) = self.output_size(width, height);
+        let output = [x as i64, y as i64];
+        let out_size = [out_width as i64, out_height as i64];
+
+        // The inverse of a signed permutation is its transpose
+        let axis = |j: usize| {
+            (0..2)
+                .map(|i| match self.m[i][j] {
+                    1 => output[i],
+                    -1 => out_size[i] - 1 - output[i],
+                    _ => 0,
+                })
+                .sum::<i64>() as u32
+        };
+
+        (axis(0), axis(1))
+    }
+
+    /// Source rectangle whose pixels end up in `rect` of the output
+    ///
+    /// Returns `None` if `rect` is empty or not inside the output.
+    pub fn source_rect(&self, rect: Rect, width: u32, height: u32) -> Option<Rect> {
+        let (out_width, out_height) = self.output_size(width, height);
+        let right = rect.x.checked_add(rect.width).filter(|&r| r <= out_width)?;
+        let bottom = rect.y.checked_add(rect.height).filter(|&b| b <= out_height)?;
+        if rect.width == 0 || rect.height == 0 {
+            return None;
+        }
+
+        let (x0, y0) = self.source_point(rect.x, rect.y, width, height);
+        let (x1, y1) = self.source_point(right - 1, bottom - 1, width, height);
+
+        Some(Rect {
+            x: x0.min(x1),
+            y: y0.min(y1),
+            width: x0.abs_diff(x1) + 1,
+            height: y0.abs_diff(y1) + 1,
+        })
+    }
+}
+
+/// Fold a sequence of steps, applied left to right, into one transform
+pub fn compose(steps: &[Step]) -> Dihedral {
+    steps.iter().fold(Dihedral::IDENTITY, |transform, &step| transform.then(step))
+}
+
+/// Apply `transform`, then crop to `crop` (in output coordinates), in one pass
+pub fn apply(
+    image_data: &[u8],
+    width: u32,
+    height: u32,
+    transform: Dihedral,
+    crop: Option<Rect>,
+) -> Result<Vec<u8>, String> {
+    apply_with(Exec::preferred(), image_data, width, height, transform, crop)
+}
+
+pub(crate) fn apply_with(
+    exec: Exec,
+    image_data: &[u8],
+    width: u32,
+    height: u32,
+    transform: Dihedral,
+    crop: Option<Rect>,
+) -> Result<Vec<u8>, String> {
+    // Validate input
+    let expected_len = (width * height * 4) as usize;
+    if image_data.len() != expected_len {
+        return Err(format!(
+            "Invalid image data length: expected {}, got {}",
+            expected_len,
+            image_data.len()
+        ));
+    }
+
+    let (out_width, out_height) = transform.output_size(width, height);
+    let area = crop.unwrap_or(Rect { x: 0, y: 0, width: out_width, height: out_height });
+    crop::validate_area(out_width, out_height, area.x, area.y, area.width, area.height)?;
+
+    let mut output = vec![0u8; (area.width * area.height * 4) as usize];
+    if output.is_empty() {
+        return Ok(output);
+    }
+
+    let source = |x: usize, y: usize| transform.source_point(area.x + x as u32, area.y + y as u32, width, height);
+
+    if transform.swaps_axes() {
+        // Output rows run along source columns
+        transpose_tiled(exec, image_data, &mut output, area.width as usize, |x, y| {
+            let (sx, sy) = source(x, y);
+            sy as usize * width as usize + sx as usize
+        });
+    } else {
+        // Output rows are (possibly mirrored) pieces of source rows
+        let mirrored = transform.m[0][0] < 0;
+        let row_len = area.width as usize * 4;
+
+        exec.rows(&mut output, row_len, |y, row| {
+            let (x0, sy) = source(0, y);
+            let (x1, _) = source(area.width as usize - 1, y);
+            let start = (sy as usize * width as usize + x0.min(x1) as usize) * 4;
+
+            row.copy_from_slice(&image_data[start..start + row_len]);
+            if mirrored {
+                row.as_chunks_mut::<4>().0.reverse();
+            }
+        });
+    }
+
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_group_has_eight_elements() {
+        let steps = [Step::FlipHorizontal, Step::FlipVertical, Step::Rotate90Cw, Step::Rotate180, Step::Rotate270Cw];
+        let mut seen = vec![Dihedral::IDENTITY];
+        let mut i = 0;
+        while i < seen.len() {
+            for &step in &steps {
+                let next = seen[i].then(step);
+                if !seen.contains(&next) {
+                    seen.push(next);
+                }
+            }
+            i += 1;
+        }
+        assert_eq!(seen.len(), 8);
+    }
+
+    #[test]
+    fn test_known_identities() {
+        use Step::*;
+        assert!(compose(&[Rotate90Cw, Rotate270Cw]).is_identity());
+        assert!(compose(&[FlipHorizontal, FlipHorizontal]).is_identity());
+        assert_eq!(compose(&[FlipHorizontal, FlipVertical]), compose(&[Rotate180]));
+        assert_eq!(compose(&[Rotate90Cw, Rotate90Cw, Rotate90Cw]), compose(&[Rotate270Cw]));
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let transform = compose(&[Step::Rotate90Cw]);
+        assert!(apply(&[0; 4], 2, 2, transform, None).is_err());
+        // 3x2 rotates to 2x3, so a 3-wide crop no longer fits
+        let crop = Rect { x: 0, y: 0, width: 3, height: 1 };
+        assert!(apply(&[0; 3 * 2 * 4], 3, 2, transform, Some(crop)).is_err());
+    }
+
+    #[test]
+    fn test_source_rect_of_empty_or_outside_rect() {
+        let transform = compose(&[Step::Rotate90Cw]);
+        let rect = |x, y, width, height| Rect { x, y, width, height };
+
+        // 3x2 rotates to 2x3; the bottom-left output pixel comes from the bottom-right source pixel
+        assert_eq!(transform.source_rect(rect(0, 2, 1, 1), 3, 2), Some(rect(2, 1, 1, 1)));
+        assert_eq!(transform.source_rect(rect(0, 0, 2, 3), 3, 2), Some(rect(0, 0, 3, 2)));
+
+        assert_eq!(transform.source_rect(rect(0, 0, 0, 1), 3, 2), None);
+        assert_eq!(transform.source_rect(rect(0, 0, 1, 0), 3, 2), None);
+        assert_eq!(transform.source_rect(rect(1, 0, 2, 1), 3, 2), None);
+        assert_eq!(transform.source_rect(rect(0, u32::MAX, 1, 1), 3, 2), None);
+    }
+
+    /// Property tests against the per-step filters
+    #[cfg(not(target_arch = "wasm32"))]
+    mod properties {
+        use super::*;
+        use crate::filters::{flip, rotate, test_image};
+        use proptest::prelude::*;
+
+        /// The step on its own, through the existing per-step filters
+        fn run_step(step: Step, data: &[u8], width: u32, height: u32) -> (Vec<u8>, u32, u32) {
+            match step {
+                Step::FlipHorizontal => (flip::apply_horizontal(data, width, height).unwrap(), width, height),
+                Step::FlipVertical => (flip::apply_vertical(data, width, height).unwrap(), width, height),
+                Step::Rotate90Cw => (rotate::rotate_90_cw(data, width, height).unwrap(), height, width),
+                Step::Rotate180 => (rotate::rotate_180(data, width, height).unwrap(), width, height),
+                Step::Rotate270Cw => (rotate::rotate_270_cw(data, width, height).unwrap(), height, width),
+            }
+        }
+
+        fn step_by_step(steps: &[Step], data: &[u8], width: u32, height: u32) -> (Vec<u8>, u32, u32) {
+            steps
+                .iter()
+                .fold((data.to_vec(), width, height), |(data, w, h), &step| run_step(step, &data, w, h))
+        }
+
+        fn any_step() -> impl Strategy<Value = Step> {
+            prop_oneof![
+                Just(Step::FlipHorizontal),
+                Just(Step::FlipVertical),
+                Just(Step::Rotate90Cw),
+                Just(Step::Rotate180),
+                Just(Step::Rotate270Cw),
+            ]
+        }
+
+        /// Image size, steps, and a crop as fractions of the output size
+        fn any_edit() -> impl Strategy<Value = (u32, u32, Vec<Step>, (f64, f64, f64, f64))> {
+            (1u32..40, 1u32..40, prop::collection::vec(any_step(), 0..8), (0.0..1.0, 0.0..1.0, 0.0..1.0, 0.0..1.0))
+        }
+
+        /// Turn crop fractions into a valid rectangle of a `width` x `height` image
+        fn crop_rect(width: u32, height: u32, (fx, fy, fw, fh): (f64, f64, f64, f64)) -> Rect {
+            let x = (fx * width as f64) as u32;
+            let y = (fy * height as f64) as u32;
+            let w = 1 + (fw * (width - x - 1) as f64) as u32;
+            let h = 1 + (fh * (height - y - 1) as f64) as u32;
+            Rect { x, y, width: w, height: h }
+        }
+
+        proptest! {
+            #[test]
+            fn prop_single_pass_equals_step_by_step((w, h, steps, _) in any_edit()) {
+                let data = test_image(w, h);
+                let (expected, ew, eh) = step_by_step(&steps, &data, w, h);
+
+                let transform = compose(&steps);
+                prop_assert_eq!(transform.output_size(w, h), (ew, eh));
+                prop_assert_eq!(apply(&data, w, h, transform, None).unwrap(), expected);
+            }
+
+            #[test]
+            fn prop_crop_is_remapped_through_steps((w, h, steps, fractions) in any_edit()) {
+                let data = test_image(w, h);
+                let (full, ew, eh) = step_by_step(&steps, &data, w, h);
+                let area = crop_rect(ew, eh, fractions);
+                let expected = crop::apply(&full, ew, eh, area.x, area.y, area.width, area.height).unwrap();
+
+                let transform = compose(&steps);
+                prop_assert_eq!(apply(&data, w, h, transform, Some(area)).unwrap(), expected.clone());
+
+                // Cropping the source first reads only the remapped rectangle
+                let src = transform.source_rect(area, w, h).unwrap();
+                let src_data = crop::apply(&data, w, h, src.x, src.y, src.width, src.height).unwrap();
+                prop_assert_eq!(apply(&src_data, src.width, src.height, transform, None).unwrap(), expected);
+            }
+
+            #[test]
+            fn prop_map_and_source_point_are_inverse((w, h, steps, (fx, fy, _, _)) in any_edit()) {
+                let transform = compose(&steps);
+                let (x, y) = ((fx * w as f64) as u32, (fy * h as f64) as u32);
+                let (ox, oy) = transform.map_point(x, y, w, h);
+                prop_assert_eq!(transform.source_point(ox, oy, w, h), (x, y));
+            }
+        }
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_parallel_matches_serial() {
+        let data = crate::filters::test_image(67, 45);
+        for steps in [vec![Step::FlipHorizontal], vec![Step::Rotate90Cw, Step::FlipVertical]] {
+            let transform = compose(&steps);
+            let serial = apply_with(Exec::Serial, &data, 67, 45, transform, None).unwrap();
+            let parallel = apply_with(Exec::Parallel, &data, 67, 45, transform, None).unwrap();
+            assert_eq!(serial, parallel);
+        }
+    }
+}
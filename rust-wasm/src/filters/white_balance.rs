@@ -0,0 +1,337 @@
+//! White balance: temperature and tint in linear light
+//!
+//! [`WhiteBalance`] scales the linear red, green and blue of every pixel.
+//! Temperature trades red against blue and tint green against magenta; the
+//! gains are normalized so grays keep their luminance. The same settings can
+//! be measured from the image: from a sample that should be neutral
+//! ([`WhiteBalance::neutral_point`]) or automatically with the gray-world or
+//! white-patch assumption ([`WhiteBalance::auto`]).
+
+use super::color::{linear_to_srgb, srgb_to_linear};
+use super::exec::Exec;
+use super::progress::{Error, Progress};
+
+/// Rec. 709 luminance weights of linear red, green and blue
+const LUMA: [f32; 3] = [0.2126, 0.7152, 0.0722];
+
+/// Largest slider value applied; the measured settings stay well inside it
+const MAX_SHIFT: f32 = 4.0;
+
+/// Share of the measured pixels, brightest first, that white-patch averages
+const WHITE_PATCH_SHARE: f32 = 0.01;
+
+/// Temperature and tint correction; the default leaves the image unchanged
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct WhiteBalance {
+    /// Negative is cooler (bluer), positive warmer (yellower); 1.0 doubles
+    /// red against blue
+    pub temperature: f32,
+    /// Negative is greener, positive more magenta; 1.0 halves green against
+    /// red and blue
+    pub tint: f32,
+}
+
+/// Assumption used by [`WhiteBalance::auto`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AutoWhiteBalance {
+    /// The average color of the scene is gray
+    GrayWorld,
+    /// The brightest surfaces are white
+    WhitePatch,
+}
+
+impl AutoWhiteBalance {
+    /// "gray-world" or "white-patch"
+    pub fn from_name(name: &str) -> Result<Self, String> {
+        match name {
+            "gray-world" => Ok(AutoWhiteBalance::GrayWorld),
+            "white-patch" => Ok(AutoWhiteBalance::WhitePatch),
+            _ => Err(format!("Unknown white balance method: {}", name)),
+        }
+    }
+}
+
+impl WhiteBalance {
+    pub fn is_identity(&self) -> bool {
+        self.temperature == 0.0 && self.tint == 0.0
+    }
+
+    /// Linear gains for red, green and blue, with a luminance of 1.0
+    pub fn gains(&self) -> [f32; 3] {
+        let temperature = self.temperature.clamp(-MAX_SHIFT, MAX_SHIFT) / 2.0;
+        let tint = self.tint.clamp(-MAX_SHIFT, MAX_SHIFT);
+        let gains = [temperature.exp2(), (-tint).exp2(), (-temperature).exp2()];
+
+        let luma: f32 = gains.iter().zip(LUMA).map(|(g, w)| g * w).sum();
+        gains.map(|g| g / luma)
+    }
+
+    /// Settings that turn the linear color `rgb` gray
+    pub fn from_neutral(rgb: [f32; 3]) -> Result<Self, String> {
+        // Below 1/1000 of white there is too little signal to judge a color
+        if rgb.iter().any(|&c| c.is_nan() || c < 1e-3) {
+            return Err("Sample is too dark to use as a neutral point".to_string());
+        }
+
+        let [r, g, b] = rgb.map(f32::log2);
+        Ok(WhiteBalance { temperature: b - r, tint: g - (r + b) / 2.0 })
+    }
+
+    /// Eyedropper: settings that make the area around (`x`, `y`) gray
+    ///
+    /// The sample averages the pixels within `radius` in both directions,
+    /// clipped to the image, so noise does not decide the result.
+    pub fn neutral_point(
+        image_data: &[u8],
+        width: u32,
+        height: u32,
+        x: u32,
+        y: u32,
+        radius: u32,
+    ) -> Result<Self, String> {
+        validate(image_data, width, height)?;
+        if x >= width || y >= height {
+            return Err(format!("Sample point ({}, {}) is outside the {}x{} image", x, y, width, height));
+        }
+
+        let (x0, x1) = (x.saturating_sub(radius), x.saturating_add(radius).saturating_add(1).min(width));
+        let (y0, y1) = (y.saturating_sub(radius), y.saturating_add(radius).saturating_add(1).min(height));
+        let row_len = width as usize * 4;
+        let pixels = (y0..y1).flat_map(|sy| {
+            let row = &image_data[sy as usize * row_len..(sy as usize + 1) * row_len];
+            row[x0 as usize * 4..x1 as usize * 4].chunks_exact(4)
+        });
+
+        Self::from_neutral(mean_linear(pixels).ok_or("Sample has no pixels")?)
+    }
+
+    /// Measure settings from the whole image
+    ///
+    /// Transparent pixels are ignored; white-patch also skips pixels with a
+    /// clipped channel, whose color is no longer known.
+    pub fn auto(image_data: &[u8], width: u32, height: u32, method: AutoWhiteBalance) -> Result<Self, String> {
+        validate(image_data, width, height)?;
+        let opaque = || image_data.chunks_exact(4).filter(|px| px[3] > 0);
+
+        let neutral = match method {
+            AutoWhiteBalance::GrayWorld => mean_linear(opaque()),
+            AutoWhiteBalance::WhitePatch => {
+                let unclipped = || opaque().filter(|px| px[..3].iter().all(|&c| c < 255));
+
+                // Brightest pixels by (encoded) luma, at least WHITE_PATCH_SHARE of them
+                let mut histogram = [0usize; 256];
+                for px in unclipped() {
+                    histogram[luma_byte(px)] += 1;
+                }
+                let wanted = ((histogram.iter().sum::<usize>() as f32 * WHITE_PATCH_SHARE).ceil() as usize).max(1);
+                let mut count = 0;
+                let threshold = (0..256).rev().find(|&v| {
+                    count += histogram[v];
+                    count >= wanted
+                });
+
+                threshold.and_then(|t| mean_linear(unclipped().filter(|px| luma_byte(px) >= t)))
+            }
+        };
+
+        Self::from_neutral(neutral.ok_or("Image has no pixels to measure")?)
+    }
+}
+
+pub fn apply(image_data: &[u8], width: u32, height: u32, settings: &WhiteBalance) -> Result<Vec<u8>, String> {
+    apply_with(Exec::preferred(), image_data, width, height, settings, &mut Progress::none()).map_err(|e| e.to_string())
+}
+
+/// [`apply`] with progress reporting and cancellation; progress runs up to `height`
+pub fn apply_tracked(
+    image_data: &[u8],
+    width: u32,
+    height: u32,
+    settings: &WhiteBalance,
+    progress: &mut Progress,
+) -> Result<Vec<u8>, Error> {
+    apply_with(Exec::preferred(), image_data, width, height, settings, progress)
+}
+
+pub(crate) fn apply_with(
+    exec: Exec,
+    image_data: &[u8],
+    width: u32,
+    height: u32,
+    settings: &WhiteBalance,
+    progress: &mut Progress,
+) -> Result<Vec<u8>, Error> {
+    validate(image_data, width, height)?;
+
+    // Each output channel depends only on the same input channel: one table per channel
+    let gains = settings.gains();
+    let tables: [[u8; 256]; 3] =
+        gains.map(|gain| std::array::from_fn(|v| linear_to_srgb(srgb_to_linear(v as u8) * gain)));
+
+    let row_len = width as usize * 4;
+    let mut output = image_data.to_vec();
+    progress.begin(height as usize)?;
+    exec.rows_tracked(&mut output, row_len, progress, |_, row| {
+        for px in row.chunks_exact_mut(4) {
+            for (value, table) in px.iter_mut().zip(&tables) {
+                *value = table[*value as usize];
+            }
+        }
+    })?;
+
+    Ok(output)
+}
+
+/// Average linear color of `pixels`, `None` when there are none
+fn mean_linear<'a>(pixels: impl Iterator<Item = &'a [u8]>) -> Option<[f32; 3]> {
+    let mut sum = [0.0f64; 3];
+    let mut count = 0usize;
+
+    for px in pixels {
+        for (total, &value) in sum.iter_mut().zip(px) {
+            *total += srgb_to_linear(value) as f64;
+        }
+        count += 1;
+    }
+
+    (count > 0).then(|| sum.map(|total| (total / count as f64) as f32))
+}
+
+/// Approximate luma of the encoded values, for ranking pixels by brightness
+fn luma_byte(px: &[u8]) -> usize {
+    (54 * px[0] as usize + 183 * px[1] as usize + 19 * px[2] as usize) >> 8
+}
+
+fn validate(image_data: &[u8], width: u32, height: u32) -> Result<(), String> {
+    let expected_len = (width * height * 4) as usize;
+    if image_data.len() != expected_len {
+        return Err(format!(
+            "Invalid image data length: expected {}, got {}",
+            expected_len,
+            image_data.len()
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::filters::test_image;
+
+    /// Scale the linear channels of every pixel, like a light of that color would
+    fn cast(data: &[u8], gains: [f32; 3]) -> Vec<u8> {
+        data.chunks_exact(4)
+            .flat_map(|px| {
+                let [r, g, b] = std::array::from_fn(|c| linear_to_srgb(srgb_to_linear(px[c]) * gains[c]));
+                [r, g, b, px[3]]
+            })
+            .collect()
+    }
+
+    fn is_gray(px: &[u8], tolerance: u8) -> bool {
+        let (min, max) = (px[..3].iter().min().unwrap(), px[..3].iter().max().unwrap());
+        max - min <= tolerance
+    }
+
+    #[test]
+    fn test_default_is_identity() {
+        let data = test_image(19, 7);
+        assert!(WhiteBalance::default().is_identity());
+        assert_eq!(apply(&data, 19, 7, &WhiteBalance::default()).unwrap(), data);
+    }
+
+    #[test]
+    fn test_temperature_and_tint() {
+        let gray = [128, 128, 128, 255];
+        let warm = apply(&gray, 1, 1, &WhiteBalance { temperature: 0.5, tint: 0.0 }).unwrap();
+        assert!(warm[0] > 128 && warm[2] < 128 && warm[3] == 255, "{:?}", warm);
+
+        let magenta = apply(&gray, 1, 1, &WhiteBalance { temperature: 0.0, tint: 0.5 }).unwrap();
+        assert!(magenta[1] < 128 && magenta[0] > 128 && magenta[2] > 128, "{:?}", magenta);
+
+        // Grays keep their luminance
+        let gains = WhiteBalance { temperature: -0.7, tint: 0.3 }.gains();
+        assert!((gains.iter().zip(LUMA).map(|(g, w)| g * w).sum::<f32>() - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_neutral_point_removes_a_cast() {
+        // Gray card under warm light, surrounded by noise
+        let mut data = test_image(20, 20);
+        for px in data.chunks_exact_mut(4).skip(20 * 5).take(20 * 10) {
+            px.copy_from_slice(&[150, 150, 150, 255]);
+        }
+        let tinted = cast(&data, [1.3, 1.0, 0.6]);
+
+        let settings = WhiteBalance::neutral_point(&tinted, 20, 20, 10, 10, 2).unwrap();
+        assert!(settings.temperature < 0.0, "{:?}", settings);
+        let corrected = apply(&tinted, 20, 20, &settings).unwrap();
+        assert!(is_gray(&corrected[(10 * 20 + 10) * 4..][..4], 1), "{:?}", &corrected[(10 * 20 + 10) * 4..][..4]);
+
+        assert!(WhiteBalance::neutral_point(&tinted, 20, 20, 20, 3, 2).is_err());
+        assert!(WhiteBalance::neutral_point(&[0, 0, 0, 255], 1, 1, 0, 0, 0).is_err());
+
+        // A radius past the image edge samples the whole image, even near u32::MAX
+        let whole = WhiteBalance::neutral_point(&tinted, 20, 20, 10, 10, 20).unwrap();
+        assert_eq!(WhiteBalance::neutral_point(&tinted, 20, 20, 10, 10, u32::MAX).unwrap(), whole);
+    }
+
+    #[test]
+    fn test_auto_white_balance() {
+        // Evenly spread colors average to gray, and the brightest is white
+        let scene: Vec<u8> = (0..64u32)
+            .flat_map(|i| {
+                let v = (i * 3) as u8;
+                [[v, 200 - v / 2, 90, 255], [200 - v / 2, 90, v, 255], [90, v, 200 - v / 2, 255], [200, 200, 200, 255]]
+            })
+            .flatten()
+            .collect();
+        let tinted = cast(&scene, [0.7, 1.0, 1.4]);
+        let (width, height) = (16, 16);
+
+        for method in [AutoWhiteBalance::GrayWorld, AutoWhiteBalance::WhitePatch] {
+            let settings = WhiteBalance::auto(&tinted, width, height, method).unwrap();
+            assert!(settings.temperature > 0.3, "{:?} {:?}", method, settings);
+            let corrected = apply(&tinted, width, height, &settings).unwrap();
+            // The white patches are white again
+            assert!(is_gray(&corrected[12..16], 3), "{:?} {:?}", method, &corrected[12..16]);
+        }
+
+        // Nothing opaque to measure
+        assert!(WhiteBalance::auto(&[10, 20, 30, 0], 1, 1, AutoWhiteBalance::GrayWorld).is_err());
+        assert!(WhiteBalance::auto(&[255, 255, 255, 255], 1, 1, AutoWhiteBalance::WhitePatch).is_err());
+        assert!(AutoWhiteBalance::from_name("retinex").is_err());
+    }
+
+    #[test]
+    fn test_tracked_reports_rows_and_cancels() {
+        use crate::filters::progress::CancelToken;
+
+        let data = test_image(19, 7);
+        let settings = WhiteBalance { temperature: 0.4, tint: -0.2 };
+        let mut reports = Vec::new();
+        let mut progress = Progress::new(3).on_progress(|done, total| reports.push((done, total)));
+        let result = apply_tracked(&data, 19, 7, &settings, &mut progress).unwrap();
+        drop(progress);
+
+        assert_eq!(result, apply(&data, 19, 7, &settings).unwrap());
+        assert_eq!(reports, vec![(3, 7), (6, 7), (7, 7)]);
+
+        let token = CancelToken::new();
+        token.cancel();
+        let mut progress = Progress::new(3).cancel_token(&token);
+        assert_eq!(apply_tracked(&data, 19, 7, &settings, &mut progress), Err(Error::Cancelled));
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_parallel_matches_serial() {
+        let data = test_image(67, 45);
+        let settings = WhiteBalance { temperature: 0.4, tint: -0.2 };
+        assert_eq!(
+            apply_with(Exec::Serial, &data, 67, 45, &settings, &mut Progress::none()).unwrap(),
+            apply_with(Exec::Parallel, &data, 67, 45, &settings, &mut Progress::none()).unwrap()
+        );
+    }
+}
@@ -0,0 +1,314 @@
+//! Incremental re-rendering with cached stage outputs
+//!
+//! [`Renderer`] runs the same stages as [`pipeline::apply`], but keeps the
+//! output of every stage in an LRU cache. A stage's cache key combines its
+//! parameters with the key of its input, so a key identifies the whole chain
+//! up to that stage. Each entry also stores that chain and is only reused
+//! when it matches, so a hash collision costs a recompute rather than
+//! returning the wrong image. On re-render, the longest chain still in the cache is
+//! reused and only the stages after it are computed, e.g. changing only the
+//! blur radius recomputes blur and crop but not grayscale or rotation.
+
+use crate::filters::progress::{Error, Progress};
+use crate::pipeline::{self, FilterState, Image, Stage};
+use std::collections::HashMap;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::sync::Arc;
+
+/// Default memory budget for cached stage outputs
+pub const DEFAULT_BUDGET_BYTES: usize = 256 * 1024 * 1024;
+
+/// What the last render reused and computed
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RenderStats {
+    /// Stages whose output came from the cache
+    pub reused: usize,
+    /// Stages that were run
+    pub computed: usize,
+}
+
+struct Entry {
+    image: Arc<Image>,
+    /// Stages that produced `image` from the source
+    chain: Vec<Stage>,
+    last_used: u64,
+}
+
+/// Pipeline executor for one source image, caching every stage output
+pub struct Renderer {
+    source: Arc<Image>,
+    /// Changes with every new source, so old entries are never matched
+    source_key: u64,
+    entries: HashMap<u64, Entry>,
+    budget_bytes: usize,
+    used_bytes: usize,
+    clock: u64,
+    last_stats: RenderStats,
+}
+
+impl Renderer {
+    pub fn new(source: Image, budget_bytes: usize) -> Self {
+        Renderer {
+            source: Arc::new(source),
+            source_key: 0,
+            entries: HashMap::new(),
+            budget_bytes,
+            used_bytes: 0,
+            clock: 0,
+            last_stats: RenderStats::default(),
+        }
+    }
+
+    /// Replace the source image, dropping everything cached for the old one
+    pub fn set_source(&mut self, source: Image) {
+        self.source = Arc::new(source);
+        self.source_key += 1;
+        self.clear();
+    }
+
+    /// Change the budget, evicting least recently used outputs to fit
+    pub fn set_budget(&mut self, budget_bytes: usize) {
+        self.budget_bytes = budget_bytes;
+        self.evict_to_fit(0);
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.used_bytes = 0;
+    }
+
+    pub fn source(&self) -> &Image {
+        &self.source
+    }
+
+    /// Bytes of pixel data currently cached
+    pub fn cached_bytes(&self) -> usize {
+        self.used_bytes
+    }
+
+    pub fn last_stats(&self) -> RenderStats {
+        self.last_stats
+    }
+
+    /// Render `state`, reusing cached stage outputs where possible
+    pub fn render(&mut self, state: &FilterState) -> Result<Arc<Image>, String> {
+        self.render_tracked(state, &mut Progress::none()).map_err(|e| e.to_string())
+    }
+
+    /// [`Renderer::render`] with progress reporting and cancellation
+    ///
+    /// Progress covers only the stages that are computed. Stages finished
+    /// before a cancellation stay cached for the next render.
+    pub fn render_tracked(&mut self, state: &FilterState, progress: &mut Progress) -> Result<Arc<Image>, Error> {
+        let stages = pipeline::stages(state);
+
+        // Key of each stage's output; keys[0] is the source
+        let mut keys = vec![self.source_key];
+        for stage in &stages {
+            keys.push(stage_key(keys[keys.len() - 1], stage));
+        }
+
+        // Longest cached prefix of the chain
+        let reused = (1..=stages.len())
+            .rev()
+            .find(|&i| self.entries.get(&keys[i]).is_some_and(|entry| entry.chain == stages[..i]))
+            .unwrap_or(0);
+        let mut current = match reused {
+            0 => self.source.clone(),
+            i => self.touch(keys[i]),
+        };
+
+        progress.begin_stages(pipeline::progress_rows(&stages[reused..], current.width, current.height))?;
+        for (i, stage) in stages.iter().enumerate().skip(reused) {
+            current = Arc::new(stage.run_tracked(&current, progress)?);
+            self.insert(keys[i + 1], &stages[..=i], current.clone());
+        }
+
+        self.last_stats = RenderStats { reused, computed: stages.len() - reused };
+        Ok(current)
+    }
+
+    fn touch(&mut self, key: u64) -> Arc<Image> {
+        self.clock += 1;
+        let entry = self.entries.get_mut(&key).expect("touched entry is cached");
+        entry.last_used = self.clock;
+        entry.image.clone()
+    }
+
+    fn insert(&mut self, key: u64, chain: &[Stage], image: Arc<Image>) {
+        let size = image.data.len();
+        if size > self.budget_bytes {
+            return;
+        }
+
+        self.evict_to_fit(size);
+        self.clock += 1;
+        self.used_bytes += size;
+        if let Some(old) = self.entries.insert(key, Entry { image, chain: chain.to_vec(), last_used: self.clock }) {
+            self.used_bytes -= old.image.data.len();
+        }
+    }
+
+    /// Evict least recently used entries until `extra` more bytes fit the budget
+    fn evict_to_fit(&mut self, extra: usize) {
+        while self.used_bytes + extra > self.budget_bytes {
+            let Some((&key, _)) = self.entries.iter().min_by_key(|(_, entry)| entry.last_used) else {
+                break;
+            };
+            let entry = self.entries.remove(&key).expect("key from iteration");
+            self.used_bytes -= entry.image.data.len();
+        }
+    }
+}
+
+/// Cache key of `stage` applied to the input with key `input_key`
+fn stage_key(input_key: u64, stage: &Stage) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    input_key.hash(&mut hasher);
+
+    // f32 parameters hash by bit pattern
+    match *stage {
+        Stage::WhiteBalance(wb) => (7u8, [wb.temperature, wb.tint].map(f32::to_bits)).hash(&mut hasher),
+        Stage::Grayscale => 0u8.hash(&mut hasher),
+        Stage::Brightness(adjustment) => (1u8, adjustment.to_bits()).hash(&mut hasher),
+        Stage::Transform(dihedral, area) => {
+            (2u8, dihedral, area.map(|a| (a.x, a.y, a.width, a.height))).hash(&mut hasher)
+        }
+        Stage::Blur(radius) => (3u8, radius.to_bits()).hash(&mut hasher),
+        Stage::Crop(area) => (4u8, area.x, area.y, area.width, area.height).hash(&mut hasher),
+        Stage::Vignette(v) => {
+            (5u8, [v.amount, v.midpoint, v.roundness, v.feather, v.highlights].map(f32::to_bits)).hash(&mut hasher)
+        }
+        Stage::Grain(g) => (6u8, [g.amount, g.size, g.roughness].map(f32::to_bits), g.seed).hash(&mut hasher),
+    }
+
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::filters::effects::Grain;
+    use crate::filters::test_image;
+    use crate::pipeline::{CropArea, Rotation};
+
+    fn state(blur: f32) -> FilterState {
+        FilterState {
+            grayscale: true,
+            brightness: 15.0,
+            rotation: Rotation::Cw90,
+            blur,
+            crop: Some(CropArea { x: 2, y: 3, width: 20, height: 30 }),
+            ..FilterState::default()
+        }
+    }
+
+    #[test]
+    fn test_only_changed_stages_rerun() {
+        let source = Image::new(40, 35, test_image(40, 35)).unwrap();
+        let mut renderer = Renderer::new(source.clone(), DEFAULT_BUDGET_BYTES);
+
+        renderer.render(&state(2.0)).unwrap();
+        assert_eq!(renderer.last_stats(), RenderStats { reused: 0, computed: 5 });
+
+        // New blur radius: grayscale, brightness and rotation are reused
+        let result = renderer.render(&state(4.0)).unwrap();
+        assert_eq!(renderer.last_stats(), RenderStats { reused: 3, computed: 2 });
+        assert_eq!(*result, pipeline::apply(&source, &state(4.0)).unwrap());
+
+        // Back to the first radius: everything is cached
+        let result = renderer.render(&state(2.0)).unwrap();
+        assert_eq!(renderer.last_stats(), RenderStats { reused: 5, computed: 0 });
+        assert_eq!(*result, pipeline::apply(&source, &state(2.0)).unwrap());
+    }
+
+    #[test]
+    fn test_new_grain_seed_reuses_earlier_stages() {
+        let source = Image::new(40, 35, test_image(40, 35)).unwrap();
+        let mut renderer = Renderer::new(source.clone(), DEFAULT_BUDGET_BYTES);
+        let grainy = |seed| FilterState { grain: Grain { amount: 0.4, seed, ..Grain::default() }, ..state(2.0) };
+
+        renderer.render(&grainy(1)).unwrap();
+        let result = renderer.render(&grainy(2)).unwrap();
+        assert_eq!(renderer.last_stats(), RenderStats { reused: 5, computed: 1 });
+        assert_eq!(*result, pipeline::apply(&source, &grainy(2)).unwrap());
+        assert_ne!(*result, *renderer.render(&grainy(1)).unwrap());
+    }
+
+    #[test]
+    fn test_budget_evicts_least_recently_used() {
+        let source = Image::new(40, 35, test_image(40, 35)).unwrap();
+        let image_bytes = source.data.len();
+
+        // Room for two full-size outputs
+        let mut renderer = Renderer::new(source.clone(), 2 * image_bytes);
+        let gray = FilterState { grayscale: true, ..FilterState::default() };
+        let gray_bright = FilterState { brightness: 10.0, ..gray.clone() };
+        let bright = FilterState { brightness: 10.0, ..FilterState::default() };
+
+        renderer.render(&gray_bright).unwrap();
+        renderer.render(&bright).unwrap();
+        assert!(renderer.cached_bytes() <= 2 * image_bytes);
+
+        // The grayscale output was the least recently used and got evicted
+        renderer.render(&gray).unwrap();
+        assert_eq!(renderer.last_stats(), RenderStats { reused: 0, computed: 1 });
+
+        renderer.set_budget(0);
+        assert_eq!(renderer.cached_bytes(), 0);
+        let result = renderer.render(&gray_bright).unwrap();
+        assert_eq!(*result, pipeline::apply(&source, &gray_bright).unwrap());
+    }
+
+    #[test]
+    fn test_cancelled_render_keeps_finished_stages() {
+        let source = Image::new(40, 35, test_image(40, 35)).unwrap();
+        let mut renderer = Renderer::new(source.clone(), DEFAULT_BUDGET_BYTES);
+
+        // Cancel once the blur has started: grayscale, brightness and rotation
+        // (35 rows each) are done
+        let rows = std::cell::Cell::new(0);
+        let mut progress = Progress::new(5)
+            .on_progress(|done, _| rows.set(done))
+            .cancel_when(|| rows.get() > 3 * 35);
+        assert_eq!(renderer.render_tracked(&state(2.0), &mut progress).err(), Some(Error::Cancelled));
+        drop(progress);
+
+        renderer.render(&state(2.0)).unwrap();
+        assert_eq!(renderer.last_stats(), RenderStats { reused: 3, computed: 2 });
+    }
+
+    #[test]
+    fn test_key_collision_is_not_reused() {
+        let source = Image::new(40, 35, test_image(40, 35)).unwrap();
+        let mut renderer = Renderer::new(source.clone(), DEFAULT_BUDGET_BYTES);
+        let gray = FilterState { grayscale: true, ..FilterState::default() };
+        let bright = FilterState { brightness: 10.0, ..FilterState::default() };
+
+        // Fake a collision: the grayscale output sits under the brightness key
+        renderer.render(&gray).unwrap();
+        let gray_key = stage_key(renderer.source_key, &Stage::Grayscale);
+        let bright_key = stage_key(renderer.source_key, &Stage::Brightness(10.0));
+        let entry = renderer.entries.remove(&gray_key).unwrap();
+        renderer.entries.insert(bright_key, entry);
+
+        let result = renderer.render(&bright).unwrap();
+        assert_eq!(renderer.last_stats(), RenderStats { reused: 0, computed: 1 });
+        assert_eq!(*result, pipeline::apply(&source, &bright).unwrap());
+        assert_eq!(renderer.cached_bytes(), source.data.len());
+    }
+
+    #[test]
+    fn test_new_source_invalidates_cache() {
+        let mut renderer = Renderer::new(Image::new(40, 35, test_image(40, 35)).unwrap(), DEFAULT_BUDGET_BYTES);
+        renderer.render(&state(1.0)).unwrap();
+
+        let other = Image::new(36, 40, test_image(36, 40)).unwrap();
+        renderer.set_source(other.clone());
+        assert_eq!(renderer.cached_bytes(), 0);
+
+        let result = renderer.render(&state(1.0)).unwrap();
+        assert_eq!(renderer.last_stats().computed, 5);
+        assert_eq!(*result, pipeline::apply(&other, &state(1.0)).unwrap());
+    }
+}
@@ -0,0 +1,403 @@
+//! Layer stack with blend modes
+//!
+//! A [`LayerStack`] holds RGBA layers on a fixed-size canvas, bottom first.
+//! Layers keep their own pixels, so opacity, visibility, offset and blend
+//! mode can change at any time without touching the image data.
+//!
+//! [`LayerStack::composite`] works in premultiplied alpha with the separable
+//! blend modes and source-over formula of the W3C Compositing and Blending
+//! spec, the same results as CSS `mix-blend-mode` and canvas
+//! `globalCompositeOperation`. Layer data and the result use straight
+//! (non-premultiplied) alpha like `ImageData`.
+
+use crate::filters::exec::Exec;
+use crate::pipeline::Image;
+
+/// How a layer's colors combine with the layers below it
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BlendMode {
+    #[default]
+    Normal,
+    Multiply,
+    Screen,
+    Overlay,
+    SoftLight,
+    Darken,
+    Lighten,
+    Difference,
+    ColorDodge,
+    ColorBurn,
+}
+
+impl BlendMode {
+    /// CSS name, e.g. "multiply" or "soft-light"
+    pub fn from_name(name: &str) -> Result<Self, String> {
+        match name {
+            "normal" => Ok(BlendMode::Normal),
+            "multiply" => Ok(BlendMode::Multiply),
+            "screen" => Ok(BlendMode::Screen),
+            "overlay" => Ok(BlendMode::Overlay),
+            "soft-light" => Ok(BlendMode::SoftLight),
+            "darken" => Ok(BlendMode::Darken),
+            "lighten" => Ok(BlendMode::Lighten),
+            "difference" => Ok(BlendMode::Difference),
+            "color-dodge" => Ok(BlendMode::ColorDodge),
+            "color-burn" => Ok(BlendMode::ColorBurn),
+            _ => Err(format!("Unknown blend mode: {}", name)),
+        }
+    }
+
+    /// Blended color of `source` over `backdrop`, both straight and in 0.0..=1.0
+    pub fn blend(self, backdrop: f32, source: f32) -> f32 {
+        let (cb, cs) = (backdrop, source);
+
+        match self {
+            BlendMode::Normal => cs,
+            BlendMode::Multiply => cb * cs,
+            BlendMode::Screen => cb + cs - cb * cs,
+            // Hard light with the layers swapped
+            BlendMode::Overlay => {
+                if cb <= 0.5 {
+                    cs * 2.0 * cb
+                } else {
+                    BlendMode::Screen.blend(cs, 2.0 * cb - 1.0)
+                }
+            }
+            BlendMode::SoftLight => {
+                if cs <= 0.5 {
+                    cb - (1.0 - 2.0 * cs) * cb * (1.0 - cb)
+                } else {
+                    let d = if cb <= 0.25 { ((16.0 * cb - 12.0) * cb + 4.0) * cb } else { cb.sqrt() };
+                    cb + (2.0 * cs - 1.0) * (d - cb)
+                }
+            }
+            BlendMode::Darken => cb.min(cs),
+            BlendMode::Lighten => cb.max(cs),
+            BlendMode::Difference => (cb - cs).abs(),
+            BlendMode::ColorDodge => {
+                if cb == 0.0 {
+                    0.0
+                } else if cs >= 1.0 {
+                    1.0
+                } else {
+                    (cb / (1.0 - cs)).min(1.0)
+                }
+            }
+            BlendMode::ColorBurn => {
+                if cb >= 1.0 {
+                    1.0
+                } else if cs == 0.0 {
+                    0.0
+                } else {
+                    1.0 - ((1.0 - cb) / cs).min(1.0)
+                }
+            }
+        }
+    }
+}
+
+/// One layer: its pixels and how they are placed and blended
+#[derive(Debug, Clone, PartialEq)]
+pub struct Layer {
+    pub image: Image,
+    /// Position of the layer's top-left corner on the canvas, may be negative
+    pub x: i32,
+    pub y: i32,
+    /// 0.0 to 1.0, multiplied with the layer's own alpha
+    pub opacity: f32,
+    pub visible: bool,
+    pub blend_mode: BlendMode,
+}
+
+impl Layer {
+    /// Visible, fully opaque layer at the canvas origin with normal blending
+    pub fn new(image: Image) -> Self {
+        Layer { image, x: 0, y: 0, opacity: 1.0, visible: true, blend_mode: BlendMode::Normal }
+    }
+}
+
+/// Layers on a `width` x `height` canvas, index 0 at the bottom
+#[derive(Debug, Clone, PartialEq)]
+pub struct LayerStack {
+    width: u32,
+    height: u32,
+    layers: Vec<Layer>,
+}
+
+impl LayerStack {
+    /// Empty stack; composites to a transparent canvas
+    pub fn new(width: u32, height: u32) -> Self {
+        LayerStack { width, height, layers: Vec::new() }
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Layers from bottom to top
+    pub fn layers(&self) -> &[Layer] {
+        &self.layers
+    }
+
+    pub fn layer_mut(&mut self, index: usize) -> Result<&mut Layer, String> {
+        let count = self.layers.len();
+        self.layers.get_mut(index).ok_or_else(|| no_layer(index, count))
+    }
+
+    /// Add `layer` on top, returning its index
+    pub fn push(&mut self, layer: Layer) -> usize {
+        self.layers.push(layer);
+        self.layers.len() - 1
+    }
+
+    pub fn remove(&mut self, index: usize) -> Result<Layer, String> {
+        if index >= self.layers.len() {
+            return Err(no_layer(index, self.layers.len()));
+        }
+        Ok(self.layers.remove(index))
+    }
+
+    /// Move the layer at `from` to `to`, shifting the layers in between
+    pub fn move_layer(&mut self, from: usize, to: usize) -> Result<(), String> {
+        let count = self.layers.len();
+        if from >= count || to >= count {
+            return Err(no_layer(from.max(to), count));
+        }
+
+        let layer = self.layers.remove(from);
+        self.layers.insert(to, layer);
+        Ok(())
+    }
+
+    /// Flatten the visible layers into one straight-alpha image
+    pub fn composite(&self) -> Image {
+        self.composite_with(Exec::preferred())
+    }
+
+    pub(crate) fn composite_with(&self, exec: Exec) -> Image {
+        let width = self.width as usize;
+        let row_len = width * 4;
+        let mut output = vec![0u8; row_len * self.height as usize];
+
+        let visible: Vec<&Layer> = self.layers.iter().filter(|l| l.visible && l.opacity > 0.0).collect();
+
+        exec.rows(&mut output, row_len, |y, row| {
+            // Premultiplied RGBA of this canvas row, starting transparent
+            let mut canvas = vec![0f32; row_len];
+
+            for layer in &visible {
+                let ly = y as i64 - layer.y as i64;
+                if ly < 0 || ly >= layer.image.height as i64 {
+                    continue;
+                }
+
+                // Canvas columns covered by the layer
+                let x0 = (layer.x as i64).max(0);
+                let x1 = (layer.x as i64 + layer.image.width as i64).min(width as i64);
+                if x0 >= x1 {
+                    continue;
+                }
+
+                let layer_row = ly as usize * layer.image.width as usize * 4;
+                let opacity = layer.opacity.min(1.0);
+                for x in x0 as usize..x1 as usize {
+                    let lx = (x as i64 - layer.x as i64) as usize;
+                    let src = &layer.image.data[layer_row + lx * 4..layer_row + lx * 4 + 4];
+                    blend_over(&mut canvas[x * 4..x * 4 + 4], src, opacity, layer.blend_mode);
+                }
+            }
+
+            for (out, px) in row.chunks_exact_mut(4).zip(canvas.chunks_exact(4)) {
+                store(px, out);
+            }
+        });
+
+        Image { width: self.width, height: self.height, data: output }
+    }
+}
+
+fn no_layer(index: usize, count: usize) -> String {
+    format!("No layer {}, the stack has {} layers", index, count)
+}
+
+/// Composite one straight RGBA pixel onto a premultiplied canvas pixel
+///
+/// co = cs * (1 - ab) + cb * (1 - as) + as * ab * B(Cb, Cs), with cs, cb
+/// premultiplied and Cs, Cb straight; ao = as + ab * (1 - as).
+#[inline]
+fn blend_over(dst: &mut [f32], src: &[u8], opacity: f32, mode: BlendMode) {
+    let sa = src[3] as f32 / 255.0 * opacity;
+    if sa <= 0.0 {
+        return;
+    }
+    let da = dst[3];
+
+    for c in 0..3 {
+        let cs = src[c] as f32 / 255.0;
+        let cb = if da > 0.0 { dst[c] / da } else { 0.0 };
+        dst[c] = sa * (1.0 - da) * cs + (1.0 - sa) * dst[c] + sa * da * mode.blend(cb, cs);
+    }
+    dst[3] = sa + da * (1.0 - sa);
+}
+
+/// Composite straight RGBA `src` at `opacity` onto a straight RGBA pixel in place
+///
+/// Same math as [`LayerStack::composite`], for drawing onto an existing image.
+#[inline]
+pub(crate) fn composite_pixel(dst: &mut [u8], src: &[u8], opacity: f32, mode: BlendMode) {
+    let alpha = dst[3] as f32 / 255.0;
+    let mut px = [0.0; 4];
+    for c in 0..3 {
+        px[c] = dst[c] as f32 / 255.0 * alpha;
+    }
+    px[3] = alpha;
+
+    blend_over(&mut px, src, opacity, mode);
+    store(&px, dst);
+}
+
+/// Premultiplied pixel back to straight bytes; fully transparent pixels are left alone
+#[inline]
+fn store(px: &[f32], out: &mut [u8]) {
+    let alpha = px[3];
+    if alpha > 0.0 {
+        for c in 0..3 {
+            out[c] = to_byte(px[c] / alpha);
+        }
+        out[3] = to_byte(alpha);
+    }
+}
+
+#[inline]
+fn to_byte(value: f32) -> u8 {
+    (value * 255.0).round().clamp(0.0, 255.0) as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::filters::test_image;
+
+    fn solid(width: u32, height: u32, pixel: [u8; 4]) -> Image {
+        Image::new(width, height, pixel.repeat((width * height) as usize)).unwrap()
+    }
+
+    #[test]
+    fn test_single_opaque_layer_is_unchanged() {
+        let mut data = test_image(13, 7);
+        data.iter_mut().skip(3).step_by(4).for_each(|a| *a = 255);
+        let image = Image::new(13, 7, data).unwrap();
+
+        let mut stack = LayerStack::new(13, 7);
+        stack.push(Layer::new(image.clone()));
+        assert_eq!(stack.composite(), image);
+    }
+
+    #[test]
+    fn test_premultiplied_alpha() {
+        // A translucent layer alone keeps its color instead of darkening
+        let mut stack = LayerStack::new(2, 2);
+        stack.push(Layer::new(solid(2, 2, [200, 100, 50, 128])));
+        assert_eq!(stack.composite().data[..4], [200, 100, 50, 128]);
+
+        // Half-opaque blue over opaque red
+        let mut stack = LayerStack::new(2, 2);
+        stack.push(Layer::new(solid(2, 2, [255, 0, 0, 255])));
+        let top = stack.push(Layer::new(solid(2, 2, [0, 0, 255, 255])));
+        stack.layer_mut(top).unwrap().opacity = 0.5;
+        assert_eq!(stack.composite().data[..4], [128, 0, 128, 255]);
+
+        // Two half-transparent layers: alpha 1 - 0.5 * 0.5
+        let mut stack = LayerStack::new(1, 1);
+        stack.push(Layer::new(solid(1, 1, [255, 255, 255, 255])));
+        stack.layer_mut(0).unwrap().opacity = 0.5;
+        stack.push(Layer::new(solid(1, 1, [0, 0, 0, 255])));
+        stack.layer_mut(1).unwrap().opacity = 0.5;
+        assert_eq!(stack.composite().data, [85, 85, 85, 191]);
+    }
+
+    #[test]
+    fn test_blend_modes() {
+        let cases = [
+            (BlendMode::Normal, 0.8),
+            (BlendMode::Multiply, 0.24),
+            (BlendMode::Screen, 0.86),
+            (BlendMode::Overlay, 0.48),
+            (BlendMode::SoftLight, 0.3 + 0.6 * (0.3f32.sqrt() - 0.3)),
+            (BlendMode::Darken, 0.3),
+            (BlendMode::Lighten, 0.8),
+            (BlendMode::Difference, 0.5),
+            (BlendMode::ColorDodge, 1.0),
+            (BlendMode::ColorBurn, 0.125),
+        ];
+        for (mode, expected) in cases {
+            let result = mode.blend(0.3, 0.8);
+            assert!((result - expected).abs() < 1e-5, "{:?}: {}", mode, result);
+        }
+
+        // Opaque layers blend fully: multiply 50% gray with itself
+        let mut stack = LayerStack::new(1, 1);
+        stack.push(Layer::new(solid(1, 1, [128, 128, 128, 255])));
+        let top = stack.push(Layer::new(solid(1, 1, [128, 128, 128, 255])));
+        stack.layer_mut(top).unwrap().blend_mode = BlendMode::Multiply;
+        assert_eq!(stack.composite().data, [64, 64, 64, 255]);
+
+        // Over transparency a blend mode falls back to the layer's own color
+        let mut stack = LayerStack::new(1, 1);
+        let only = stack.push(Layer::new(solid(1, 1, [90, 180, 30, 255])));
+        stack.layer_mut(only).unwrap().blend_mode = BlendMode::Difference;
+        assert_eq!(stack.composite().data, [90, 180, 30, 255]);
+    }
+
+    #[test]
+    fn test_offset_visibility_and_order() {
+        let mut stack = LayerStack::new(4, 3);
+        stack.push(Layer::new(solid(4, 3, [0, 0, 0, 255])));
+        let white = stack.push(Layer::new(solid(2, 2, [255, 255, 255, 255])));
+        // Half outside the canvas: only (3, 2) is covered
+        let layer = stack.layer_mut(white).unwrap();
+        (layer.x, layer.y) = (3, 2);
+
+        let result = stack.composite();
+        for (i, px) in result.data.chunks_exact(4).enumerate() {
+            let expected = if i == 2 * 4 + 3 { 255 } else { 0 };
+            assert_eq!(px, [expected, expected, expected, 255], "pixel {}", i);
+        }
+
+        stack.layer_mut(white).unwrap().visible = false;
+        assert!(stack.composite().data.chunks_exact(4).all(|px| px == [0, 0, 0, 255]));
+
+        // Black on top covers white everywhere
+        stack.layer_mut(white).unwrap().visible = true;
+        stack.move_layer(white, 0).unwrap();
+        assert!(stack.composite().data.chunks_exact(4).all(|px| px == [0, 0, 0, 255]));
+
+        assert!(stack.move_layer(0, 2).is_err());
+        assert!(stack.remove(5).is_err());
+        assert_eq!(stack.remove(0).unwrap().image.width, 2);
+        assert_eq!(stack.layers().len(), 1);
+    }
+
+    #[test]
+    fn test_blend_mode_names() {
+        assert_eq!(BlendMode::from_name("soft-light"), Ok(BlendMode::SoftLight));
+        assert_eq!(BlendMode::from_name("color-dodge"), Ok(BlendMode::ColorDodge));
+        assert!(BlendMode::from_name("hue").is_err());
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_parallel_matches_serial() {
+        let mut stack = LayerStack::new(67, 45);
+        stack.push(Layer::new(Image::new(67, 45, test_image(67, 45)).unwrap()));
+        let top = stack.push(Layer::new(Image::new(40, 30, test_image(40, 30)).unwrap()));
+        let layer = stack.layer_mut(top).unwrap();
+        (layer.x, layer.y, layer.opacity, layer.blend_mode) = (-5, 20, 0.7, BlendMode::Overlay);
+
+        assert_eq!(stack.composite_with(Exec::Serial), stack.composite_with(Exec::Parallel));
+    }
+}
@@ -0,0 +1,408 @@
+//! Native API over the filters, in the same order as the web editor
+//!
+//! [`FilterState`] mirrors `web/src/types/filters.ts` and [`apply`] runs the
+//! steps in the order of the filter pipeline in `App.tsx`, so an edit made in
+//! the browser can be reproduced byte for byte on the server or in the CLI.
+
+use crate::filters::tiled::Rect;
+use crate::filters::transform::{self, Dihedral, Step};
+use crate::filters::effects::{self, Grain, Vignette};
+use crate::filters::white_balance::{self, WhiteBalance};
+use crate::filters::{blur, brightness, crop, grayscale};
+use crate::filters::progress::{Error, Progress};
+
+/// RGBA image with its dimensions
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    pub width: u32,
+    pub height: u32,
+    /// RGBA, 4 bytes per pixel, row-major
+    pub data: Vec<u8>,
+}
+
+impl Image {
+    /// Wrap RGBA data, checking its length against the dimensions
+    pub fn new(width: u32, height: u32, data: Vec<u8>) -> Result<Self, String> {
+        let expected_len = (width * height * 4) as usize;
+        if data.len() != expected_len {
+            return Err(format!(
+                "Invalid image data length: expected {}, got {}",
+                expected_len,
+                data.len()
+            ));
+        }
+
+        Ok(Image { width, height, data })
+    }
+}
+
+/// Clockwise rotation in quarter turns
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Rotation {
+    #[default]
+    None,
+    Cw90,
+    Cw180,
+    Cw270,
+}
+
+impl Rotation {
+    /// 0, 90, 180 or 270 degrees
+    pub fn from_degrees(degrees: u32) -> Result<Self, String> {
+        match degrees {
+            0 => Ok(Rotation::None),
+            90 => Ok(Rotation::Cw90),
+            180 => Ok(Rotation::Cw180),
+            270 => Ok(Rotation::Cw270),
+            _ => Err(format!("Rotation must be 0, 90, 180 or 270 degrees, got {}", degrees)),
+        }
+    }
+}
+
+/// Crop rectangle in the coordinates of the rotated image, as in the web editor
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CropArea {
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+}
+
+/// Every filter setting of the editor; the default leaves the image unchanged
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FilterState {
+    /// Temperature and tint, measured on the unedited image when automatic
+    pub white_balance: WhiteBalance,
+    pub grayscale: bool,
+    /// Blur radius, 0 = off
+    pub blur: f32,
+    /// Brightness adjustment, -255.0 to 255.0, 0 = off
+    pub brightness: f32,
+    pub flip_horizontal: bool,
+    pub flip_vertical: bool,
+    pub rotation: Rotation,
+    pub crop: Option<CropArea>,
+    /// Vignette of the final frame, amount 0 = off
+    pub vignette: Vignette,
+    /// Film grain, amount 0 = off; the seed makes it reproducible
+    pub grain: Grain,
+}
+
+/// One enabled step of the pipeline with its parameters
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Stage {
+    WhiteBalance(WhiteBalance),
+    Grayscale,
+    Brightness(f32),
+    /// Flips and rotation folded into one pass, with the crop when it follows directly
+    Transform(Dihedral, Option<CropArea>),
+    Blur(f32),
+    Crop(CropArea),
+    Vignette(Vignette),
+    Grain(Grain),
+}
+
+impl Stage {
+    /// Run this stage on `image`, returning a new image
+    pub fn run(&self, image: &Image) -> Result<Image, String> {
+        self.run_tracked(image, &mut Progress::none()).map_err(|e| e.to_string())
+    }
+
+    /// [`Stage::run`] with progress reporting and cancellation
+    ///
+    /// White balance and blur report row by row; the other stages are quick
+    /// per-pixel passes and count their rows at once when they are done.
+    pub fn run_tracked(&self, image: &Image, progress: &mut Progress) -> Result<Image, Error> {
+        let Image { width, height, ref data } = *image;
+        let (out_width, out_height) = self.output_size(width, height);
+        let tracked = matches!(self, Stage::WhiteBalance(_) | Stage::Blur(_));
+        if !tracked {
+            progress.begin(height as usize)?;
+        }
+
+        let data = match *self {
+            Stage::WhiteBalance(settings) => white_balance::apply_tracked(data, width, height, &settings, progress)?,
+            Stage::Grayscale => grayscale::apply(data, width, height)?,
+            Stage::Brightness(adjustment) => brightness::apply(data, width, height, adjustment)?,
+            Stage::Transform(dihedral, area) => {
+                let rect = area.map(|a| Rect { x: a.x, y: a.y, width: a.width, height: a.height });
+                transform::apply(data, width, height, dihedral, rect)?
+            }
+            Stage::Blur(radius) => blur::apply_tracked(data, width, height, radius, progress)?,
+            Stage::Crop(area) => crop::apply(data, width, height, area.x, area.y, area.width, area.height)?,
+            Stage::Vignette(settings) => effects::vignette(data, width, height, &settings)?,
+            Stage::Grain(settings) => effects::grain(data, width, height, &settings)?,
+        };
+
+        if !tracked {
+            progress.advance(height as usize)?;
+        }
+        progress.end_stage();
+
+        Ok(Image::new(out_width, out_height, data)?)
+    }
+
+    /// Size of the image this stage returns for a `width` x `height` input
+    pub fn output_size(&self, width: u32, height: u32) -> (u32, u32) {
+        match *self {
+            Stage::Transform(_, Some(area)) | Stage::Crop(area) => (area.width, area.height),
+            Stage::Transform(dihedral, None) => dihedral.output_size(width, height),
+            _ => (width, height),
+        }
+    }
+
+    /// Rows this stage counts in a [`Progress`] for an input `height` rows high
+    fn progress_rows(&self, height: u32) -> usize {
+        match self {
+            // Three box blurs, each a horizontal and a vertical pass
+            Stage::Blur(_) => 6 * height as usize,
+            _ => height as usize,
+        }
+    }
+}
+
+/// The enabled stages of `state`, in pipeline order
+///
+/// Order: white balance, grayscale, brightness, flips, rotation, blur, crop,
+/// vignette, grain. White balance comes first so it corrects the colors as
+/// shot. The flips and rotation run as a single [`Stage::Transform`], which
+/// also takes the crop when no blur sits in between. Vignette and grain come
+/// last so they apply to the final frame.
+pub fn stages(state: &FilterState) -> Vec<Stage> {
+    let mut stages = Vec::new();
+
+    if !state.white_balance.is_identity() {
+        stages.push(Stage::WhiteBalance(state.white_balance));
+    }
+    if state.grayscale {
+        stages.push(Stage::Grayscale);
+    }
+    if state.brightness != 0.0 {
+        stages.push(Stage::Brightness(state.brightness));
+    }
+
+    let dihedral = transform::compose(&geometry_steps(state));
+    let blur = state.blur > 0.0;
+    let mut crop = state.crop;
+
+    if !dihedral.is_identity() {
+        stages.push(Stage::Transform(dihedral, if blur { None } else { crop.take() }));
+    }
+    if blur {
+        stages.push(Stage::Blur(state.blur));
+    }
+    if let Some(area) = crop {
+        stages.push(Stage::Crop(area));
+    }
+    if state.vignette.amount != 0.0 {
+        stages.push(Stage::Vignette(state.vignette));
+    }
+    if state.grain.amount > 0.0 {
+        stages.push(Stage::Grain(state.grain));
+    }
+
+    stages
+}
+
+/// Flips and rotation of `state`, in pipeline order
+fn geometry_steps(state: &FilterState) -> Vec<Step> {
+    let mut steps = Vec::new();
+
+    if state.flip_horizontal {
+        steps.push(Step::FlipHorizontal);
+    }
+    if state.flip_vertical {
+        steps.push(Step::FlipVertical);
+    }
+    match state.rotation {
+        Rotation::None => {}
+        Rotation::Cw90 => steps.push(Step::Rotate90Cw),
+        Rotation::Cw180 => steps.push(Step::Rotate180),
+        Rotation::Cw270 => steps.push(Step::Rotate270Cw),
+    }
+
+    steps
+}
+
+/// Apply every enabled filter of `state` to `image`, see [`stages`] for the order
+pub fn apply(image: &Image, state: &FilterState) -> Result<Image, String> {
+    apply_tracked(image, state, &mut Progress::none()).map_err(|e| e.to_string())
+}
+
+/// [`apply`] with progress reporting and cancellation
+///
+/// Progress runs over the rows of all stages together, see [`progress_rows`].
+pub fn apply_tracked(image: &Image, state: &FilterState, progress: &mut Progress) -> Result<Image, Error> {
+    let stages = stages(state);
+    progress.begin_stages(progress_rows(&stages, image.width, image.height))?;
+
+    let mut current: Option<Image> = None;
+    for stage in &stages {
+        current = Some(stage.run_tracked(current.as_ref().unwrap_or(image), progress)?);
+    }
+
+    Ok(current.unwrap_or_else(|| image.clone()))
+}
+
+/// Rows `stages` count in a [`Progress`] when run in turn on a `width` x `height` image
+///
+/// Each stage counts the rows of its input; blur counts them six times, once
+/// per pass.
+pub fn progress_rows(stages: &[Stage], width: u32, height: u32) -> usize {
+    let mut size = (width, height);
+    stages
+        .iter()
+        .map(|stage| {
+            let rows = stage.progress_rows(size.1);
+            size = stage.output_size(size.0, size.1);
+            rows
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::filters::{flip, rotate, test_image};
+
+    #[test]
+    fn test_default_state_is_identity() {
+        let image = Image::new(5, 3, test_image(5, 3)).unwrap();
+        assert_eq!(apply(&image, &FilterState::default()).unwrap(), image);
+    }
+
+    #[test]
+    fn test_matches_individual_filters() {
+        // Same calls, in the same order, as the web pipeline
+        let (w, h) = (67, 45);
+        let data = test_image(w, h);
+        let mut expected = grayscale::apply(&data, w, h).unwrap();
+        expected = brightness::apply(&expected, w, h, 20.0).unwrap();
+        expected = flip::apply_horizontal(&expected, w, h).unwrap();
+        expected = rotate::rotate_90_cw(&expected, w, h).unwrap();
+        expected = blur::apply(&expected, h, w, 3.0).unwrap();
+        expected = crop::apply(&expected, h, w, 4, 5, 20, 30).unwrap();
+
+        let state = FilterState {
+            grayscale: true,
+            brightness: 20.0,
+            flip_horizontal: true,
+            rotation: Rotation::Cw90,
+            blur: 3.0,
+            crop: Some(CropArea { x: 4, y: 5, width: 20, height: 30 }),
+            ..FilterState::default()
+        };
+        let result = apply(&Image::new(w, h, data).unwrap(), &state).unwrap();
+
+        assert_eq!((result.width, result.height), (20, 30));
+        assert_eq!(result.data, expected);
+    }
+
+    #[test]
+    fn test_effects_run_on_the_cropped_frame() {
+        let (w, h) = (40, 30);
+        let data = test_image(w, h);
+        let vignette = Vignette { amount: -0.5, ..Vignette::default() };
+        let grain = Grain { amount: 0.3, seed: 9, ..Grain::default() };
+
+        let mut expected = crop::apply(&data, w, h, 5, 5, 20, 10).unwrap();
+        expected = effects::vignette(&expected, 20, 10, &vignette).unwrap();
+        expected = effects::grain(&expected, 20, 10, &grain).unwrap();
+
+        let state = FilterState { crop: Some(CropArea { x: 5, y: 5, width: 20, height: 10 }), vignette, grain, ..FilterState::default() };
+        let result = apply(&Image::new(w, h, data).unwrap(), &state).unwrap();
+        assert_eq!(result.data, expected);
+        assert_eq!(stages(&state).len(), 3);
+    }
+
+    #[test]
+    fn test_white_balance_runs_first() {
+        let (w, h) = (30, 20);
+        let data = test_image(w, h);
+        let settings = WhiteBalance { temperature: 0.6, tint: -0.3 };
+
+        let mut expected = white_balance::apply(&data, w, h, &settings).unwrap();
+        expected = grayscale::apply(&expected, w, h).unwrap();
+
+        let state = FilterState { white_balance: settings, grayscale: true, ..FilterState::default() };
+        let result = apply(&Image::new(w, h, data).unwrap(), &state).unwrap();
+        assert_eq!(result.data, expected);
+    }
+
+    #[test]
+    fn test_crop_after_rotation_uses_rotated_size() {
+        // 4x2 rotated to 2x4; a 2x4 crop only fits the rotated image
+        let image = Image::new(4, 2, test_image(4, 2)).unwrap();
+        let state = FilterState {
+            rotation: Rotation::Cw270,
+            crop: Some(CropArea { x: 0, y: 0, width: 2, height: 4 }),
+            ..FilterState::default()
+        };
+        assert!(apply(&image, &state).is_ok());
+    }
+
+    #[test]
+    fn test_geometry_folds_into_one_stage() {
+        // Flip + rotate + crop without blur: a single pass
+        let state = FilterState {
+            flip_horizontal: true,
+            flip_vertical: true,
+            rotation: Rotation::Cw90,
+            crop: Some(CropArea { x: 1, y: 2, width: 10, height: 12 }),
+            ..FilterState::default()
+        };
+        assert_eq!(stages(&state).len(), 1);
+
+        let (w, h) = (23, 17);
+        let data = test_image(w, h);
+        let mut expected = flip::apply_horizontal(&data, w, h).unwrap();
+        expected = flip::apply_vertical(&expected, w, h).unwrap();
+        expected = rotate::rotate_90_cw(&expected, w, h).unwrap();
+        expected = crop::apply(&expected, h, w, 1, 2, 10, 12).unwrap();
+
+        let result = apply(&Image::new(w, h, data).unwrap(), &state).unwrap();
+        assert_eq!(result.data, expected);
+
+        // Flipping both ways and rotating 180 cancels out
+        let identity = FilterState { flip_horizontal: true, flip_vertical: true, rotation: Rotation::Cw180, ..FilterState::default() };
+        assert!(stages(&identity).is_empty());
+    }
+
+    #[test]
+    fn test_tracked_progress_spans_all_stages() {
+        let image = Image::new(30, 20, test_image(30, 20)).unwrap();
+        let state = FilterState {
+            white_balance: WhiteBalance { temperature: 0.3, tint: 0.0 },
+            grayscale: true,
+            rotation: Rotation::Cw90,
+            blur: 2.0,
+            crop: Some(CropArea { x: 1, y: 2, width: 10, height: 12 }),
+            ..FilterState::default()
+        };
+
+        // White balance 20, grayscale 20, rotation 20, blur 6 x 30 (rotated), crop 30
+        let total = progress_rows(&stages(&state), 30, 20);
+        assert_eq!(total, 20 + 20 + 20 + 6 * 30 + 30);
+
+        let mut reports = Vec::new();
+        let mut progress = Progress::new(8).on_progress(|done, total| reports.push((done, total)));
+        let result = apply_tracked(&image, &state, &mut progress).unwrap();
+        drop(progress);
+
+        assert_eq!(result, apply(&image, &state).unwrap());
+        assert!(reports.iter().all(|&(_, t)| t == total));
+        assert!(reports.windows(2).all(|w| w[0].0 <= w[1].0));
+        assert_eq!(reports.last(), Some(&(total, total)));
+
+        // Cancelled in the middle of the blur
+        let mut progress = Progress::new(8).cancel_when(|| true);
+        assert_eq!(apply_tracked(&image, &state, &mut progress), Err(Error::Cancelled));
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        assert!(Image::new(2, 2, vec![0; 4]).is_err());
+        assert!(Rotation::from_degrees(45).is_err());
+        assert_eq!(Rotation::from_degrees(270), Ok(Rotation::Cw270));
+    }
+}
@@ -0,0 +1,284 @@
+//! Proxy rendering on a mipmap pyramid for interactive previews
+//!
+//! [`Pyramid`] keeps the image at full resolution plus successive 2x box
+//! downsamplings. [`render`] picks the smallest level that still covers the
+//! display size and runs the same [`pipeline`] on it, with the resolution
+//! dependent settings (blur radius, crop rectangle, grain size) scaled to
+//! that level. The full-resolution result, scaled down to the same size, then
+//! differs from the proxy only by resampling error, except for grain, which
+//! keeps its size and strength but not its exact pattern.
+
+use crate::filters::blur;
+use crate::filters::effects::Grain;
+use crate::pipeline::{self, CropArea, FilterState, Image, Rotation};
+
+/// Levels are halved until the next one would be smaller than this on its longer side
+pub const MIN_LEVEL_SIZE: u32 = 64;
+
+/// An image and its downsampled levels, largest first
+#[derive(Debug, Clone)]
+pub struct Pyramid {
+    levels: Vec<Image>,
+}
+
+impl Pyramid {
+    pub fn new(image: Image) -> Self {
+        let mut levels = vec![image];
+
+        loop {
+            let last = &levels[levels.len() - 1];
+            if last.width.max(last.height) / 2 < MIN_LEVEL_SIZE || last.width.min(last.height) < 2 {
+                break;
+            }
+            let next = downsample(last);
+            levels.push(next);
+        }
+
+        Pyramid { levels }
+    }
+
+    /// Level 0 is the full-resolution image
+    pub fn levels(&self) -> &[Image] {
+        &self.levels
+    }
+
+    pub fn full(&self) -> &Image {
+        &self.levels[0]
+    }
+
+    /// Smallest level whose rendered output of `state` still covers a
+    /// `max_width` x `max_height` display area when fitted into it
+    pub fn level_for(&self, state: &FilterState, max_width: u32, max_height: u32) -> &Image {
+        let (out_width, out_height) = output_size(self.full(), state);
+
+        // Scale at which the full output fits the display area
+        let needed = (max_width as f64 / out_width.max(1) as f64).min(max_height as f64 / out_height.max(1) as f64);
+
+        self.levels
+            .iter()
+            .rev()
+            .find(|level| level_scale(self.full(), level) >= needed)
+            .unwrap_or(self.full())
+    }
+}
+
+/// Render `state` at preview resolution for a `max_width` x `max_height` display area
+pub fn render(pyramid: &Pyramid, state: &FilterState, max_width: u32, max_height: u32) -> Result<Image, String> {
+    let level = pyramid.level_for(state, max_width, max_height);
+    pipeline::apply(level, &scale_state(state, pyramid.full(), level))
+}
+
+/// `state` with blur radius, crop rectangle and grain size scaled from `full` to `level`
+///
+/// Each level is exactly half of the one above it, as [`downsample`] maps
+/// level pixel `i` to pixels `2i` and `2i + 1` and drops a trailing odd
+/// row/column. The scale is therefore a power of 0.5, not `level.width /
+/// full.width`, which drifts by a pixel near the far edge of odd sizes.
+///
+/// The blur radius is clamped to the full image's limit before scaling, so a
+/// radius beyond it previews as the radius the full render actually uses.
+/// The level's own, proportionally equal limit then trims less than one
+/// proxy pixel.
+pub fn scale_state(state: &FilterState, full: &Image, level: &Image) -> FilterState {
+    let scale = level_scale(full, level);
+
+    // Crop coordinates are in the rotated image, where the axes may be swapped
+    let (level_width, level_height) = match state.rotation {
+        Rotation::Cw90 | Rotation::Cw270 => (level.height, level.width),
+        Rotation::None | Rotation::Cw180 => (level.width, level.height),
+    };
+
+    let crop = state.crop.map(|area| {
+        let x = ((area.x as f64 * scale).floor() as u32).min(level_width - 1);
+        let y = ((area.y as f64 * scale).floor() as u32).min(level_height - 1);
+        let x1 = (((area.x + area.width) as f64 * scale).ceil() as u32).clamp(x + 1, level_width);
+        let y1 = (((area.y + area.height) as f64 * scale).ceil() as u32).clamp(y + 1, level_height);
+        CropArea { x, y, width: x1 - x, height: y1 - y }
+    });
+
+    let scale = scale as f32;
+    FilterState {
+        // Blur runs on the rotated image; the limit takes the smaller side, so the order does not matter
+        blur: blur::resolve_radius(state.blur, full.width, full.height) * scale,
+        crop,
+        grain: Grain { size: state.grain.size * scale, ..state.grain },
+        ..state.clone()
+    }
+}
+
+/// 0.5 per [`downsample`] step from `full` to `level`
+fn level_scale(full: &Image, level: &Image) -> f64 {
+    let mut scale = 1.0;
+    let mut width = full.width;
+    while width > level.width {
+        width = (width / 2).max(1);
+        scale *= 0.5;
+    }
+    scale
+}
+
+/// Size of the pipeline output for `image`
+fn output_size(image: &Image, state: &FilterState) -> (u32, u32) {
+    match (state.crop, state.rotation) {
+        (Some(area), _) => (area.width, area.height),
+        (None, Rotation::Cw90 | Rotation::Cw270) => (image.height, image.width),
+        (None, _) => (image.width, image.height),
+    }
+}
+
+/// Halve both dimensions, averaging 2x2 blocks (a trailing odd row/column is dropped)
+pub fn downsample(image: &Image) -> Image {
+    let width = (image.width / 2).max(1);
+    let height = (image.height / 2).max(1);
+    let src_width = image.width as usize;
+
+    let at = |x: usize, y: usize, c: usize| {
+        let x = x.min(image.width as usize - 1);
+        let y = y.min(image.height as usize - 1);
+        image.data[(y * src_width + x) * 4 + c] as u32
+    };
+
+    let mut data = Vec::with_capacity((width * height * 4) as usize);
+    for y in 0..height as usize {
+        for x in 0..width as usize {
+            for c in 0..4 {
+                let sum = at(2 * x, 2 * y, c) + at(2 * x + 1, 2 * y, c) + at(2 * x, 2 * y + 1, c) + at(2 * x + 1, 2 * y + 1, c);
+                data.push(((sum + 2) / 4) as u8);
+            }
+        }
+    }
+
+    Image { width, height, data }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Smooth RGBA gradient, so resampling error stays small
+    fn gradient(width: u32, height: u32) -> Image {
+        let data = (0..height)
+            .flat_map(|y| (0..width).flat_map(move |x| [(x * 255 / width) as u8, (y * 255 / height) as u8, ((x + y) % 256) as u8, 255]))
+            .collect();
+        Image::new(width, height, data).unwrap()
+    }
+
+    fn mean_abs_diff(a: &Image, b: &Image) -> f64 {
+        assert_eq!((a.width, a.height), (b.width, b.height));
+        let total: u64 = a.data.iter().zip(&b.data).map(|(&x, &y)| (x as i32 - y as i32).unsigned_abs() as u64).sum();
+        total as f64 / a.data.len() as f64
+    }
+
+    #[test]
+    fn test_pyramid_levels() {
+        let pyramid = Pyramid::new(gradient(640, 480));
+        let sizes: Vec<_> = pyramid.levels().iter().map(|l| (l.width, l.height)).collect();
+        assert_eq!(sizes, vec![(640, 480), (320, 240), (160, 120), (80, 60)]);
+    }
+
+    #[test]
+    fn test_level_for_display_size() {
+        let pyramid = Pyramid::new(gradient(640, 480));
+        let state = FilterState::default();
+
+        assert_eq!(pyramid.level_for(&state, 300, 300).width, 320);
+        assert_eq!(pyramid.level_for(&state, 1000, 1000).width, 640);
+        assert_eq!(pyramid.level_for(&state, 10, 10).width, 80);
+
+        // A small crop needs more resolution for the same display size
+        let cropped = FilterState { crop: Some(CropArea { x: 0, y: 0, width: 200, height: 200 }), ..state };
+        assert_eq!(pyramid.level_for(&cropped, 150, 150).width, 640);
+    }
+
+    #[test]
+    fn test_scale_state_with_rotation() {
+        let full = gradient(640, 480);
+        let level = downsample(&full);
+        let state = FilterState {
+            blur: 8.0,
+            rotation: Rotation::Cw90,
+            // In the rotated 480x640 image
+            crop: Some(CropArea { x: 40, y: 100, width: 400, height: 500 }),
+            grain: Grain { amount: 0.5, size: 3.0, ..Grain::default() },
+            ..FilterState::default()
+        };
+
+        let scaled = scale_state(&state, &full, &level);
+        assert_eq!(scaled.blur, 4.0);
+        assert_eq!(scaled.grain, Grain { size: 1.5, ..state.grain });
+        assert_eq!(scaled.crop, Some(CropArea { x: 20, y: 50, width: 200, height: 250 }));
+        assert!(pipeline::apply(&level, &scaled).is_ok());
+    }
+
+    #[test]
+    fn test_scale_state_on_odd_size() {
+        // 641x481 halves to 320x240: level pixel i covers full pixels 2i and 2i + 1
+        let pyramid = Pyramid::new(gradient(641, 481));
+        let (full, level) = (pyramid.full(), &pyramid.levels()[2]);
+        assert_eq!((level.width, level.height), (160, 120));
+
+        let state = FilterState {
+            blur: 4.0,
+            crop: Some(CropArea { x: 600, y: 400, width: 40, height: 80 }),
+            ..FilterState::default()
+        };
+        let scaled = scale_state(&state, full, level);
+        assert_eq!(scaled.blur, 1.0);
+        assert_eq!(scaled.crop, Some(CropArea { x: 150, y: 100, width: 10, height: 20 }));
+
+        let proxy = render(&pyramid, &state, 10, 20).unwrap();
+        assert_eq!((proxy.width, proxy.height), (10, 20));
+        let mut reference = pipeline::apply(full, &state).unwrap();
+        while reference.width > proxy.width {
+            reference = downsample(&reference);
+        }
+        assert!(mean_abs_diff(&proxy, &reference) < 2.0, "{}", mean_abs_diff(&proxy, &reference));
+    }
+
+    #[test]
+    fn test_large_blur_radius_is_clamped_at_full_resolution() {
+        let pyramid = Pyramid::new(gradient(512, 384));
+        let full = pyramid.full();
+
+        // 160 is below the full image's limit (191), 400 beyond it
+        for radius in [160.0, 400.0] {
+            let state = FilterState { blur: radius, ..FilterState::default() };
+            let level = pyramid.level_for(&state, 128, 96);
+            assert_eq!(level.width, 128);
+
+            let scaled = scale_state(&state, full, level);
+            assert_eq!(scaled.blur, radius.min(191.0) / 4.0);
+
+            let proxy = render(&pyramid, &state, 128, 96).unwrap();
+            let mut reference = pipeline::apply(full, &state).unwrap();
+            while reference.width > proxy.width {
+                reference = downsample(&reference);
+            }
+            assert!(mean_abs_diff(&proxy, &reference) < 2.0, "radius {}: {}", radius, mean_abs_diff(&proxy, &reference));
+        }
+    }
+
+    #[test]
+    fn test_proxy_agrees_with_full_resolution() {
+        let pyramid = Pyramid::new(gradient(512, 384));
+        let state = FilterState {
+            grayscale: true,
+            brightness: 30.0,
+            blur: 6.0,
+            flip_horizontal: true,
+            rotation: Rotation::Cw270,
+            crop: Some(CropArea { x: 64, y: 32, width: 256, height: 384 }),
+            ..FilterState::default()
+        };
+
+        let proxy = render(&pyramid, &state, 64, 96).unwrap();
+        assert_eq!((proxy.width, proxy.height), (64, 96));
+
+        let mut full = pipeline::apply(pyramid.full(), &state).unwrap();
+        while full.width > proxy.width {
+            full = downsample(&full);
+        }
+
+        assert!(mean_abs_diff(&proxy, &full) < 2.0, "{}", mean_abs_diff(&proxy, &full));
+    }
+}
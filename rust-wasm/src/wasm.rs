@@ -0,0 +1,1705 @@
+//! wasm-bindgen exports (`wasm` feature)
+//!
+//! Thin wrappers over [`crate::filters`] and [`crate::analysis`] that turn
+//! errors into `JsValue`s and log timing through the browser console.
+
+use wasm_bindgen::prelude::*;
+use log::{info, LevelFilter};
+use std::cell::RefCell;
+use crate::{analysis, draw, filters, incremental, layers, pipeline, preview, watermark};
+
+// Thread pool setup for the `parallel` feature.
+// JS must `await initThreadPool(navigator.hardwareConcurrency)` once after init.
+#[cfg(all(feature = "parallel", target_arch = "wasm32"))]
+pub use wasm_bindgen_rayon::init_thread_pool;
+
+// Note: wee_alloc is removed in favor of default allocator
+// Modern WASM runtime allocators are already quite efficient
+
+/// Initialize the WASM module
+/// This should be called once when the module is loaded
+#[wasm_bindgen(start)]
+pub fn init() {
+    // Set up panic hook for better error messages
+    console_error_panic_hook::set_once();
+
+    // Initialize logger; it accepts every level so `set_log_level` can
+    // raise or lower the limit later, starting from the previous default
+    wasm_logger::init(wasm_logger::Config::new(log::Level::Trace));
+    log::set_max_level(DEFAULT_LOG_LEVEL);
+
+    info!("PixLab WASM module initialized! 🎨");
+}
+
+/// Log level used until `set_log_level` is called
+const DEFAULT_LOG_LEVEL: LevelFilter = LevelFilter::Debug;
+
+/// Change the console log level at runtime
+/// level: "off", "error", "warn", "info", "debug" or "trace" (case-insensitive)
+#[wasm_bindgen]
+pub fn set_log_level(level: &str) -> Result<(), JsValue> {
+    let filter = parse_log_level(level).map_err(|e| JsValue::from_str(&e))?;
+    log::set_max_level(filter);
+    Ok(())
+}
+
+fn parse_log_level(level: &str) -> Result<LevelFilter, String> {
+    level.parse().map_err(|_| format!("Unknown log level: {}", level))
+}
+
+/// Get a greeting message (test function)
+#[wasm_bindgen]
+pub fn greet(name: &str) -> String {
+    info!("Greeting: {}", name);
+    format!("Hello from PixLab, {}! 🚀", name)
+}
+
+/// Convert image to grayscale
+/// mask: optional selection, one byte per pixel (see `Mask.data`)
+#[wasm_bindgen]
+pub fn apply_grayscale(image_data: &[u8], width: u32, height: u32, mask: Option<Vec<u8>>) -> Result<Vec<u8>, JsValue> {
+    let timer = Timer::start("apply_grayscale", width, height);
+    info!("Starting grayscale conversion, size: {} bytes ({}x{})", image_data.len(), width, height);
+
+    let result = mask_from(mask, width, height)
+        .and_then(|mask| filters::grayscale::apply_masked(image_data, width, height, mask.as_ref()))
+        .map_err(|e| JsValue::from_str(&format!("Grayscale error: {}", e)))?;
+
+    if let Some(elapsed) = timer.finish() {
+        info!("Grayscale completed in {:.2}ms", elapsed);
+    }
+
+    Ok(result)
+}
+
+/// Apply Gaussian blur
+///
+/// on_progress: optional `(doneRows, totalRows) => void`, called every `progress_every`
+/// rows (default 64); rows of all 6 blur passes count, so totalRows is 6 x height
+/// cancel: optional `AbortSignal` or `Int32Array` flag, checked between row chunks;
+/// throws `Cancelled` once aborted / once the flag's first element is non-zero.
+/// The call is synchronous, so an `AbortSignal` can only be aborted from inside
+/// `on_progress`; to cancel from another thread (e.g. the page while this runs in
+/// a worker), pass an `Int32Array` over a `SharedArrayBuffer` and `Atomics.store` 1 into it
+/// mask: optional selection, one byte per pixel (see `Mask.data`)
+#[wasm_bindgen]
+#[allow(clippy::too_many_arguments)]
+pub fn apply_blur(
+    image_data: &[u8],
+    width: u32,
+    height: u32,
+    radius: f32,
+    on_progress: Option<js_sys::Function>,
+    progress_every: Option<u32>,
+    cancel: Option<CancelSource>,
+    mask: Option<Vec<u8>>,
+) -> Result<Vec<u8>, JsValue> {
+    let timer = Timer::start("apply_blur", width, height);
+    info!("Starting blur (radius={}), size: {} bytes ({}x{})", radius, image_data.len(), width, height);
+
+    let mask = mask_from(mask, width, height).map_err(|e| JsValue::from_str(&format!("Blur error: {}", e)))?;
+    let mut progress = js_progress(on_progress, progress_every, cancel);
+    let blurred = filters::blur::apply_tracked(image_data, width, height, radius, &mut progress)
+        .map_err(|e| progress_error("apply_blur", "Blur", e))?;
+    let result = filters::mask::select(image_data, blurred, width, height, mask.as_ref())
+        .map_err(|e| JsValue::from_str(&format!("Blur error: {}", e)))?;
+
+    if let Some(elapsed) = timer.finish() {
+        info!("Blur completed in {:.2}ms", elapsed);
+    }
+
+    Ok(result)
+}
+
+/// Apply brightness adjustment
+/// adjustment: -255.0 (darker) to +255.0 (brighter)
+/// mask: optional selection, one byte per pixel (see `Mask.data`)
+#[wasm_bindgen]
+pub fn apply_brightness(
+    image_data: &[u8],
+    width: u32,
+    height: u32,
+    adjustment: f32,
+    mask: Option<Vec<u8>>,
+) -> Result<Vec<u8>, JsValue> {
+    let timer = Timer::start("apply_brightness", width, height);
+    info!("Starting brightness adjustment ({}), size: {} bytes ({}x{})", adjustment, image_data.len(), width, height);
+
+    let result = mask_from(mask, width, height)
+        .and_then(|mask| filters::brightness::apply_masked(image_data, width, height, adjustment, mask.as_ref()))
+        .map_err(|e| JsValue::from_str(&format!("Brightness error: {}", e)))?;
+
+    if let Some(elapsed) = timer.finish() {
+        info!("Brightness adjustment completed in {:.2}ms", elapsed);
+    }
+
+    Ok(result)
+}
+
+/// Apply horizontal flip (mirror left-right)
+/// mask: optional selection, mirrored within its bounding box
+#[wasm_bindgen]
+pub fn apply_flip_horizontal(image_data: &[u8], width: u32, height: u32, mask: Option<Vec<u8>>) -> Result<Vec<u8>, JsValue> {
+    let timer = Timer::start("apply_flip_horizontal", width, height);
+    info!("Starting horizontal flip, size: {} bytes ({}x{})", image_data.len(), width, height);
+
+    let result = mask_from(mask, width, height)
+        .and_then(|mask| filters::flip::apply_horizontal_masked(image_data, width, height, mask.as_ref()))
+        .map_err(|e| JsValue::from_str(&format!("Horizontal flip error: {}", e)))?;
+
+    if let Some(elapsed) = timer.finish() {
+        info!("Horizontal flip completed in {:.2}ms", elapsed);
+    }
+
+    Ok(result)
+}
+
+/// Apply vertical flip (mirror top-bottom)
+/// mask: optional selection, mirrored within its bounding box
+#[wasm_bindgen]
+pub fn apply_flip_vertical(image_data: &[u8], width: u32, height: u32, mask: Option<Vec<u8>>) -> Result<Vec<u8>, JsValue> {
+    let timer = Timer::start("apply_flip_vertical", width, height);
+    info!("Starting vertical flip, size: {} bytes ({}x{})", image_data.len(), width, height);
+
+    let result = mask_from(mask, width, height)
+        .and_then(|mask| filters::flip::apply_vertical_masked(image_data, width, height, mask.as_ref()))
+        .map_err(|e| JsValue::from_str(&format!("Vertical flip error: {}", e)))?;
+
+    if let Some(elapsed) = timer.finish() {
+        info!("Vertical flip completed in {:.2}ms", elapsed);
+    }
+
+    Ok(result)
+}
+
+/// Apply horizontal flip in place on a caller-provided buffer (no output allocation)
+#[wasm_bindgen]
+pub fn apply_flip_horizontal_in_place(image_data: &mut [u8], width: u32, height: u32) -> Result<(), JsValue> {
+    let timer = Timer::start("apply_flip_horizontal_in_place", width, height);
+    info!("Starting in-place horizontal flip, size: {} bytes ({}x{})", image_data.len(), width, height);
+
+    filters::flip::apply_horizontal_in_place(image_data, width, height)
+        .map_err(|e| JsValue::from_str(&format!("Horizontal flip error: {}", e)))?;
+
+    if let Some(elapsed) = timer.finish() {
+        info!("In-place horizontal flip completed in {:.2}ms", elapsed);
+    }
+
+    Ok(())
+}
+
+/// Apply vertical flip in place on a caller-provided buffer (no output allocation)
+#[wasm_bindgen]
+pub fn apply_flip_vertical_in_place(image_data: &mut [u8], width: u32, height: u32) -> Result<(), JsValue> {
+    let timer = Timer::start("apply_flip_vertical_in_place", width, height);
+    info!("Starting in-place vertical flip, size: {} bytes ({}x{})", image_data.len(), width, height);
+
+    filters::flip::apply_vertical_in_place(image_data, width, height)
+        .map_err(|e| JsValue::from_str(&format!("Vertical flip error: {}", e)))?;
+
+    if let Some(elapsed) = timer.finish() {
+        info!("In-place vertical flip completed in {:.2}ms", elapsed);
+    }
+
+    Ok(())
+}
+
+/// Rotate image 90 degrees clockwise
+/// mask: optional selection, turned within its bounding box, which must be square
+#[wasm_bindgen]
+pub fn apply_rotate_90_cw(image_data: &[u8], width: u32, height: u32, mask: Option<Vec<u8>>) -> Result<Vec<u8>, JsValue> {
+    let timer = Timer::start("apply_rotate_90_cw", width, height);
+    info!("Starting 90° CW rotation, size: {} bytes ({}x{})", image_data.len(), width, height);
+
+    let result = mask_from(mask, width, height)
+        .and_then(|mask| filters::rotate::rotate_90_cw_masked(image_data, width, height, mask.as_ref()))
+        .map_err(|e| JsValue::from_str(&format!("Rotate 90° CW error: {}", e)))?;
+
+    if let Some(elapsed) = timer.finish() {
+        info!("90° CW rotation completed in {:.2}ms", elapsed);
+    }
+
+    Ok(result)
+}
+
+/// Rotate image 180 degrees
+/// mask: optional selection, turned within its bounding box
+#[wasm_bindgen]
+pub fn apply_rotate_180(image_data: &[u8], width: u32, height: u32, mask: Option<Vec<u8>>) -> Result<Vec<u8>, JsValue> {
+    let timer = Timer::start("apply_rotate_180", width, height);
+    info!("Starting 180° rotation, size: {} bytes ({}x{})", image_data.len(), width, height);
+
+    let result = mask_from(mask, width, height)
+        .and_then(|mask| filters::rotate::rotate_180_masked(image_data, width, height, mask.as_ref()))
+        .map_err(|e| JsValue::from_str(&format!("Rotate 180° error: {}", e)))?;
+
+    if let Some(elapsed) = timer.finish() {
+        info!("180° rotation completed in {:.2}ms", elapsed);
+    }
+
+    Ok(result)
+}
+
+/// Rotate image 180 degrees in place on a caller-provided buffer (no output allocation)
+#[wasm_bindgen]
+pub fn apply_rotate_180_in_place(image_data: &mut [u8], width: u32, height: u32) -> Result<(), JsValue> {
+    let timer = Timer::start("apply_rotate_180_in_place", width, height);
+    info!("Starting in-place 180° rotation, size: {} bytes ({}x{})", image_data.len(), width, height);
+
+    filters::rotate::rotate_180_in_place(image_data, width, height)
+        .map_err(|e| JsValue::from_str(&format!("Rotate 180° error: {}", e)))?;
+
+    if let Some(elapsed) = timer.finish() {
+        info!("In-place 180° rotation completed in {:.2}ms", elapsed);
+    }
+
+    Ok(())
+}
+
+/// Rotate image 270 degrees clockwise (90 degrees counter-clockwise)
+/// mask: optional selection, turned within its bounding box, which must be square
+#[wasm_bindgen]
+pub fn apply_rotate_270_cw(image_data: &[u8], width: u32, height: u32, mask: Option<Vec<u8>>) -> Result<Vec<u8>, JsValue> {
+    let timer = Timer::start("apply_rotate_270_cw", width, height);
+    info!("Starting 270° CW rotation, size: {} bytes ({}x{})", image_data.len(), width, height);
+
+    let result = mask_from(mask, width, height)
+        .and_then(|mask| filters::rotate::rotate_270_cw_masked(image_data, width, height, mask.as_ref()))
+        .map_err(|e| JsValue::from_str(&format!("Rotate 270° CW error: {}", e)))?;
+
+    if let Some(elapsed) = timer.finish() {
+        info!("270° CW rotation completed in {:.2}ms", elapsed);
+    }
+
+    Ok(result)
+}
+
+/// Crop image to specified rectangle
+/// mask: optional selection over the whole image; unselected pixels of the crop become transparent
+#[wasm_bindgen]
+#[allow(clippy::too_many_arguments)]
+pub fn apply_crop(
+    image_data: &[u8],
+    width: u32,
+    height: u32,
+    x: u32,
+    y: u32,
+    crop_width: u32,
+    crop_height: u32,
+    mask: Option<Vec<u8>>,
+) -> Result<Vec<u8>, JsValue> {
+    let timer = Timer::start("apply_crop", width, height);
+    info!(
+        "Starting crop, size: {} bytes ({}x{}) -> crop at ({},{}) with size {}x{}",
+        image_data.len(), width, height, x, y, crop_width, crop_height
+    );
+
+    let result = mask_from(mask, width, height)
+        .and_then(|mask| {
+            filters::crop::apply_masked(image_data, width, height, x, y, crop_width, crop_height, mask.as_ref())
+        })
+        .map_err(|e| JsValue::from_str(&format!("Crop error: {}", e)))?;
+
+    if let Some(elapsed) = timer.finish() {
+        info!("Crop completed in {:.2}ms", elapsed);
+    }
+
+    Ok(result)
+}
+
+/// Correct white balance in linear light
+/// temperature: negative is cooler, positive warmer; 1.0 doubles red against blue
+/// tint: negative is greener, positive more magenta
+/// on_progress, progress_every, cancel: as for `apply_blur`; totalRows is the height
+#[wasm_bindgen]
+#[allow(clippy::too_many_arguments)]
+pub fn apply_white_balance(
+    image_data: &[u8],
+    width: u32,
+    height: u32,
+    temperature: f32,
+    tint: f32,
+    on_progress: Option<js_sys::Function>,
+    progress_every: Option<u32>,
+    cancel: Option<CancelSource>,
+) -> Result<Vec<u8>, JsValue> {
+    let timer = Timer::start("apply_white_balance", width, height);
+    info!("Starting white balance ({}, {}), size: {} bytes ({}x{})", temperature, tint, image_data.len(), width, height);
+
+    let settings = filters::white_balance::WhiteBalance { temperature, tint };
+    let mut progress = js_progress(on_progress, progress_every, cancel);
+    let result = filters::white_balance::apply_tracked(image_data, width, height, &settings, &mut progress)
+        .map_err(|e| progress_error("apply_white_balance", "White balance", e))?;
+
+    if let Some(elapsed) = timer.finish() {
+        info!("White balance completed in {:.2}ms", elapsed);
+    }
+
+    Ok(result)
+}
+
+/// Eyedropper: white balance that makes the area around (x, y) neutral gray
+///
+/// Returns [temperature, tint] for `apply_white_balance` or the sliders.
+/// radius: pixels averaged around the point in each direction (default 2, a 5x5 sample)
+#[wasm_bindgen]
+pub fn white_balance_from_point(
+    image_data: &[u8],
+    width: u32,
+    height: u32,
+    x: u32,
+    y: u32,
+    radius: Option<u32>,
+) -> Result<Vec<f32>, JsValue> {
+    let settings = filters::white_balance::WhiteBalance::neutral_point(image_data, width, height, x, y, radius.unwrap_or(2))
+        .map_err(white_balance_error)?;
+    info!("Neutral point ({}, {}): temperature {:.3}, tint {:.3}", x, y, settings.temperature, settings.tint);
+
+    Ok(vec![settings.temperature, settings.tint])
+}
+
+/// Automatic white balance, measured on the unedited image
+///
+/// Returns [temperature, tint] for `apply_white_balance` or the sliders.
+/// method: "gray-world" (the average color is gray) or "white-patch" (the brightest surfaces are white)
+#[wasm_bindgen]
+pub fn auto_white_balance(image_data: &[u8], width: u32, height: u32, method: &str) -> Result<Vec<f32>, JsValue> {
+    let timer = Timer::start("auto_white_balance", width, height);
+
+    let method = filters::white_balance::AutoWhiteBalance::from_name(method).map_err(white_balance_error)?;
+    let settings = filters::white_balance::WhiteBalance::auto(image_data, width, height, method).map_err(white_balance_error)?;
+
+    if let Some(elapsed) = timer.finish() {
+        info!("Auto white balance ({:?}) in {:.2}ms: temperature {:.3}, tint {:.3}", method, elapsed, settings.temperature, settings.tint);
+    }
+
+    Ok(vec![settings.temperature, settings.tint])
+}
+
+fn white_balance_error(e: String) -> JsValue {
+    JsValue::from_str(&format!("White balance error: {}", e))
+}
+
+/// Apply a vignette
+/// amount: -1.0 (black edges) to 1.0 (white edges)
+/// midpoint: 0.0 (center) to 1.0 (corners), default 0.5
+/// roundness: -1.0 (rounded rectangle) to 1.0 (circle), default 0.0 (image aspect)
+/// feather: 0.0 (hard) to 1.0 (soft), default 0.5
+/// highlights: 0.0 to 1.0, how much bright pixels are spared from darkening, default 0.0
+#[wasm_bindgen]
+#[allow(clippy::too_many_arguments)]
+pub fn apply_vignette(
+    image_data: &[u8],
+    width: u32,
+    height: u32,
+    amount: f32,
+    midpoint: Option<f32>,
+    roundness: Option<f32>,
+    feather: Option<f32>,
+    highlights: Option<f32>,
+) -> Result<Vec<u8>, JsValue> {
+    let timer = Timer::start("apply_vignette", width, height);
+    info!("Starting vignette ({}), size: {} bytes ({}x{})", amount, image_data.len(), width, height);
+
+    let settings = vignette_settings(amount, midpoint, roundness, feather, highlights);
+    let result = filters::effects::vignette(image_data, width, height, &settings)
+        .map_err(|e| JsValue::from_str(&format!("Vignette error: {}", e)))?;
+
+    if let Some(elapsed) = timer.finish() {
+        info!("Vignette completed in {:.2}ms", elapsed);
+    }
+
+    Ok(result)
+}
+
+/// Apply film grain; the same seed always gives the same grain
+/// amount: 0.0 to 1.0
+/// size: grain size in pixels, default 1.0
+/// roughness: 0.0 (soft) to 1.0 (rough), default 0.5
+/// seed: default 0
+#[wasm_bindgen]
+pub fn apply_grain(
+    image_data: &[u8],
+    width: u32,
+    height: u32,
+    amount: f32,
+    size: Option<f32>,
+    roughness: Option<f32>,
+    seed: Option<u32>,
+) -> Result<Vec<u8>, JsValue> {
+    let timer = Timer::start("apply_grain", width, height);
+    info!("Starting grain ({}), size: {} bytes ({}x{})", amount, image_data.len(), width, height);
+
+    let settings = grain_settings(amount, size, roughness, seed);
+    let result = filters::effects::grain(image_data, width, height, &settings)
+        .map_err(|e| JsValue::from_str(&format!("Grain error: {}", e)))?;
+
+    if let Some(elapsed) = timer.finish() {
+        info!("Grain completed in {:.2}ms", elapsed);
+    }
+
+    Ok(result)
+}
+
+/// Vignette settings with the unset ones at their defaults
+fn vignette_settings(
+    amount: f32,
+    midpoint: Option<f32>,
+    roundness: Option<f32>,
+    feather: Option<f32>,
+    highlights: Option<f32>,
+) -> filters::effects::Vignette {
+    let defaults = filters::effects::Vignette::default();
+    filters::effects::Vignette {
+        amount,
+        midpoint: midpoint.unwrap_or(defaults.midpoint),
+        roundness: roundness.unwrap_or(defaults.roundness),
+        feather: feather.unwrap_or(defaults.feather),
+        highlights: highlights.unwrap_or(defaults.highlights),
+    }
+}
+
+/// Grain settings with the unset ones at their defaults
+fn grain_settings(amount: f32, size: Option<f32>, roughness: Option<f32>, seed: Option<u32>) -> filters::effects::Grain {
+    let defaults = filters::effects::Grain::default();
+    filters::effects::Grain {
+        amount,
+        size: size.unwrap_or(defaults.size),
+        roughness: roughness.unwrap_or(defaults.roughness),
+        seed: seed.unwrap_or(defaults.seed),
+    }
+}
+
+/// One tile of a tiled run, see `plan_tiles`
+///
+/// `input_*` is the area to read from the source image, `core_*` the area
+/// this tile's result covers in the output image.
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy)]
+pub struct Tile {
+    pub core_x: u32,
+    pub core_y: u32,
+    pub core_width: u32,
+    pub core_height: u32,
+    pub input_x: u32,
+    pub input_y: u32,
+    pub input_width: u32,
+    pub input_height: u32,
+}
+
+impl From<filters::tiled::Tile> for Tile {
+    fn from(tile: filters::tiled::Tile) -> Self {
+        let filters::tiled::Tile { core, input } = tile;
+        Tile {
+            core_x: core.x,
+            core_y: core.y,
+            core_width: core.width,
+            core_height: core.height,
+            input_x: input.x,
+            input_y: input.y,
+            input_width: input.width,
+            input_height: input.height,
+        }
+    }
+}
+
+impl From<&Tile> for filters::tiled::Tile {
+    fn from(tile: &Tile) -> Self {
+        filters::tiled::Tile {
+            core: filters::tiled::Rect { x: tile.core_x, y: tile.core_y, width: tile.core_width, height: tile.core_height },
+            input: filters::tiled::Rect { x: tile.input_x, y: tile.input_y, width: tile.input_width, height: tile.input_height },
+        }
+    }
+}
+
+/// Split an image into tiles for processing pieces of it at a time
+///
+/// halo: context pixels per side, e.g. from `blur_halo`; 0 for per-pixel filters
+#[wasm_bindgen]
+pub fn plan_tiles(width: u32, height: u32, tile_size: u32, halo: u32) -> Result<Vec<Tile>, JsValue> {
+    let tiles = filters::tiled::plan(width, height, tile_size, halo)
+        .map_err(|e| JsValue::from_str(&format!("Tiling error: {}", e)))?;
+
+    Ok(tiles.into_iter().map(Tile::from).collect())
+}
+
+/// Halo needed by `apply_blur_tile` for a seamless result
+#[wasm_bindgen]
+pub fn blur_halo(image_width: u32, image_height: u32, radius: f32) -> u32 {
+    filters::blur::halo(image_width, image_height, radius)
+}
+
+/// Blur one tile of a large image
+///
+/// tile_data: RGBA pixels of the tile's input area
+/// Returns the RGBA pixels of the tile's core area, to be placed at (core_x, core_y)
+#[wasm_bindgen]
+pub fn apply_blur_tile(
+    tile_data: &[u8],
+    tile: &Tile,
+    image_width: u32,
+    image_height: u32,
+    radius: f32,
+) -> Result<Vec<u8>, JsValue> {
+    let timer = Timer::start("apply_blur_tile", tile.input_width, tile.input_height);
+    info!(
+        "Starting blur tile (radius={}) at ({},{}), size: {} bytes ({}x{})",
+        radius, tile.core_x, tile.core_y, tile_data.len(), tile.input_width, tile.input_height
+    );
+
+    let result = filters::blur::apply_tile(tile_data, &tile.into(), image_width, image_height, radius)
+        .map_err(|e| JsValue::from_str(&format!("Blur error: {}", e)))?;
+
+    if let Some(elapsed) = timer.finish() {
+        info!("Blur tile completed in {:.2}ms", elapsed);
+    }
+
+    Ok(result)
+}
+
+/// Filter settings of the editor, mirrors `FilterState` in `web/src/types/filters.ts`
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FilterSettings {
+    /// White balance, see `apply_white_balance`
+    pub temperature: f32,
+    pub tint: f32,
+    pub grayscale: bool,
+    pub blur: f32,
+    pub brightness: f32,
+    pub flip_horizontal: bool,
+    pub flip_vertical: bool,
+    /// Clockwise degrees: 0, 90, 180 or 270
+    pub rotation: u32,
+    crop: Option<(u32, u32, u32, u32)>,
+    vignette: filters::effects::Vignette,
+    grain: filters::effects::Grain,
+}
+
+#[wasm_bindgen]
+impl FilterSettings {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Crop rectangle in the coordinates of the rotated image
+    pub fn set_crop(&mut self, x: u32, y: u32, width: u32, height: u32) {
+        self.crop = Some((x, y, width, height));
+    }
+
+    pub fn clear_crop(&mut self) {
+        self.crop = None;
+    }
+
+    /// Same parameters as `apply_vignette`; amount 0 turns it off
+    pub fn set_vignette(
+        &mut self,
+        amount: f32,
+        midpoint: Option<f32>,
+        roundness: Option<f32>,
+        feather: Option<f32>,
+        highlights: Option<f32>,
+    ) {
+        self.vignette = vignette_settings(amount, midpoint, roundness, feather, highlights);
+    }
+
+    /// Same parameters as `apply_grain`; amount 0 turns it off
+    pub fn set_grain(&mut self, amount: f32, size: Option<f32>, roughness: Option<f32>, seed: Option<u32>) {
+        self.grain = grain_settings(amount, size, roughness, seed);
+    }
+}
+
+impl TryFrom<&FilterSettings> for pipeline::FilterState {
+    type Error = String;
+
+    fn try_from(settings: &FilterSettings) -> Result<Self, String> {
+        Ok(pipeline::FilterState {
+            white_balance: filters::white_balance::WhiteBalance { temperature: settings.temperature, tint: settings.tint },
+            grayscale: settings.grayscale,
+            blur: settings.blur,
+            brightness: settings.brightness,
+            flip_horizontal: settings.flip_horizontal,
+            flip_vertical: settings.flip_vertical,
+            rotation: pipeline::Rotation::from_degrees(settings.rotation)?,
+            crop: settings.crop.map(|(x, y, width, height)| pipeline::CropArea { x, y, width, height }),
+            vignette: settings.vignette,
+            grain: settings.grain,
+        })
+    }
+}
+
+/// Output of a pipeline render
+#[wasm_bindgen(getter_with_clone)]
+pub struct RenderedImage {
+    pub width: u32,
+    pub height: u32,
+    /// RGBA pixels
+    pub data: Vec<u8>,
+}
+
+impl From<pipeline::Image> for RenderedImage {
+    fn from(image: pipeline::Image) -> Self {
+        RenderedImage { width: image.width, height: image.height, data: image.data }
+    }
+}
+
+/// An image kept in WASM memory with its mipmap pyramid, for fast previews
+///
+/// `render_preview` runs the edits on the smallest level that covers the
+/// display size, with blur radius and crop scaled to it; `render_full`
+/// runs them at full resolution for commit or export.
+#[wasm_bindgen]
+pub struct ProxyImage {
+    pyramid: preview::Pyramid,
+}
+
+#[wasm_bindgen]
+impl ProxyImage {
+    #[wasm_bindgen(constructor)]
+    pub fn new(image_data: Vec<u8>, width: u32, height: u32) -> Result<ProxyImage, JsValue> {
+        let timer = Timer::start("ProxyImage.new", width, height);
+
+        let image = pipeline::Image::new(width, height, image_data)
+            .map_err(|e| JsValue::from_str(&format!("Proxy error: {}", e)))?;
+        let pyramid = preview::Pyramid::new(image);
+
+        if let Some(elapsed) = timer.finish() {
+            info!("Proxy pyramid with {} levels built in {:.2}ms", pyramid.levels().len(), elapsed);
+        }
+
+        Ok(ProxyImage { pyramid })
+    }
+
+    pub fn level_count(&self) -> usize {
+        self.pyramid.levels().len()
+    }
+
+    /// Render for a `max_width` x `max_height` display area
+    pub fn render_preview(&self, settings: &FilterSettings, max_width: u32, max_height: u32) -> Result<RenderedImage, JsValue> {
+        let full = self.pyramid.full();
+        let timer = Timer::start("ProxyImage.render_preview", full.width, full.height);
+
+        let state = settings.try_into().map_err(|e: String| JsValue::from_str(&format!("Preview error: {}", e)))?;
+        let result = preview::render(&self.pyramid, &state, max_width, max_height)
+            .map_err(|e| JsValue::from_str(&format!("Preview error: {}", e)))?;
+
+        if let Some(elapsed) = timer.finish() {
+            info!("Preview rendered at {}x{} in {:.2}ms", result.width, result.height, elapsed);
+        }
+
+        Ok(result.into())
+    }
+
+    /// Render at full resolution
+    ///
+    /// on_progress, progress_every, cancel: as for `apply_blur`; totalRows
+    /// counts the rows of every stage (6 x height for blur)
+    pub fn render_full(
+        &self,
+        settings: &FilterSettings,
+        on_progress: Option<js_sys::Function>,
+        progress_every: Option<u32>,
+        cancel: Option<CancelSource>,
+    ) -> Result<RenderedImage, JsValue> {
+        let full = self.pyramid.full();
+        let timer = Timer::start("ProxyImage.render_full", full.width, full.height);
+
+        let state = settings.try_into().map_err(|e: String| JsValue::from_str(&format!("Render error: {}", e)))?;
+        let mut progress = js_progress(on_progress, progress_every, cancel);
+        let result = pipeline::apply_tracked(full, &state, &mut progress)
+            .map_err(|e| progress_error("ProxyImage.render_full", "Render", e))?;
+
+        if let Some(elapsed) = timer.finish() {
+            info!("Full render completed in {:.2}ms", elapsed);
+        }
+
+        Ok(result.into())
+    }
+}
+
+/// Re-renders edits of one image, recomputing only stages whose settings changed
+///
+/// Every stage output is cached (keyed by its settings and its input) within
+/// a memory budget; least recently used outputs are evicted first.
+#[wasm_bindgen]
+pub struct IncrementalRenderer {
+    renderer: incremental::Renderer,
+}
+
+#[wasm_bindgen]
+impl IncrementalRenderer {
+    /// budget_bytes: memory for cached stage outputs (default 256MB)
+    #[wasm_bindgen(constructor)]
+    pub fn new(image_data: Vec<u8>, width: u32, height: u32, budget_bytes: Option<u32>) -> Result<IncrementalRenderer, JsValue> {
+        let image = pipeline::Image::new(width, height, image_data)
+            .map_err(|e| JsValue::from_str(&format!("Renderer error: {}", e)))?;
+        let budget = budget_bytes.map_or(incremental::DEFAULT_BUDGET_BYTES, |b| b as usize);
+
+        Ok(IncrementalRenderer { renderer: incremental::Renderer::new(image, budget) })
+    }
+
+    /// Replace the source image and drop the cache
+    pub fn set_source(&mut self, image_data: Vec<u8>, width: u32, height: u32) -> Result<(), JsValue> {
+        let image = pipeline::Image::new(width, height, image_data)
+            .map_err(|e| JsValue::from_str(&format!("Renderer error: {}", e)))?;
+        self.renderer.set_source(image);
+        Ok(())
+    }
+
+    pub fn set_budget(&mut self, budget_bytes: u32) {
+        self.renderer.set_budget(budget_bytes as usize);
+    }
+
+    pub fn cached_bytes(&self) -> usize {
+        self.renderer.cached_bytes()
+    }
+
+    pub fn clear(&mut self) {
+        self.renderer.clear();
+    }
+
+    /// on_progress, progress_every, cancel: as for `apply_blur`; totalRows counts
+    /// the rows of the stages that are recomputed. Stages finished before a
+    /// cancellation stay cached.
+    pub fn render(
+        &mut self,
+        settings: &FilterSettings,
+        on_progress: Option<js_sys::Function>,
+        progress_every: Option<u32>,
+        cancel: Option<CancelSource>,
+    ) -> Result<RenderedImage, JsValue> {
+        let source = self.renderer.source();
+        let timer = Timer::start("IncrementalRenderer.render", source.width, source.height);
+
+        let state = settings.try_into().map_err(|e: String| JsValue::from_str(&format!("Render error: {}", e)))?;
+        let mut progress = js_progress(on_progress, progress_every, cancel);
+        let result = self
+            .renderer
+            .render_tracked(&state, &mut progress)
+            .map_err(|e| progress_error("IncrementalRenderer.render", "Render", e))?;
+
+        if let Some(elapsed) = timer.finish() {
+            let stats = self.renderer.last_stats();
+            info!("Render completed in {:.2}ms ({} stages reused, {} computed)", elapsed, stats.reused, stats.computed);
+        }
+
+        Ok(pipeline::Image::clone(&result).into())
+    }
+}
+
+/// Selection mask: one coverage byte per pixel, 0 = unselected, 255 = selected
+///
+/// Build it from shapes, combine and feather it, then pass `data()` as the
+/// `mask` argument of a filter.
+#[wasm_bindgen]
+pub struct Mask {
+    mask: filters::mask::Mask,
+}
+
+#[wasm_bindgen]
+impl Mask {
+    /// Nothing selected
+    #[wasm_bindgen(constructor)]
+    pub fn new(width: u32, height: u32) -> Mask {
+        Mask { mask: filters::mask::Mask::empty(width, height) }
+    }
+
+    /// Everything selected
+    pub fn full(width: u32, height: u32) -> Mask {
+        Mask { mask: filters::mask::Mask::full(width, height) }
+    }
+
+    pub fn rect(width: u32, height: u32, x: u32, y: u32, rect_width: u32, rect_height: u32) -> Mask {
+        let rect = filters::tiled::Rect { x, y, width: rect_width, height: rect_height };
+        Mask { mask: filters::mask::Mask::rect(width, height, rect) }
+    }
+
+    pub fn ellipse(width: u32, height: u32, cx: f32, cy: f32, rx: f32, ry: f32) -> Mask {
+        Mask { mask: filters::mask::Mask::ellipse(width, height, cx, cy, rx, ry) }
+    }
+
+    /// points: flat `[x0, y0, x1, y1, ...]`, closed automatically
+    pub fn polygon(width: u32, height: u32, points: Vec<f32>) -> Result<Mask, JsValue> {
+        if !points.len().is_multiple_of(2) {
+            return Err(mask_error("Polygon points need an x and a y each".to_string()));
+        }
+        let points: Vec<(f32, f32)> = points.chunks_exact(2).map(|p| (p[0], p[1])).collect();
+        Ok(Mask { mask: filters::mask::Mask::polygon(width, height, &points) })
+    }
+
+    pub fn union(&mut self, other: &Mask) -> Result<(), JsValue> {
+        self.mask.union(&other.mask).map_err(mask_error)
+    }
+
+    pub fn subtract(&mut self, other: &Mask) -> Result<(), JsValue> {
+        self.mask.subtract(&other.mask).map_err(mask_error)
+    }
+
+    pub fn intersect(&mut self, other: &Mask) -> Result<(), JsValue> {
+        self.mask.intersect(&other.mask).map_err(mask_error)
+    }
+
+    pub fn invert(&mut self) {
+        self.mask.invert();
+    }
+
+    /// Copy with edges softened by a Gaussian blur of `radius` pixels
+    pub fn feather(&self, radius: f32) -> Mask {
+        Mask { mask: self.mask.feather(radius) }
+    }
+
+    /// `[x, y, width, height]` of the selected area, e.g. for `apply_crop`; undefined if empty
+    pub fn bounds(&self) -> Option<Vec<u32>> {
+        self.mask.bounds().map(|r| vec![r.x, r.y, r.width, r.height])
+    }
+
+    pub fn data(&self) -> Vec<u8> {
+        self.mask.data().to_vec()
+    }
+}
+
+/// Magic wand: select pixels within `tolerance` of the color at (`x`, `y`)
+///
+/// contiguous: only pixels connected to the seed (flood fill), otherwise all matches
+/// color_space: "rgb" (default, tolerance 0-441) or "lab" (CIE76 delta E)
+#[wasm_bindgen]
+#[allow(clippy::too_many_arguments)]
+pub fn magic_wand(
+    image_data: &[u8],
+    width: u32,
+    height: u32,
+    x: u32,
+    y: u32,
+    tolerance: f32,
+    contiguous: bool,
+    color_space: Option<String>,
+) -> Result<Mask, JsValue> {
+    let timer = Timer::start("magic_wand", width, height);
+    info!("Starting magic wand at ({}, {}), tolerance {}", x, y, tolerance);
+
+    let space = match color_space {
+        Some(name) => filters::selection::ColorSpace::from_name(&name).map_err(selection_error)?,
+        None => filters::selection::ColorSpace::Rgb,
+    };
+    let mask = filters::selection::magic_wand(image_data, width, height, x, y, tolerance, contiguous, space)
+        .map_err(selection_error)?;
+
+    if let Some(elapsed) = timer.finish() {
+        info!("Magic wand completed in {:.2}ms", elapsed);
+    }
+
+    Ok(Mask { mask })
+}
+
+/// Hue, saturation and lightness bounds for `select_color_range`; selects everything by default
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy)]
+pub struct ColorRange {
+    /// Center hue in degrees: 0 red, 120 green, 240 blue
+    pub hue: f32,
+    /// Degrees either side of `hue`; 180 covers all hues
+    pub hue_tolerance: f32,
+    pub min_saturation: f32,
+    pub max_saturation: f32,
+    pub min_lightness: f32,
+    pub max_lightness: f32,
+    /// Soft falloff outside the bounds, 0.0 (hard) to 1.0
+    pub fuzziness: f32,
+}
+
+#[wasm_bindgen]
+impl ColorRange {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        filters::selection::HslRange::default().into()
+    }
+}
+
+impl Default for ColorRange {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<filters::selection::HslRange> for ColorRange {
+    fn from(r: filters::selection::HslRange) -> Self {
+        ColorRange {
+            hue: r.hue,
+            hue_tolerance: r.hue_tolerance,
+            min_saturation: r.min_saturation,
+            max_saturation: r.max_saturation,
+            min_lightness: r.min_lightness,
+            max_lightness: r.max_lightness,
+            fuzziness: r.fuzziness,
+        }
+    }
+}
+
+impl From<&ColorRange> for filters::selection::HslRange {
+    fn from(r: &ColorRange) -> Self {
+        filters::selection::HslRange {
+            hue: r.hue,
+            hue_tolerance: r.hue_tolerance,
+            min_saturation: r.min_saturation,
+            max_saturation: r.max_saturation,
+            min_lightness: r.min_lightness,
+            max_lightness: r.max_lightness,
+            fuzziness: r.fuzziness,
+        }
+    }
+}
+
+/// Select pixels by hue, saturation and lightness, soft edged with `fuzziness`
+#[wasm_bindgen]
+pub fn select_color_range(image_data: &[u8], width: u32, height: u32, range: &ColorRange) -> Result<Mask, JsValue> {
+    let timer = Timer::start("select_color_range", width, height);
+    info!("Starting color range selection around hue {}", range.hue);
+
+    let mask = filters::selection::color_range(image_data, width, height, &range.into()).map_err(selection_error)?;
+
+    if let Some(elapsed) = timer.finish() {
+        info!("Color range selection completed in {:.2}ms", elapsed);
+    }
+
+    Ok(Mask { mask })
+}
+
+fn selection_error(e: String) -> JsValue {
+    JsValue::from_str(&format!("Selection error: {}", e))
+}
+
+fn mask_error(e: String) -> JsValue {
+    JsValue::from_str(&format!("Mask error: {}", e))
+}
+
+/// Mask bytes passed to a filter, checked against the image size
+fn mask_from(mask: Option<Vec<u8>>, width: u32, height: u32) -> Result<Option<filters::mask::Mask>, String> {
+    mask.map(|data| filters::mask::Mask::from_data(width, height, data)).transpose()
+}
+
+/// Layers on a fixed-size canvas, composited with blend modes
+///
+/// Index 0 is the bottom layer. Layer data and `composite` output use
+/// straight alpha, like `ImageData`.
+#[wasm_bindgen]
+pub struct LayerStack {
+    stack: layers::LayerStack,
+}
+
+#[wasm_bindgen]
+impl LayerStack {
+    #[wasm_bindgen(constructor)]
+    pub fn new(width: u32, height: u32) -> LayerStack {
+        LayerStack { stack: layers::LayerStack::new(width, height) }
+    }
+
+    pub fn layer_count(&self) -> usize {
+        self.stack.layers().len()
+    }
+
+    /// Add a visible, opaque, normal layer at (0, 0) on top; returns its index
+    pub fn add_layer(&mut self, image_data: Vec<u8>, width: u32, height: u32) -> Result<usize, JsValue> {
+        let image = pipeline::Image::new(width, height, image_data).map_err(layer_error)?;
+        Ok(self.stack.push(layers::Layer::new(image)))
+    }
+
+    /// Replace a layer's pixels, keeping its settings
+    pub fn set_layer_data(&mut self, index: usize, image_data: Vec<u8>, width: u32, height: u32) -> Result<(), JsValue> {
+        let image = pipeline::Image::new(width, height, image_data).map_err(layer_error)?;
+        self.stack.layer_mut(index).map_err(layer_error)?.image = image;
+        Ok(())
+    }
+
+    pub fn remove_layer(&mut self, index: usize) -> Result<(), JsValue> {
+        self.stack.remove(index).map_err(layer_error)?;
+        Ok(())
+    }
+
+    pub fn move_layer(&mut self, from: usize, to: usize) -> Result<(), JsValue> {
+        self.stack.move_layer(from, to).map_err(layer_error)
+    }
+
+    /// opacity: 0.0 to 1.0
+    pub fn set_opacity(&mut self, index: usize, opacity: f32) -> Result<(), JsValue> {
+        self.stack.layer_mut(index).map_err(layer_error)?.opacity = opacity.clamp(0.0, 1.0);
+        Ok(())
+    }
+
+    pub fn set_visible(&mut self, index: usize, visible: bool) -> Result<(), JsValue> {
+        self.stack.layer_mut(index).map_err(layer_error)?.visible = visible;
+        Ok(())
+    }
+
+    /// Position of the layer's top-left corner on the canvas, may be negative
+    pub fn set_offset(&mut self, index: usize, x: i32, y: i32) -> Result<(), JsValue> {
+        let layer = self.stack.layer_mut(index).map_err(layer_error)?;
+        (layer.x, layer.y) = (x, y);
+        Ok(())
+    }
+
+    /// mode: CSS `mix-blend-mode` name: "normal", "multiply", "screen", "overlay",
+    /// "soft-light", "darken", "lighten", "difference", "color-dodge" or "color-burn"
+    pub fn set_blend_mode(&mut self, index: usize, mode: &str) -> Result<(), JsValue> {
+        let mode = layers::BlendMode::from_name(mode).map_err(layer_error)?;
+        self.stack.layer_mut(index).map_err(layer_error)?.blend_mode = mode;
+        Ok(())
+    }
+
+    /// Flatten the visible layers into one image of the canvas size
+    pub fn composite(&self) -> RenderedImage {
+        let timer = Timer::start("LayerStack.composite", self.stack.width(), self.stack.height());
+        let result = self.stack.composite();
+
+        if let Some(elapsed) = timer.finish() {
+            info!("Composited {} layers in {:.2}ms", self.stack.layers().len(), elapsed);
+        }
+
+        result.into()
+    }
+}
+
+fn layer_error(e: String) -> JsValue {
+    JsValue::from_str(&format!("Layer error: {}", e))
+}
+
+/// Fill and outline for the `draw_*` functions; colors are 0xRRGGBBAA, unset means none
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DrawStyle {
+    pub fill: Option<u32>,
+    pub stroke: Option<u32>,
+    /// Stroke width in pixels, centred on the shape's edge
+    pub stroke_width: f32,
+}
+
+#[wasm_bindgen]
+impl DrawStyle {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl From<&DrawStyle> for draw::Style {
+    fn from(s: &DrawStyle) -> Self {
+        draw::Style { fill: s.fill.map(u32::to_be_bytes), stroke: s.stroke.map(u32::to_be_bytes), stroke_width: s.stroke_width }
+    }
+}
+
+/// Anti-aliased line with round caps, drawn in place; color is 0xRRGGBBAA
+#[wasm_bindgen]
+#[allow(clippy::too_many_arguments)]
+pub fn draw_line(
+    image_data: &mut [u8],
+    width: u32,
+    height: u32,
+    x1: f32,
+    y1: f32,
+    x2: f32,
+    y2: f32,
+    color: u32,
+    line_width: f32,
+) -> Result<(), JsValue> {
+    let timer = Timer::start("draw_line", width, height);
+
+    let mut canvas = draw::Canvas::new(image_data, width, height).map_err(draw_error)?;
+    canvas.line((x1, y1), (x2, y2), color.to_be_bytes(), line_width);
+
+    if let Some(elapsed) = timer.finish() {
+        info!("Drew line in {:.2}ms", elapsed);
+    }
+
+    Ok(())
+}
+
+/// Rectangle with its top-left corner at (`x`, `y`), drawn in place
+#[wasm_bindgen]
+#[allow(clippy::too_many_arguments)]
+pub fn draw_rect(
+    image_data: &mut [u8],
+    width: u32,
+    height: u32,
+    x: f32,
+    y: f32,
+    rect_width: f32,
+    rect_height: f32,
+    style: &DrawStyle,
+) -> Result<(), JsValue> {
+    let timer = Timer::start("draw_rect", width, height);
+
+    let mut canvas = draw::Canvas::new(image_data, width, height).map_err(draw_error)?;
+    canvas.rect(x, y, rect_width, rect_height, &style.into());
+
+    if let Some(elapsed) = timer.finish() {
+        info!("Drew rectangle in {:.2}ms", elapsed);
+    }
+
+    Ok(())
+}
+
+/// Ellipse centred on (`cx`, `cy`), drawn in place
+#[wasm_bindgen]
+#[allow(clippy::too_many_arguments)]
+pub fn draw_ellipse(
+    image_data: &mut [u8],
+    width: u32,
+    height: u32,
+    cx: f32,
+    cy: f32,
+    rx: f32,
+    ry: f32,
+    style: &DrawStyle,
+) -> Result<(), JsValue> {
+    let timer = Timer::start("draw_ellipse", width, height);
+
+    let mut canvas = draw::Canvas::new(image_data, width, height).map_err(draw_error)?;
+    canvas.ellipse(cx, cy, rx, ry, &style.into());
+
+    if let Some(elapsed) = timer.finish() {
+        info!("Drew ellipse in {:.2}ms", elapsed);
+    }
+
+    Ok(())
+}
+
+/// Closed polygon drawn in place; points are flat [x0, y0, x1, y1, ...]
+#[wasm_bindgen]
+pub fn draw_polygon(image_data: &mut [u8], width: u32, height: u32, points: Vec<f32>, style: &DrawStyle) -> Result<(), JsValue> {
+    let timer = Timer::start("draw_polygon", width, height);
+
+    if !points.len().is_multiple_of(2) {
+        return Err(draw_error(format!("Odd number of coordinates: {}", points.len())));
+    }
+    let points: Vec<draw::Point> = points.chunks_exact(2).map(|p| (p[0], p[1])).collect();
+
+    let mut canvas = draw::Canvas::new(image_data, width, height).map_err(draw_error)?;
+    canvas.polygon(&points, &style.into());
+
+    if let Some(elapsed) = timer.finish() {
+        info!("Drew polygon with {} points in {:.2}ms", points.len(), elapsed);
+    }
+
+    Ok(())
+}
+
+/// Arrow from (`x1`, `y1`) to a head at (`x2`, `y2`), drawn in place
+///
+/// head_length: pixels (default 4 x line_width)
+#[wasm_bindgen]
+#[allow(clippy::too_many_arguments)]
+pub fn draw_arrow(
+    image_data: &mut [u8],
+    width: u32,
+    height: u32,
+    x1: f32,
+    y1: f32,
+    x2: f32,
+    y2: f32,
+    color: u32,
+    line_width: f32,
+    head_length: Option<f32>,
+) -> Result<(), JsValue> {
+    let timer = Timer::start("draw_arrow", width, height);
+
+    let mut canvas = draw::Canvas::new(image_data, width, height).map_err(draw_error)?;
+    let head_length = head_length.unwrap_or(4.0 * line_width);
+    canvas.arrow((x1, y1), (x2, y2), color.to_be_bytes(), line_width, head_length);
+
+    if let Some(elapsed) = timer.finish() {
+        info!("Drew arrow in {:.2}ms", elapsed);
+    }
+
+    Ok(())
+}
+
+/// A TTF or OTF font for `draw_text`
+#[cfg(feature = "text")]
+#[wasm_bindgen]
+pub struct Font {
+    font: draw::text::Font,
+}
+
+#[cfg(feature = "text")]
+#[wasm_bindgen]
+impl Font {
+    /// Parse the bytes of a .ttf or .otf file
+    #[wasm_bindgen(constructor)]
+    pub fn new(data: Vec<u8>) -> Result<Font, JsValue> {
+        let font = draw::text::Font::from_bytes(data).map_err(draw_error)?;
+        Ok(Font { font })
+    }
+
+    /// [width, height] of `text` at `size` pixels
+    pub fn measure(&self, text: &str, size: f32) -> Vec<f32> {
+        let (width, height) = self.font.measure(text, size);
+        vec![width, height]
+    }
+}
+
+/// Size, color, alignment and rotation for `draw_text`
+#[cfg(feature = "text")]
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy)]
+pub struct TextStyle {
+    style: draw::text::TextStyle,
+}
+
+#[cfg(feature = "text")]
+#[wasm_bindgen]
+impl TextStyle {
+    /// size: pixels; color: 0xRRGGBBAA. Left aligned and unrotated until set.
+    #[wasm_bindgen(constructor)]
+    pub fn new(size: f32, color: u32) -> TextStyle {
+        TextStyle { style: draw::text::TextStyle { size, color: color.to_be_bytes(), ..Default::default() } }
+    }
+
+    /// align: "left", "center" or "right" of the anchor point
+    pub fn set_align(&mut self, align: &str) -> Result<(), JsValue> {
+        self.style.align = draw::text::Align::from_name(align).map_err(draw_error)?;
+        Ok(())
+    }
+
+    /// Clockwise degrees around the anchor point
+    pub fn set_rotation(&mut self, degrees: f32) {
+        self.style.rotation = degrees;
+    }
+}
+
+/// Draw `text` in place with its first baseline through (`x`, `y`); lines split on "\n"
+#[cfg(feature = "text")]
+#[wasm_bindgen]
+#[allow(clippy::too_many_arguments)]
+pub fn draw_text(
+    image_data: &mut [u8],
+    width: u32,
+    height: u32,
+    font: &Font,
+    text: &str,
+    x: f32,
+    y: f32,
+    style: &TextStyle,
+) -> Result<(), JsValue> {
+    let timer = Timer::start("draw_text", width, height);
+
+    let mut canvas = draw::Canvas::new(image_data, width, height).map_err(draw_error)?;
+    canvas.text(&font.font, text, x, y, &style.style);
+
+    if let Some(elapsed) = timer.finish() {
+        info!("Drew {} characters in {:.2}ms", text.chars().count(), elapsed);
+    }
+
+    Ok(())
+}
+
+fn draw_error(e: String) -> JsValue {
+    JsValue::from_str(&format!("Draw error: {}", e))
+}
+
+/// An image or text mark for `apply_watermark`, rendered once and reusable across exports
+///
+/// Bottom-right with a 16px margin, fully opaque and not tiled until set.
+#[wasm_bindgen]
+pub struct Watermark {
+    watermark: watermark::Watermark,
+}
+
+#[wasm_bindgen]
+impl Watermark {
+    /// RGBA `mark` scaled by `scale` and rotated clockwise by `rotation` degrees
+    pub fn image(mark: Vec<u8>, width: u32, height: u32, scale: f32, rotation: f32) -> Result<Watermark, JsValue> {
+        let mark = pipeline::Image::new(width, height, mark).map_err(watermark_error)?;
+        let watermark = watermark::Watermark::from_image(&mark, scale, rotation).map_err(watermark_error)?;
+        Ok(Watermark { watermark })
+    }
+
+    /// `text` in `style`, rotated around the center of the text block
+    #[cfg(feature = "text")]
+    pub fn text(font: &Font, text: &str, style: &TextStyle) -> Result<Watermark, JsValue> {
+        let watermark = watermark::Watermark::from_text(&font.font, text, &style.style).map_err(watermark_error)?;
+        Ok(Watermark { watermark })
+    }
+
+    /// anchor: "top-left", "top", "top-right", "left", "center", "right",
+    /// "bottom-left", "bottom" or "bottom-right"
+    pub fn set_anchor(&mut self, anchor: &str) -> Result<(), JsValue> {
+        self.watermark.placement.anchor = watermark::Anchor::from_name(anchor).map_err(watermark_error)?;
+        Ok(())
+    }
+
+    /// Distance in pixels from the edges next to the anchor
+    pub fn set_margin(&mut self, margin: u32) {
+        self.watermark.placement.margin = margin;
+    }
+
+    /// opacity: 0.0 to 1.0
+    pub fn set_opacity(&mut self, opacity: f32) {
+        self.watermark.placement.opacity = opacity.clamp(0.0, 1.0);
+    }
+
+    /// Repeat the mark over the whole image with `spacing` pixels between copies (default 0)
+    pub fn set_tile(&mut self, tile: bool, spacing: Option<u32>) {
+        self.watermark.placement.tile = tile;
+        self.watermark.placement.spacing = spacing.unwrap_or(0);
+    }
+
+    /// Size of the rendered mark, [width, height]
+    pub fn size(&self) -> Vec<u32> {
+        vec![self.watermark.stamp().width, self.watermark.stamp().height]
+    }
+}
+
+/// Overlay `watermark` on an image, e.g. as the last step before encoding an export
+#[wasm_bindgen]
+pub fn apply_watermark(image_data: &[u8], width: u32, height: u32, watermark: &Watermark) -> Result<Vec<u8>, JsValue> {
+    let timer = Timer::start("apply_watermark", width, height);
+    info!("Starting watermark, size: {} bytes ({}x{})", image_data.len(), width, height);
+
+    let image = pipeline::Image::new(width, height, image_data.to_vec()).map_err(watermark_error)?;
+    let result = watermark.watermark.apply(&image);
+
+    if let Some(elapsed) = timer.finish() {
+        info!("Watermark completed in {:.2}ms", elapsed);
+    }
+
+    Ok(result.data)
+}
+
+fn watermark_error(e: String) -> JsValue {
+    JsValue::from_str(&format!("Watermark error: {}", e))
+}
+
+/// A color lookup table parsed from a `.cube` file, for `apply_lut`
+#[wasm_bindgen]
+pub struct Lut {
+    lut: filters::lut::Lut,
+}
+
+#[wasm_bindgen]
+impl Lut {
+    /// Parse the text of an Adobe or Resolve `.cube` file (1D, 3D or 1D shaper + 3D)
+    #[wasm_bindgen(constructor)]
+    pub fn new(text: &str) -> Result<Lut, JsValue> {
+        let lut = filters::lut::Lut::parse_cube(text).map_err(lut_error)?;
+        Ok(Lut { lut })
+    }
+
+    /// TITLE of the file, if it has one
+    pub fn title(&self) -> Option<String> {
+        self.lut.title().map(str::to_string)
+    }
+}
+
+/// Map an image through a color lookup table
+/// interpolation: "tetrahedral" (default) or "trilinear"
+/// strength: 0.0 (original) to 1.0 (full look), default 1.0
+/// on_progress, progress_every, cancel: as for `apply_blur`; totalRows is the height
+#[wasm_bindgen]
+#[allow(clippy::too_many_arguments)]
+pub fn apply_lut(
+    image_data: &[u8],
+    width: u32,
+    height: u32,
+    lut: &Lut,
+    interpolation: Option<String>,
+    strength: Option<f32>,
+    on_progress: Option<js_sys::Function>,
+    progress_every: Option<u32>,
+    cancel: Option<CancelSource>,
+) -> Result<Vec<u8>, JsValue> {
+    let timer = Timer::start("apply_lut", width, height);
+    info!("Starting LUT, size: {} bytes ({}x{})", image_data.len(), width, height);
+
+    let interpolation = match interpolation {
+        Some(name) => filters::lut::Interpolation::from_name(&name).map_err(lut_error)?,
+        None => filters::lut::Interpolation::default(),
+    };
+    let mut progress = js_progress(on_progress, progress_every, cancel);
+    let result =
+        filters::lut::apply_tracked(image_data, width, height, &lut.lut, interpolation, strength.unwrap_or(1.0), &mut progress)
+            .map_err(|e| progress_error("apply_lut", "LUT", e))?;
+
+    if let Some(elapsed) = timer.finish() {
+        info!("LUT completed in {:.2}ms", elapsed);
+    }
+
+    Ok(result)
+}
+
+fn lut_error(e: String) -> JsValue {
+    JsValue::from_str(&format!("LUT error: {}", e))
+}
+
+/// Compute R, G, B, luminance and alpha histograms in one pass
+///
+/// Returns a `Uint32Array` of 5 x 256 bins in the order R, G, B, luminance, alpha.
+/// stride: sample every n-th pixel in both directions (default 1)
+/// x, y, roi_width, roi_height: optional region of interest, same rules as `apply_crop`
+#[wasm_bindgen]
+#[allow(clippy::too_many_arguments)]
+pub fn compute_histogram(
+    image_data: &[u8],
+    width: u32,
+    height: u32,
+    stride: Option<u32>,
+    x: Option<u32>,
+    y: Option<u32>,
+    roi_width: Option<u32>,
+    roi_height: Option<u32>,
+) -> Result<Vec<u32>, JsValue> {
+    let timer = Timer::start("compute_histogram", width, height);
+    info!("Starting histogram, size: {} bytes ({}x{})", image_data.len(), width, height);
+
+    let region = match (x, y, roi_width, roi_height) {
+        (Some(x), Some(y), Some(width), Some(height)) => Some(analysis::histogram::Region { x, y, width, height }),
+        (None, None, None, None) => None,
+        _ => return Err(JsValue::from_str("Histogram error: region needs x, y, width and height")),
+    };
+
+    let result = analysis::histogram::compute(image_data, width, height, stride.unwrap_or(1), region)
+        .map_err(|e| JsValue::from_str(&format!("Histogram error: {}", e)))?;
+
+    if let Some(elapsed) = timer.finish() {
+        info!("Histogram completed in {:.2}ms ({} samples)", elapsed, result.samples);
+    }
+
+    Ok(result.to_flat())
+}
+
+/// Perceptual hashes of an image as 16-digit hex strings
+#[wasm_bindgen(getter_with_clone)]
+pub struct PerceptualHashes {
+    pub ahash: String,
+    pub dhash: String,
+    pub phash: String,
+}
+
+/// Compute aHash, dHash and pHash for near-duplicate detection
+/// Matches the `hashes` field returned by the image-metadata service
+#[wasm_bindgen]
+pub fn compute_perceptual_hashes(image_data: &[u8], width: u32, height: u32) -> Result<PerceptualHashes, JsValue> {
+    let timer = Timer::start("compute_perceptual_hashes", width, height);
+    info!("Starting perceptual hashing, size: {} bytes ({}x{})", image_data.len(), width, height);
+
+    let hashes = analysis::phash::compute(image_data, width, height)
+        .map_err(|e| JsValue::from_str(&format!("Perceptual hash error: {}", e)))?;
+
+    if let Some(elapsed) = timer.finish() {
+        info!("Perceptual hashing completed in {:.2}ms", elapsed);
+    }
+
+    Ok(PerceptualHashes {
+        ahash: analysis::phash::to_hex(hashes.ahash),
+        dhash: analysis::phash::to_hex(hashes.dhash),
+        phash: analysis::phash::to_hex(hashes.phash),
+    })
+}
+
+/// Hamming distance (0-64) between two hex hashes of the same kind
+#[wasm_bindgen]
+pub fn hamming_distance(hash_a: &str, hash_b: &str) -> Result<u32, JsValue> {
+    let a = analysis::phash::from_hex(hash_a).map_err(|e| JsValue::from_str(&e))?;
+    let b = analysis::phash::from_hex(hash_b).map_err(|e| JsValue::from_str(&e))?;
+    Ok(analysis::phash::hamming_distance(a, b))
+}
+
+/// Whether two pHashes are close enough to flag as a near-duplicate upload
+#[wasm_bindgen]
+pub fn is_near_duplicate(phash_a: &str, phash_b: &str) -> Result<bool, JsValue> {
+    Ok(hamming_distance(phash_a, phash_b)? <= analysis::phash::NEAR_DUPLICATE_THRESHOLD)
+}
+
+/// Default number of rows between progress callbacks
+const DEFAULT_PROGRESS_EVERY: u32 = 64;
+
+#[wasm_bindgen]
+extern "C" {
+    /// `cancel` argument of the exports taking a progress callback
+    #[wasm_bindgen(typescript_type = "AbortSignal | Int32Array")]
+    pub type CancelSource;
+}
+
+/// Thrown when a call is stopped through its `cancel` argument
+/// Check with `error instanceof Cancelled`
+#[wasm_bindgen(getter_with_clone)]
+pub struct Cancelled {
+    /// Name of the exported function that was cancelled
+    pub operation: String,
+}
+
+/// [`filters::progress::Progress`] calling a JS function between row chunks
+fn js_progress(
+    on_progress: Option<js_sys::Function>,
+    every: Option<u32>,
+    cancel: Option<CancelSource>,
+) -> filters::progress::Progress<'static> {
+    // Nothing to report or check: one chunk per pass, as in `blur::apply`.
+    // Chunking would make every vertical box pass prime its window again per chunk.
+    if on_progress.is_none() && cancel.is_none() {
+        return filters::progress::Progress::none();
+    }
+
+    let mut progress = filters::progress::Progress::new(every.unwrap_or(DEFAULT_PROGRESS_EVERY) as usize);
+
+    if let Some(f) = on_progress {
+        progress = progress.on_progress(move |done, total| {
+            // A throwing callback must not abort the filter
+            let _ = f.call2(&JsValue::NULL, &JsValue::from(done as u32), &JsValue::from(total as u32));
+        });
+    }
+
+    if let Some(source) = cancel {
+        // Both are polled on this thread, between row chunks; rayon workers never see them
+        if let Some(flag) = source.dyn_ref::<js_sys::Int32Array>() {
+            let flag = flag.clone();
+            progress = progress.cancel_when(move || js_sys::Atomics::load(&flag, 0).map(|v| v != 0).unwrap_or(false));
+        } else {
+            let signal = source.unchecked_into::<web_sys::AbortSignal>();
+            progress = progress.cancel_when(move || signal.aborted());
+        }
+    }
+
+    progress
+}
+
+/// Invalid input as a string error like the other exports, cancellation as `Cancelled`
+fn progress_error(operation: &str, label: &str, error: filters::progress::Error) -> JsValue {
+    match error {
+        filters::progress::Error::Invalid(e) => JsValue::from_str(&format!("{} error: {}", label, e)),
+        filters::progress::Error::Cancelled => {
+            info!("{} cancelled", label);
+            JsValue::from(Cancelled { operation: operation.to_string() })
+        }
+    }
+}
+
+/// Timing of the most recent successful filter/analysis call
+#[wasm_bindgen(getter_with_clone)]
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimingReport {
+    /// Name of the exported function, e.g. `apply_blur`
+    pub operation: String,
+    /// Input dimensions
+    pub width: u32,
+    pub height: u32,
+    /// Wall time in milliseconds; `undefined` when no `performance` clock is available
+    pub elapsed_ms: Option<f64>,
+}
+
+thread_local! {
+    static LAST_TIMING: RefCell<Option<TimingReport>> = const { RefCell::new(None) };
+}
+
+/// Timing report of the last successful call on this thread (main thread or worker)
+#[wasm_bindgen]
+pub fn last_timing() -> Option<TimingReport> {
+    LAST_TIMING.with(|last| last.borrow().clone())
+}
+
+/// Measures one exported call and records it as the last [`TimingReport`]
+struct Timer {
+    operation: &'static str,
+    width: u32,
+    height: u32,
+    start: Option<f64>,
+}
+
+impl Timer {
+    fn start(operation: &'static str, width: u32, height: u32) -> Self {
+        Timer { operation, width, height, start: performance_now() }
+    }
+
+    /// Record the report; returns the elapsed milliseconds if timing is available
+    fn finish(self) -> Option<f64> {
+        let elapsed_ms = performance_now().zip(self.start).map(|(now, start)| now - start);
+
+        LAST_TIMING.with(|last| {
+            *last.borrow_mut() = Some(TimingReport {
+                operation: self.operation.to_string(),
+                width: self.width,
+                height: self.height,
+                elapsed_ms,
+            })
+        });
+
+        elapsed_ms
+    }
+}
+
+/// Helper to get performance.now() from the current global scope
+/// Works on the main thread (`Window`) and in workers (`WorkerGlobalScope`);
+/// `None` anywhere else (e.g. native benchmarks), timing is then skipped
+#[cfg(target_arch = "wasm32")]
+fn performance_now() -> Option<f64> {
+    let global = js_sys::global();
+
+    let performance = if let Some(window) = global.dyn_ref::<web_sys::Window>() {
+        window.performance()
+    } else if let Some(worker) = global.dyn_ref::<web_sys::WorkerGlobalScope>() {
+        worker.performance()
+    } else {
+        None
+    };
+
+    performance.map(|p| p.now())
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn performance_now() -> Option<f64> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_greet() {
+        let result = greet("Test");
+        assert!(result.contains("Test"));
+    }
+
+    #[test]
+    fn test_exports_run_without_window() {
+        // Timing is skipped instead of panicking when there is no window
+        assert_eq!(performance_now(), None);
+        let result = apply_grayscale(&[255, 0, 0, 255], 1, 1, None);
+        assert_eq!(result.unwrap(), vec![85, 85, 85, 255]);
+    }
+
+    #[test]
+    fn test_last_timing() {
+        apply_rotate_90_cw(&[0; 3 * 2 * 4], 3, 2, None).unwrap();
+        let report = last_timing().unwrap();
+        assert_eq!(report.operation, "apply_rotate_90_cw");
+        assert_eq!((report.width, report.height), (3, 2));
+        assert_eq!(report.elapsed_ms, None);
+    }
+
+    #[test]
+    fn test_set_log_level() {
+        assert_eq!(parse_log_level("WARN"), Ok(LevelFilter::Warn));
+        assert_eq!(parse_log_level("off"), Ok(LevelFilter::Off));
+        assert!(parse_log_level("loud").is_err());
+
+        set_log_level("trace").unwrap();
+        assert_eq!(log::max_level(), LevelFilter::Trace);
+        set_log_level("debug").unwrap();
+    }
+
+    // Needs a JS engine for the typed array
+    #[cfg(target_arch = "wasm32")]
+    #[wasm_bindgen_test::wasm_bindgen_test]
+    fn test_int32array_cancel_flag() {
+        let image = filters::test_image(4, 4);
+        let flag = js_sys::Int32Array::new_with_length(1);
+        let run = |flag: &js_sys::Int32Array| {
+            let mut progress = js_progress(None, None, Some(flag.clone().unchecked_into()));
+            filters::blur::apply_tracked(&image, 4, 4, 1.0, &mut progress)
+        };
+
+        assert!(run(&flag).is_ok());
+        js_sys::Atomics::store(&flag, 0, 1).unwrap();
+        assert!(matches!(run(&flag), Err(filters::progress::Error::Cancelled)));
+    }
+}
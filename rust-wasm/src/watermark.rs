@@ -0,0 +1,414 @@
+//! Watermarks for exported images
+//!
+//! A [`Watermark`] renders its mark once, as an RGBA stamp: an image
+//! scaled and rotated with bilinear sampling, or text drawn with
+//! [`crate::draw`]. [`Watermark::apply`] then composites the stamp at an
+//! anchor position or tiled over the whole image, source-over with the same
+//! math as [`crate::layers`], so one watermark can stamp any number of
+//! exports.
+
+#[cfg(feature = "text")]
+use crate::draw::{self, text::{Align, Font, TextStyle}};
+use crate::filters::exec::Exec;
+use crate::layers::{self, BlendMode};
+use crate::pipeline::Image;
+use crate::preview;
+
+/// Largest stamp in pixels (8192 x 8192, 256 MB of RGBA); larger marks are rejected
+pub const MAX_STAMP_PIXELS: usize = 8192 * 8192;
+
+/// Where a watermark sits in the image
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Anchor {
+    TopLeft,
+    Top,
+    TopRight,
+    Left,
+    Center,
+    Right,
+    BottomLeft,
+    Bottom,
+    #[default]
+    BottomRight,
+}
+
+impl Anchor {
+    /// "top-left", "top", "top-right", "left", "center", "right",
+    /// "bottom-left", "bottom" or "bottom-right"
+    pub fn from_name(name: &str) -> Result<Self, String> {
+        match name {
+            "top-left" => Ok(Anchor::TopLeft),
+            "top" => Ok(Anchor::Top),
+            "top-right" => Ok(Anchor::TopRight),
+            "left" => Ok(Anchor::Left),
+            "center" => Ok(Anchor::Center),
+            "right" => Ok(Anchor::Right),
+            "bottom-left" => Ok(Anchor::BottomLeft),
+            "bottom" => Ok(Anchor::Bottom),
+            "bottom-right" => Ok(Anchor::BottomRight),
+            _ => Err(format!("Unknown anchor: {}", name)),
+        }
+    }
+
+    /// Horizontal and vertical position: 0 start, 1 middle, 2 end
+    fn cell(self) -> (u8, u8) {
+        match self {
+            Anchor::TopLeft => (0, 0),
+            Anchor::Top => (1, 0),
+            Anchor::TopRight => (2, 0),
+            Anchor::Left => (0, 1),
+            Anchor::Center => (1, 1),
+            Anchor::Right => (2, 1),
+            Anchor::BottomLeft => (0, 2),
+            Anchor::Bottom => (1, 2),
+            Anchor::BottomRight => (2, 2),
+        }
+    }
+}
+
+/// Position, opacity and tiling of a [`Watermark`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Placement {
+    pub anchor: Anchor,
+    /// Distance in pixels from the image edges the anchor is next to
+    pub margin: u32,
+    /// 0.0 (invisible) to 1.0
+    pub opacity: f32,
+    /// Repeat the stamp over the whole image, on a grid through the anchored one
+    pub tile: bool,
+    /// Gap in pixels between tiles
+    pub spacing: u32,
+}
+
+impl Default for Placement {
+    fn default() -> Self {
+        Placement { anchor: Anchor::BottomRight, margin: 16, opacity: 1.0, tile: false, spacing: 0 }
+    }
+}
+
+/// A rendered mark and where to put it
+#[derive(Debug, Clone, PartialEq)]
+pub struct Watermark {
+    stamp: Image,
+    pub placement: Placement,
+}
+
+impl Watermark {
+    /// `mark` scaled by `scale` and rotated clockwise by `rotation` degrees
+    pub fn from_image(mark: &Image, scale: f32, rotation: f32) -> Result<Self, String> {
+        if !(scale > 0.0 && scale.is_finite()) {
+            return Err(format!("Invalid watermark scale: {}", scale));
+        }
+
+        Ok(Watermark { stamp: transform(mark, scale, rotation)?, placement: Placement::default() })
+    }
+
+    /// `text` in `style`; lines are aligned within the block by `style.align`
+    /// and the block is rotated around its center
+    #[cfg(feature = "text")]
+    pub fn from_text(font: &Font, text: &str, style: &TextStyle) -> Result<Self, String> {
+        let (width, height) = font.measure(text, style.size);
+        let (sin, cos) = style.rotation.to_radians().sin_cos();
+        let (stamp_w, stamp_h) = rotated_size(width, height, sin, cos);
+        let mut stamp = Image { width: stamp_w, height: stamp_h, data: stamp_buffer(stamp_w, stamp_h)? };
+
+        // Center of the text block relative to the alignment point on the first baseline
+        let dx = match style.align {
+            Align::Left => width / 2.0,
+            Align::Center => 0.0,
+            Align::Right => -width / 2.0,
+        };
+        let dy = height / 2.0 - font.ascent(style.size);
+        let (cx, cy) = (stamp_w as f32 / 2.0, stamp_h as f32 / 2.0);
+        let x = cx - (dx * cos - dy * sin);
+        let y = cy - (dx * sin + dy * cos);
+
+        if let Ok(mut canvas) = draw::Canvas::new(&mut stamp.data, stamp_w, stamp_h) {
+            canvas.text(font, text, x, y, style);
+        }
+
+        Ok(Watermark { stamp, placement: Placement::default() })
+    }
+
+    /// The rendered mark, straight RGBA
+    pub fn stamp(&self) -> &Image {
+        &self.stamp
+    }
+
+    /// `image` with the watermark composited on top
+    pub fn apply(&self, image: &Image) -> Image {
+        self.apply_with(Exec::preferred(), image)
+    }
+
+    pub(crate) fn apply_with(&self, exec: Exec, image: &Image) -> Image {
+        let mut result = image.clone();
+        let (stamp_w, stamp_h) = (self.stamp.width as i64, self.stamp.height as i64);
+        if stamp_w == 0 || stamp_h == 0 || self.placement.opacity <= 0.0 {
+            return result;
+        }
+
+        let p = &self.placement;
+        let (col, row) = p.anchor.cell();
+        let x0 = origin(col, image.width as i64, stamp_w, p.margin as i64);
+        let y0 = origin(row, image.height as i64, stamp_h, p.margin as i64);
+        let (step_x, step_y) = (stamp_w + p.spacing as i64, stamp_h + p.spacing as i64);
+        let opacity = p.opacity.min(1.0);
+
+        // Position within the stamp of image coordinate `v`, if one covers it
+        let local = |v: i64, start: i64, step: i64, size: i64| {
+            let offset = if p.tile { (v - start).rem_euclid(step) } else { v - start };
+            (0..size).contains(&offset).then_some(offset)
+        };
+
+        exec.rows(&mut result.data, image.width as usize * 4, |y, out_row| {
+            let Some(sy) = local(y as i64, y0, step_y, stamp_h) else {
+                return;
+            };
+            let stamp_row = &self.stamp.data[(sy * stamp_w * 4) as usize..((sy + 1) * stamp_w * 4) as usize];
+
+            for (x, px) in out_row.chunks_exact_mut(4).enumerate() {
+                if let Some(sx) = local(x as i64, x0, step_x, stamp_w) {
+                    let sx = sx as usize * 4;
+                    layers::composite_pixel(px, &stamp_row[sx..sx + 4], opacity, BlendMode::Normal);
+                }
+            }
+        });
+
+        result
+    }
+}
+
+/// Top or left edge of a `size` pixel stamp in a `length` pixel image
+fn origin(cell: u8, length: i64, size: i64, margin: i64) -> i64 {
+    match cell {
+        0 => margin,
+        1 => (length - size) / 2,
+        _ => length - size - margin,
+    }
+}
+
+/// Whole-pixel size of a `width` x `height` box rotated by the angle with `sin` and `cos`,
+/// with a pixel of room on each side for anti-aliased edges
+fn rotated_size(width: f32, height: f32, sin: f32, cos: f32) -> (u32, u32) {
+    let w = width * cos.abs() + height * sin.abs();
+    let h = width * sin.abs() + height * cos.abs();
+    // Quarter turns leave a rounding error in sin or cos
+    let size = |v: f32| ((v - 1e-3).ceil().max(0.0) as u32).saturating_add(2);
+    (size(w), size(h))
+}
+
+/// Transparent RGBA buffer for a stamp, sized in `usize` so large marks are rejected rather than wrapping
+fn stamp_buffer(width: u32, height: u32) -> Result<Vec<u8>, String> {
+    let pixels = (width as usize).checked_mul(height as usize).filter(|&p| p <= MAX_STAMP_PIXELS);
+    match pixels {
+        Some(pixels) => Ok(vec![0; pixels * 4]),
+        None => Err(format!(
+            "Watermark of {}x{} exceeds the limit of {} pixels",
+            width, height, MAX_STAMP_PIXELS
+        )),
+    }
+}
+
+/// `mark` scaled and rotated clockwise around its center, on a transparent
+/// stamp just large enough to hold it
+fn transform(mark: &Image, scale: f32, rotation: f32) -> Result<Image, String> {
+    let (w, h) = (mark.width as f32 * scale, mark.height as f32 * scale);
+
+    // Bilinear sampling skips pixels below half size; halve in 2x2 averages first
+    let mut source = mark.clone();
+    while (w as u32) < source.width / 2 && (h as u32) < source.height / 2 {
+        source = preview::downsample(&source);
+    }
+    // Per axis, as halving drops an odd last row or column
+    let (scale_x, scale_y) = (w / source.width as f32, h / source.height as f32);
+
+    let (sin, cos) = rotation.to_radians().sin_cos();
+    let (stamp_w, stamp_h) = rotated_size(w, h, sin, cos);
+    let (cx, cy) = (stamp_w as f32 / 2.0, stamp_h as f32 / 2.0);
+    let mut data = stamp_buffer(stamp_w, stamp_h)?;
+
+    for (i, px) in data.chunks_exact_mut(4).enumerate() {
+        // Rotate the pixel center back, then scale into the source
+        let (x, y) = (i % stamp_w as usize, i / stamp_w as usize);
+        let (rx, ry) = (x as f32 + 0.5 - cx, y as f32 + 0.5 - cy);
+        let (ux, uy) = (rx * cos + ry * sin, -rx * sin + ry * cos);
+        let (sx, sy) = ((ux + w / 2.0) / scale_x - 0.5, (uy + h / 2.0) / scale_y - 0.5);
+        px.copy_from_slice(&sample(&source, sx, sy));
+    }
+
+    Ok(Image { width: stamp_w, height: stamp_h, data })
+}
+
+/// Bilinear sample at (`x`, `y`) in pixel-center coordinates, premultiplied;
+/// transparent outside the image so edges come out anti-aliased
+fn sample(image: &Image, x: f32, y: f32) -> [u8; 4] {
+    let (x0, y0) = (x.floor(), y.floor());
+    let (fx, fy) = (x - x0, y - y0);
+    let (x0, y0) = (x0 as i64, y0 as i64);
+
+    let mut sum = [0.0f32; 4];
+    for (dx, dy, weight) in [(0, 0, (1.0 - fx) * (1.0 - fy)), (1, 0, fx * (1.0 - fy)), (0, 1, (1.0 - fx) * fy), (1, 1, fx * fy)] {
+        let (px, py) = (x0 + dx, y0 + dy);
+        if weight <= 0.0 || px < 0 || py < 0 || px >= image.width as i64 || py >= image.height as i64 {
+            continue;
+        }
+
+        let i = (py as usize * image.width as usize + px as usize) * 4;
+        let alpha = image.data[i + 3] as f32 * weight;
+        for (total, &value) in sum.iter_mut().zip(&image.data[i..i + 3]) {
+            *total += value as f32 * alpha;
+        }
+        sum[3] += alpha;
+    }
+
+    if sum[3] <= 0.0 {
+        return [0; 4];
+    }
+    [
+        (sum[0] / sum[3]).round() as u8,
+        (sum[1] / sum[3]).round() as u8,
+        (sum[2] / sum[3]).round() as u8,
+        sum[3].round().min(255.0) as u8,
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::filters::test_image;
+
+    const RED: [u8; 4] = [255, 0, 0, 255];
+
+    fn solid(width: u32, height: u32, pixel: [u8; 4]) -> Image {
+        Image::new(width, height, pixel.repeat((width * height) as usize)).unwrap()
+    }
+
+    fn pixel(image: &Image, x: u32, y: u32) -> [u8; 4] {
+        let i = ((y * image.width + x) * 4) as usize;
+        image.data[i..i + 4].try_into().unwrap()
+    }
+
+    fn count(image: &Image, color: [u8; 4]) -> usize {
+        image.data.chunks_exact(4).filter(|px| *px == color).count()
+    }
+
+    #[test]
+    fn test_anchored_image() {
+        let mark = Watermark::from_image(&solid(4, 3, RED), 1.0, 0.0).unwrap();
+        // A transparent pixel of room on each side
+        assert_eq!((mark.stamp().width, mark.stamp().height), (6, 5));
+
+        let image = solid(20, 10, [255; 4]);
+        let mut mark = mark;
+        mark.placement.margin = 2;
+        let result = mark.apply(&image);
+        assert_eq!(count(&result, RED), 12);
+        assert_eq!(pixel(&result, 13, 4), RED);
+        assert_eq!(pixel(&result, 16, 6), RED);
+        assert_eq!(pixel(&result, 17, 6), [255; 4]);
+
+        mark.placement.anchor = Anchor::TopLeft;
+        let result = mark.apply(&image);
+        assert_eq!(pixel(&result, 3, 3), RED);
+        assert_eq!(pixel(&result, 2, 2), [255; 4]);
+
+        assert!(Anchor::from_name("middle").is_err());
+        assert!(Watermark::from_image(&solid(4, 3, RED), 0.0, 0.0).is_err());
+    }
+
+    #[test]
+    fn test_opacity_uses_layer_compositing() {
+        let mut mark = Watermark::from_image(&solid(2, 2, [0, 0, 0, 255]), 1.0, 0.0).unwrap();
+        mark.placement = Placement { anchor: Anchor::Center, opacity: 0.5, ..Placement::default() };
+        let result = mark.apply(&solid(6, 6, [255; 4]));
+
+        assert_eq!(pixel(&result, 3, 3), [128, 128, 128, 255]);
+        assert_eq!(count(&result, [128, 128, 128, 255]), 4);
+    }
+
+    #[test]
+    fn test_tiling() {
+        let mut mark = Watermark::from_image(&solid(2, 2, RED), 1.0, 0.0).unwrap();
+        mark.placement = Placement { anchor: Anchor::TopLeft, margin: 0, tile: true, spacing: 2, ..Placement::default() };
+        let result = mark.apply(&solid(18, 12, [255; 4]));
+
+        // 4x4 stamps every 6 pixels, red in the middle 2x2 of each
+        assert_eq!(count(&result, RED), 3 * 2 * 4);
+        for (x, y) in [(1, 1), (2, 2), (7, 1), (13, 7)] {
+            assert_eq!(pixel(&result, x, y), RED, "({}, {})", x, y);
+        }
+        assert_eq!(pixel(&result, 3, 1), [255; 4]);
+
+        // Anchored at the bottom right, the grid still reaches the top left corner
+        mark.placement.anchor = Anchor::BottomRight;
+        let result = mark.apply(&solid(17, 11, [255; 4]));
+        assert_eq!(pixel(&result, 14, 8), RED);
+        assert_eq!(pixel(&result, 2, 2), RED);
+    }
+
+    #[test]
+    fn test_scale_and_rotation() {
+        let image = Image::new(40, 20, test_image(40, 20).chunks_exact(4).flat_map(|px| [px[0], px[1], px[2], 255]).collect()).unwrap();
+
+        let quarter = Watermark::from_image(&image, 0.25, 0.0).unwrap();
+        assert_eq!((quarter.stamp().width, quarter.stamp().height), (12, 7));
+
+        let turned = Watermark::from_image(&image, 1.0, 90.0).unwrap();
+        assert_eq!((turned.stamp().width, turned.stamp().height), (22, 42));
+        // A quarter turn clockwise puts the top-left pixel at the top right
+        assert_eq!(pixel(turned.stamp(), 20, 1), pixel(&image, 0, 0));
+        assert_eq!(pixel(turned.stamp(), 1, 40), pixel(&image, 39, 19));
+
+        // Rotated by 30 degrees, the corners of the stamp stay transparent
+        let tilted = Watermark::from_image(&solid(30, 10, RED), 1.0, 30.0).unwrap();
+        assert_eq!(pixel(tilted.stamp(), 0, 0)[3], 0);
+        let coverage: f32 = tilted.stamp().data.chunks_exact(4).map(|px| px[3] as f32 / 255.0).sum();
+        assert!((coverage - 300.0).abs() < 3.0, "coverage {}", coverage);
+    }
+
+    #[test]
+    fn test_oversized_stamp_is_rejected() {
+        // 2 * 40000 + 2 pixels square would overflow u32 byte counts
+        assert!(Watermark::from_image(&solid(2, 2, RED), 40_000.0, 0.0).is_err());
+        assert!(Watermark::from_image(&solid(2, 2, RED), 1e30, 45.0).is_err());
+        assert!(stamp_buffer(u32::MAX, u32::MAX).is_err());
+
+        assert!(stamp_buffer(8192, 8193).is_err());
+        assert_eq!(stamp_buffer(3, 2).unwrap().len(), 3 * 2 * 4);
+    }
+
+    #[cfg(feature = "text")]
+    #[test]
+    fn test_text() {
+        let font = Font::from_bytes(include_bytes!("../tests/fixtures/Roboto-Regular.ttf").to_vec()).unwrap();
+        let style = TextStyle { size: 24.0, color: [255, 255, 255, 255], rotation: -30.0, ..TextStyle::default() };
+        let mark = Watermark::from_text(&font, "DRAFT", &style).unwrap();
+        assert!(Watermark::from_text(&font, "DRAFT", &TextStyle { size: 1e6, ..style }).is_err());
+        let stamp = mark.stamp();
+
+        // The rotated text fills the middle of the stamp without touching its edges
+        let inked: Vec<(u32, u32)> = (0..stamp.height)
+            .flat_map(|y| (0..stamp.width).map(move |x| (x, y)))
+            .filter(|&(x, y)| pixel(stamp, x, y)[3] > 0)
+            .collect();
+        assert!(inked.len() > 100);
+        let (min_x, max_x) = (inked.iter().map(|p| p.0).min().unwrap(), inked.iter().map(|p| p.0).max().unwrap());
+        let (min_y, max_y) = (inked.iter().map(|p| p.1).min().unwrap(), inked.iter().map(|p| p.1).max().unwrap());
+        assert!(min_x > 0 && min_y > 0 && max_x < stamp.width - 1 && max_y < stamp.height - 1);
+        // The layout box is centred, descender space and side bearings included
+        assert!(((min_x + max_x) as i64 - stamp.width as i64).abs() <= 8, "{:?} in {}x{}", (min_x, min_y, max_x, max_y), stamp.width, stamp.height);
+        // Rising to the right: the rightmost ink is near the top
+        let right_y = inked.iter().filter(|p| p.0 == max_x).map(|p| p.1).min().unwrap();
+        assert!(right_y < stamp.height / 2);
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_parallel_matches_serial() {
+        let image = Image::new(67, 45, test_image(67, 45)).unwrap();
+        let mut mark = Watermark::from_image(&Image::new(9, 7, test_image(9, 7)).unwrap(), 1.5, 20.0).unwrap();
+        mark.placement = Placement { opacity: 0.7, tile: true, spacing: 3, ..Placement::default() };
+
+        assert_eq!(mark.apply_with(Exec::Serial, &image), mark.apply_with(Exec::Parallel, &image));
+    }
+}
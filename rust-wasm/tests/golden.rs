@@ -0,0 +1,80 @@
+//! Golden images for the pixel-changing filters
+//!
+//! Each filter output is compared with a PNG in `tests/fixtures/`, allowing
+//! small per-channel differences. After an intended output change, rewrite
+//! the fixtures with `UPDATE_GOLDEN=1 cargo test --target x86_64-unknown-linux-gnu --test golden`
+//! and review the new images.
+#![cfg(not(target_arch = "wasm32"))]
+
+use pixlab_wasm::filters::{blur, grayscale, rotate};
+use std::path::PathBuf;
+
+/// Largest allowed difference of any channel value
+const MAX_CHANNEL_DIFF: u8 = 1;
+/// Largest allowed share of channel values that differ at all
+const MAX_DIFFERING: f64 = 0.01;
+
+fn fixture(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures").join(name)
+}
+
+fn load(name: &str) -> (u32, u32, Vec<u8>) {
+    let image = image::open(fixture(name))
+        .unwrap_or_else(|e| panic!("cannot read fixture {}: {}", name, e))
+        .to_rgba8();
+    let (width, height) = image.dimensions();
+    (width, height, image.into_raw())
+}
+
+/// Compare `actual` with the golden image `name`, or rewrite it with UPDATE_GOLDEN=1
+fn check_golden(name: &str, width: u32, height: u32, actual: &[u8]) {
+    if std::env::var_os("UPDATE_GOLDEN").is_some() {
+        image::save_buffer(fixture(name), actual, width, height, image::ColorType::Rgba8).unwrap();
+        return;
+    }
+
+    let (golden_width, golden_height, golden) = load(name);
+    assert_eq!((golden_width, golden_height), (width, height), "{}: size differs", name);
+
+    let mut differing = 0;
+    for (i, (&a, &g)) in actual.iter().zip(&golden).enumerate() {
+        let diff = a.abs_diff(g);
+        assert!(
+            diff <= MAX_CHANNEL_DIFF,
+            "{}: pixel ({}, {}) channel {} is {}, golden {}",
+            name,
+            (i / 4) as u32 % width,
+            (i / 4) as u32 / width,
+            i % 4,
+            a,
+            g
+        );
+        differing += (diff > 0) as usize;
+    }
+
+    let share = differing as f64 / actual.len() as f64;
+    assert!(share <= MAX_DIFFERING, "{}: {:.2}% of channel values differ", name, share * 100.0);
+}
+
+#[test]
+fn golden_grayscale() {
+    let (w, h, input) = load("input.png");
+    check_golden("grayscale.png", w, h, &grayscale::apply(&input, w, h).unwrap());
+}
+
+#[test]
+fn golden_blur() {
+    let (w, h, input) = load("input.png");
+    for radius in [1.0, 2.7, 3.0, 10.0] {
+        let output = blur::apply(&input, w, h, radius).unwrap();
+        check_golden(&format!("blur_r{}.png", radius), w, h, &output);
+    }
+}
+
+#[test]
+fn golden_quarter_turns() {
+    // The red square starts top left: clockwise it ends top right, counter-clockwise bottom left
+    let (w, h, input) = load("input.png");
+    check_golden("rotate_90_cw.png", h, w, &rotate::rotate_90_cw(&input, w, h).unwrap());
+    check_golden("rotate_270_cw.png", h, w, &rotate::rotate_270_cw(&input, w, h).unwrap());
+}
@@ -0,0 +1,85 @@
+//! Algebraic properties of the filters on random images
+#![cfg(not(target_arch = "wasm32"))]
+
+use pixlab_wasm::filters::{brightness, crop, flip, grayscale, rotate};
+use proptest::prelude::*;
+
+/// Random RGBA image of up to 24x24 pixels
+fn any_image() -> impl Strategy<Value = (u32, u32, Vec<u8>)> {
+    (1u32..24, 1u32..24).prop_flat_map(|(w, h)| (Just(w), Just(h), prop::collection::vec(any::<u8>(), (w * h * 4) as usize)))
+}
+
+proptest! {
+    #[test]
+    fn flip_twice_is_identity((w, h, data) in any_image()) {
+        let twice = flip::apply_horizontal(&flip::apply_horizontal(&data, w, h).unwrap(), w, h).unwrap();
+        prop_assert_eq!(&twice, &data);
+
+        let twice = flip::apply_vertical(&flip::apply_vertical(&data, w, h).unwrap(), w, h).unwrap();
+        prop_assert_eq!(&twice, &data);
+    }
+
+    #[test]
+    fn in_place_flips_match_copies((w, h, data) in any_image()) {
+        let mut in_place = data.clone();
+        flip::apply_horizontal_in_place(&mut in_place, w, h).unwrap();
+        prop_assert_eq!(&in_place, &flip::apply_horizontal(&data, w, h).unwrap());
+
+        let mut in_place = data.clone();
+        flip::apply_vertical_in_place(&mut in_place, w, h).unwrap();
+        prop_assert_eq!(&in_place, &flip::apply_vertical(&data, w, h).unwrap());
+    }
+
+    #[test]
+    fn four_quarter_turns_are_identity((w, h, data) in any_image()) {
+        let mut current = data.clone();
+        let (mut cw, mut ch) = (w, h);
+        for _ in 0..4 {
+            current = rotate::rotate_90_cw(&current, cw, ch).unwrap();
+            (cw, ch) = (ch, cw);
+        }
+        prop_assert_eq!(current, data);
+    }
+
+    #[test]
+    fn rotate_270_inverts_rotate_90((w, h, data) in any_image()) {
+        let turned = rotate::rotate_90_cw(&data, w, h).unwrap();
+        prop_assert_eq!(&rotate::rotate_270_cw(&turned, h, w).unwrap(), &data);
+
+        let turned = rotate::rotate_270_cw(&data, w, h).unwrap();
+        prop_assert_eq!(&rotate::rotate_90_cw(&turned, h, w).unwrap(), &data);
+    }
+
+    #[test]
+    fn rotate_180_is_two_quarter_turns((w, h, data) in any_image()) {
+        let twice = rotate::rotate_90_cw(&rotate::rotate_90_cw(&data, w, h).unwrap(), h, w).unwrap();
+        prop_assert_eq!(&rotate::rotate_180(&data, w, h).unwrap(), &twice);
+    }
+
+    #[test]
+    fn full_crop_is_identity((w, h, data) in any_image()) {
+        prop_assert_eq!(crop::apply(&data, w, h, 0, 0, w, h).unwrap(), data);
+    }
+
+    #[test]
+    fn brightness_is_monotonic((w, h, data) in any_image(), a in -255.0f32..255.0, b in -255.0f32..255.0) {
+        let (low, high) = (a.min(b), a.max(b));
+        let darker = brightness::apply(&data, w, h, low).unwrap();
+        let brighter = brightness::apply(&data, w, h, high).unwrap();
+
+        for (i, &src) in data.iter().enumerate() {
+            if i % 4 == 3 {
+                // Alpha is never touched
+                prop_assert_eq!((darker[i], brighter[i]), (src, src));
+            } else {
+                prop_assert!(darker[i] <= brighter[i]);
+            }
+        }
+    }
+
+    #[test]
+    fn grayscale_is_idempotent((w, h, data) in any_image()) {
+        let once = grayscale::apply(&data, w, h).unwrap();
+        prop_assert_eq!(&grayscale::apply(&once, w, h).unwrap(), &once);
+    }
+}
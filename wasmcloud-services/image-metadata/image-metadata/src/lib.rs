@@ -1,27 +1,68 @@
 use wasmcloud_component::http;
-use serde::{Deserialize, Serialize};
+use serde::Serialize;
 use std::io::{Read, Cursor};
 use image::ImageReader;
 
+mod stats;
+
+// ブラウザ側と同じクレートを使う（ハッシュ値が必ず一致する）
+use pixlab_phash::{self as phash, Hashes};
+use stats::ImageStats;
+
 struct Component;
 
 http::export!(Component);
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize)]
 struct ImageMetadata {
     size_bytes: usize,
     format: String,
     width: Option<u32>,
     height: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    hashes: Option<HexHashes>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stats: Option<ImageStats>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    duplicate: Option<DuplicateCheck>,
     message: String,
 }
 
+/// レスポンス用のハッシュ（16桁の16進文字列）
+#[derive(Serialize)]
+struct HexHashes {
+    ahash: String,
+    dhash: String,
+    phash: String,
+}
+
+impl From<Hashes> for HexHashes {
+    fn from(h: Hashes) -> Self {
+        HexHashes {
+            ahash: phash::to_hex(h.ahash),
+            dhash: phash::to_hex(h.dhash),
+            phash: phash::to_hex(h.phash),
+        }
+    }
+}
+
+/// `?compare=<pHash>` 指定時の重複判定結果
+#[derive(Serialize, Debug, PartialEq)]
+struct DuplicateCheck {
+    compared_to: String,
+    phash_distance: u32,
+    near_duplicate: bool,
+}
+
 impl http::Server for Component {
     fn handle(
         request: http::IncomingRequest,
     ) -> http::Result<http::Response<impl http::OutgoingBody>> {
         let path = request.uri().path().to_string();
         let method = request.method().to_string();
+        let query = request.uri().query().unwrap_or("").to_string();
+        let want_stats = query_flag(&query, "stats");
+        let compare_to = query_value(&query, "compare").map(str::to_string);
 
         // HTTPボディからバイト列を読み取る
         let mut request_body = request.into_body();
@@ -39,26 +80,45 @@ impl http::Server for Component {
 
         let size_bytes = body_bytes.len();
 
+        // 比較対象のハッシュは先に検証しておく
+        let compare_hash = match compare_to.as_deref().map(phash::from_hex) {
+            Some(Err(e)) => return Ok(error_response(http::StatusCode::BAD_REQUEST, &format!("Invalid compare hash: {}", e))),
+            Some(Ok(hash)) => Some(hash),
+            None => None,
+        };
+
         // 画像解析
-        let (format, width, height) = if size_bytes > 0 {
+        let (format, image) = if size_bytes > 0 {
             match ImageReader::new(Cursor::new(&body_bytes)).with_guessed_format() {
                 Ok(reader) => {
                     let format_str = reader.format()
                         .map(|f| format!("{:?}", f).to_lowercase())
                         .unwrap_or_else(|| "unknown".to_string());
 
-                    match reader.decode() {
-                        Ok(img) => {
-                            let (w, h) = (img.width(), img.height());
-                            (format_str, Some(w), Some(h))
-                        }
-                        Err(_) => (format_str, None, None)
-                    }
+                    (format_str, reader.decode().ok())
                 }
-                Err(_) => ("unknown".to_string(), None, None)
+                Err(_) => ("unknown".to_string(), None)
             }
         } else {
-            ("none".to_string(), None, None)
+            ("none".to_string(), None)
+        };
+
+        let rgba = image.map(|img| img.to_rgba8());
+        let width = rgba.as_ref().map(|img| img.width());
+        let height = rgba.as_ref().map(|img| img.height());
+
+        // 知覚ハッシュ（ブラウザ側の compute_perceptual_hashes と同じ値になる）
+        let raw_hashes = rgba
+            .as_ref()
+            .and_then(|img| phash::compute(img.as_raw(), img.width(), img.height()).ok());
+
+        // 統計は ?stats=true のときだけ計算する（大きな画像では重いため）
+        let stats = rgba.as_ref().filter(|_| want_stats).map(stats::compute);
+
+        // 比較を求められたのにハッシュがない（デコード失敗）場合は黙って省略せずエラーにする
+        let duplicate = match check_duplicate(compare_hash, raw_hashes) {
+            Ok(duplicate) => duplicate,
+            Err(e) => return Ok(error_response(http::StatusCode::UNPROCESSABLE_ENTITY, &e)),
         };
 
         let metadata = ImageMetadata {
@@ -66,6 +126,9 @@ impl http::Server for Component {
             format,
             width,
             height,
+            hashes: raw_hashes.map(HexHashes::from),
+            stats,
+            duplicate,
             message: format!("Received {} bytes - Method: {}, Path: {}", size_bytes, method, path),
         };
 
@@ -81,3 +144,74 @@ impl http::Server for Component {
         Ok(response)
     }
 }
+
+/// `compare` と画像のハッシュを比較する（`compare` がなければ `None`）
+fn check_duplicate(compare: Option<u64>, hashes: Option<Hashes>) -> Result<Option<DuplicateCheck>, String> {
+    let Some(other) = compare else {
+        return Ok(None);
+    };
+    let hashes = hashes.ok_or("Cannot compare: the image could not be decoded")?;
+
+    let phash_distance = phash::hamming_distance(hashes.phash, other);
+    Ok(Some(DuplicateCheck {
+        compared_to: phash::to_hex(other),
+        phash_distance,
+        near_duplicate: phash_distance <= phash::NEAR_DUPLICATE_THRESHOLD,
+    }))
+}
+
+/// `{"error": message}` を `status` で返す
+fn error_response(status: http::StatusCode, message: &str) -> http::Response<String> {
+    let mut response = http::Response::new(serde_json::json!({ "error": message }).to_string());
+    *response.status_mut() = status;
+    response.headers_mut().insert(
+        "content-type",
+        "application/json".parse().unwrap(),
+    );
+    response
+}
+
+/// クエリ文字列から `name` の値を取り出す
+fn query_value<'a>(query: &'a str, name: &str) -> Option<&'a str> {
+    query
+        .split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .find(|(key, _)| *key == name)
+        .map(|(_, value)| value)
+}
+
+/// クエリ文字列の `name=true` / `name=1` を判定する
+fn query_flag(query: &str, name: &str) -> bool {
+    matches!(query_value(query, name), Some("true") | Some("1"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hashes(phash: u64) -> Hashes {
+        Hashes { ahash: 0, dhash: 0, phash }
+    }
+
+    #[test]
+    fn test_check_duplicate() {
+        assert_eq!(check_duplicate(None, Some(hashes(1))), Ok(None));
+        assert_eq!(check_duplicate(None, None), Ok(None));
+
+        let near = check_duplicate(Some(0b1111), Some(hashes(0))).unwrap().unwrap();
+        assert_eq!(near, DuplicateCheck { compared_to: "000000000000000f".to_string(), phash_distance: 4, near_duplicate: true });
+        let far = check_duplicate(Some(u64::MAX), Some(hashes(0))).unwrap().unwrap();
+        assert!(!far.near_duplicate);
+
+        // 画像をデコードできなかったときは比較を省略せずエラー
+        assert!(check_duplicate(Some(0), None).is_err());
+    }
+
+    #[test]
+    fn test_query() {
+        assert_eq!(query_value("stats=true&compare=00ff", "compare"), Some("00ff"));
+        assert_eq!(query_value("stats=true", "compare"), None);
+        assert!(query_flag("a=1&stats=1", "stats"));
+        assert!(!query_flag("stats=false", "stats"));
+    }
+}
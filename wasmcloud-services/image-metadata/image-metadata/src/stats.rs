@@ -0,0 +1,297 @@
+use image::RgbaImage;
+use serde::Serialize;
+
+/// パレットとして返す代表色の最大数
+const PALETTE_SIZE: usize = 5;
+
+/// パレット計算時の量子化ビット数（チャンネルあたり5bit = 32階調）
+const PALETTE_QUANT_BITS: u32 = 5;
+
+/// `?stats=true` 指定時に返す画素統計
+#[derive(Serialize)]
+pub struct ImageStats {
+    pub pixel_count: u64,
+    pub red: ChannelStats,
+    pub green: ChannelStats,
+    pub blue: ChannelStats,
+    pub luminance: ChannelStats,
+    /// 輝度が255に張り付いた画素の、不透明画素に対する割合（0.0〜1.0）
+    pub clipped_highlights_fraction: f64,
+    /// 輝度が0に張り付いた画素の、不透明画素に対する割合（0.0〜1.0）
+    pub clipped_shadows_fraction: f64,
+    /// alpha == 0 の画素の、全画素に対する割合（0.0〜1.0）
+    pub transparent_fraction: f64,
+    /// 出現頻度の高い順に並べた代表色
+    pub dominant_colors: Vec<PaletteColor>,
+}
+
+/// 1チャンネル分の統計（256ビンのヒストグラム付き、完全透明な画素は数えない）
+#[derive(Serialize)]
+pub struct ChannelStats {
+    pub mean: f64,
+    pub std_dev: f64,
+    pub histogram: Vec<u64>,
+}
+
+#[derive(Serialize)]
+pub struct PaletteColor {
+    pub hex: String,
+    pub rgb: [u8; 3],
+    /// 不透明画素に占める割合（0.0〜1.0）
+    pub ratio: f64,
+}
+
+/// RGBA画像から統計を計算する
+///
+/// 完全透明（alpha == 0）な画素は色が意味を持たないため、ヒストグラム・
+/// 白飛び/黒つぶれ率・パレットのすべてから除外する。
+pub fn compute(img: &RgbaImage) -> ImageStats {
+    let mut hist_r = [0u64; 256];
+    let mut hist_g = [0u64; 256];
+    let mut hist_b = [0u64; 256];
+    let mut hist_l = [0u64; 256];
+    let mut transparent = 0u64;
+
+    // パレット用の量子化ヒストグラム
+    let levels = 1usize << PALETTE_QUANT_BITS;
+    let mut buckets = vec![0u64; levels * levels * levels];
+
+    for pixel in img.pixels() {
+        let [r, g, b, a] = pixel.0;
+
+        if a == 0 {
+            transparent += 1;
+            continue;
+        }
+
+        hist_r[r as usize] += 1;
+        hist_g[g as usize] += 1;
+        hist_b[b as usize] += 1;
+        hist_l[luminance(r, g, b) as usize] += 1;
+        buckets[bucket_index(r, g, b)] += 1;
+    }
+
+    let pixel_count = img.width() as u64 * img.height() as u64;
+    let opaque_count = pixel_count - transparent;
+
+    ImageStats {
+        pixel_count,
+        red: channel_stats(&hist_r),
+        green: channel_stats(&hist_g),
+        blue: channel_stats(&hist_b),
+        luminance: channel_stats(&hist_l),
+        clipped_highlights_fraction: ratio(hist_l[255], opaque_count),
+        clipped_shadows_fraction: ratio(hist_l[0], opaque_count),
+        transparent_fraction: ratio(transparent, pixel_count),
+        dominant_colors: dominant_colors(&buckets, opaque_count),
+    }
+}
+
+/// Rec.709 の係数で輝度を計算する
+fn luminance(r: u8, g: u8, b: u8) -> u8 {
+    let l = 0.2126 * r as f64 + 0.7152 * g as f64 + 0.0722 * b as f64;
+    l.round().clamp(0.0, 255.0) as u8
+}
+
+fn channel_stats(hist: &[u64; 256]) -> ChannelStats {
+    let count: u64 = hist.iter().sum();
+    if count == 0 {
+        return ChannelStats { mean: 0.0, std_dev: 0.0, histogram: hist.to_vec() };
+    }
+
+    let mean = hist
+        .iter()
+        .enumerate()
+        .map(|(value, &n)| value as f64 * n as f64)
+        .sum::<f64>()
+        / count as f64;
+
+    let variance = hist
+        .iter()
+        .enumerate()
+        .map(|(value, &n)| {
+            let d = value as f64 - mean;
+            d * d * n as f64
+        })
+        .sum::<f64>()
+        / count as f64;
+
+    ChannelStats {
+        mean,
+        std_dev: variance.sqrt(),
+        histogram: hist.to_vec(),
+    }
+}
+
+fn bucket_index(r: u8, g: u8, b: u8) -> usize {
+    let shift = 8 - PALETTE_QUANT_BITS;
+    let (r, g, b) = ((r >> shift) as usize, (g >> shift) as usize, (b >> shift) as usize);
+    (r << (2 * PALETTE_QUANT_BITS)) | (g << PALETTE_QUANT_BITS) | b
+}
+
+/// バケットの中心色
+fn bucket_color(index: usize) -> [u8; 3] {
+    let shift = 8 - PALETTE_QUANT_BITS;
+    let mask = (1usize << PALETTE_QUANT_BITS) - 1;
+    let half = 1u8 << (shift - 1);
+    let component = |v: usize| ((v as u8) << shift) | half;
+    [
+        component((index >> (2 * PALETTE_QUANT_BITS)) & mask),
+        component((index >> PALETTE_QUANT_BITS) & mask),
+        component(index & mask),
+    ]
+}
+
+/// メディアンカットで代表色を求める
+fn dominant_colors(buckets: &[u64], opaque_count: u64) -> Vec<PaletteColor> {
+    let entries: Vec<([u8; 3], u64)> = buckets
+        .iter()
+        .enumerate()
+        .filter(|(_, &n)| n > 0)
+        .map(|(i, &n)| (bucket_color(i), n))
+        .collect();
+
+    if entries.is_empty() {
+        return Vec::new();
+    }
+
+    let mut boxes = vec![entries];
+    while boxes.len() < PALETTE_SIZE {
+        // 最も色の広がりが大きいボックスを分割する
+        let Some((index, channel)) = boxes
+            .iter()
+            .enumerate()
+            .filter(|(_, b)| b.len() > 1)
+            .map(|(i, b)| {
+                let (channel, range) = widest_channel(b);
+                (i, channel, range)
+            })
+            .max_by_key(|&(_, _, range)| range)
+            .map(|(i, channel, _)| (i, channel))
+        else {
+            break;
+        };
+
+        let mut entries = boxes.swap_remove(index);
+        entries.sort_by_key(|(color, _)| color[channel]);
+
+        // 画素数の中央で分割する
+        let total: u64 = entries.iter().map(|(_, n)| n).sum();
+        let mut acc = 0;
+        let mut split = entries.len() / 2;
+        for (i, (_, n)) in entries.iter().enumerate() {
+            acc += n;
+            if acc * 2 >= total {
+                split = (i + 1).min(entries.len() - 1);
+                break;
+            }
+        }
+
+        let upper = entries.split_off(split);
+        boxes.push(entries);
+        boxes.push(upper);
+    }
+
+    let mut palette: Vec<PaletteColor> = boxes
+        .iter()
+        .map(|entries| {
+            let count: u64 = entries.iter().map(|(_, n)| n).sum();
+            let mut sum = [0u64; 3];
+            for (color, n) in entries {
+                for c in 0..3 {
+                    sum[c] += color[c] as u64 * n;
+                }
+            }
+            let rgb = sum.map(|s| (s as f64 / count as f64).round() as u8);
+            PaletteColor {
+                hex: format!("#{:02x}{:02x}{:02x}", rgb[0], rgb[1], rgb[2]),
+                rgb,
+                ratio: ratio(count, opaque_count),
+            }
+        })
+        .collect();
+
+    palette.sort_by(|a, b| b.ratio.total_cmp(&a.ratio));
+    palette
+}
+
+/// ボックス内で値の幅が最大のチャンネルとその幅
+fn widest_channel(entries: &[([u8; 3], u64)]) -> (usize, u8) {
+    (0..3)
+        .map(|c| {
+            let min = entries.iter().map(|(color, _)| color[c]).min().unwrap_or(0);
+            let max = entries.iter().map(|(color, _)| color[c]).max().unwrap_or(0);
+            (c, max - min)
+        })
+        .max_by_key(|&(_, range)| range)
+        .unwrap_or((0, 0))
+}
+
+fn ratio(n: u64, total: u64) -> f64 {
+    if total == 0 {
+        0.0
+    } else {
+        n as f64 / total as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::Rgba;
+
+    #[test]
+    fn test_channel_stats_and_clipping() {
+        // 白2・黒1・灰色1の2x2
+        let img = RgbaImage::from_fn(2, 2, |x, y| match (x, y) {
+            (0, 0) | (1, 0) => Rgba([255, 255, 255, 255]),
+            (0, 1) => Rgba([0, 0, 0, 255]),
+            _ => Rgba([128, 128, 128, 255]),
+        });
+        let stats = compute(&img);
+
+        assert_eq!(stats.pixel_count, 4);
+        assert_eq!(stats.red.histogram[255], 2);
+        assert_eq!(stats.luminance.histogram.iter().sum::<u64>(), 4);
+        assert!((stats.red.mean - (255.0 * 2.0 + 128.0) / 4.0).abs() < 1e-9);
+        assert_eq!(stats.clipped_highlights_fraction, 0.5);
+        assert_eq!(stats.clipped_shadows_fraction, 0.25);
+        assert_eq!(stats.transparent_fraction, 0.0);
+    }
+
+    #[test]
+    fn test_transparent_pixels_are_skipped() {
+        // 左半分は完全透明な黒、右半分は不透明な赤
+        let img = RgbaImage::from_fn(4, 2, |x, _| if x < 2 { Rgba([0, 0, 0, 0]) } else { Rgba([200, 0, 0, 255]) });
+        let stats = compute(&img);
+
+        assert_eq!(stats.transparent_fraction, 0.5);
+        assert_eq!(stats.red.histogram.iter().sum::<u64>(), 4);
+        assert_eq!(stats.red.mean, 200.0);
+        assert_eq!(stats.clipped_shadows_fraction, 0.0);
+        assert_eq!(stats.dominant_colors.len(), 1);
+        assert_eq!(stats.dominant_colors[0].ratio, 1.0);
+    }
+
+    #[test]
+    fn test_fully_transparent_image() {
+        let stats = compute(&RgbaImage::new(3, 3));
+
+        assert_eq!(stats.transparent_fraction, 1.0);
+        assert_eq!(stats.luminance.mean, 0.0);
+        assert_eq!(stats.clipped_shadows_fraction, 0.0);
+        assert!(stats.dominant_colors.is_empty());
+    }
+
+    #[test]
+    fn test_palette_orders_by_ratio() {
+        // 青6・黄2
+        let img = RgbaImage::from_fn(4, 2, |x, y| if x + y * 4 < 6 { Rgba([20, 40, 200, 255]) } else { Rgba([240, 220, 10, 255]) });
+        let palette = compute(&img).dominant_colors;
+
+        assert_eq!(palette.len(), 2);
+        assert_eq!(palette[0].ratio, 0.75);
+        assert!(palette[0].rgb[2] > 190 && palette[1].rgb[0] > 230);
+        assert_eq!(palette[0].hex, format!("#{:02x}{:02x}{:02x}", palette[0].rgb[0], palette[0].rgb[1], palette[0].rgb[2]));
+    }
+}
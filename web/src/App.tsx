@@ -9,7 +9,7 @@ import { uploadImage, downloadImageAsImageData } from './lib/storage'
 import { saveCachedState, loadCachedState } from './lib/cache'
 import { analyzeImage, type ImageMetadata } from './lib/imageMetadata'
 import logger from './utils/logger'
-import { type FilterState, initialFilterState } from './types/filters'
+import { type FilterState, initialFilterState, withFilterDefaults } from './types/filters'
 
 function App() {
   const { wasmModule, isLoading, error } = useWasm()
@@ -68,8 +68,8 @@ function App() {
             timestamp: cached.timestamp,
           })
 
-          // Restore filters immediately
-          setFilters(cached.filters)
+          // Restore filters immediately; rooms cached by older versions lack newer settings
+          setFilters(withFilterDefaults(cached.filters))
 
           // Download and restore image
           const imageData = await downloadImageAsImageData(cached.imageUrl)
@@ -264,6 +264,12 @@ function App() {
       let currentHeight = image.height
 
       // Apply filters in pipeline order
+      if (filters.whiteBalance.temperature !== 0 || filters.whiteBalance.tint !== 0) {
+        logger.debug('Applying white balance', { action: 'FILTER_PIPELINE', whiteBalance: filters.whiteBalance })
+        const { temperature, tint } = filters.whiteBalance
+        current = new Uint8Array(wasmModule.apply_white_balance(current, currentWidth, currentHeight, temperature, tint))
+      }
+
       if (filters.grayscale) {
         logger.debug('Applying grayscale filter', { action: 'FILTER_PIPELINE' })
         current = new Uint8Array(wasmModule.apply_grayscale(current, currentWidth, currentHeight))
@@ -315,6 +321,19 @@ function App() {
         currentHeight = height
       }
 
+      // Effects (after crop, so they frame the final picture)
+      if (filters.vignette.amount !== 0) {
+        logger.debug('Applying vignette', { action: 'FILTER_PIPELINE', vignette: filters.vignette })
+        const { amount, midpoint, roundness, feather, highlights } = filters.vignette
+        current = new Uint8Array(wasmModule.apply_vignette(current, currentWidth, currentHeight, amount, midpoint, roundness, feather, highlights))
+      }
+
+      if (filters.grain.amount > 0) {
+        logger.debug('Applying grain', { action: 'FILTER_PIPELINE', grain: filters.grain })
+        const { amount, size, roughness, seed } = filters.grain
+        current = new Uint8Array(wasmModule.apply_grain(current, currentWidth, currentHeight, amount, size, roughness, seed))
+      }
+
       const elapsed = performance.now() - start
       logger.info('Filter pipeline completed', {
         action: 'FILTER_PIPELINE_COMPLETE',
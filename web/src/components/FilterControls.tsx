@@ -22,7 +22,7 @@ export function FilterControls({ filters, onFiltersChange, disabled, imageData,
   }, [])
 
   // Debounced filter updates (150ms)
-  const updateFilter = (key: keyof FilterState, value: number | boolean) => {
+  const updateFilter = <K extends keyof FilterState>(key: K, value: FilterState[K]) => {
     if (debounceRef.current) {
       clearTimeout(debounceRef.current)
     }
@@ -36,7 +36,8 @@ export function FilterControls({ filters, onFiltersChange, disabled, imageData,
     onFiltersChange(initialFilterState)
   }
 
-  const hasActiveFilters = filters.grayscale || filters.blur > 0 || filters.brightness !== 0 || filters.flipHorizontal || filters.flipVertical || filters.rotation !== 0 || filters.cropArea !== null
+  const hasWhiteBalance = filters.whiteBalance.temperature !== 0 || filters.whiteBalance.tint !== 0
+  const hasActiveFilters = hasWhiteBalance || filters.grayscale || filters.blur > 0 || filters.brightness !== 0 || filters.flipHorizontal || filters.flipVertical || filters.rotation !== 0 || filters.cropArea !== null || filters.vignette.amount !== 0 || filters.grain.amount > 0
 
   // Handle rotation: increment by 90° (0 -> 90 -> 180 -> 270 -> 0)
   const handleRotate = () => {
@@ -89,6 +90,47 @@ export function FilterControls({ filters, onFiltersChange, disabled, imageData,
           グレースケール {filters.grayscale && '✓'}
         </button>
 
+        {/* White Balance */}
+        <div>
+          <label className="block text-sm text-gray-300 mb-2">
+            色温度: {filters.whiteBalance.temperature > 0 ? '+' : ''}{filters.whiteBalance.temperature.toFixed(2)}
+          </label>
+          <input
+            type="range"
+            min="-1"
+            max="1"
+            step="0.05"
+            value={filters.whiteBalance.temperature}
+            onChange={(e) => updateFilter('whiteBalance', { ...filters.whiteBalance, temperature: Number(e.target.value) })}
+            className="w-full accent-accent"
+            disabled={disabled}
+          />
+          <div className="flex justify-between text-xs text-gray-500 mt-1">
+            <span>寒色</span>
+            <span>暖色</span>
+          </div>
+        </div>
+
+        <div>
+          <label className="block text-sm text-gray-300 mb-2">
+            色かぶり補正: {filters.whiteBalance.tint > 0 ? '+' : ''}{filters.whiteBalance.tint.toFixed(2)}
+          </label>
+          <input
+            type="range"
+            min="-1"
+            max="1"
+            step="0.05"
+            value={filters.whiteBalance.tint}
+            onChange={(e) => updateFilter('whiteBalance', { ...filters.whiteBalance, tint: Number(e.target.value) })}
+            className="w-full accent-accent"
+            disabled={disabled}
+          />
+          <div className="flex justify-between text-xs text-gray-500 mt-1">
+            <span>グリーン</span>
+            <span>マゼンタ</span>
+          </div>
+        </div>
+
         {/* Blur with Real-time Preview */}
         <div>
           <label className="block text-sm text-gray-300 mb-2">
@@ -130,6 +172,40 @@ export function FilterControls({ filters, onFiltersChange, disabled, imageData,
           </div>
         </div>
 
+        {/* Vignette */}
+        <div>
+          <label className="block text-sm text-gray-300 mb-2">
+            周辺光量: {filters.vignette.amount > 0 ? '+' : ''}{filters.vignette.amount.toFixed(2)}
+          </label>
+          <input
+            type="range"
+            min="-1"
+            max="1"
+            step="0.05"
+            value={filters.vignette.amount}
+            onChange={(e) => updateFilter('vignette', { ...filters.vignette, amount: Number(e.target.value) })}
+            className="w-full accent-accent"
+            disabled={disabled}
+          />
+        </div>
+
+        {/* Film Grain */}
+        <div>
+          <label className="block text-sm text-gray-300 mb-2">
+            粒子: {filters.grain.amount.toFixed(2)}
+          </label>
+          <input
+            type="range"
+            min="0"
+            max="1"
+            step="0.05"
+            value={filters.grain.amount}
+            onChange={(e) => updateFilter('grain', { ...filters.grain, amount: Number(e.target.value) })}
+            className="w-full accent-accent"
+            disabled={disabled}
+          />
+        </div>
+
         {/* Transform Tools (Compact Icon Buttons) */}
         <div>
           <label className="block text-sm text-gray-300 mb-2">変形ツール</label>
@@ -183,6 +259,11 @@ export function FilterControls({ filters, onFiltersChange, disabled, imageData,
           <div className="pt-4 border-t border-[#333333]">
             <div className="text-xs text-gray-400 mb-2">適用中のフィルター:</div>
             <div className="flex flex-wrap gap-2">
+              {hasWhiteBalance && (
+                <span className="px-2 py-1 bg-accent/20 text-accent text-xs rounded">
+                  ホワイトバランス ({filters.whiteBalance.temperature.toFixed(2)}, {filters.whiteBalance.tint.toFixed(2)})
+                </span>
+              )}
               {filters.grayscale && (
                 <span className="px-2 py-1 bg-accent/20 text-accent text-xs rounded">
                   グレースケール
@@ -213,6 +294,16 @@ export function FilterControls({ filters, onFiltersChange, disabled, imageData,
                   🔄 {filters.rotation}°
                 </span>
               )}
+              {filters.vignette.amount !== 0 && (
+                <span className="px-2 py-1 bg-accent/20 text-accent text-xs rounded">
+                  周辺光量 ({filters.vignette.amount > 0 ? '+' : ''}{filters.vignette.amount.toFixed(2)})
+                </span>
+              )}
+              {filters.grain.amount > 0 && (
+                <span className="px-2 py-1 bg-accent/20 text-accent text-xs rounded">
+                  粒子 ({filters.grain.amount.toFixed(2)})
+                </span>
+              )}
               {filters.cropArea && (
                 <span className="px-2 py-1 bg-accent/20 text-accent text-xs rounded">
                   ✂️ トリミング ({filters.cropArea.width}x{filters.cropArea.height})
@@ -7,7 +7,7 @@ import type {
   ImageUploadPayload,
   FilterChangePayload,
 } from '../types/collaboration'
-import type { FilterState } from '../types/filters'
+import { type FilterState, withFilterDefaults } from '../types/filters'
 
 const COLORS = [
   '#FF6B6B', '#4ECDC4', '#45B7D1', '#FFA07A', '#98D8C8',
@@ -208,7 +208,8 @@ export function useCollaboration(): UseCollaborationResult {
 
         if (data.userId === userId) return // Don't process own changes
 
-        setSharedFilters(data.filters)
+        // Peers on an older version send states without the newer settings
+        setSharedFilters(withFilterDefaults(data.filters))
       })
       .subscribe((status) => {
         setIsConnected(status === 'SUBSCRIBED')
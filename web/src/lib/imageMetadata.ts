@@ -5,38 +5,104 @@
 
 import logger from '../utils/logger'
 
+export interface ChannelStats {
+  mean: number
+  std_dev: number
+  /** 256 bins, index = channel value */
+  histogram: number[]
+}
+
+export interface PaletteColor {
+  hex: string
+  rgb: [number, number, number]
+  /** Share of non-transparent pixels (0.0 - 1.0) */
+  ratio: number
+}
+
+export interface ImageStats {
+  pixel_count: number
+  red: ChannelStats
+  green: ChannelStats
+  blue: ChannelStats
+  luminance: ChannelStats
+  /** Share of non-transparent pixels with luminance 255 (0.0 - 1.0) */
+  clipped_highlights_fraction: number
+  /** Share of non-transparent pixels with luminance 0 (0.0 - 1.0) */
+  clipped_shadows_fraction: number
+  /** Share of all pixels with alpha 0 (0.0 - 1.0) */
+  transparent_fraction: number
+  dominant_colors: PaletteColor[]
+}
+
+/** 64-bit perceptual hashes as 16-digit hex strings */
+export interface PerceptualHashes {
+  ahash: string
+  dhash: string
+  phash: string
+}
+
+export interface DuplicateCheck {
+  compared_to: string
+  phash_distance: number
+  near_duplicate: boolean
+}
+
 export interface ImageMetadata {
   size_bytes: number
   format: string
   width: number | null
   height: number | null
+  /** Present whenever the image could be decoded */
+  hashes?: PerceptualHashes
+  /** Only present when requested with `{ stats: true }` */
+  stats?: ImageStats
+  /** Only present when requested with `{ compare: phash }` */
+  duplicate?: DuplicateCheck
   message: string
 }
 
+export interface AnalyzeOptions {
+  /** Request pixel statistics (`?stats=true`); scans every pixel server-side */
+  stats?: boolean
+  /** pHash of another upload to compare against (`?compare=<phash>`) */
+  compare?: string
+}
+
+function buildQuery(options: AnalyzeOptions): string {
+  const params = new URLSearchParams()
+  if (options.stats) params.set('stats', 'true')
+  if (options.compare) params.set('compare', options.compare)
+  const query = params.toString()
+  return query ? `?${query}` : ''
+}
+
 const WASMCLOUD_API_URL = import.meta.env.VITE_WASMCLOUD_API_URL || 'http://127.0.0.1:8000'
 
 /**
  * Common fetch logic for wasmCloud API calls
  * @param body - Request body (ArrayBuffer or Blob)
  * @param source - Source of the request for logging
+ * @param options - Optional analysis flags
  * @returns Image metadata
  */
 async function fetchImageMetadata(
   body: ArrayBuffer | Blob,
-  source: 'file' | 'imagedata'
+  source: 'file' | 'imagedata',
+  options: AnalyzeOptions = {}
 ): Promise<ImageMetadata> {
   const startTime = performance.now()
   const bodySize = body instanceof Blob ? body.size : body.byteLength
+  const endpoint = `${WASMCLOUD_API_URL}/analyze${buildQuery(options)}`
 
   logger.info('Sending image to wasmCloud for analysis', {
     action: 'WASMCLOUD_API_REQUEST',
     source,
     bodySize,
-    endpoint: `${WASMCLOUD_API_URL}/analyze`,
+    endpoint,
   })
 
   try {
-    const response = await fetch(`${WASMCLOUD_API_URL}/analyze`, {
+    const response = await fetch(endpoint, {
       method: 'POST',
       headers: {
         'Content-Type': 'application/octet-stream',
@@ -89,9 +155,10 @@ async function fetchImageMetadata(
 /**
  * Analyze an image using the wasmCloud metadata service
  * @param file - The image file to analyze
+ * @param options - Optional analysis flags
  * @returns Image metadata including dimensions, format, and size
  */
-export async function analyzeImage(file: File): Promise<ImageMetadata> {
+export async function analyzeImage(file: File, options: AnalyzeOptions = {}): Promise<ImageMetadata> {
   logger.debug('Starting image analysis from File', {
     action: 'ANALYZE_IMAGE_START',
     fileName: file.name,
@@ -101,7 +168,7 @@ export async function analyzeImage(file: File): Promise<ImageMetadata> {
 
   try {
     const arrayBuffer = await file.arrayBuffer()
-    return await fetchImageMetadata(arrayBuffer, 'file')
+    return await fetchImageMetadata(arrayBuffer, 'file', options)
   } catch (error) {
     logger.error('Failed to analyze image from File', {
       action: 'ANALYZE_IMAGE_ERROR',
@@ -116,9 +183,13 @@ export async function analyzeImage(file: File): Promise<ImageMetadata> {
  * Analyze an image from ImageData
  * Converts ImageData to PNG blob and sends to wasmCloud service
  * @param imageData - The ImageData to analyze
+ * @param options - Optional analysis flags
  * @returns Image metadata
  */
-export async function analyzeImageData(imageData: ImageData): Promise<ImageMetadata> {
+export async function analyzeImageData(
+  imageData: ImageData,
+  options: AnalyzeOptions = {}
+): Promise<ImageMetadata> {
   logger.debug('Starting image analysis from ImageData', {
     action: 'ANALYZE_IMAGEDATA_START',
     width: imageData.width,
@@ -160,7 +231,7 @@ export async function analyzeImageData(imageData: ImageData): Promise<ImageMetad
       blobSize: blob.size,
     })
 
-    return await fetchImageMetadata(blob, 'imagedata')
+    return await fetchImageMetadata(blob, 'imagedata', options)
   } catch (error) {
     logger.error('Failed to analyze ImageData', {
       action: 'ANALYZE_IMAGEDATA_ERROR',
@@ -0,0 +1,50 @@
+import { describe, expect, it } from 'vitest'
+import { type FilterState, initialFilterState, withFilterDefaults } from './filters'
+
+// A room cached (or a peer running) before white balance, vignette and grain existed
+const preEffectsState = {
+  grayscale: true,
+  blur: 3,
+  brightness: 20,
+  flipHorizontal: false,
+  flipVertical: true,
+  rotation: 90,
+  cropArea: { x: 10, y: 20, width: 300, height: 200 },
+} as unknown as FilterState
+
+describe('withFilterDefaults', () => {
+  it('restores a state cached before white balance, vignette and grain', () => {
+    const restored = withFilterDefaults(preEffectsState)
+
+    expect(restored.whiteBalance).toEqual(initialFilterState.whiteBalance)
+    expect(restored.whiteBalance.temperature).toBe(0)
+
+    expect(restored.vignette).toEqual(initialFilterState.vignette)
+    expect(restored.grain).toEqual(initialFilterState.grain)
+    expect(restored.vignette.amount).toBe(0)
+    expect(restored.grain.amount).toBe(0)
+
+    // Everything the old state did have is kept
+    expect(restored.grayscale).toBe(true)
+    expect(restored.rotation).toBe(90)
+    expect(restored.cropArea).toEqual(preEffectsState.cropArea)
+  })
+
+  it('merges nested settings field by field', () => {
+    const partial = { ...initialFilterState, grain: { amount: 0.4 } } as unknown as FilterState
+    const restored = withFilterDefaults(partial)
+
+    expect(restored.grain).toEqual({ ...initialFilterState.grain, amount: 0.4 })
+  })
+
+  it('keeps a complete state unchanged', () => {
+    const state: FilterState = {
+      ...initialFilterState,
+      whiteBalance: { temperature: 0.3, tint: -0.1 },
+      vignette: { amount: -0.5, midpoint: 0.3, roundness: 0.2, feather: 0.8, highlights: 0.1 },
+      grain: { amount: 0.2, size: 2, roughness: 0.7, seed: 42 },
+    }
+
+    expect(withFilterDefaults(state)).toEqual(state)
+  })
+})
@@ -14,7 +14,38 @@ export interface CropArea {
   height: number
 }
 
+/**
+ * White balance in linear light; both 0 = off (see `apply_white_balance`).
+ * `auto_white_balance` and `white_balance_from_point` return values for it.
+ */
+export interface WhiteBalanceSettings {
+  temperature: number
+  tint: number
+}
+
+/**
+ * Vignette of the final frame; amount 0 = off (see `apply_vignette`)
+ */
+export interface VignetteSettings {
+  amount: number
+  midpoint: number
+  roundness: number
+  feather: number
+  highlights: number
+}
+
+/**
+ * Film grain; amount 0 = off. The seed makes every collaborator see the same grain.
+ */
+export interface GrainSettings {
+  amount: number
+  size: number
+  roughness: number
+  seed: number
+}
+
 export interface FilterState {
+  whiteBalance: WhiteBalanceSettings
   grayscale: boolean
   blur: number
   brightness: number
@@ -22,12 +53,15 @@ export interface FilterState {
   flipVertical: boolean
   rotation: RotationAngle
   cropArea: CropArea | null
+  vignette: VignetteSettings
+  grain: GrainSettings
 }
 
 /**
  * Initial filter state with all filters disabled
  */
 export const initialFilterState: FilterState = {
+  whiteBalance: { temperature: 0, tint: 0 },
   grayscale: false,
   blur: 0,
   brightness: 0,
@@ -35,4 +69,21 @@ export const initialFilterState: FilterState = {
   flipVertical: false,
   rotation: 0,
   cropArea: null,
+  vignette: { amount: 0, midpoint: 0.5, roundness: 0, feather: 0.5, highlights: 0 },
+  grain: { amount: 0, size: 1, roughness: 0.5, seed: 0 },
+}
+
+/**
+ * Fill in settings missing from a filter state saved or broadcast by an older
+ * client (cached rooms, peers that have not reloaded yet), so nested settings
+ * are always present. Nested objects are merged field by field.
+ */
+export function withFilterDefaults(incoming: Partial<FilterState>): FilterState {
+  return {
+    ...initialFilterState,
+    ...incoming,
+    whiteBalance: { ...initialFilterState.whiteBalance, ...incoming.whiteBalance },
+    vignette: { ...initialFilterState.vignette, ...incoming.vignette },
+    grain: { ...initialFilterState.grain, ...incoming.grain },
+  }
 }